@@ -0,0 +1,523 @@
+use bumpalo::Bump;
+use hdx_ast::css::{ImportLayer, ImportRule, ImportSupportsCondition, OptionalLayerRuleBlock, Rule, StyleSheet};
+use hdx_lexer::{SourceOffset, Span};
+use hdx_parser::{CursorSink, CursorStream, Features, Parser, SourceMap, SourceMapWriter, ToCursors};
+use miette::{self, Diagnostic};
+use std::{
+	fmt,
+	ops::Range,
+	path::{Path, PathBuf},
+};
+use thiserror::{self, Error};
+
+use crate::{is_error, render, source_map::read_source_map};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Could not find {0} to import.")]
+#[diagnostic(help("Local imports are resolved relative to the file they're in."), code(hdx::ImportNotFound))]
+pub struct ImportNotFound(pub String, #[label("This import")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("This import can't be bundled, as it leads back to itself: {0}")]
+#[diagnostic(help("Remove one of the imports in the cycle."), code(hdx::ImportCycle))]
+pub struct ImportCycle(pub String, #[label("This import")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("This import can't be moved to the top of the bundle, as its conditions can't be combined with {0}.")]
+#[diagnostic(
+	help("Imports of remote URLs must come first, so they're moved above the bundled rules. Try importing this URL from the entry file instead."),
+	code(hdx::UnhoistableImport)
+)]
+pub struct UnhoistableImport(pub String, #[label("This import")] pub Span);
+
+/// A file which was read while bundling.
+pub struct BundledFile {
+	pub path: PathBuf,
+	pub source_text: String,
+	/// The Source Map this file was compiled with, if it has one. Maps which couldn't be read are rendered as a
+	/// warning.
+	pub source_map: Option<Result<SourceMap, String>>,
+}
+
+/// A part of the bundled text which was copied, unchanged, from one of the [files][Bundle::files].
+pub struct Chunk {
+	pub start: u32,
+	pub file: usize,
+	pub range: Range<u32>,
+}
+
+/// One stylesheet, made by replacing each local `@import` with the contents of the file it imports. Only the
+/// `@import`s at the start of a file (after any `@charset` and `@layer` statements) are bundled, and a file which is
+/// imported more than once is only inlined the first time. Imports with
+/// layer(), supports() or media conditions are wrapped in the matching `@layer`, `@supports` & `@media` rules, so
+/// the inlined rules apply in the same way. Imports of remote URLs are moved to the top of the stylesheet (after any
+/// `@charset`), as an `@import` after other rules is ignored, taking the conditions of the imports they came through.
+pub struct Bundle {
+	pub text: String,
+	pub files: Vec<BundledFile>,
+	pub chunks: Vec<Chunk>,
+}
+
+// The rules of a file which the bundle changes, copied out so the file's allocator can be dropped before recursing.
+enum Edit {
+	// A local @import, replaced with the contents of the file it imports.
+	Inline { range: Range<u32>, url: String, conditions: Conditions },
+	// An @import of a remote URL, moved to the top of the bundle.
+	Hoist { range: Range<u32>, url: String, conditions: Conditions },
+	// Only the first @charset in a stylesheet counts, so those of imported files are dropped. The entry's @charset
+	// is kept, as an empty range at its end, which is where hoisted imports go.
+	Drop { range: Range<u32> },
+}
+
+impl Edit {
+	fn range(&self) -> Range<u32> {
+		match self {
+			Self::Inline { range, .. } | Self::Hoist { range, .. } | Self::Drop { range } => range.clone(),
+		}
+	}
+}
+
+// The layer(), supports() & media conditions of an @import.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Conditions {
+	// The media query list.
+	media: Option<String>,
+	// A <supports-condition>; declarations are wrapped in parens.
+	supports: Option<String>,
+	// Some(None) for an anonymous layer.
+	layer: Option<Option<String>>,
+}
+
+impl Bundle {
+	pub fn read(entry: &Path) -> Result<Self, String> {
+		let mut bundle = Self { text: String::new(), files: vec![], chunks: vec![] };
+		let mut hoisted = vec![];
+		let charset_end = bundle.include(entry, &mut vec![], &mut vec![], &Conditions::default(), &mut hoisted)?;
+		bundle.insert(charset_end, &hoisted);
+		Ok(bundle)
+	}
+
+	/// Adds each file & chunk to `writer`, so that Cursors parsed from [text][Bundle::text] map back to the files
+	/// they came from. Source indexes match the index of each file.
+	pub fn add_sources<'a, F: fmt::Write>(&'a self, writer: &mut SourceMapWriter<'a, F>) {
+		for file in &self.files {
+			writer.add_source(&file.path.to_string_lossy(), &file.source_text);
+		}
+		for chunk in &self.chunks {
+			writer.add_range(chunk.start, chunk.file, chunk.range.clone());
+		}
+	}
+
+	// Inlines the file into the bundle, adding the remote imports it has (or imports) to `hoisted`. Each file is only
+	// inlined once, the first time it's imported, which `seen` keeps track of. Returns the end of the file's
+	// @charset, or 0 if it has none.
+	fn include(
+		&mut self,
+		path: &Path,
+		stack: &mut Vec<PathBuf>,
+		seen: &mut Vec<PathBuf>,
+		outer: &Conditions,
+		hoisted: &mut Vec<String>,
+	) -> Result<u32, String> {
+		let file_name = path.to_string_lossy().into_owned();
+		let source_text =
+			std::fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}\n", file_name, e))?;
+		let dir = path.parent().unwrap_or(Path::new(""));
+		let mut charset_end = 0;
+		let (edits, source_map) = {
+			let allocator = Bump::default();
+			let result = Parser::new(&allocator, &source_text, Features::default()).parse_entirely::<StyleSheet>();
+			// Warnings are reported when the bundle is parsed.
+			if result.output.is_none() || result.errors.iter().any(is_error) {
+				return Err(render(&file_name, &source_text, result.errors));
+			}
+			let mut edits = vec![];
+			for rule in &result.output.as_ref().unwrap().rules {
+				// An @import can only follow @charset, @layer statements and other @imports; any after that are
+				// ignored, so they're left as they are.
+				match rule {
+					Rule::ImportRule(import) => {
+						let url = import.url.cursor().parse_str(&source_text, &allocator);
+						let conditions = conditions(import, &source_text, &allocator);
+						if is_remote(url) {
+							let url = slice(&source_text, range(&import.url, &allocator)).to_owned();
+							edits.push(Edit::Hoist { range: range(import, &allocator), url, conditions });
+						} else {
+							let url = url.to_owned();
+							edits.push(Edit::Inline { range: range(import, &allocator), url, conditions });
+						}
+					}
+					Rule::CharsetRule(charset) if stack.is_empty() => {
+						charset_end = range(charset, &allocator).end;
+						edits.push(Edit::Drop { range: charset_end..charset_end });
+					}
+					Rule::CharsetRule(charset) => edits.push(Edit::Drop { range: range(charset, &allocator) }),
+					Rule::LayerRule(layer) if matches!(layer.block, OptionalLayerRuleBlock::None(_)) => {}
+					_ => break,
+				}
+			}
+			let source_map =
+				read_source_map(&result, dir).map(|map| map.map_err(|e| render(&file_name, &source_text, vec![e])));
+			(edits, source_map)
+		};
+		let file = self.files.len();
+		self.files.push(BundledFile { path: path.to_path_buf(), source_text, source_map });
+		let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+		seen.push(canonical.clone());
+		stack.push(canonical);
+		let mut pos = 0;
+		for edit in edits {
+			let range = edit.range();
+			self.copy(file, pos..range.start);
+			pos = range.end;
+			let span = Span::new(SourceOffset(range.start), SourceOffset(range.end));
+			match edit {
+				Edit::Drop { .. } => {}
+				Edit::Hoist { url, conditions, .. } => match outer.combine(&conditions) {
+					Ok(conditions) => {
+						let import = format!("@import {}{};", url, conditions);
+						if !hoisted.contains(&import) {
+							hoisted.push(import);
+						}
+					}
+					Err(reason) => {
+						let error = UnhoistableImport(reason.to_owned(), span);
+						return Err(render(&file_name, &self.files[file].source_text, vec![error.into()]));
+					}
+				},
+				Edit::Inline { url, conditions, .. } => {
+					let Ok(imported) = std::fs::canonicalize(dir.join(&url)) else {
+						let error = ImportNotFound(url, span);
+						return Err(render(&file_name, &self.files[file].source_text, vec![error.into()]));
+					};
+					if let Some(i) = stack.iter().position(|path| path == &imported) {
+						let cycle =
+							stack[i..].iter().chain([&imported]).map(|path| path.to_string_lossy()).collect::<Vec<_>>();
+						let error = ImportCycle(cycle.join(" -> "), span);
+						return Err(render(&file_name, &self.files[file].source_text, vec![error.into()]));
+					}
+					if seen.contains(&imported) {
+						continue;
+					}
+					let wrappers = conditions.wrappers();
+					for wrapper in &wrappers {
+						self.text.push_str(wrapper);
+					}
+					// Conditions which can't be combined only matter if there's a remote import to hoist, so the
+					// error is raised there.
+					let inner = outer.combine(&conditions).unwrap_or(conditions);
+					self.include(&dir.join(&url), stack, seen, &inner, hoisted)?;
+					for _ in &wrappers {
+						self.text.push('}');
+					}
+				}
+			}
+		}
+		self.copy(file, pos..self.files[file].source_text.len() as u32);
+		stack.pop();
+		Ok(charset_end)
+	}
+
+	// Inserts the hoisted imports into the text at `at`, moving the chunks after them along.
+	fn insert(&mut self, at: u32, hoisted: &[String]) {
+		if hoisted.is_empty() {
+			return;
+		}
+		let text = if at == 0 { format!("{}\n", hoisted.join("\n")) } else { format!("\n{}", hoisted.join("\n")) };
+		self.text.insert_str(at as usize, &text);
+		for chunk in self.chunks.iter_mut().filter(|chunk| chunk.start >= at) {
+			chunk.start += text.len() as u32;
+		}
+	}
+
+	fn copy(&mut self, file: usize, range: Range<u32>) {
+		if range.is_empty() {
+			return;
+		}
+		let start = self.text.len() as u32;
+		self.text.push_str(&self.files[file].source_text[range.start as usize..range.end as usize]);
+		self.chunks.push(Chunk { start, file, range });
+	}
+}
+
+fn is_remote(url: &str) -> bool {
+	url.starts_with("//") || url.contains(':')
+}
+
+// The source text covered by a node, from the start of its first Cursor to the end of its last.
+fn range(node: &impl ToCursors, allocator: &Bump) -> Range<u32> {
+	let mut stream = CursorStream::new(allocator);
+	ToCursors::to_cursors(node, &mut stream);
+	// Cursors which were added by the parser (rather than lexed) have no place in the source text.
+	let mut cursors = stream.iter_cursors().filter(|c| c.offset() != SourceOffset::DUMMY);
+	let Some(first) = cursors.next() else { return 0..0 };
+	let last = cursors.last().unwrap_or(first);
+	first.offset().0..last.end_offset().0
+}
+
+fn slice(source_text: &str, range: Range<u32>) -> &str {
+	source_text[range.start as usize..range.end as usize].trim()
+}
+
+fn conditions(import: &ImportRule, source_text: &str, allocator: &Bump) -> Conditions {
+	let supports = import.supports.as_ref().map(|supports| {
+		let condition = slice(source_text, range(&supports.condition, allocator));
+		match supports.condition {
+			ImportSupportsCondition::Condition(_) => condition.to_owned(),
+			ImportSupportsCondition::Declaration(_) => format!("({})", condition),
+		}
+	});
+	Conditions {
+		media: import.media.as_ref().map(|media| slice(source_text, range(media, allocator)).to_owned()),
+		supports,
+		layer: import.layer.as_ref().map(|layer| match layer {
+			ImportLayer::Anonymous(_) => None,
+			ImportLayer::Named(_, name, _) => Some(slice(source_text, range(name, allocator)).to_owned()),
+		}),
+	}
+}
+
+impl Conditions {
+	// The opening of each rule an import's contents are wrapped in, outermost first. The import's conditions decide
+	// whether its layer applies at all, so the layer is innermost.
+	fn wrappers(&self) -> Vec<String> {
+		let mut wrappers = vec![];
+		if let Some(media) = &self.media {
+			wrappers.push(format!("@media {}{{", media));
+		}
+		if let Some(supports) = &self.supports {
+			wrappers.push(format!("@supports {}{{", supports));
+		}
+		match &self.layer {
+			Some(None) => wrappers.push("@layer{".to_owned()),
+			Some(Some(name)) => wrappers.push(format!("@layer {}{{", name)),
+			None => {}
+		}
+		wrappers
+	}
+
+	// The conditions of an import made within a file which was itself imported with these conditions.
+	fn combine(&self, inner: &Conditions) -> Result<Conditions, &'static str> {
+		let media = match (&self.media, &inner.media) {
+			(Some(outer), Some(inner)) => {
+				Some(combine_media(outer, inner).ok_or("the media queries it's imported with")?)
+			}
+			(outer, inner) => outer.clone().or(inner.clone()),
+		};
+		let supports = match (&self.supports, &inner.supports) {
+			(Some(outer), Some(inner)) => Some(format!("{} and {}", in_parens(outer), in_parens(inner))),
+			(outer, inner) => outer.clone().or(inner.clone()),
+		};
+		let layer = match (&self.layer, &inner.layer) {
+			(Some(Some(outer)), Some(Some(inner))) => Some(Some(format!("{}.{}", outer, inner))),
+			// Anonymous layers can't be named, so nothing can be nested within one, and it can't be nested in another.
+			(Some(None), Some(_)) | (Some(_), Some(None)) => return Err("the anonymous layer it's imported into"),
+			(outer, inner) => outer.clone().or(inner.clone()),
+		};
+		Ok(Conditions { media, supports, layer })
+	}
+}
+
+// Writes the conditions as they follow the URL of an @import.
+impl fmt::Display for Conditions {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.layer {
+			Some(None) => write!(f, " layer")?,
+			Some(Some(name)) => write!(f, " layer({})", name)?,
+			None => {}
+		}
+		if let Some(supports) = &self.supports {
+			// A single parenthesized condition or declaration can be written without its parens.
+			let supports = if in_parens(supports) == *supports { &supports[1..supports.len() - 1] } else { supports };
+			write!(f, " supports({})", supports)?;
+		}
+		if let Some(media) = &self.media {
+			write!(f, " {}", media)?;
+		}
+		Ok(())
+	}
+}
+
+// Splits the text at each top level occurrence of `separator`, ignoring any within parens.
+fn split_top_level(str: &str, separator: char) -> Vec<&str> {
+	let mut parts = vec![];
+	let mut depth = 0;
+	let mut start = 0;
+	for (i, char) in str.char_indices() {
+		match char {
+			'(' => depth += 1,
+			')' => depth -= 1,
+			char if char == separator && depth == 0 => {
+				parts.push(str[start..i].trim());
+				start = i + char.len_utf8();
+			}
+			_ => {}
+		}
+	}
+	parts.push(str[start..].trim());
+	parts
+}
+
+// Wraps a condition in parens, unless it's already a single parenthesized condition, so that it can be combined with
+// another using `and`.
+fn in_parens(condition: &str) -> String {
+	let words = split_top_level(condition, ' ');
+	if words.len() == 1 && condition.starts_with('(') {
+		condition.to_owned()
+	} else {
+		format!("({})", condition)
+	}
+}
+
+// https://drafts.csswg.org/mediaqueries/#media
+// Combines two media query lists into one which matches where both do: each query of one with each query of the
+// other. Queries with a `not` or `only` modifier can't be combined, as `not` would need to negate only part of the
+// result.
+fn combine_media(outer: &str, inner: &str) -> Option<String> {
+	let mut queries = vec![];
+	for outer in split_top_level(outer, ',') {
+		for inner in split_top_level(inner, ',') {
+			let (outer_type, outer_condition) = media_query(outer)?;
+			let (inner_type, inner_condition) = media_query(inner)?;
+			let media_type = match (outer_type, inner_type) {
+				(Some(outer), Some(inner)) if outer.eq_ignore_ascii_case("all") => Some(inner),
+				(Some(outer), Some(inner))
+					if inner.eq_ignore_ascii_case("all") || outer.eq_ignore_ascii_case(inner) =>
+				{
+					Some(outer)
+				}
+				// Queries for two different media types never match, so there's nothing to add.
+				(Some(_), Some(_)) => continue,
+				(outer, inner) => outer.or(inner),
+			};
+			let condition = match (outer_condition, inner_condition) {
+				(Some(outer), Some(inner)) => Some(format!("{} and {}", in_parens(outer), in_parens(inner))),
+				(outer, inner) => outer.or(inner).map(str::to_owned),
+			};
+			queries.push(match (media_type, condition) {
+				(Some(media_type), Some(condition)) => format!("{} and {}", media_type, in_parens(&condition)),
+				(Some(media_type), None) => media_type.to_owned(),
+				(None, Some(condition)) => condition,
+				(None, None) => "all".to_owned(),
+			});
+		}
+	}
+	Some(if queries.is_empty() { "not all".to_owned() } else { queries.join(", ") })
+}
+
+// Splits a media query into its media type & condition, or None if it has a `not` or `only` modifier.
+fn media_query(query: &str) -> Option<(Option<&str>, Option<&str>)> {
+	if query.starts_with('(') || query.get(..4).is_some_and(|not| not.eq_ignore_ascii_case("not(")) {
+		return Some((None, Some(query)));
+	}
+	let (first, rest) = query.split_once(char::is_whitespace).unwrap_or((query, ""));
+	let rest = rest.trim_start();
+	if first.eq_ignore_ascii_case("not") {
+		return rest.starts_with('(').then_some((None, Some(query)));
+	}
+	if first.eq_ignore_ascii_case("only") {
+		return None;
+	}
+	if rest.is_empty() {
+		return Some((Some(first), None));
+	}
+	let (and, condition) = rest.split_once(char::is_whitespace)?;
+	and.eq_ignore_ascii_case("and").then_some((Some(first), Some(condition.trim())))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("hdx-bundle-{}-{}", name, std::process::id()));
+		for (file, source_text) in files {
+			let path = dir.join(file);
+			std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+			std::fs::write(path, source_text).unwrap();
+		}
+		dir
+	}
+
+	#[test]
+	fn test_bundle() {
+		let dir = write_files(
+			"wrap",
+			&[
+				("a.css", "@charset \"utf-8\";\n@import \"b.css\" layer(base) supports(display: grid) screen;\na{}"),
+				("b.css", "@charset \"utf-8\";\n@import url(c/c.css);\n@import url(https://example.com/x.css);\nb{}"),
+				("c/c.css", "c{}"),
+			],
+		);
+		let bundle = Bundle::read(&dir.join("a.css")).unwrap();
+		assert_eq!(
+			bundle.text,
+			"@charset \"utf-8\";\n@import url(https://example.com/x.css) layer(base) supports(display: grid) screen;\n@media screen{@supports (display: grid){@layer base{\nc{}\n\nb{}}}}\na{}"
+		);
+		assert_eq!(bundle.files.len(), 3);
+		for chunk in &bundle.chunks {
+			let text = &bundle.text[chunk.start as usize..][..chunk.range.len()];
+			assert_eq!(
+				text,
+				&bundle.files[chunk.file].source_text[chunk.range.start as usize..chunk.range.end as usize]
+			);
+		}
+	}
+
+	#[test]
+	fn test_hoist() {
+		let dir = write_files(
+			"hoist",
+			&[
+				("a.css", "@import \"b.css\" layer(x) print;a{}"),
+				("b.css", "@import url(//cdn/y.css) layer(y) print;\n@import \"c.css\" supports(display: flex);b{}"),
+				("c.css", "@import \"https://example.com/z.css\" supports(display: grid) screen;"),
+			],
+		);
+		let bundle = Bundle::read(&dir.join("a.css")).unwrap();
+		assert_eq!(
+			bundle.text,
+			"@import url(//cdn/y.css) layer(x.y) print;\n@import \"https://example.com/z.css\" layer(x) supports((display: flex) and (display: grid)) not all;\n@media print{@layer x{\n@supports (display: flex){}b{}}}a{}"
+		);
+		let dir = write_files(
+			"unhoistable",
+			&[("a.css", "@import \"b.css\" layer;"), ("b.css", "@import url(//cdn/y.css) layer(y);")],
+		);
+		assert!(Bundle::read(&dir.join("a.css")).err().unwrap().contains("the anonymous layer it's imported into"));
+	}
+
+	#[test]
+	fn test_leading_imports() {
+		let dir = write_files(
+			"leading",
+			&[
+				("a.css", "@layer x;\n@import \"b.css\";\n@import \"c.css\";\na{}\n@import \"d.css\";"),
+				("b.css", "@import \"d.css\";b{}"),
+				("c.css", "@import \"d.css\";c{}"),
+				("d.css", "d{}"),
+			],
+		);
+		let bundle = Bundle::read(&dir.join("a.css")).unwrap();
+		assert_eq!(bundle.text, "@layer x;\nd{}b{}\nc{}\na{}\n@import \"d.css\";");
+		assert_eq!(bundle.files.len(), 4);
+	}
+
+	#[test]
+	fn test_combine_media() {
+		assert_eq!(combine_media("screen", "(color)"), Some("screen and (color)".into()));
+		assert_eq!(combine_media("screen, print", "all"), Some("screen, print".into()));
+		assert_eq!(combine_media("screen", "print"), Some("not all".into()));
+		assert_eq!(combine_media("all", "(a) or (b)"), Some("all and ((a) or (b))".into()));
+		assert_eq!(combine_media("(a), print", "(b)"), Some("(a) and (b), print and (b)".into()));
+		assert_eq!(combine_media("not screen", "print"), None);
+		assert_eq!(combine_media("not (a)", "(b)"), Some("(not (a)) and (b)".into()));
+	}
+
+	#[test]
+	fn test_errors() {
+		let dir = write_files("missing", &[("a.css", "@import \"missing.css\";")]);
+		assert!(Bundle::read(&dir.join("a.css")).err().unwrap().contains("Could not find missing.css to import."));
+		let dir = write_files("cycle", &[("a.css", "@import \"b.css\";"), ("b.css", "@import \"a.css\";")]);
+		assert!(Bundle::read(&dir.join("a.css")).err().unwrap().contains("leads back to itself"));
+	}
+}
@@ -1,10 +1,26 @@
+mod bundle;
+mod source_map;
+
 use bumpalo::Bump;
-use clap::{crate_version, Parser, Subcommand};
+use bundle::Bundle;
+use clap::{crate_version, ArgAction, Parser, Subcommand, ValueEnum};
 use hdx_ast::css::StyleSheet;
+use hdx_lexer::Kind;
+use hdx_lint::Linter;
 use hdx_lsp::server_with_handlers;
-use hdx_parser::{CursorStream, ToCursors};
+use hdx_parser::{
+	CursorSink, CursorStream, CursorWrite, IndentStyle, ParserReturn, PrettyOptions, SourceMap, SourceMapWriter,
+	ToCursors,
+};
+use hdx_transform::{FlattenNesting, Pass, PassReport, Pipeline, PipelineReport, Transform, TransformOptions};
 use miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
-use std::io;
+use similar::TextDiff;
+use source_map::read_source_map;
+use std::{
+	io,
+	path::{Component, Path, PathBuf},
+	sync::atomic::{AtomicUsize, Ordering},
+};
 use tracing::{level_filters::LevelFilter, trace};
 use tracing_subscriber::{fmt, layer::SubscriberExt, registry, util::SubscriberInitExt, Layer};
 
@@ -18,6 +34,20 @@ struct Cli {
 	debug: bool,
 }
 
+#[derive(ValueEnum, Debug, Copy, Clone, PartialEq, Eq)]
+enum Indent {
+	Tabs,
+	Spaces,
+}
+
+#[derive(ValueEnum, Debug, Copy, Clone, PartialEq, Eq)]
+enum SourceMapKind {
+	/// Embed the source map in the output, as a data URL.
+	Inline,
+	/// Write the source map next to the output, as `<output>.map`.
+	File,
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
 	/// Report potential issues around some CSS files
@@ -29,6 +59,14 @@ enum Commands {
 		/// Automatically apply suggested fixes
 		#[arg(short, long, value_parser)]
 		fix: bool,
+
+		/// Disable a lint by name. Can be passed multiple times.
+		#[arg(long, value_parser = clap::builder::PossibleValuesParser::new(hdx_lint::LINTS))]
+		disable: Vec<String>,
+
+		/// Exit with a failure if any warnings are reported, not just errors.
+		#[arg(long, value_parser)]
+		deny_warnings: bool,
 	},
 
 	/// Format CSS files to make them more readable.
@@ -41,6 +79,26 @@ enum Commands {
 		/// This will exit with a non-zero status code if any changes need to be made. Useful for CI.
 		#[arg(short, long, value_parser)]
 		check: bool,
+
+		/// Indent with tabs or spaces.
+		#[arg(long, value_enum, default_value_t = Indent::Tabs)]
+		indent: Indent,
+
+		/// How many spaces to indent with, when indenting with spaces.
+		#[arg(long, default_value_t = 2)]
+		indent_width: u8,
+
+		/// Put each declaration on its own line.
+		#[arg(long, default_value_t = true, action = ArgAction::Set)]
+		newline_per_declaration: bool,
+
+		/// Put a space between the colon and value of each declaration.
+		#[arg(long, default_value_t = true, action = ArgAction::Set)]
+		space_after_colon: bool,
+
+		/// How many blank lines to leave between rules.
+		#[arg(long, default_value_t = 1)]
+		blank_lines_between_rules: u8,
 	},
 
 	#[command(hide = true)]
@@ -54,7 +112,7 @@ enum Commands {
 	/// Convert one or more CSS files into production ready CSS.
 	#[command(arg_required_else_help(true))]
 	Build {
-		/// A list of CSS files to build. Each input will result in one output file.
+		/// A list of CSS files (or glob patterns) to build. Each input will result in one output file.
 		#[arg(required = true, value_parser)]
 		input: Vec<String>,
 
@@ -62,24 +120,371 @@ enum Commands {
 		#[arg(short, long, value_parser)]
 		minify: bool,
 
-		/// Where to save files.
+		/// Flatten nested rules into top level rules, for browsers which don't support CSS Nesting.
+		#[arg(long, value_parser)]
+		flatten_nesting: bool,
+
+		/// Print how long each transform took, and how many changes it made, for every file.
+		#[arg(long, value_parser)]
+		timings: bool,
+
+		/// Inline local @import rules, so each output contains the CSS of every file it imports.
+		#[arg(long, value_parser)]
+		bundle: bool,
+
+		/// Generate a source map for each output, mapping it back to its input.
+		#[arg(long, value_enum, num_args = 0..=1, require_equals = true, default_missing_value = "file")]
+		source_map: Option<SourceMapKind>,
+
+		/// Where to save the file. Only valid when building a single file.
 		#[arg(short, long, group = "output_file", value_parser)]
 		output: Option<String>,
+
+		/// A directory to save files into. The directory structure of the inputs will be mirrored inside of it.
+		#[arg(long, group = "output_file", value_parser)]
+		out_dir: Option<PathBuf>,
 	},
 
 	/// Run the LSP server. It's unlikely you want to run this, but your IDE might!
 	Lsp {},
 }
 
+fn render(file_name: &str, source_text: &str, errors: Vec<miette::Error>) -> String {
+	let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+	let mut report = String::new();
+	for err in errors {
+		let named = NamedSource::new(file_name, source_text.to_string());
+		let err = err.with_source_code(named);
+		handler.render_report(&mut report, err.as_ref()).unwrap();
+		report.push('\n');
+	}
+	report
+}
+
+// Diagnostics which are only warnings, such as a selector using an undeclared namespace prefix, describe CSS which
+// parsed but will be ignored by browsers, so they don't stop a file from being formatted or built.
+fn is_error(error: &miette::Error) -> bool {
+	error.severity().unwrap_or(miette::Severity::Error) == miette::Severity::Error
+}
+
+fn report(file_name: &str, source_text: &str, errors: Vec<miette::Error>) {
+	eprint!("{}", render(file_name, source_text, errors));
+}
+
+// Formatting must never change what a file means. The formatted text is parsed again, and the tokens of both trees
+// are compared, ignoring whitespace & optional semicolons, so any difference means the printer has lost something.
+fn formats_equivalently(result: &ParserReturn<StyleSheet>, formatted: &str) -> bool {
+	let allocator = Bump::default();
+	let reparsed =
+		hdx_parser::Parser::new(&allocator, formatted, hdx_parser::Features::default()).parse_entirely::<StyleSheet>();
+	if reparsed.output.is_none() || reparsed.errors.iter().any(is_error) {
+		return false;
+	}
+	let tokens = |result: &ParserReturn<StyleSheet>| {
+		let mut stream = CursorStream::new(&allocator);
+		ToCursors::to_cursors(result, &mut stream);
+		stream
+			.iter_cursors()
+			.filter(|c| !matches!(c.token().kind(), Kind::Whitespace | Kind::Semicolon))
+			.map(|c| {
+				let mut str = String::new();
+				c.write_str(result.source_text, &mut str).unwrap();
+				(c.token().kind(), str)
+			})
+			.collect::<Vec<_>>()
+	};
+	tokens(result) == tokens(&reparsed)
+}
+
+// Inputs may be paths or glob patterns. Patterns that match nothing are kept as paths, so they are reported as
+// missing files when read.
+fn expand_inputs(input: &[String]) -> Vec<PathBuf> {
+	let mut files = vec![];
+	for pattern in input {
+		let matches: Vec<PathBuf> = glob::glob(pattern).map(|paths| paths.flatten().collect()).unwrap_or_default();
+		if matches.is_empty() {
+			files.push(PathBuf::from(pattern));
+		} else {
+			files.extend(matches);
+		}
+	}
+	files.sort();
+	files.dedup();
+	files
+}
+
+// The deepest directory which contains every file, used to mirror the input tree into an output directory.
+fn common_base(files: &[PathBuf]) -> PathBuf {
+	let mut base = files.first().and_then(|file| file.parent()).map(Path::to_path_buf).unwrap_or_default();
+	for file in files {
+		while !file.starts_with(&base) {
+			if !base.pop() {
+				break;
+			}
+		}
+	}
+	base
+}
+
+struct Build {
+	css: String,
+	map: Option<SourceMap>,
+	report: PipelineReport,
+	// Problems which didn't stop the file from being built, rendered the same way as errors.
+	warnings: String,
+}
+
+type BuildResult = Result<Build, String>;
+
+fn write_file(
+	result: &ParserReturn<StyleSheet>,
+	minify: bool,
+	stream: &mut CursorStream,
+	f: &mut impl CursorWrite,
+) -> std::fmt::Result {
+	if minify {
+		hdx_transform::write_minified_with_map(result, result.source_text, stream, f)
+	} else {
+		result.write_with_map(stream, f)
+	}
+}
+
+// Writes a built file, creating any directories it needs.
+fn write_output(dest: &Path, contents: &str) -> Result<(), String> {
+	if let Some(parent) = dest.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+		std::fs::create_dir_all(parent).map_err(|e| format!("Could not create {}: {}\n", parent.display(), e))?;
+	}
+	std::fs::write(dest, contents).map_err(|e| format!("Could not write {}: {}\n", dest.display(), e))
+}
+
+// Everything which affects how each file is built, shared by every worker thread.
+#[derive(Debug, Copy, Clone)]
+struct BuildOptions {
+	transforms: TransformOptions,
+	minify: bool,
+	source_map: bool,
+	bundle: bool,
+	flatten_nesting: bool,
+}
+
+fn build_file(file: &Path, options: &BuildOptions) -> BuildResult {
+	let BuildOptions { transforms, minify, source_map, bundle, flatten_nesting } = *options;
+	let file_name = file.to_string_lossy();
+	let (source_text, bundle) = if bundle {
+		let mut bundle = Bundle::read(file)?;
+		(std::mem::take(&mut bundle.text), Some(bundle))
+	} else {
+		let source_text =
+			std::fs::read_to_string(file).map_err(|e| format!("Could not read {}: {}\n", file_name, e))?;
+		(source_text, None)
+	};
+	let allocator = Bump::default();
+	let mut result = hdx_parser::Parser::new(&allocator, source_text.as_str(), hdx_parser::Features::default())
+		.parse_entirely::<StyleSheet>();
+	let (errors, warnings): (Vec<_>, Vec<_>) = std::mem::take(&mut result.errors).into_iter().partition(is_error);
+	if result.output.is_none() || !errors.is_empty() {
+		return Err(render(&file_name, &source_text, errors));
+	}
+	let mut warnings = if warnings.is_empty() { String::new() } else { render(&file_name, &source_text, warnings) };
+	let mut report = PipelineReport::default();
+	// FlattenNesting runs once, ahead of the Pipeline, as rules it adds may be reduced by the Pipeline's passes.
+	if flatten_nesting {
+		let start = std::time::Instant::now();
+		let mut flatten = FlattenNesting::new(&allocator, result.source_text);
+		let changes = flatten.run(&mut result);
+		result.source_text = flatten.source_text();
+		report.passes.push(PassReport { name: FlattenNesting::NAME, duration: start.elapsed(), changes });
+	}
+	let pipeline_report = Pipeline::from_options(&transforms).run(&mut result);
+	report.passes.extend(pipeline_report.passes);
+	report.iterations = pipeline_report.iterations;
+	let mut stream = CursorStream::new(&allocator);
+	let (css, mut map) = if source_map {
+		let mut writer = SourceMapWriter::new(String::new());
+		if let Some(bundle) = &bundle {
+			bundle.add_sources(&mut writer);
+		} else {
+			writer = writer.with_source(&file_name, &source_text);
+		}
+		write_file(&result, minify, &mut stream, &mut writer)
+			.map(|_| writer.finish())
+			.map(|(css, map)| (css, Some(map)))
+	} else {
+		let mut css = String::new();
+		write_file(&result, minify, &mut stream, &mut css).map(|_| (css, None))
+	}
+	.map_err(|e| format!("Could not write {}: {}\n", file_name, e))?;
+	// CSS which was compiled by another tool may have its own source map, which should be followed back to the
+	// original sources.
+	if let Some(map) = map.as_mut() {
+		if let Some(bundle) = &bundle {
+			// Composing drops the composed source, so work backwards to keep the indexes of earlier files.
+			for (i, file) in bundle.files.iter().enumerate().rev() {
+				if let Some(Ok(input)) = &file.source_map {
+					map.compose(i as u32, input);
+				}
+			}
+			warnings.extend(bundle.files.iter().filter_map(|file| file.source_map.as_ref()?.as_ref().err().cloned()));
+		} else {
+			match read_source_map(&result, file.parent().unwrap_or(Path::new(""))) {
+				Some(Ok(input)) => map.compose(0, &input),
+				Some(Err(error)) => warnings.push_str(&render(&file_name, &source_text, vec![error])),
+				None => {}
+			}
+		}
+	}
+	Ok(Build { css, map, report, warnings })
+}
+
+// Source maps refer to their sources relative to where the map is, using URL separators.
+fn relative_path(path: &Path, dir: &Path) -> String {
+	let (Ok(path), Ok(dir)) = (std::path::absolute(path), std::path::absolute(dir)) else {
+		return path.to_string_lossy().replace('\\', "/");
+	};
+	let (path, dir) = (normalize(&path), normalize(&dir));
+	let common = path.components().zip(dir.components()).take_while(|(a, b)| a == b).count();
+	let parents = dir.components().skip(common).map(|_| "..".to_owned());
+	let rest = path.components().skip(common).map(|c| c.as_os_str().to_string_lossy().into_owned());
+	parents.chain(rest).collect::<Vec<_>>().join("/")
+}
+
+// Removes `.` & `..` from an absolute path, without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+	let mut normalized = PathBuf::new();
+	for component in path.components() {
+		match component {
+			Component::CurDir => {}
+			Component::ParentDir => {
+				normalized.pop();
+			}
+			component => normalized.push(component),
+		}
+	}
+	normalized
+}
+
+// Each file is parsed on a worker thread, with its own allocator, so that large builds can make use of every core.
+// Results are returned in the same order as the given files.
+fn build_files(files: &[PathBuf], options: &BuildOptions) -> Vec<BuildResult> {
+	let workers = std::thread::available_parallelism().map(usize::from).unwrap_or(1).min(files.len());
+	let next = AtomicUsize::new(0);
+	let mut results: Vec<(usize, BuildResult)> = std::thread::scope(|scope| {
+		let handles: Vec<_> = (0..workers)
+			.map(|_| {
+				scope.spawn(|| {
+					let mut results = vec![];
+					loop {
+						let i = next.fetch_add(1, Ordering::Relaxed);
+						let Some(file) = files.get(i) else { break };
+						results.push((i, build_file(file, options)));
+					}
+					results
+				})
+			})
+			.collect();
+		handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+	});
+	results.sort_by_key(|(i, _)| *i);
+	results.into_iter().map(|(_, result)| result).collect()
+}
+
 fn main() {
 	let cli = Cli::parse();
 
 	match &cli.command {
-		Commands::Check { input, fix } => {
-			todo!("Check ({:?}, {:?})", input, fix);
+		Commands::Check { input, fix, disable, deny_warnings } => {
+			if *fix {
+				eprintln!("Warning: automatic fixes not yet supported");
+			}
+			let linter = disable.iter().fold(Linter::new(), |linter, name| linter.disable(name));
+			let mut failed = false;
+			for file_name in input {
+				let source_text = match std::fs::read_to_string(file_name) {
+					Ok(source_text) => source_text,
+					Err(e) => {
+						eprintln!("Could not read {}: {}", file_name, e);
+						failed = true;
+						continue;
+					}
+				};
+				let allocator = Bump::default();
+				let result = hdx_parser::Parser::new(&allocator, source_text.as_str(), hdx_parser::Features::default())
+					.parse_entirely::<StyleSheet>();
+				let mut errors = result.errors;
+				if let Some(stylesheet) = &result.output {
+					errors.extend(linter.run(stylesheet, source_text.as_str()));
+				}
+				failed |= errors.iter().any(|e| *deny_warnings || is_error(e));
+				report(file_name, &source_text, errors);
+			}
+			if failed {
+				std::process::exit(1);
+			}
 		}
-		Commands::Fmt { input, check } => {
-			todo!("Fmt ({:?}, {:?})", input, check);
+		Commands::Fmt {
+			input,
+			check,
+			indent,
+			indent_width,
+			newline_per_declaration,
+			space_after_colon,
+			blank_lines_between_rules,
+		} => {
+			let options = PrettyOptions {
+				indent_style: match indent {
+					Indent::Tabs => IndentStyle::Tabs,
+					Indent::Spaces => IndentStyle::Spaces(*indent_width),
+				},
+				newline_per_declaration: *newline_per_declaration,
+				space_after_colon: *space_after_colon,
+				blank_lines_between_rules: *blank_lines_between_rules,
+			};
+			let mut failed = false;
+			for file_name in input {
+				let source_text = match std::fs::read_to_string(file_name) {
+					Ok(source_text) => source_text,
+					Err(e) => {
+						eprintln!("Could not read {}: {}", file_name, e);
+						failed = true;
+						continue;
+					}
+				};
+				let allocator = Bump::default();
+				let mut result =
+					hdx_parser::Parser::new(&allocator, source_text.as_str(), hdx_parser::Features::default())
+						.parse_entirely::<StyleSheet>();
+				if result.output.is_none() || result.errors.iter().any(is_error) {
+					failed = true;
+					report(file_name, &source_text, result.errors);
+					continue;
+				}
+				report(file_name, &source_text, std::mem::take(&mut result.errors));
+				let mut str = String::new();
+				let mut stream = CursorStream::new(&allocator);
+				result.write_pretty(&mut stream, &options, &mut str).unwrap();
+				if str == source_text {
+					continue;
+				}
+				if !formats_equivalently(&result, &str) {
+					failed = true;
+					eprintln!(
+						"{}: formatting would change the meaning of this file, so it has been left as is",
+						file_name
+					);
+					continue;
+				}
+				if *check {
+					failed = true;
+					let diff = TextDiff::from_lines(source_text.as_str(), str.as_str());
+					print!("{}", diff.unified_diff().header(file_name, file_name));
+				} else if let Err(e) = std::fs::write(file_name, str.as_bytes()) {
+					failed = true;
+					eprintln!("Could not write {}: {}", file_name, e);
+				}
+			}
+			if failed {
+				std::process::exit(1);
+			}
 		}
 		Commands::DbgParse { input } => {
 			let source_text = std::fs::read_to_string(input).unwrap();
@@ -90,55 +495,101 @@ fn main() {
 			if let Some(stylesheet) = &result.output {
 				println!("{:#?}", stylesheet);
 			} else {
-				let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
-				for err in result.errors {
-					let mut report = String::new();
-					let named = NamedSource::new(input, source_text.clone());
-					let err = err.with_source_code(named);
-					handler.render_report(&mut report, err.as_ref()).unwrap();
-					println!("{}", report);
-				}
+				report(input, &source_text, result.errors);
 			}
 		}
-		Commands::Build { input, minify, output } => {
-			if input.len() > 1 {
-				todo!("Can't handle multiple files yet")
+		Commands::Build { input, minify, flatten_nesting, timings, bundle, source_map, output, out_dir } => {
+			let files = expand_inputs(input);
+			if files.is_empty() {
+				eprintln!("No files matched {:?}", input);
+				std::process::exit(1);
+			}
+			if output.is_some() && files.len() > 1 {
+				eprintln!("--output can only be used with a single file, use --out-dir to build multiple files");
+				std::process::exit(1);
+			}
+			if *source_map == Some(SourceMapKind::File) && output.is_none() && out_dir.is_none() {
+				eprintln!("--source-map=file needs somewhere to write the map, use --output or --out-dir");
+				std::process::exit(1);
 			}
 
-			let file_name = input.first().unwrap();
-			let source_text = std::fs::read_to_string(file_name).unwrap();
-			let allocator = Bump::default();
+			let options = BuildOptions {
+				transforms: if *minify { TransformOptions::minify() } else { TransformOptions::default() },
+				minify: *minify,
+				source_map: source_map.is_some(),
+				bundle: *bundle,
+				flatten_nesting: *flatten_nesting,
+			};
 			let start = std::time::Instant::now();
-			let result = hdx_parser::Parser::new(&allocator, source_text.as_str(), hdx_parser::Features::default())
-				.parse_entirely::<StyleSheet>();
-			{
-				if result.output.is_some() {
-					let mut str = String::new();
-					let mut stream = CursorStream::new(&allocator);
-					result.to_cursors(&mut stream);
-					if let Err(e) = result.write(&mut stream, &mut str) {
-						println!("{}", e);
-					}
-					if let Some(file) = output {
-						std::fs::write(file, str.as_bytes()).unwrap();
-					} else {
-						println!("{}", str);
-						eprintln!("Slurped up CSS in {:?}! Neat!", start.elapsed());
-						if *minify {
-							eprintln!("Warning: minification not yet supported");
+			let results = build_files(&files, &options);
+			let mut failed = false;
+			let base = common_base(&files);
+			for (file, result) in files.iter().zip(results) {
+				match result {
+					Ok(Build { css: mut str, map, report, warnings }) => {
+						eprint!("{}", warnings);
+						if *timings {
+							eprintln!("{}:\n{}", file.display(), report);
+						}
+						let dest = if let Some(out_dir) = out_dir {
+							// Falling back to the full path would join an absolute path onto out_dir, which is the
+							// input itself, so the source would be overwritten.
+							let Ok(relative) = file.strip_prefix(&base) else {
+								failed = true;
+								eprintln!("Could not mirror {} into {}", file.display(), out_dir.display());
+								continue;
+							};
+							Some(out_dir.join(relative))
+						} else {
+							output.as_ref().map(PathBuf::from)
+						};
+						if let (Some(mut map), Some(kind)) = (map, source_map) {
+							let dir = dest.as_ref().and_then(|dest| dest.parent()).unwrap_or(Path::new(""));
+							map.file = dest
+								.as_ref()
+								.and_then(|dest| dest.file_name())
+								.map(|name| name.to_string_lossy().into_owned());
+							for source in map.sources.iter_mut() {
+								// Sources from an input source map may be URLs rather than paths.
+								if !source.contains("://") {
+									*source = relative_path(Path::new(source), dir);
+								}
+							}
+							let url = match kind {
+								SourceMapKind::Inline => map.to_data_url(),
+								SourceMapKind::File => {
+									// --output or --out-dir are required for file source maps.
+									let dest = dest.as_ref().unwrap();
+									let map_file = format!("{}.map", dest.file_name().unwrap().to_string_lossy());
+									if let Err(e) = write_output(&dir.join(&map_file), &map.to_json()) {
+										failed = true;
+										eprint!("{}", e);
+										continue;
+									}
+									map_file
+								}
+							};
+							str.push_str(&format!("\n/*# sourceMappingURL={} */", url));
+						}
+						if let Some(dest) = dest {
+							if let Err(e) = write_output(&dest, &str) {
+								failed = true;
+								eprint!("{}", e);
+							}
+						} else {
+							println!("{}", str);
 						}
 					}
-				} else {
-					let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
-					for err in result.errors {
-						let mut report = String::new();
-						let named = NamedSource::new(file_name, source_text.clone());
-						let err = err.with_source_code(named);
-						handler.render_report(&mut report, err.as_ref()).unwrap();
-						println!("{}", report);
+					Err(report) => {
+						failed = true;
+						eprint!("{}", report);
 					}
 				}
 			}
+			eprintln!("Slurped up {} CSS file(s) in {:?}! Neat!", files.len(), start.elapsed());
+			if failed {
+				std::process::exit(1);
+			}
 		}
 		Commands::Lsp {} => {
 			let server = server_with_handlers(crate_version!());
@@ -0,0 +1,72 @@
+use hdx_ast::css::StyleSheet;
+use hdx_lexer::Span;
+use hdx_parser::{ParserReturn, SourceMap};
+use miette::{self, Diagnostic};
+use std::path::Path;
+use thiserror::{self, Error};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The source map for this file couldn't be read: {0}")]
+#[diagnostic(
+	help("The output will be mapped back to this file, rather than the files it was compiled from."),
+	code(hdx::InvalidSourceMap)
+)]
+pub struct InvalidSourceMap(pub String, #[label("This source map")] pub Span);
+
+/// Reads the Source Map named by the file's `sourceMappingURL` comment, if it has one. Sidecar files are found
+/// relative to `dir`, which should be the directory the file was read from.
+pub fn read_source_map(result: &ParserReturn<StyleSheet>, dir: &Path) -> Option<Result<SourceMap, miette::Error>> {
+	let (cursor, url) = result.source_mapping_url()?;
+	Some(load(url, dir).map_err(|reason| InvalidSourceMap(reason, cursor.span()).into()))
+}
+
+// Reads a `data:` URL, or a file relative to `dir`. Sources which are relative paths are resolved against the
+// directory the map was found in.
+fn load(url: &str, dir: &Path) -> Result<SourceMap, String> {
+	let (mut map, dir) = if url.starts_with("data:") {
+		(SourceMap::from_data_url(url)?, dir.to_path_buf())
+	} else {
+		let path = dir.join(url.split(['?', '#']).next().unwrap_or_default());
+		let json = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+		(SourceMap::from_json(&json)?, path.parent().map(Path::to_path_buf).unwrap_or_default())
+	};
+	for source in map.sources.iter_mut() {
+		// Sources may be URLs (e.g. `webpack://`), which can't be resolved as paths.
+		if !has_scheme(source) && !Path::new(source).is_absolute() {
+			*source = dir.join(&*source).to_string_lossy().into_owned();
+		}
+	}
+	Ok(map)
+}
+
+// https://url.spec.whatwg.org/#url-scheme-string
+// Schemes of a single letter are taken to be Windows drive letters, such as `C:\a.css`.
+fn has_scheme(url: &str) -> bool {
+	let Some((scheme, _)) = url.split_once(':') else { return false };
+	scheme.len() > 1
+		&& scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+		&& scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_has_scheme() {
+		assert!(has_scheme("webpack://app/a.scss"));
+		assert!(has_scheme("file:///a.scss"));
+		assert!(!has_scheme("C:\\a.scss"));
+		assert!(!has_scheme("src/a.scss"));
+		assert!(!has_scheme("1x:a.scss"));
+	}
+
+	#[test]
+	fn test_load() {
+		// {"version":3,"sources":["a.scss","webpack://b.scss"],"mappings":""}
+		let url = "data:application/json,%7B%22version%22%3A3%2C%22sources%22%3A%5B%22a.scss%22%2C%22webpack%3A%2F%2Fb.scss%22%5D%2C%22mappings%22%3A%22%22%7D";
+		let map = load(url, Path::new("src")).unwrap();
+		assert_eq!(map.sources, vec![Path::new("src").join("a.scss").to_string_lossy(), "webpack://b.scss".into()]);
+		assert!(load("missing.css.map", Path::new("src")).is_err());
+	}
+}
@@ -1,16 +1,21 @@
 use std::{fmt::Debug, hash::Hash};
 
-use hdx_atom::atom;
+use bumpalo::Bump;
+use hdx_atom::{atom, Atom};
 use hdx_lexer::{Cursor, KindSet};
 use hdx_parser::{
-	CursorSink, Declaration, DeclarationValue, Important, Is, Parse, Parser, Result as ParserResult, State, ToCursors,
-	T,
+	CursorSink, Declaration, DeclarationValue, Features, Important, Is, Parse, Parser, Result as ParserResult, State,
+	ToCursors, T,
 };
 use hdx_proc_macro::visit;
 
-use crate::{css::values, syntax::ComponentValues};
+use crate::{
+	css::{types::MathFunction, values},
+	syntax::ComponentValues,
+	traits::Initial,
+};
 
-use super::{Visit, Visitable};
+use super::{Visit, VisitMut, Visitable, VisitableMut};
 
 // The build.rs generates a list of CSS properties from the value mods
 include!(concat!(env!("OUT_DIR"), "/css_apply_properties.rs"));
@@ -36,28 +41,19 @@ impl<'a> ToCursors for Custom<'a> {
 	}
 }
 
+// A value which starts with var(), or with a math function that the property's own value couldn't hold. A value that
+// is just a single math function is typed as [MathFunction], otherwise the value can only be understood after
+// substitution, so it is kept as ComponentValues.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct Computed<'a>(pub ComponentValues<'a>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Computed<'a> {
+	Math(MathFunction<'a>),
+	Values(ComponentValues<'a>),
+}
 
 impl<'a> Is<'a> for Computed<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
-		<T![Function]>::is(p, c)
-			&& matches!(
-				p.parse_atom_lower(c),
-				atom!("var")
-					| atom!("calc") | atom!("min")
-					| atom!("max") | atom!("clamp")
-					| atom!("round")
-					| atom!("mod") | atom!("rem")
-					| atom!("sin") | atom!("cos")
-					| atom!("tan") | atom!("asin")
-					| atom!("atan") | atom!("atan2")
-					| atom!("pow") | atom!("sqrt")
-					| atom!("hypot")
-					| atom!("log") | atom!("exp")
-					| atom!("abs") | atom!("sign")
-			)
+		MathFunction::is(p, c) || (<T![Function]>::is(p, c) && p.parse_atom_lower(c) == atom!("var"))
 	}
 }
 
@@ -65,16 +61,34 @@ impl<'a> Parse<'a> for Computed<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let state = p.set_state(State::Nested);
 		let stop = p.set_stop(KindSet::RIGHT_CURLY_OR_SEMICOLON);
-		let values = p.parse::<ComponentValues>();
+		let value = Self::parse_value(p);
 		p.set_state(state);
 		p.set_stop(stop);
-		Ok(Self(values?))
+		value
+	}
+}
+
+impl<'a> Computed<'a> {
+	fn parse_value(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<MathFunction>() {
+			let checkpoint = p.checkpoint();
+			// Errors within the math function, such as `calc(1px + 2s)`, make the whole value invalid.
+			let math = p.parse::<MathFunction>()?;
+			if p.at_end() || p.peek_n(1) == KindSet::RIGHT_CURLY_OR_SEMICOLON || p.peek::<T![!]>() {
+				return Ok(Self::Math(math));
+			}
+			p.rewind(checkpoint);
+		}
+		p.parse::<ComponentValues>().map(Self::Values)
 	}
 }
 
 impl<'a> ToCursors for Computed<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
-		ToCursors::to_cursors(&self.0, s);
+		match self {
+			Self::Math(math) => ToCursors::to_cursors(math, s),
+			Self::Values(values) => ToCursors::to_cursors(values, s),
+		}
 	}
 }
 
@@ -142,6 +156,13 @@ impl<'a> Visitable<'a> for Property<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for Property<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_property(self);
+		VisitableMut::accept_mut(&mut self.value, v);
+	}
+}
+
 macro_rules! style_value {
     ( $(
 				$name: ident: $ty: ident$(<$a: lifetime>)? = $atom: pat,
@@ -187,7 +208,9 @@ impl<'a> DeclarationValue<'a> for StyleValue<'a> {
 				_ => {}
 			}
 		}
-		if p.peek::<Computed>() {
+		// A math function may be checked against the property's type, but var() can only be understood after
+		// substitution.
+		if p.peek::<Computed>() && !p.peek::<MathFunction>() {
 			return p.parse::<Computed>().map(Self::Computed);
 		}
 		let checkpoint = p.checkpoint();
@@ -210,16 +233,56 @@ impl<'a> DeclarationValue<'a> for StyleValue<'a> {
 			}
 		}
 		apply_properties!(parse_declaration_value);
+		p.rewind(checkpoint);
 		if p.peek::<Computed>() {
-			p.rewind(checkpoint);
 			Ok(Self::Computed(p.parse::<Computed>()?))
 		} else {
-			p.rewind(checkpoint);
 			Ok(Self::Unknown(p.parse::<Unknown>()?))
 		}
 	}
 }
 
+impl<'a> StyleValue<'a> {
+	/// The initial value of the named property, as written in its specification. Unknown properties, and those whose
+	/// initial value is only described in prose (such as "see individual properties"), have no initial value.
+	pub fn initial_text(name: &Atom) -> Option<&'static str> {
+		macro_rules! match_initial_text {
+			( $(
+				$name: ident: $ty: ident$(<$a: lifetime>)? = $atom: pat,
+			)+ ) => {
+				match *name {
+					$( $atom => <values::$ty as Initial>::INITIAL, )+
+					_ => None,
+				}
+			}
+		}
+		apply_properties!(match_initial_text)
+	}
+
+	/// Parses the initial value of the named property. Cursors within the returned value point into
+	/// [StyleValue::initial_text], so it must be used as the source text when writing the value.
+	pub fn default_for(allocator: &'a Bump, name: &Atom) -> Option<Self> {
+		let mut p = Parser::new(allocator, Self::initial_text(name)?, Features::default());
+		Self::parse_initial(&mut p, name)
+	}
+
+	/// Parses the initial value of the named property from the Parser, which must contain only that value.
+	pub fn parse_initial(p: &mut Parser<'a>, name: &Atom) -> Option<Self> {
+		macro_rules! parse_initial {
+			( $(
+				$name: ident: $ty: ident$(<$a: lifetime>)? = $atom: pat,
+			)+ ) => {
+				match *name {
+					$( $atom => p.parse::<values::$ty>().ok().map(Self::$name), )+
+					_ => None,
+				}
+			}
+		}
+		let value = apply_properties!(parse_initial);
+		value.filter(|_| p.at_end())
+	}
+}
+
 impl<'a> ToCursors for StyleValue<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		macro_rules! match_value {
@@ -246,6 +309,34 @@ impl<'a> ToCursors for StyleValue<'a> {
 impl<'a> Visitable<'a> for StyleValue<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		v.visit_style_value(self);
+		macro_rules! match_value {
+			( $(
+				$name: ident: $ty: ident$(<$a: lifetime>)? = $atom: pat,
+			)+ ) => {
+				match self {
+					$( Self::$name(value) => Visitable::accept(value, v), )+
+					_ => {}
+				}
+			}
+		}
+		apply_properties!(match_value);
+	}
+}
+
+impl<'a> VisitableMut<'a> for StyleValue<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_style_value(self);
+		macro_rules! match_value {
+			( $(
+				$name: ident: $ty: ident$(<$a: lifetime>)? = $atom: pat,
+			)+ ) => {
+				match self {
+					$( Self::$name(value) => VisitableMut::accept_mut(value, v), )+
+					_ => {}
+				}
+			}
+		}
+		apply_properties!(match_value);
 	}
 }
 
@@ -256,18 +347,88 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(Property, 400);
-		assert_size!(StyleValue, 328);
+		assert_size!(Property, 432);
+		assert_size!(StyleValue, 360);
 	}
 
 	#[test]
 	fn test_writes() {
 		assert_parse!(Property, "float:none!important");
 		assert_parse!(Property, "width:1px");
-		assert_parse!(Property, "width:min(1px, 2px)");
+		assert_parse!(Property, "width:min(1px, 2px)", "width:min(1px,2px)");
+		assert_parse!(Property, "width:calc(100% - 10px)");
+		assert_parse!(Property, "width:calc(var(--x)*2)!important");
+		assert_parse!(Property, "margin:calc(1px + 2px) 3px", "margin:calc(1px + 2px)3px");
 		assert_parse!(Property, "border:1px solid var(--red)");
 		// Should still parse unknown properties
 		assert_parse!(Property, "dunno:like whatever");
 		assert_parse!(Property, "rotate:1.21gw");
 	}
+
+	#[test]
+	fn test_initial() {
+		let allocator = Bump::default();
+		macro_rules! failed_initials {
+			( $(
+				$name: ident: $ty: ident$(<$a: lifetime>)? = $atom: pat,
+			)+ ) => {
+				[$(
+					(stringify!($name), <values::$ty as Initial>::INITIAL, <values::$ty as Initial>::initial(&allocator).is_some()),
+				)+]
+				.into_iter()
+				.filter(|(_, initial, parsed)| initial.is_some() && !parsed)
+				.map(|(name, initial, _)| (name, initial.unwrap()))
+				.collect::<Vec<_>>()
+			}
+		}
+		assert_eq!(apply_properties!(failed_initials), vec![]);
+		assert_eq!(StyleValue::initial_text(&atom!("margin-top")), Some("0"));
+		assert_eq!(StyleValue::initial_text(&atom!("margin")), Some("0"));
+		assert_eq!(StyleValue::initial_text(&atom!("dunno")), None);
+		assert!(matches!(StyleValue::default_for(&allocator, &atom!("direction")), Some(StyleValue::Direction(_))));
+	}
+
+	#[test]
+	fn test_computed() {
+		let allocator = Bump::default();
+		let mut p = Parser::new(&allocator, "width:calc(100% - 10px)", Features::default());
+		let property = p.parse_entirely::<Property>().output.unwrap();
+		assert!(matches!(
+			property.value,
+			StyleValue::Width(values::WidthStyleValue::LengthPercentage(crate::css::units::LengthPercentage::Math(_)))
+		));
+		let mut p = Parser::new(&allocator, "margin:calc(1px + 2px) 3px", Features::default());
+		let property = p.parse_entirely::<Property>().output.unwrap();
+		assert!(matches!(property.value, StyleValue::Margin(_)));
+		let mut p = Parser::new(&allocator, "line-height:calc(1 + 1)", Features::default());
+		let result = p.parse_entirely::<Property>();
+		assert!(result.errors.is_empty());
+		assert!(matches!(result.output.unwrap().value, StyleValue::Computed(Computed::Math(_))));
+		let mut p = Parser::new(&allocator, "margin:var(--a) 3px", Features::default());
+		let property = p.parse_entirely::<Property>().output.unwrap();
+		assert!(matches!(property.value, StyleValue::Computed(Computed::Values(_))));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(Property, "width:calc(1px + 2s)");
+		assert_parse_error!(Property, "width:min(1px, 2deg)");
+		assert_parse_error!(Property, "width:sin(45deg)");
+	}
+
+	#[test]
+	fn test_math_type_errors_keep_declaration() {
+		let allocator = Bump::default();
+		let mut p = Parser::new(&allocator, "width:sin(45deg)", Features::default());
+		let result = p.parse_entirely::<Property>();
+		assert_eq!(result.errors.len(), 1);
+		assert!(matches!(result.output.unwrap().value, StyleValue::Width(_)));
+		let mut p = Parser::new(&allocator, "width:calc(1px+2px)", Features::default());
+		let result = p.parse_entirely::<Property>();
+		assert_eq!(result.errors.len(), 1);
+		assert!(matches!(result.output.as_ref().unwrap().value, StyleValue::Width(_)));
+		let mut str = String::new();
+		result.write(&mut hdx_parser::CursorStream::new(&allocator), &mut str).unwrap();
+		assert_eq!(str, "width:calc(1px+2px)");
+	}
 }
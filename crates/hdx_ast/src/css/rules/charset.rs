@@ -3,7 +3,7 @@ use hdx_lexer::Cursor;
 use hdx_parser::{diagnostics, Parse, Parser, Result as ParserResult, ToCursors, T};
 use hdx_proc_macro::visit;
 
-use crate::css::{Visit, Visitable};
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-syntax-3/#charset-rule
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -56,6 +56,12 @@ impl<'a> Visitable<'a> for CharsetRule {
 	}
 }
 
+impl<'a> VisitableMut<'a> for CharsetRule {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_charset_rule(self);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1,7 +1,240 @@
-use crate::Todo;
+use bumpalo::collections::Vec;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{
+	diagnostics, keyword_typedef, AtRule, CursorSink, Declaration, DeclarationList, DeclarationValue, Parse, Parser,
+	Result as ParserResult, ToCursors, T,
+};
+use hdx_proc_macro::visit;
+
+use crate::css::{rules::ImportUrl, Visit, VisitMut, Visitable, VisitableMut};
+
+// https://drafts.csswg.org/css-color-5/#at-profile
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct ColorProfileRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub name: ColorProfileName,
+	pub block: ColorProfileRuleBlock<'a>,
+}
+
+impl<'a> Parse<'a> for ColorProfileRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (at_keyword, name, block) = Self::parse_at_rule(p, Some(atom!("color-profile")))?;
+		if let Some(name) = name {
+			Ok(Self { at_keyword, name, block })
+		} else {
+			let c: Cursor = at_keyword.into();
+			Err(diagnostics::MissingAtRulePrelude(c.into()))?
+		}
+	}
+}
+
+impl<'a> AtRule<'a> for ColorProfileRule<'a> {
+	type Prelude = ColorProfileName;
+	type Block = ColorProfileRuleBlock<'a>;
+}
+
+impl ToCursors for ColorProfileRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		s.append(self.name.into());
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for ColorProfileRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_color_profile_rule(self);
+		for property in &self.block.properties {
+			Visitable::accept(property, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for ColorProfileRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_color_profile_rule(self);
+		for property in &mut self.block.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
 
 // https://drafts.csswg.org/css-color-5/#at-profile
-pub type ColorProfileRule = Todo;
+// <dashed-ident> | device-cmyk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum ColorProfileName {
+	Dashed(T![DashedIdent]),
+	DeviceCmyk(T![Ident]),
+}
+
+impl<'a> Parse<'a> for ColorProfileName {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![DashedIdent]>() {
+			return p.parse::<T![DashedIdent]>().map(Self::Dashed);
+		}
+		let ident = p.parse::<T![Ident]>()?;
+		let c: Cursor = ident.into();
+		match p.parse_atom_lower(c) {
+			atom!("device-cmyk") => Ok(Self::DeviceCmyk(ident)),
+			atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
+		}
+	}
+}
+
+impl From<ColorProfileName> for Cursor {
+	fn from(value: ColorProfileName) -> Self {
+		match value {
+			ColorProfileName::Dashed(ident) => ident.into(),
+			ColorProfileName::DeviceCmyk(ident) => ident.into(),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ColorProfileRuleBlock<'a> {
+	pub open: T!['{'],
+	pub properties: Vec<'a, ColorProfileRuleProperty<'a>>,
+	pub close: Option<T!['}']>,
+}
+
+impl<'a> Parse<'a> for ColorProfileRuleBlock<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (open, properties, close) = Self::parse_declaration_list(p)?;
+		Ok(Self { open, properties, close })
+	}
+}
+
+impl<'a> DeclarationList<'a> for ColorProfileRuleBlock<'a> {
+	type Declaration = ColorProfileRuleProperty<'a>;
+}
+
+impl ToCursors for ColorProfileRuleBlock<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for property in &self.properties {
+			ToCursors::to_cursors(property, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename = "property"))]
+#[visit]
+pub struct ColorProfileRuleProperty<'a> {
+	pub name: T![Ident],
+	pub colon: T![:],
+	pub value: ColorProfileRuleStyleValue<'a>,
+	pub semicolon: Option<T![;]>,
+}
+
+impl<'a> Parse<'a> for ColorProfileRuleProperty<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (name, colon, value, important, semicolon) = Self::parse_declaration(p)?;
+		if let Some(important) = important {
+			let c: Cursor = important.bang.into();
+			Err(diagnostics::DisallowedImportant(c.into()))?
+		}
+		Ok(Self { name, colon, value, semicolon })
+	}
+}
+
+impl<'a> Declaration<'a> for ColorProfileRuleProperty<'a> {
+	type DeclarationValue = ColorProfileRuleStyleValue<'a>;
+	fn valid_property(p: &Parser, c: Cursor) -> bool {
+		matches!(p.parse_atom_lower(c), atom!("src") | atom!("rendering-intent") | atom!("components"))
+	}
+}
+
+impl ToCursors for ColorProfileRuleProperty<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.name.into());
+		s.append(self.colon.into());
+		ToCursors::to_cursors(&self.value, s);
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for ColorProfileRuleProperty<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_color_profile_rule_property(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for ColorProfileRuleProperty<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_color_profile_rule_property(self);
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum ColorProfileRuleStyleValue<'a> {
+	// https://drafts.csswg.org/css-color-5/#descdef-color-profile-src
+	Src(ImportUrl),
+	// https://drafts.csswg.org/css-color-5/#descdef-color-profile-rendering-intent
+	RenderingIntent(RenderingIntent),
+	// https://drafts.csswg.org/css-color-5/#descdef-color-profile-components
+	// <ident>#
+	Components(Vec<'a, (T![Ident], Option<T![,]>)>),
+}
+
+impl<'a> DeclarationValue<'a> for ColorProfileRuleStyleValue<'a> {
+	fn parse_declaration_value(name: Cursor, p: &mut Parser<'a>) -> ParserResult<Self> {
+		Ok(match p.parse_atom_lower(name) {
+			atom!("src") => Self::Src(p.parse::<ImportUrl>()?),
+			atom!("rendering-intent") => Self::RenderingIntent(p.parse::<RenderingIntent>()?),
+			atom!("components") => {
+				let mut components = Vec::new_in(p.bump());
+				loop {
+					let ident = p.parse::<T![Ident]>()?;
+					let comma = p.parse_if_peek::<T![,]>()?;
+					let done = comma.is_none();
+					components.push((ident, comma));
+					if done {
+						break;
+					}
+				}
+				Self::Components(components)
+			}
+			atom => Err(diagnostics::UnexpectedIdent(atom, name.into()))?,
+		})
+	}
+}
+
+impl ToCursors for ColorProfileRuleStyleValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Src(url) => ToCursors::to_cursors(url, s),
+			Self::RenderingIntent(intent) => s.append((*intent).into()),
+			Self::Components(components) => {
+				for (ident, comma) in components {
+					s.append(ident.into());
+					if let Some(comma) = comma {
+						s.append(comma.into());
+					}
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-color-5/#descdef-color-profile-rendering-intent
+keyword_typedef!(RenderingIntent {
+	RelativeColorimetric: atom!("relative-colorimetric"),
+	AbsoluteColorimetric: atom!("absolute-colorimetric"),
+	Perceptual: atom!("perceptual"),
+	Saturation: atom!("saturation"),
+});
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +243,33 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(ColorProfileRule, 0);
+		assert_size!(ColorProfileRule, 96);
+		assert_size!(ColorProfileRuleStyleValue, 40);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(ColorProfileRule, "@color-profile --swop5c {\n\tsrc: url(\"https://example.org/SWOP2006_Coated5v2.icc\");}");
+		assert_parse!(ColorProfileRule, "@color-profile --swop5c{}");
+		assert_parse!(
+			ColorProfileRule,
+			"@color-profile --swop5c{src:url(https://example.org/SWOP2006_Coated5v2.icc);}"
+		);
+		assert_parse!(
+			ColorProfileRule,
+			"@color-profile device-cmyk{src:url('https://example.org/Coated_Fogra39L_VIGC_300.icc');rendering-intent:perceptual}"
+		);
+		assert_parse!(
+			ColorProfileRule,
+			"@color-profile --fogra55beta{src:url(fogra55.icc);components:cyan,magenta,yellow,black,orange,green,violet;}"
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ColorProfileRule, "@color-profile{}");
+		assert_parse_error!(ColorProfileRule, "@color-profile swop5c{}");
+		assert_parse_error!(ColorProfileRule, "@color-profile --swop5c{rendering-intent:bright}");
+		assert_parse_error!(ColorProfileRule, "@color-profile --swop5c{src:url(a.icc)!important}");
+		assert_parse_error!(ColorProfileRule, "@color-profile --swop5c{color:red}");
 	}
 }
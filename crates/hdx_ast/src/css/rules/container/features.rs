@@ -1,3 +1,4 @@
+use super::kw;
 use crate::css::{properties::Property, types::Ratio, units::Length, Visit, Visitable};
 use bumpalo::collections::Vec;
 use hdx_atom::atom;
@@ -9,36 +10,36 @@ use hdx_parser::{
 use hdx_proc_macro::visit;
 
 #[visit]
-ranged_feature!(WidthContainerFeature[atom!("width")], Length);
+ranged_feature!(WidthContainerFeature<'a>[atom!("width")], Length<'a>);
 
-impl<'a> Visitable<'a> for WidthContainerFeature {
+impl<'a> Visitable<'a> for WidthContainerFeature<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		v.visit_width_container_feature(self);
 	}
 }
 
 #[visit]
-ranged_feature!(HeightContainerFeature[atom!("height")], Length);
+ranged_feature!(HeightContainerFeature<'a>[atom!("height")], Length<'a>);
 
-impl<'a> Visitable<'a> for HeightContainerFeature {
+impl<'a> Visitable<'a> for HeightContainerFeature<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		v.visit_height_container_feature(self);
 	}
 }
 
 #[visit]
-ranged_feature!(InlineSizeContainerFeature[atom!("inline-size")], Length);
+ranged_feature!(InlineSizeContainerFeature<'a>[atom!("inline-size")], Length<'a>);
 
-impl<'a> Visitable<'a> for InlineSizeContainerFeature {
+impl<'a> Visitable<'a> for InlineSizeContainerFeature<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		v.visit_inline_size_container_feature(self);
 	}
 }
 
 #[visit]
-ranged_feature!(BlockSizeContainerFeature[atom!("block-size")], Length);
+ranged_feature!(BlockSizeContainerFeature<'a>[atom!("block-size")], Length<'a>);
 
-impl<'a> Visitable<'a> for BlockSizeContainerFeature {
+impl<'a> Visitable<'a> for BlockSizeContainerFeature<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		v.visit_block_size_container_feature(self);
 	}
@@ -68,49 +69,65 @@ impl<'a> Visitable<'a> for OrientationContainerFeature {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 pub enum StyleQuery<'a> {
-	Is(Property<'a>),
-	Not(Box<StyleQuery<'a>>),
-	And(Vec<'a, Property<'a>>),
-	Or(Vec<'a, Property<'a>>),
+	Is(StyleQueryFeature<'a>),
+	Not(T![Ident], Box<StyleQuery<'a>>),
+	And(Vec<'a, (StyleQueryFeature<'a>, Option<T![Ident]>)>),
+	Or(Vec<'a, (StyleQueryFeature<'a>, Option<T![Ident]>)>),
+	Parens(T!['('], Box<StyleQuery<'a>>, T![')']),
 }
 
 impl<'a> ConditionalAtRule<'a> for StyleQuery<'a> {
-	type Feature = Property<'a>;
-	fn new_is(feature: Property<'a>) -> Self {
+	type Feature = StyleQueryFeature<'a>;
+	fn new_is(feature: StyleQueryFeature<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn new_not(condition: StyleQuery<'a>) -> Self {
-		Self::Not(Box::new(condition))
+	fn new_not(keyword: T![Ident], condition: StyleQuery<'a>) -> Self {
+		Self::Not(keyword, Box::new(condition))
 	}
-	fn new_and(feature: Vec<'a, Property<'a>>) -> Self {
-		Self::And(feature)
+	fn new_and(features: Vec<'a, (StyleQueryFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::And(features)
 	}
-	fn new_or(feature: Vec<'a, Property<'a>>) -> Self {
-		Self::Or(feature)
+	fn new_or(features: Vec<'a, (StyleQueryFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::Or(features)
+	}
+	fn new_parens(open: T!['('], condition: StyleQuery<'a>, close: T![')']) -> Self {
+		Self::Parens(open, Box::new(condition), close)
 	}
 }
 
+// https://drafts.csswg.org/css-conditional-5/#typedef-style-query
 impl<'a> Parse<'a> for StyleQuery<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		Self::parse_condition(p)
+		// A lone <style-feature> such as `style(--theme: dark)` needs no parens.
+		if p.peek::<T!['(']>() || p.peek::<kw::Not>() {
+			Self::parse_condition(p)
+		} else {
+			p.parse::<StyleQueryFeature>().map(Self::Is)
+		}
 	}
 }
 
 impl ToCursors for StyleQuery<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
-			Self::Is(c) => ToCursors::to_cursors(c, s),
-			Self::Not(c) => ToCursors::to_cursors(c.as_ref(), s),
-			Self::And(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
-				}
+			Self::Is(feature) => ToCursors::to_cursors(feature, s),
+			Self::Not(keyword, condition) => {
+				s.append(keyword.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
 			}
-			Self::Or(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
+			Self::And(features) | Self::Or(features) => {
+				for (feature, keyword) in features {
+					ToCursors::to_cursors(feature, s);
+					if let Some(keyword) = keyword {
+						s.append(keyword.into());
+					}
 				}
 			}
+			Self::Parens(open, condition, close) => {
+				s.append(open.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
+				s.append(close.into());
+			}
 		}
 	}
 }
@@ -119,14 +136,9 @@ impl<'a> Visitable<'a> for StyleQuery<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		match self {
 			Self::Is(feature) => Visitable::accept(feature, v),
-			Self::Not(feature) => Visitable::accept(feature.as_ref(), v),
-			Self::And(features) => {
-				for feature in features {
-					Visitable::accept(feature, v);
-				}
-			}
-			Self::Or(features) => {
-				for feature in features {
+			Self::Not(_, condition) | Self::Parens(_, condition, _) => Visitable::accept(condition.as_ref(), v),
+			Self::And(features) | Self::Or(features) => {
+				for (feature, _) in features {
 					Visitable::accept(feature, v);
 				}
 			}
@@ -134,52 +146,104 @@ impl<'a> Visitable<'a> for StyleQuery<'a> {
 	}
 }
 
+// https://drafts.csswg.org/css-conditional-5/#typedef-style-feature
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct StyleQueryFeature<'a> {
+	pub open: Option<T!['(']>,
+	pub property: Property<'a>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Parse<'a> for StyleQueryFeature<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let open = p.parse_if_peek::<T!['(']>()?;
+		let property = p.parse::<Property>()?;
+		let close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
+		Ok(Self { open, property, close })
+	}
+}
+
+impl ToCursors for StyleQueryFeature<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(open) = self.open {
+			s.append(open.into());
+		}
+		ToCursors::to_cursors(&self.property, s);
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for StyleQueryFeature<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		Visitable::accept(&self.property, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 pub enum ScrollStateQuery<'a> {
-	Is(ScrollStateFeature),
-	Not(Box<ScrollStateQuery<'a>>),
-	And(Vec<'a, ScrollStateFeature>),
-	Or(Vec<'a, ScrollStateFeature>),
+	Is(ScrollStateQueryFeature),
+	Not(T![Ident], Box<ScrollStateQuery<'a>>),
+	And(Vec<'a, (ScrollStateQueryFeature, Option<T![Ident]>)>),
+	Or(Vec<'a, (ScrollStateQueryFeature, Option<T![Ident]>)>),
+	Parens(T!['('], Box<ScrollStateQuery<'a>>, T![')']),
 }
 
 impl<'a> ConditionalAtRule<'a> for ScrollStateQuery<'a> {
-	type Feature = ScrollStateFeature;
-	fn new_is(feature: ScrollStateFeature) -> Self {
+	type Feature = ScrollStateQueryFeature;
+	fn new_is(feature: ScrollStateQueryFeature) -> Self {
 		Self::Is(feature)
 	}
-	fn new_not(condition: ScrollStateQuery<'a>) -> Self {
-		Self::Not(Box::new(condition))
+	fn new_not(keyword: T![Ident], condition: ScrollStateQuery<'a>) -> Self {
+		Self::Not(keyword, Box::new(condition))
 	}
-	fn new_and(feature: Vec<'a, ScrollStateFeature>) -> Self {
-		Self::And(feature)
+	fn new_and(features: Vec<'a, (ScrollStateQueryFeature, Option<T![Ident]>)>) -> Self {
+		Self::And(features)
 	}
-	fn new_or(feature: Vec<'a, ScrollStateFeature>) -> Self {
-		Self::Or(feature)
+	fn new_or(features: Vec<'a, (ScrollStateQueryFeature, Option<T![Ident]>)>) -> Self {
+		Self::Or(features)
+	}
+	fn new_parens(open: T!['('], condition: ScrollStateQuery<'a>, close: T![')']) -> Self {
+		Self::Parens(open, Box::new(condition), close)
 	}
 }
 
+// https://drafts.csswg.org/css-conditional-5/#typedef-scroll-state-query
 impl<'a> Parse<'a> for ScrollStateQuery<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		Self::parse_condition(p)
+		// A lone <scroll-state-feature> such as `scroll-state(stuck: top)` needs no parens.
+		if p.peek::<T!['(']>() || p.peek::<kw::Not>() {
+			Self::parse_condition(p)
+		} else {
+			p.parse::<ScrollStateQueryFeature>().map(Self::Is)
+		}
 	}
 }
 
 impl ToCursors for ScrollStateQuery<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
-			Self::Is(c) => ToCursors::to_cursors(c, s),
-			Self::Not(c) => ToCursors::to_cursors(c.as_ref(), s),
-			Self::And(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
-				}
+			Self::Is(feature) => ToCursors::to_cursors(feature, s),
+			Self::Not(keyword, condition) => {
+				s.append(keyword.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
 			}
-			Self::Or(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
+			Self::And(features) | Self::Or(features) => {
+				for (feature, keyword) in features {
+					ToCursors::to_cursors(feature, s);
+					if let Some(keyword) = keyword {
+						s.append(keyword.into());
+					}
 				}
 			}
+			Self::Parens(open, condition, close) => {
+				s.append(open.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
+				s.append(close.into());
+			}
 		}
 	}
 }
@@ -188,14 +252,9 @@ impl<'a> Visitable<'a> for ScrollStateQuery<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		match self {
 			Self::Is(feature) => Visitable::accept(feature, v),
-			Self::Not(feature) => Visitable::accept(feature.as_ref(), v),
-			Self::And(features) => {
-				for feature in features {
-					Visitable::accept(feature, v);
-				}
-			}
-			Self::Or(features) => {
-				for feature in features {
+			Self::Not(_, condition) | Self::Parens(_, condition, _) => Visitable::accept(condition.as_ref(), v),
+			Self::And(features) | Self::Or(features) => {
+				for (feature, _) in features {
 					Visitable::accept(feature, v);
 				}
 			}
@@ -203,7 +262,43 @@ impl<'a> Visitable<'a> for ScrollStateQuery<'a> {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+// https://drafts.csswg.org/css-conditional-5/#typedef-scroll-state-in-parens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ScrollStateQueryFeature {
+	pub open: Option<T!['(']>,
+	pub feature: ScrollStateFeature,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Parse<'a> for ScrollStateQueryFeature {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let open = p.parse_if_peek::<T!['(']>()?;
+		let feature = p.parse::<ScrollStateFeature>()?;
+		let close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
+		Ok(Self { open, feature, close })
+	}
+}
+
+impl ToCursors for ScrollStateQueryFeature {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(open) = self.open {
+			s.append(open.into());
+		}
+		ToCursors::to_cursors(&self.feature, s);
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for ScrollStateQueryFeature {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		Visitable::accept(&self.feature, v);
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum ScrollStateFeature {
 	Scrollable(ScrollableScrollStateFeature),
@@ -320,14 +415,16 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(WidthContainerFeature, 100);
-		assert_size!(HeightContainerFeature, 100);
-		assert_size!(InlineSizeContainerFeature, 100);
-		assert_size!(BlockSizeContainerFeature, 100);
+		assert_size!(WidthContainerFeature, 104);
+		assert_size!(HeightContainerFeature, 104);
+		assert_size!(InlineSizeContainerFeature, 104);
+		assert_size!(BlockSizeContainerFeature, 104);
 		assert_size!(AspectRatioContainerFeature, 164);
 		assert_size!(OrientationContainerFeature, 40);
-		assert_size!(StyleQuery, 400);
-		assert_size!(ScrollStateQuery, 48);
+		assert_size!(StyleQuery, 464);
+		assert_size!(StyleQueryFeature, 464);
+		assert_size!(ScrollStateQuery, 80);
+		assert_size!(ScrollStateQueryFeature, 76);
 		assert_size!(ScrollStateFeature, 44);
 		assert_size!(ScrollableScrollStateFeature, 40);
 		assert_size!(SnappedScrollStateFeature, 40);
@@ -344,6 +441,12 @@ mod tests {
 		assert_parse!(HeightContainerFeature, "height>=1400px");
 		assert_parse!(HeightContainerFeature, "100px<=height");
 		assert_parse!(HeightContainerFeature, "100px<=height>1400px");
+		assert_parse!(StyleQuery, "(--theme:dark) and (--accent:blue)", "(--theme:dark)and (--accent:blue)");
+		assert_parse!(StyleQuery, "(--theme:dark) or (--theme:light)", "(--theme:dark)or (--theme:light)");
+		assert_parse!(StyleQuery, "not (--theme:dark)");
+		assert_parse!(ScrollStateQuery, "(stuck:top) and (snapped:x)", "(stuck:top)and (snapped:x)");
+		assert_parse!(ScrollStateQuery, "(stuck:top) or (stuck:bottom)", "(stuck:top)or (stuck:bottom)");
+		assert_parse!(ScrollStateQuery, "not (stuck:top)");
 	}
 
 	#[test]
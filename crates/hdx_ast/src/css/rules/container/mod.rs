@@ -1,13 +1,13 @@
 use bumpalo::collections::Vec;
 use hdx_atom::atom;
-use hdx_lexer::{Kind, Span};
+use hdx_lexer::{Cursor, Kind, SourceOffset, Span};
 use hdx_parser::{
-	diagnostics, AtRule, ConditionalAtRule, CursorSink, Parse, Parser, Peek, PreludeList, Result as ParserResult,
-	RuleList, ToCursors, T,
+	diagnostics, start_offset, AtRule, Block, ConditionalAtRule, CursorSink, Parse, Parser, Peek, PreludeList,
+	Result as ParserResult, RuleList, State, ToCursors, T,
 };
 use hdx_proc_macro::visit;
 
-use crate::css::{stylesheet::Rule, Visit, Visitable};
+use crate::css::{properties::Property, stylesheet::Rule, Visit, VisitMut, Visitable, VisitableMut};
 
 mod features;
 pub use features::*;
@@ -61,24 +61,46 @@ impl<'a> Visitable<'a> for ContainerRule<'a> {
 		for condition in &self.query.0 {
 			Visitable::accept(condition, v);
 		}
+		for declaration in &self.block.declarations {
+			Visitable::accept(declaration, v);
+		}
 		for rule in &self.block.rules {
 			Visitable::accept(rule, v);
 		}
 	}
 }
 
+impl<'a> VisitableMut<'a> for ContainerRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_container_rule(self);
+		for declaration in &mut self.block.declarations {
+			VisitableMut::accept_mut(declaration, v);
+		}
+		for rule in &mut self.block.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct ContainerRules<'a> {
 	pub open: T!['{'],
+	// https://drafts.csswg.org/css-nesting/#conditionals
+	// Declarations are only parsed when nested inside a style rule, where they apply to the parent rule's selector.
+	pub declarations: Vec<'a, Property<'a>>,
 	pub rules: Vec<'a, Rule<'a>>,
 	pub close: Option<T!['}']>,
 }
 
 impl<'a> Parse<'a> for ContainerRules<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.is(State::Nested) {
+			let (open, declarations, rules, close) = Self::parse_block(p)?;
+			return Ok(Self { open, declarations, rules, close });
+		}
 		let (open, rules, close) = Self::parse_rule_list(p)?;
-		Ok(Self { open, rules, close })
+		Ok(Self { open, declarations: Vec::new_in(p.bump()), rules, close })
 	}
 }
 
@@ -86,12 +108,28 @@ impl<'a> RuleList<'a> for ContainerRules<'a> {
 	type Rule = Rule<'a>;
 }
 
+impl<'a> Block<'a> for ContainerRules<'a> {
+	type Declaration = Property<'a>;
+	type Rule = Rule<'a>;
+}
+
 impl<'a> ToCursors for ContainerRules<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		s.append(self.open.into());
+		// Declarations & rules may be interleaved, so each declaration is written before the first rule after it.
+		let mut declarations = self.declarations.iter().peekable();
 		for rule in &self.rules {
+			if declarations.peek().is_some() {
+				let start = start_offset(rule).unwrap_or(SourceOffset::DUMMY);
+				while let Some(declaration) = declarations.next_if(|d| start_offset(*d).is_some_and(|d| d < start)) {
+					ToCursors::to_cursors(declaration, s);
+				}
+			}
 			ToCursors::to_cursors(rule, s);
 		}
+		for declaration in declarations {
+			ToCursors::to_cursors(declaration, s);
+		}
 		if let Some(close) = self.close {
 			s.append(close.into());
 		}
@@ -168,14 +206,15 @@ impl<'a> Visitable<'a> for ContainerCondition<'a> {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum ContainerQuery<'a> {
 	Is(ContainerFeature<'a>),
-	Not(Box<ContainerQuery<'a>>),
-	And(Vec<'a, ContainerFeature<'a>>),
-	Or(Vec<'a, ContainerFeature<'a>>),
+	Not(T![Ident], Box<ContainerQuery<'a>>),
+	And(Vec<'a, (ContainerFeature<'a>, Option<T![Ident]>)>),
+	Or(Vec<'a, (ContainerFeature<'a>, Option<T![Ident]>)>),
+	Parens(T!['('], Box<ContainerQuery<'a>>, T![')']),
 }
 
 impl<'a> Peek<'a> for ContainerQuery<'a> {
 	fn peek(p: &Parser<'a>) -> bool {
-		p.peek::<T![Function]>() || p.peek::<T![Ident]>()
+		p.peek::<T![Function]>() || p.peek::<T!['(']>() || p.peek::<kw::Not>()
 	}
 }
 
@@ -190,32 +229,41 @@ impl<'a> ConditionalAtRule<'a> for ContainerQuery<'a> {
 	fn new_is(feature: ContainerFeature<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn new_not(condition: ContainerQuery<'a>) -> Self {
-		Self::Not(Box::new(condition))
+	fn new_not(keyword: T![Ident], condition: ContainerQuery<'a>) -> Self {
+		Self::Not(keyword, Box::new(condition))
 	}
-	fn new_and(feature: Vec<'a, ContainerFeature<'a>>) -> Self {
-		Self::And(feature)
+	fn new_and(features: Vec<'a, (ContainerFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::And(features)
 	}
-	fn new_or(feature: Vec<'a, ContainerFeature<'a>>) -> Self {
-		Self::Or(feature)
+	fn new_or(features: Vec<'a, (ContainerFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::Or(features)
+	}
+	fn new_parens(open: T!['('], condition: ContainerQuery<'a>, close: T![')']) -> Self {
+		Self::Parens(open, Box::new(condition), close)
 	}
 }
 
 impl<'a> ToCursors for ContainerQuery<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
-			Self::Is(c) => ToCursors::to_cursors(c, s),
-			Self::Not(c) => ToCursors::to_cursors(c.as_ref(), s),
-			Self::And(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
-				}
+			Self::Is(feature) => ToCursors::to_cursors(feature, s),
+			Self::Not(keyword, condition) => {
+				s.append(keyword.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
 			}
-			Self::Or(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
+			Self::And(features) | Self::Or(features) => {
+				for (feature, keyword) in features {
+					ToCursors::to_cursors(feature, s);
+					if let Some(keyword) = keyword {
+						s.append(keyword.into());
+					}
 				}
 			}
+			Self::Parens(open, condition, close) => {
+				s.append(open.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
+				s.append(close.into());
+			}
 		}
 	}
 }
@@ -224,14 +272,9 @@ impl<'a> Visitable<'a> for ContainerQuery<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		match self {
 			Self::Is(feature) => Visitable::accept(feature, v),
-			Self::Not(feature) => Visitable::accept(feature.as_ref(), v),
-			Self::And(features) => {
-				for feature in features {
-					Visitable::accept(feature, v);
-				}
-			}
-			Self::Or(features) => {
-				for feature in features {
+			Self::Not(_, condition) | Self::Parens(_, condition, _) => Visitable::accept(condition.as_ref(), v),
+			Self::And(features) | Self::Or(features) => {
+				for (feature, _) in features {
 					Visitable::accept(feature, v);
 				}
 			}
@@ -240,13 +283,13 @@ impl<'a> Visitable<'a> for ContainerQuery<'a> {
 }
 
 macro_rules! container_feature {
-	( $($name: ident($typ: ident): atom!($atom: tt),)+) => {
+	( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt),)+) => {
 		#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 		#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 		pub enum ContainerFeature<'a> {
-			$($name(T!['('], $typ, T![')']),)+
-			Style(StyleQuery<'a>),
-			ScrollState(ScrollStateQuery<'a>),
+			$($name(T!['('], $typ$(<$lt>)?, T![')']),)+
+			Style(T![Function], StyleQuery<'a>, T![')']),
+			ScrollState(T![Function], ScrollStateQuery<'a>, T![')']),
 		}
 	}
 }
@@ -256,12 +299,26 @@ apply_size_feature!(container_feature);
 impl<'a> Parse<'a> for ContainerFeature<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		if p.peek::<T![Function]>() {
-			todo!();
+			let function = p.parse::<T![Function]>()?;
+			let c: Cursor = function.into();
+			return match p.parse_atom_lower(c) {
+				atom!("style") => {
+					let query = p.parse::<StyleQuery>()?;
+					let close = p.parse::<T![')']>()?;
+					Ok(Self::Style(function, query, close))
+				}
+				atom!("scroll-state") => {
+					let query = p.parse::<ScrollStateQuery>()?;
+					let close = p.parse::<T![')']>()?;
+					Ok(Self::ScrollState(function, query, close))
+				}
+				atom => Err(diagnostics::UnexpectedFunction(atom, c.into()))?,
+			};
 		}
 		let open = p.parse::<T![LeftParen]>()?;
 		let mut c = p.peek_n(1);
 		macro_rules! match_feature {
-			( $($name: ident($typ: ident): atom!($atom: tt),)+) => {
+			( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt),)+) => {
 				// Only peek at the token as the underlying media feature parser needs to parse the leading atom.
 				{
 					match p.parse_atom_lower(c) {
@@ -291,15 +348,23 @@ impl<'a> Parse<'a> for ContainerFeature<'a> {
 impl ToCursors for ContainerFeature<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		macro_rules! match_feature {
-			( $($name: ident($typ: ident): atom!($atom: tt),)+) => {
+			( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt),)+) => {
 				match self {
 					$(Self::$name(open, c, close) => {
 						s.append(open.into());
 						ToCursors::to_cursors(c, s);
 						s.append(close.into());
 					},)+
-					Self::Style(c) => ToCursors::to_cursors(c, s),
-					Self::ScrollState(c) => ToCursors::to_cursors(c, s),
+					Self::Style(function, c, close) => {
+						s.append(function.into());
+						ToCursors::to_cursors(c, s);
+						s.append(close.into());
+					}
+					Self::ScrollState(function, c, close) => {
+						s.append(function.into());
+						ToCursors::to_cursors(c, s);
+						s.append(close.into());
+					}
 				}
 			};
 		}
@@ -310,11 +375,11 @@ impl ToCursors for ContainerFeature<'_> {
 impl<'a> Visitable<'a> for ContainerFeature<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		macro_rules! match_feature {
-			( $($name: ident($typ: ident): atom!($atom: tt),)+) => {
+			( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt),)+) => {
 				match self {
 					$(Self::$name(_, f, _) => Visitable::accept(f, v),)+
-					Self::Style(f) => Visitable::accept(f, v),
-					Self::ScrollState(f) => Visitable::accept(f, v),
+					Self::Style(_, f, _) => Visitable::accept(f, v),
+					Self::ScrollState(_, f, _) => Visitable::accept(f, v),
 				}
 			};
 		}
@@ -326,10 +391,10 @@ macro_rules! apply_size_feature {
 	($macro: ident) => {
 		$macro! {
 			// https://drafts.csswg.org/css-conditional-5/#container-features
-			Width(WidthContainerFeature): atom!("width"),
-			Height(HeightContainerFeature): atom!("height"),
-			InlineSize(InlineSizeContainerFeature): atom!("inline-size"),
-			BlockSize(BlockSizeContainerFeature): atom!("block-size"),
+			Width(WidthContainerFeature<'a>): atom!("width"),
+			Height(HeightContainerFeature<'a>): atom!("height"),
+			InlineSize(InlineSizeContainerFeature<'a>): atom!("inline-size"),
+			BlockSize(BlockSizeContainerFeature<'a>): atom!("block-size"),
 			AspectRatio(AspectRatioContainerFeature): atom!("aspect-ratio"),
 			Orientation(OrientationContainerFeature): atom!("orientation"),
 		}
@@ -344,10 +409,10 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(ContainerRule, 112);
+		assert_size!(ContainerRule, 144);
 		assert_size!(ContainerConditionList, 32);
-		assert_size!(ContainerCondition, 416);
-		assert_size!(ContainerQuery, 400);
+		assert_size!(ContainerCondition, 504);
+		assert_size!(ContainerQuery, 488);
 	}
 
 	#[test]
@@ -359,5 +424,47 @@ mod tests {
 		assert_parse!(ContainerRule, "@container foo (width:2px){}");
 		assert_parse!(ContainerRule, "@container foo (10em<width<10em){}");
 		assert_parse!(ContainerRule, "@container foo (width:2px){body{color:black}}");
+		assert_parse!(ContainerCondition, "style(--theme:dark)");
+		assert_parse!(ContainerCondition, "style((--theme:dark))");
+		assert_parse!(ContainerCondition, "style(color:red)");
+		assert_parse!(ContainerCondition, "scroll-state(stuck:top)");
+		assert_parse!(ContainerCondition, "scroll-state(scrollable)");
+		assert_parse!(ContainerCondition, "card scroll-state((snapped:x))");
+		assert_parse!(ContainerRule, "@container style(--theme:dark){a{color:white}}");
+		assert_parse!(ContainerRule, "@container sidebar scroll-state(stuck:top){a{color:white}}");
+		assert_parse!(ContainerQuery, "(width>400px) and (height>400px)", "(width>400px)and (height>400px)");
+		assert_parse!(
+			ContainerQuery,
+			"style(--theme:dark) or scroll-state(stuck:top)",
+			"style(--theme:dark)or scroll-state(stuck:top)"
+		);
+		assert_parse!(ContainerQuery, "not (width>400px)");
+		assert_parse!(ContainerQuery, "not ((width>400px) or (height>400px))", "not ((width>400px)or (height>400px))");
+		assert_parse!(
+			ContainerCondition,
+			"card (width>400px) and (orientation:portrait)",
+			"card (width>400px)and (orientation:portrait)"
+		);
+	}
+
+	#[test]
+	fn test_conditions() {
+		let allocator = bumpalo::Bump::default();
+		let source_text = "style((--theme:dark) and (--accent:blue)) or scroll-state(not (stuck:top))";
+		let result =
+			Parser::new(&allocator, source_text, hdx_parser::Features::default()).parse_entirely::<ContainerQuery>();
+		assert!(result.errors.is_empty(), "{:?}", result.errors);
+		let Some(ContainerQuery::Or(features)) = result.output else { panic!("expected an or condition") };
+		assert!(matches!(features[0], (ContainerFeature::Style(_, StyleQuery::And(_), _), Some(_))));
+		assert!(matches!(features[1], (ContainerFeature::ScrollState(_, ScrollStateQuery::Not(..), _), None)));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ContainerCondition, "style()");
+		assert_parse_error!(ContainerCondition, "style(--theme:dark");
+		assert_parse_error!(ContainerCondition, "scroll-state(stuck:middle)");
+		assert_parse_error!(ContainerCondition, "scroll-state(overflowing:top)");
+		assert_parse_error!(ContainerCondition, "state(--theme:dark)");
 	}
 }
@@ -1,7 +1,626 @@
-use crate::Todo;
+use bumpalo::collections::Vec;
+use hdx_atom::{atom, Atom};
+use hdx_lexer::{Cursor, Span};
+use hdx_parser::{
+	diagnostics, AtRule, CursorSink, Declaration, DeclarationList, DeclarationValue, Parse, Parser, Peek,
+	Result as ParserResult, ToCursors, T,
+};
+use hdx_proc_macro::visit;
+
+use crate::css::{types::Symbol, units::CSSInt, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-counter-styles-3/#the-counter-style-rule
-pub type CounterStyleRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct CounterStyleRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub name: T![Ident],
+	pub block: CounterStyleRuleBlock<'a>,
+}
+
+impl<'a> Parse<'a> for CounterStyleRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let start = p.offset();
+		let (at_keyword, name, block) = Self::parse_at_rule(p, Some(atom!("counter-style")))?;
+		let Some(name) = name else {
+			let c: Cursor = at_keyword.into();
+			Err(diagnostics::MissingAtRulePrelude(c.into()))?
+		};
+		let c: Cursor = name.into();
+		let atom = p.parse_atom_lower(c);
+		// The predefined counter styles which can't be overridden are also reserved here.
+		let predefined = matches!(
+			atom,
+			atom!("decimal")
+				| atom!("disc")
+				| atom!("square")
+				| atom!("circle")
+				| atom!("disclosure-open")
+				| atom!("disclosure-closed")
+		);
+		// Rules with a reserved name or an invalid set of descriptors are kept, so they can still be written, with
+		// an error which explains why they'd be ignored.
+		if predefined || CounterStyleName::is_reserved(&atom) {
+			p.push_error(diagnostics::ReservedCounterStyleName(atom, c.into()).into());
+		}
+		block.validate(p, Span::new(start, p.offset()));
+		Ok(Self { at_keyword, name, block })
+	}
+}
+
+impl<'a> AtRule<'a> for CounterStyleRule<'a> {
+	type Prelude = T![Ident];
+	type Block = CounterStyleRuleBlock<'a>;
+}
+
+impl ToCursors for CounterStyleRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		s.append(self.name.into());
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for CounterStyleRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_counter_style_rule(self);
+		for property in &self.block.properties {
+			Visitable::accept(property, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for CounterStyleRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_counter_style_rule(self);
+		for property in &mut self.block.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct CounterStyleRuleBlock<'a> {
+	pub open: T!['{'],
+	pub properties: Vec<'a, CounterStyleRuleProperty<'a>>,
+	pub close: Option<T!['}']>,
+}
+
+impl<'a> CounterStyleRuleBlock<'a> {
+	// The last declaration of a descriptor wins, as with properties.
+	fn last(&self, f: impl Fn(&CounterStyleRuleStyleValue<'a>) -> bool) -> Option<&CounterStyleRuleProperty<'a>> {
+		self.properties.iter().rev().find(|property| f(&property.value))
+	}
+
+	// https://drafts.csswg.org/css-counter-styles-3/#counter-style-system
+	// Each system needs a different set of descriptors to be able to generate a representation.
+	fn validate(&self, p: &mut Parser<'a>, span: Span) {
+		let system = self.last(|value| matches!(value, CounterStyleRuleStyleValue::System(_))).map(|property| {
+			let CounterStyleRuleStyleValue::System(system) = &property.value else { unreachable!() };
+			system
+		});
+		let symbols = self.last(|value| matches!(value, CounterStyleRuleStyleValue::Symbols(_)));
+		let additive_symbols = self.last(|value| matches!(value, CounterStyleRuleStyleValue::AdditiveSymbols(_)));
+		let name = system.map(|system| system.atom()).unwrap_or(atom!("symbolic"));
+		match system {
+			Some(CounterStyleSystem::Extends(..)) => {
+				if let Some(property) = symbols.or(additive_symbols) {
+					let c: Cursor = property.name.into();
+					p.push_error(diagnostics::CounterStyleDisallowedDescriptor(name, property.atom(), c.into()).into());
+				}
+			}
+			Some(CounterStyleSystem::Additive(_)) => {
+				if additive_symbols.is_none() {
+					p.push_error(
+						diagnostics::CounterStyleMissingDescriptor(name, atom!("additive-symbols"), span).into(),
+					);
+				}
+			}
+			_ => {
+				let Some(property) = symbols else {
+					p.push_error(diagnostics::CounterStyleMissingDescriptor(name, atom!("symbols"), span).into());
+					return;
+				};
+				let CounterStyleRuleStyleValue::Symbols(list) = &property.value else { unreachable!() };
+				if matches!(system, Some(CounterStyleSystem::Alphabetic(_) | CounterStyleSystem::Numeric(_)))
+					&& list.len() < 2
+				{
+					let c: Cursor = property.name.into();
+					p.push_error(diagnostics::CounterStyleTooFewSymbols(name, c.into()).into());
+				}
+			}
+		}
+	}
+}
+
+impl<'a> Parse<'a> for CounterStyleRuleBlock<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (open, properties, close) = Self::parse_declaration_list(p)?;
+		Ok(Self { open, properties, close })
+	}
+}
+
+impl<'a> DeclarationList<'a> for CounterStyleRuleBlock<'a> {
+	type Declaration = CounterStyleRuleProperty<'a>;
+}
+
+impl ToCursors for CounterStyleRuleBlock<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for property in &self.properties {
+			ToCursors::to_cursors(property, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename = "property"))]
+#[visit]
+pub struct CounterStyleRuleProperty<'a> {
+	pub name: T![Ident],
+	pub colon: T![:],
+	pub value: CounterStyleRuleStyleValue<'a>,
+	pub semicolon: Option<T![;]>,
+}
+
+impl CounterStyleRuleProperty<'_> {
+	fn atom(&self) -> Atom {
+		match self.value {
+			CounterStyleRuleStyleValue::System(_) => atom!("system"),
+			CounterStyleRuleStyleValue::Negative(..) => atom!("negative"),
+			CounterStyleRuleStyleValue::Prefix(_) => atom!("prefix"),
+			CounterStyleRuleStyleValue::Suffix(_) => atom!("suffix"),
+			CounterStyleRuleStyleValue::Range(_) => atom!("range"),
+			CounterStyleRuleStyleValue::Pad(..) => atom!("pad"),
+			CounterStyleRuleStyleValue::Fallback(_) => atom!("fallback"),
+			CounterStyleRuleStyleValue::Symbols(_) => atom!("symbols"),
+			CounterStyleRuleStyleValue::AdditiveSymbols(_) => atom!("additive-symbols"),
+			CounterStyleRuleStyleValue::SpeakAs(_) => atom!("speak-as"),
+		}
+	}
+}
+
+impl<'a> Parse<'a> for CounterStyleRuleProperty<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (name, colon, value, important, semicolon) = Self::parse_declaration(p)?;
+		if let Some(important) = important {
+			let c: Cursor = important.bang.into();
+			Err(diagnostics::DisallowedImportant(c.into()))?
+		}
+		Ok(Self { name, colon, value, semicolon })
+	}
+}
+
+impl<'a> Declaration<'a> for CounterStyleRuleProperty<'a> {
+	type DeclarationValue = CounterStyleRuleStyleValue<'a>;
+	fn valid_property(p: &Parser, c: Cursor) -> bool {
+		matches!(
+			p.parse_atom_lower(c),
+			atom!("system")
+				| atom!("negative")
+				| atom!("prefix")
+				| atom!("suffix")
+				| atom!("range")
+				| atom!("pad")
+				| atom!("fallback")
+				| atom!("symbols")
+				| atom!("additive-symbols")
+				| atom!("speak-as")
+		)
+	}
+}
+
+impl ToCursors for CounterStyleRuleProperty<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.name.into());
+		s.append(self.colon.into());
+		ToCursors::to_cursors(&self.value, s);
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for CounterStyleRuleProperty<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_counter_style_rule_property(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for CounterStyleRuleProperty<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_counter_style_rule_property(self);
+	}
+}
+
+// https://drafts.csswg.org/css-counter-styles-3/#counter-style-system
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CounterStyleRuleStyleValue<'a> {
+	// cyclic | numeric | alphabetic | symbolic | additive | [fixed <integer>?] | [ extends <counter-style-name> ]
+	System(CounterStyleSystem),
+	// <symbol> <symbol>?
+	Negative(Symbol<'a>, Option<Symbol<'a>>),
+	// <symbol>
+	Prefix(Symbol<'a>),
+	// <symbol>
+	Suffix(Symbol<'a>),
+	// [ [ <integer> | infinite ]{2} ]# | auto
+	Range(CounterStyleRange<'a>),
+	// <integer [0,∞]> && <symbol>
+	Pad(CounterStyleWeightedSymbol<'a>),
+	// <counter-style-name>
+	Fallback(CounterStyleName),
+	// <symbol>+
+	Symbols(Vec<'a, Symbol<'a>>),
+	// [ <integer [0,∞]> && <symbol> ]#
+	AdditiveSymbols(Vec<'a, (CounterStyleWeightedSymbol<'a>, Option<T![,]>)>),
+	// auto | bullets | numbers | words | spell-out | <counter-style-name>
+	SpeakAs(CounterStyleSpeakAs),
+}
+
+impl<'a> DeclarationValue<'a> for CounterStyleRuleStyleValue<'a> {
+	fn parse_declaration_value(name: Cursor, p: &mut Parser<'a>) -> ParserResult<Self> {
+		Ok(match p.parse_atom_lower(name) {
+			atom!("system") => Self::System(p.parse::<CounterStyleSystem>()?),
+			atom!("negative") => Self::Negative(p.parse::<Symbol>()?, p.parse_if_peek::<Symbol>()?),
+			atom!("prefix") => Self::Prefix(p.parse::<Symbol>()?),
+			atom!("suffix") => Self::Suffix(p.parse::<Symbol>()?),
+			atom!("range") => Self::Range(p.parse::<CounterStyleRange>()?),
+			atom!("pad") => Self::Pad(p.parse::<CounterStyleWeightedSymbol>()?),
+			atom!("fallback") => Self::Fallback(p.parse::<CounterStyleName>()?),
+			atom!("symbols") => {
+				let mut symbols = Vec::new_in(p.bump());
+				symbols.push(p.parse::<Symbol>()?);
+				while let Some(symbol) = p.parse_if_peek::<Symbol>()? {
+					symbols.push(symbol);
+				}
+				Self::Symbols(symbols)
+			}
+			atom!("additive-symbols") => {
+				let mut symbols = Vec::new_in(p.bump());
+				loop {
+					let symbol = p.parse::<CounterStyleWeightedSymbol>()?;
+					let comma = p.parse_if_peek::<T![,]>()?;
+					let done = comma.is_none();
+					symbols.push((symbol, comma));
+					if done {
+						break;
+					}
+				}
+				Self::AdditiveSymbols(symbols)
+			}
+			atom!("speak-as") => Self::SpeakAs(p.parse::<CounterStyleSpeakAs>()?),
+			_ => Err(diagnostics::UnknownDeclaration(name.into()))?,
+		})
+	}
+}
+
+impl ToCursors for CounterStyleRuleStyleValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::System(system) => ToCursors::to_cursors(system, s),
+			Self::Negative(first, second) => {
+				ToCursors::to_cursors(first, s);
+				if let Some(second) = second {
+					ToCursors::to_cursors(second, s);
+				}
+			}
+			Self::Prefix(symbol) | Self::Suffix(symbol) => ToCursors::to_cursors(symbol, s),
+			Self::Range(range) => ToCursors::to_cursors(range, s),
+			Self::Pad(pad) => ToCursors::to_cursors(pad, s),
+			Self::Fallback(name) => ToCursors::to_cursors(name, s),
+			Self::SpeakAs(speak_as) => ToCursors::to_cursors(speak_as, s),
+			Self::Symbols(symbols) => {
+				for symbol in symbols {
+					ToCursors::to_cursors(symbol, s);
+				}
+			}
+			Self::AdditiveSymbols(symbols) => {
+				for (symbol, comma) in symbols {
+					ToCursors::to_cursors(symbol, s);
+					if let Some(comma) = comma {
+						s.append(comma.into());
+					}
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-counter-styles-3/#typedef-counter-style-name
+// A <custom-ident> which isn't `none`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct CounterStyleName(pub T![Ident]);
+
+impl CounterStyleName {
+	// https://drafts.csswg.org/css-values-4/#custom-idents
+	fn is_reserved(atom: &Atom) -> bool {
+		matches!(
+			atom,
+			&atom!("none")
+				| &atom!("initial")
+				| &atom!("inherit")
+				| &atom!("unset")
+				| &atom!("revert")
+				| &atom!("revert-layer")
+				| &atom!("default")
+		)
+	}
+}
+
+impl<'a> Peek<'a> for CounterStyleName {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![Ident]>()
+	}
+}
+
+impl<'a> Parse<'a> for CounterStyleName {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let ident = p.parse::<T![Ident]>()?;
+		let c: Cursor = ident.into();
+		let atom = p.parse_atom_lower(c);
+		if Self::is_reserved(&atom) {
+			Err(diagnostics::ReservedCounterStyleName(atom, c.into()))?
+		}
+		Ok(Self(ident))
+	}
+}
+
+impl ToCursors for CounterStyleName {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.0.into());
+	}
+}
+
+// https://drafts.csswg.org/css-counter-styles-3/#counter-style-speak-as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CounterStyleSpeakAs {
+	Auto(T![Ident]),
+	Bullets(T![Ident]),
+	Numbers(T![Ident]),
+	Words(T![Ident]),
+	SpellOut(T![Ident]),
+	Name(CounterStyleName),
+}
+
+impl<'a> Parse<'a> for CounterStyleSpeakAs {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let c = p.peek_n(1);
+		if !p.peek::<T![Ident]>() {
+			return p.parse::<CounterStyleName>().map(Self::Name);
+		}
+		Ok(match p.parse_atom_lower(c) {
+			atom!("auto") => Self::Auto(p.parse::<T![Ident]>()?),
+			atom!("bullets") => Self::Bullets(p.parse::<T![Ident]>()?),
+			atom!("numbers") => Self::Numbers(p.parse::<T![Ident]>()?),
+			atom!("words") => Self::Words(p.parse::<T![Ident]>()?),
+			atom!("spell-out") => Self::SpellOut(p.parse::<T![Ident]>()?),
+			_ => Self::Name(p.parse::<CounterStyleName>()?),
+		})
+	}
+}
+
+impl ToCursors for CounterStyleSpeakAs {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Auto(ident)
+			| Self::Bullets(ident)
+			| Self::Numbers(ident)
+			| Self::Words(ident)
+			| Self::SpellOut(ident) => s.append(ident.into()),
+			Self::Name(name) => ToCursors::to_cursors(name, s),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-counter-styles-3/#counter-style-system
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CounterStyleSystem {
+	Cyclic(T![Ident]),
+	Numeric(T![Ident]),
+	Alphabetic(T![Ident]),
+	Symbolic(T![Ident]),
+	Additive(T![Ident]),
+	Fixed(T![Ident], Option<CSSInt>),
+	Extends(T![Ident], CounterStyleName),
+}
+
+impl CounterStyleSystem {
+	fn atom(&self) -> Atom {
+		match self {
+			Self::Cyclic(_) => atom!("cyclic"),
+			Self::Numeric(_) => atom!("numeric"),
+			Self::Alphabetic(_) => atom!("alphabetic"),
+			Self::Symbolic(_) => atom!("symbolic"),
+			Self::Additive(_) => atom!("additive"),
+			Self::Fixed(..) => atom!("fixed"),
+			Self::Extends(..) => atom!("extends"),
+		}
+	}
+}
+
+impl<'a> Parse<'a> for CounterStyleSystem {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let ident = p.parse::<T![Ident]>()?;
+		let c: Cursor = ident.into();
+		Ok(match p.parse_atom_lower(c) {
+			atom!("cyclic") => Self::Cyclic(ident),
+			atom!("numeric") => Self::Numeric(ident),
+			atom!("alphabetic") => Self::Alphabetic(ident),
+			atom!("symbolic") => Self::Symbolic(ident),
+			atom!("additive") => Self::Additive(ident),
+			atom!("fixed") => Self::Fixed(ident, p.parse_if_peek::<CSSInt>()?),
+			atom!("extends") => Self::Extends(ident, p.parse::<CounterStyleName>()?),
+			atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
+		})
+	}
+}
+
+impl ToCursors for CounterStyleSystem {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Cyclic(ident)
+			| Self::Numeric(ident)
+			| Self::Alphabetic(ident)
+			| Self::Symbolic(ident)
+			| Self::Additive(ident) => s.append(ident.into()),
+			Self::Fixed(ident, first) => {
+				s.append(ident.into());
+				if let Some(first) = first {
+					s.append((*first).into());
+				}
+			}
+			Self::Extends(ident, name) => {
+				s.append(ident.into());
+				ToCursors::to_cursors(name, s);
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-counter-styles-3/#counter-style-range
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CounterStyleRange<'a> {
+	Auto(T![Ident]),
+	Ranges(Vec<'a, (CounterStyleRangeBound, CounterStyleRangeBound, Option<T![,]>)>),
+}
+
+impl<'a> Parse<'a> for CounterStyleRange<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![Ident]>() && p.parse_atom_lower(p.peek_n(1)) == atom!("auto") {
+			return p.parse::<T![Ident]>().map(Self::Auto);
+		}
+		let mut ranges = Vec::new_in(p.bump());
+		loop {
+			let lower = p.parse::<CounterStyleRangeBound>()?;
+			let upper = p.parse::<CounterStyleRangeBound>()?;
+			if let (CounterStyleRangeBound::Integer(l), CounterStyleRangeBound::Integer(u)) = (lower, upper) {
+				if i32::from(l) > i32::from(u) {
+					let start: Cursor = l.into();
+					let end: Cursor = u.into();
+					p.push_error(diagnostics::CounterStyleInvalidRange(Span::from(start) + Span::from(end)).into());
+				}
+			}
+			let comma = p.parse_if_peek::<T![,]>()?;
+			let done = comma.is_none();
+			ranges.push((lower, upper, comma));
+			if done {
+				return Ok(Self::Ranges(ranges));
+			}
+		}
+	}
+}
+
+impl ToCursors for CounterStyleRange<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Auto(ident) => s.append(ident.into()),
+			Self::Ranges(ranges) => {
+				for (lower, upper, comma) in ranges {
+					s.append(lower.into());
+					s.append(upper.into());
+					if let Some(comma) = comma {
+						s.append(comma.into());
+					}
+				}
+			}
+		}
+	}
+}
+
+// <integer> | infinite
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CounterStyleRangeBound {
+	Integer(CSSInt),
+	Infinite(T![Ident]),
+}
+
+impl<'a> Parse<'a> for CounterStyleRangeBound {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<CSSInt>() {
+			return p.parse::<CSSInt>().map(Self::Integer);
+		}
+		let ident = p.parse::<T![Ident]>()?;
+		let c: Cursor = ident.into();
+		match p.parse_atom_lower(c) {
+			atom!("infinite") => Ok(Self::Infinite(ident)),
+			atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
+		}
+	}
+}
+
+impl From<&CounterStyleRangeBound> for Cursor {
+	fn from(value: &CounterStyleRangeBound) -> Self {
+		match value {
+			CounterStyleRangeBound::Integer(int) => (*int).into(),
+			CounterStyleRangeBound::Infinite(ident) => ident.into(),
+		}
+	}
+}
+
+// <integer [0,∞]> && <symbol>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CounterStyleWeightedSymbol<'a> {
+	WeightFirst(CSSInt, Symbol<'a>),
+	SymbolFirst(Symbol<'a>, CSSInt),
+}
+
+impl<'a> Parse<'a> for CounterStyleWeightedSymbol<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let value = if p.peek::<CSSInt>() {
+			Self::WeightFirst(p.parse::<CSSInt>()?, p.parse::<Symbol>()?)
+		} else {
+			Self::SymbolFirst(p.parse::<Symbol>()?, p.parse::<CSSInt>()?)
+		};
+		let weight = value.weight();
+		if i32::from(weight) < 0 {
+			let c: Cursor = weight.into();
+			Err(diagnostics::NumberTooSmall(0.0, c.into()))?
+		}
+		Ok(value)
+	}
+}
+
+impl CounterStyleWeightedSymbol<'_> {
+	pub fn weight(&self) -> CSSInt {
+		match self {
+			Self::WeightFirst(weight, _) | Self::SymbolFirst(_, weight) => *weight,
+		}
+	}
+}
+
+impl ToCursors for CounterStyleWeightedSymbol<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::WeightFirst(weight, symbol) => {
+				s.append((*weight).into());
+				ToCursors::to_cursors(symbol, s);
+			}
+			Self::SymbolFirst(symbol, weight) => {
+				ToCursors::to_cursors(symbol, s);
+				s.append((*weight).into());
+			}
+		}
+	}
+}
+
+impl<'a> Peek<'a> for CounterStyleWeightedSymbol<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CSSInt>() || p.peek::<Symbol>()
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +629,73 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(CounterStyleRule, 0);
+		assert_size!(CounterStyleRule, 88);
+		assert_size!(CounterStyleRuleProperty, 456);
+		assert_size!(CounterStyleRuleStyleValue, 416);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(CounterStyleRule, "@counter-style thumbs {}");
+		assert_parse!(CounterStyleRule, "@counter-style thumbs{system:cyclic;symbols:'👍';suffix:' ';}");
+		assert_parse!(CounterStyleRule, "@counter-style thumbs{symbols:'👍'}");
+		assert_parse!(CounterStyleRule, "@counter-style letters{system:alphabetic;symbols:a b c;}");
+		assert_parse!(
+			CounterStyleRule,
+			"@counter-style circled{system:fixed 1;symbols:url(one.svg) url(two.svg);}",
+			"@counter-style circled{system:fixed 1;symbols:url(one.svg)url(two.svg);}"
+		);
+		assert_parse!(
+			CounterStyleRule,
+			"@counter-style roman{system:additive;additive-symbols:10 X,5 V,'I' 1;range:1 3999;pad:2 '0';}",
+			"@counter-style roman{system:additive;additive-symbols:10 X,5 V,'I'1;range:1 3999;pad:2'0';}"
+		);
+		assert_parse!(
+			CounterStyleRule,
+			"@counter-style negative{system:extends decimal;negative:'(' ')';range:infinite -1,1 infinite;}",
+			"@counter-style negative{system:extends decimal;negative:'('')';range:infinite -1,1 infinite;}"
+		);
+		assert_parse!(
+			CounterStyleRule,
+			"@counter-style dots{system:cyclic;symbols:'•';prefix:'';fallback:disc;speak-as:bullets;range:auto;}"
+		);
+		assert_parse!(CounterStyleRule, "@counter-style thumbs{symbols:'👍';fallback:decimal;speak-as:spell-out;}");
+		assert_parse!(CounterStyleRule, "@counter-style thumbs{symbols:'👍';speak-as:thumbs;}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CounterStyleRule, "@counter-style{symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style none{symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style decimal{symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{color:red;symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{symbols:'a' !important}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{system:additive;symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{system:alphabetic;symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{system:extends decimal;symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{system:unknown;symbols:'a'}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{symbols:'a';range:10 1;}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{system:additive;additive-symbols:-1 'a';}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{symbols:'a';fallback:none;}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{symbols:'a';speak-as:inherit;}");
+		assert_parse_error!(CounterStyleRule, "@counter-style thumbs{system:extends none;}");
+		assert_parse_error!(CounterStyleRule, "@counter-style inherit{symbols:'a'}");
+	}
+
+	#[test]
+	fn test_invalid_rules_are_kept() {
+		for source_text in [
+			"@counter-style none{symbols:'a'}",
+			"@counter-style thumbs{system:additive;symbols:'a'}",
+			"@counter-style thumbs{system:alphabetic;symbols:'a'}",
+			"@counter-style thumbs{symbols:'a';range:10 1;}",
+		] {
+			let allocator = bumpalo::Bump::default();
+			let result = Parser::new(&allocator, source_text, hdx_parser::Features::default())
+				.parse_entirely::<crate::css::StyleSheet>();
+			assert_eq!(result.errors.len(), 1, "{}", source_text);
+			let rules = &result.output.unwrap().rules;
+			assert!(matches!(rules[..], [crate::css::Rule::CounterStyleRule(_)]), "{}", source_text);
+		}
 	}
 }
@@ -6,7 +6,7 @@ use hdx_parser::{
 };
 use hdx_proc_macro::visit;
 
-use crate::css::{stylesheet::Rule, Visit, Visitable};
+use crate::css::{stylesheet::Rule, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://www.w3.org/TR/2012/WD-css3-conditional-20120911/#at-document
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -51,6 +51,14 @@ impl<'a> Visitable<'a> for DocumentRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for DocumentRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_document_rule(self);
+		VisitableMut::accept_mut(&mut self.matchers, v);
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct DocumentMatcherList<'a>(pub Vec<'a, (DocumentMatcher, Option<T![,]>)>);
@@ -84,6 +92,14 @@ impl<'a> Visitable<'a> for DocumentMatcherList<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for DocumentMatcherList<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for (matcher, _) in &mut self.0 {
+			VisitableMut::accept_mut(matcher, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
@@ -158,6 +174,12 @@ impl<'a> Visitable<'a> for DocumentMatcher {
 	}
 }
 
+impl<'a> VisitableMut<'a> for DocumentMatcher {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_document_matcher(self);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
 pub struct DocumentRuleBlock<'a> {
@@ -198,6 +220,14 @@ impl<'a> Visitable<'a> for DocumentRuleBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for DocumentRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for rule in &mut self.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
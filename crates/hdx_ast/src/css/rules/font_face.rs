@@ -7,7 +7,7 @@ use hdx_parser::{
 };
 use hdx_proc_macro::visit;
 
-use crate::css::{properties::StyleValue, Visit, Visitable};
+use crate::css::{properties::StyleValue, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-fonts/#font-face-rule
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -44,6 +44,13 @@ impl<'a> Visitable<'a> for FontFaceRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for FontFaceRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_face_rule(self);
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct FontFaceRuleBlock<'a> {
@@ -83,6 +90,14 @@ impl<'a> Visitable<'a> for FontFaceRuleBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for FontFaceRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for property in &mut self.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename = "property"))]
 #[visit]
@@ -145,6 +160,13 @@ impl<'a> Visitable<'a> for FontFaceRuleProperty<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for FontFaceRuleProperty<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_face_rule_property(self);
+		VisitableMut::accept_mut(&mut self.value, v);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1,7 +1,357 @@
-use crate::Todo;
+use bumpalo::collections::Vec;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{
+	diagnostics, keyword_typedef, AtRule, CursorSink, Declaration, DeclarationList, DeclarationRuleList,
+	DeclarationValue, NoPreludeAllowed, Parse, Parser, Result as ParserResult, ToCursors, T,
+};
+use hdx_proc_macro::visit;
+
+use crate::css::{types::FamilyNameList, units::CSSInt, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-fonts/#at-ruledef-font-feature-values
-pub type FontFeatureValuesRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct FontFeatureValuesRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub family_names: FamilyNameList<'a>,
+	pub block: FontFeatureValuesRuleBlock<'a>,
+}
+
+impl<'a> Parse<'a> for FontFeatureValuesRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (at_keyword, family_names, block) = Self::parse_at_rule(p, Some(atom!("font-feature-values")))?;
+		if let Some(family_names) = family_names {
+			Ok(Self { at_keyword, family_names, block })
+		} else {
+			let c: Cursor = at_keyword.into();
+			Err(diagnostics::MissingAtRulePrelude(c.into()))?
+		}
+	}
+}
+
+impl<'a> AtRule<'a> for FontFeatureValuesRule<'a> {
+	type Prelude = FamilyNameList<'a>;
+	type Block = FontFeatureValuesRuleBlock<'a>;
+}
+
+impl ToCursors for FontFeatureValuesRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		ToCursors::to_cursors(&self.family_names, s);
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for FontFeatureValuesRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_font_feature_values_rule(self);
+		for property in &self.block.properties {
+			Visitable::accept(property, v);
+		}
+		for rule in &self.block.rules {
+			Visitable::accept(rule, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for FontFeatureValuesRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_feature_values_rule(self);
+		for property in &mut self.block.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+		for rule in &mut self.block.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontFeatureValuesRuleBlock<'a> {
+	pub open: T!['{'],
+	pub properties: Vec<'a, FontFeatureValuesRuleProperty>,
+	pub rules: Vec<'a, FontFeatureValueBlock<'a>>,
+	pub close: Option<T!['}']>,
+}
+
+impl<'a> Parse<'a> for FontFeatureValuesRuleBlock<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (open, properties, rules, close) = Self::parse_declaration_rule_list(p)?;
+		Ok(Self { open, properties, rules, close })
+	}
+}
+
+impl<'a> DeclarationRuleList<'a> for FontFeatureValuesRuleBlock<'a> {
+	type Declaration = FontFeatureValuesRuleProperty;
+	type AtRule = FontFeatureValueBlock<'a>;
+}
+
+impl ToCursors for FontFeatureValuesRuleBlock<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for property in &self.properties {
+			ToCursors::to_cursors(property, s);
+		}
+		for rule in &self.rules {
+			ToCursors::to_cursors(rule, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts/#font-display-font-feature-values
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename = "property"))]
+#[visit]
+pub struct FontFeatureValuesRuleProperty {
+	pub name: T![Ident],
+	pub colon: T![:],
+	pub value: FontDisplay,
+	pub semicolon: Option<T![;]>,
+}
+
+impl<'a> Parse<'a> for FontFeatureValuesRuleProperty {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (name, colon, value, important, semicolon) = Self::parse_declaration(p)?;
+		if let Some(important) = important {
+			let c: Cursor = important.bang.into();
+			Err(diagnostics::DisallowedImportant(c.into()))?
+		}
+		Ok(Self { name, colon, value, semicolon })
+	}
+}
+
+impl Declaration<'_> for FontFeatureValuesRuleProperty {
+	type DeclarationValue = FontDisplay;
+	fn valid_property(p: &Parser, c: Cursor) -> bool {
+		matches!(p.parse_atom_lower(c), atom!("font-display"))
+	}
+}
+
+impl ToCursors for FontFeatureValuesRuleProperty {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.name.into());
+		s.append(self.colon.into());
+		s.append(self.value.into());
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for FontFeatureValuesRuleProperty {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_font_feature_values_rule_property(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for FontFeatureValuesRuleProperty {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_feature_values_rule_property(self);
+	}
+}
+
+// https://drafts.csswg.org/css-fonts/#font-display-desc
+keyword_typedef!(FontDisplay {
+	Auto: atom!("auto"),
+	Block: atom!("block"),
+	Swap: atom!("swap"),
+	Fallback: atom!("fallback"),
+	Optional: atom!("optional"),
+});
+
+impl<'a> DeclarationValue<'a> for FontDisplay {
+	fn parse_declaration_value(_: Cursor, p: &mut Parser<'a>) -> ParserResult<Self> {
+		p.parse::<Self>()
+	}
+}
+
+// https://drafts.csswg.org/css-fonts/#feature-value-blocks
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
+pub enum FontFeatureValueType {
+	Stylistic,
+	HistoricalForms,
+	Styleset,
+	CharacterVariant,
+	Swash,
+	Ornaments,
+	Annotation,
+}
+
+impl FontFeatureValueType {
+	// The number of values each feature value declaration may have, `None` meaning any number.
+	pub fn max_values(&self) -> Option<usize> {
+		match self {
+			Self::Styleset => None,
+			Self::CharacterVariant => Some(2),
+			_ => Some(1),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct FontFeatureValueBlock<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub feature: FontFeatureValueType,
+	pub block: FontFeatureValueDeclarations<'a>,
+}
+
+impl<'a> Parse<'a> for FontFeatureValueBlock<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let c = p.peek_n(1);
+		let feature = match p.parse_atom_lower(c) {
+			atom!("stylistic") => FontFeatureValueType::Stylistic,
+			atom!("historical-forms") => FontFeatureValueType::HistoricalForms,
+			atom!("styleset") => FontFeatureValueType::Styleset,
+			atom!("character-variant") => FontFeatureValueType::CharacterVariant,
+			atom!("swash") => FontFeatureValueType::Swash,
+			atom!("ornaments") => FontFeatureValueType::Ornaments,
+			atom!("annotation") => FontFeatureValueType::Annotation,
+			atom => Err(diagnostics::UnexpectedAtRule(atom, c.into()))?,
+		};
+		let (at_keyword, _, block) = Self::parse_at_rule(p, None)?;
+		if let Some(max) = feature.max_values() {
+			for declaration in &block.declarations {
+				if declaration.values.len() > max {
+					let atom = p.parse_atom_lower(at_keyword.into());
+					let c: Cursor = declaration.name.into();
+					Err(diagnostics::TooManyFontFeatureValues(atom, max, c.into()))?
+				}
+			}
+		}
+		Ok(Self { at_keyword, feature, block })
+	}
+}
+
+impl<'a> AtRule<'a> for FontFeatureValueBlock<'a> {
+	type Prelude = NoPreludeAllowed;
+	type Block = FontFeatureValueDeclarations<'a>;
+}
+
+impl ToCursors for FontFeatureValueBlock<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for FontFeatureValueBlock<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_font_feature_value_block(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for FontFeatureValueBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_feature_value_block(self);
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontFeatureValueDeclarations<'a> {
+	pub open: T!['{'],
+	pub declarations: Vec<'a, FontFeatureValueDeclaration<'a>>,
+	pub close: Option<T!['}']>,
+}
+
+impl<'a> Parse<'a> for FontFeatureValueDeclarations<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (open, declarations, close) = Self::parse_declaration_list(p)?;
+		Ok(Self { open, declarations, close })
+	}
+}
+
+impl<'a> DeclarationList<'a> for FontFeatureValueDeclarations<'a> {
+	type Declaration = FontFeatureValueDeclaration<'a>;
+}
+
+impl ToCursors for FontFeatureValueDeclarations<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for declaration in &self.declarations {
+			ToCursors::to_cursors(declaration, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts/#feature-value-blocks
+// Each declaration names a set of feature indexes: <ident>: <integer [0,∞]>+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontFeatureValueDeclaration<'a> {
+	pub name: T![Ident],
+	pub colon: T![:],
+	pub values: FontFeatureValueList<'a>,
+	pub semicolon: Option<T![;]>,
+}
+
+impl<'a> Parse<'a> for FontFeatureValueDeclaration<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (name, colon, values, important, semicolon) = Self::parse_declaration(p)?;
+		if let Some(important) = important {
+			let c: Cursor = important.bang.into();
+			Err(diagnostics::DisallowedImportant(c.into()))?
+		}
+		Ok(Self { name, colon, values, semicolon })
+	}
+}
+
+impl<'a> Declaration<'a> for FontFeatureValueDeclaration<'a> {
+	type DeclarationValue = FontFeatureValueList<'a>;
+}
+
+impl ToCursors for FontFeatureValueDeclaration<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.name.into());
+		s.append(self.colon.into());
+		for value in self.values.iter() {
+			s.append((*value).into());
+		}
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontFeatureValueList<'a>(pub Vec<'a, CSSInt>);
+
+impl<'a> std::ops::Deref for FontFeatureValueList<'a> {
+	type Target = Vec<'a, CSSInt>;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<'a> DeclarationValue<'a> for FontFeatureValueList<'a> {
+	fn parse_declaration_value(_: Cursor, p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut values = Vec::new_in(p.bump());
+		loop {
+			let value = p.parse::<CSSInt>()?;
+			if i32::from(value) < 0 {
+				let c: Cursor = value.into();
+				Err(diagnostics::NumberTooSmall(0.0, c.into()))?
+			}
+			values.push(value);
+			if !p.peek::<CSSInt>() {
+				return Ok(Self(values));
+			}
+		}
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +360,33 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(FontFeatureValuesRule, 0);
+		assert_size!(FontFeatureValuesRule, 144);
+		assert_size!(FontFeatureValueBlock, 80);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(FontFeatureValuesRule, "@font-feature-values Taisho Gothic {}");
+		assert_parse!(FontFeatureValuesRule, "@font-feature-values Taisho Gothic{}");
+		assert_parse!(FontFeatureValuesRule, "@font-feature-values Bungee,'Bungee Color'{font-display:swap;}");
+		assert_parse!(
+			FontFeatureValuesRule,
+			"@font-feature-values Jupiter Sans{@styleset{nice:12;sharp:1 3 5;}@swash{swishy:1;}}"
+		);
+		assert_parse!(
+			FontFeatureValuesRule,
+			"@font-feature-values Foo{@character-variant{alt-g:1 2;}@stylistic{alt:2}@annotation{circled:1;}}"
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values{}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{@unknown{nice:1;}}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{@swash{swishy:1 2;}}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{@character-variant{alt-g:1 2 3;}}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{@styleset{nice:-1;}}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{@styleset{nice:red;}}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{font-display:red;}");
+		assert_parse_error!(FontFeatureValuesRule, "@font-feature-values Foo{.foo{}}");
 	}
 }
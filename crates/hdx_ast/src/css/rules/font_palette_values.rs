@@ -1,7 +1,258 @@
-use crate::Todo;
+use bumpalo::collections::Vec;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{
+	diagnostics, AtRule, Build, CursorSink, Declaration, DeclarationList, DeclarationValue, Is, Parse, Parser,
+	Result as ParserResult, ToCursors, T,
+};
+use hdx_proc_macro::visit;
+
+use crate::css::{
+	types::{Color, FamilyNameList},
+	units::CSSInt,
+	Visit, VisitMut, Visitable, VisitableMut,
+};
 
 // https://drafts.csswg.org/css-fonts/#at-ruledef-font-palette-values
-pub type FontPaletteValuesRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct FontPaletteValuesRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub name: T![DashedIdent],
+	pub block: FontPaletteValuesRuleBlock<'a>,
+}
+
+impl<'a> Parse<'a> for FontPaletteValuesRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (at_keyword, name, block) = Self::parse_at_rule(p, Some(atom!("font-palette-values")))?;
+		let Some(name) = name else {
+			let c: Cursor = at_keyword.into();
+			Err(diagnostics::MissingAtRulePrelude(c.into()))?
+		};
+		// A palette with any other name can't be used by font-palette, so the rule is kept but reported.
+		let c: Cursor = name.into();
+		if !<T![DashedIdent]>::is(p, c) {
+			p.push_error(diagnostics::FontPaletteValuesNameMustBeDashed(p.parse_atom(c), c.into()).into());
+		}
+		Ok(Self { at_keyword, name: <T![DashedIdent]>::build(p, c), block })
+	}
+}
+
+impl<'a> AtRule<'a> for FontPaletteValuesRule<'a> {
+	type Prelude = T![Ident];
+	type Block = FontPaletteValuesRuleBlock<'a>;
+}
+
+impl ToCursors for FontPaletteValuesRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		s.append(self.name.into());
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for FontPaletteValuesRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_font_palette_values_rule(self);
+		for property in &self.block.properties {
+			Visitable::accept(property, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for FontPaletteValuesRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_palette_values_rule(self);
+		for property in &mut self.block.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontPaletteValuesRuleBlock<'a> {
+	pub open: T!['{'],
+	pub properties: Vec<'a, FontPaletteValuesRuleProperty<'a>>,
+	pub close: Option<T!['}']>,
+}
+
+impl<'a> Parse<'a> for FontPaletteValuesRuleBlock<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (open, properties, close) = Self::parse_declaration_list(p)?;
+		Ok(Self { open, properties, close })
+	}
+}
+
+impl<'a> DeclarationList<'a> for FontPaletteValuesRuleBlock<'a> {
+	type Declaration = FontPaletteValuesRuleProperty<'a>;
+}
+
+impl ToCursors for FontPaletteValuesRuleBlock<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for property in &self.properties {
+			ToCursors::to_cursors(property, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename = "property"))]
+#[visit]
+pub struct FontPaletteValuesRuleProperty<'a> {
+	pub name: T![Ident],
+	pub colon: T![:],
+	pub value: FontPaletteValuesRuleStyleValue<'a>,
+	pub semicolon: Option<T![;]>,
+}
+
+impl<'a> Parse<'a> for FontPaletteValuesRuleProperty<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (name, colon, value, important, semicolon) = Self::parse_declaration(p)?;
+		if let Some(important) = important {
+			let c: Cursor = important.bang.into();
+			Err(diagnostics::DisallowedImportant(c.into()))?
+		}
+		Ok(Self { name, colon, value, semicolon })
+	}
+}
+
+impl<'a> Declaration<'a> for FontPaletteValuesRuleProperty<'a> {
+	type DeclarationValue = FontPaletteValuesRuleStyleValue<'a>;
+	fn valid_property(p: &Parser, c: Cursor) -> bool {
+		matches!(p.parse_atom_lower(c), atom!("font-family") | atom!("base-palette") | atom!("override-colors"))
+	}
+}
+
+impl ToCursors for FontPaletteValuesRuleProperty<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.name.into());
+		s.append(self.colon.into());
+		ToCursors::to_cursors(&self.value, s);
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for FontPaletteValuesRuleProperty<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_font_palette_values_rule_property(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for FontPaletteValuesRuleProperty<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_font_palette_values_rule_property(self);
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum FontPaletteValuesRuleStyleValue<'a> {
+	// https://drafts.csswg.org/css-fonts/#font-family-2-desc
+	FontFamily(FamilyNameList<'a>),
+	// https://drafts.csswg.org/css-fonts/#base-palette-desc
+	BasePalette(BasePalette),
+	// https://drafts.csswg.org/css-fonts/#override-color
+	// [ <integer [0,∞]> <color> ]#
+	OverrideColors(Vec<'a, (CSSInt, Color<'a>, Option<T![,]>)>),
+}
+
+impl<'a> DeclarationValue<'a> for FontPaletteValuesRuleStyleValue<'a> {
+	fn parse_declaration_value(name: Cursor, p: &mut Parser<'a>) -> ParserResult<Self> {
+		Ok(match p.parse_atom_lower(name) {
+			atom!("font-family") => Self::FontFamily(p.parse::<FamilyNameList>()?),
+			atom!("base-palette") => Self::BasePalette(p.parse::<BasePalette>()?),
+			atom!("override-colors") => {
+				let mut colors = Vec::new_in(p.bump());
+				loop {
+					let index = parse_palette_index(p)?;
+					// The override color must be an absolute color, so currentcolor is not allowed.
+					let c = p.peek_n(1);
+					if p.peek::<T![Ident]>() && p.parse_atom_lower(c) == atom!("currentcolor") {
+						Err(diagnostics::UnexpectedIdent(atom!("currentcolor"), c.into()))?
+					}
+					let color = p.parse::<Color>()?;
+					let comma = p.parse_if_peek::<T![,]>()?;
+					let done = comma.is_none();
+					colors.push((index, color, comma));
+					if done {
+						break;
+					}
+				}
+				Self::OverrideColors(colors)
+			}
+			atom => Err(diagnostics::UnexpectedIdent(atom, name.into()))?,
+		})
+	}
+}
+
+impl ToCursors for FontPaletteValuesRuleStyleValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::FontFamily(names) => ToCursors::to_cursors(names, s),
+			Self::BasePalette(palette) => ToCursors::to_cursors(palette, s),
+			Self::OverrideColors(colors) => {
+				for (index, color, comma) in colors {
+					s.append((*index).into());
+					ToCursors::to_cursors(color, s);
+					if let Some(comma) = comma {
+						s.append(comma.into());
+					}
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts/#base-palette-desc
+// light | dark | <integer [0,∞]>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum BasePalette {
+	Light(T![Ident]),
+	Dark(T![Ident]),
+	Integer(CSSInt),
+}
+
+impl<'a> Parse<'a> for BasePalette {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![Ident]>() {
+			let ident = p.parse::<T![Ident]>()?;
+			let c: Cursor = ident.into();
+			return match p.parse_atom_lower(c) {
+				atom!("light") => Ok(Self::Light(ident)),
+				atom!("dark") => Ok(Self::Dark(ident)),
+				atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
+			};
+		}
+		parse_palette_index(p).map(Self::Integer)
+	}
+}
+
+impl ToCursors for BasePalette {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Light(ident) | Self::Dark(ident) => s.append(ident.into()),
+			Self::Integer(int) => s.append((*int).into()),
+		}
+	}
+}
+
+fn parse_palette_index(p: &mut Parser) -> ParserResult<CSSInt> {
+	let index = p.parse::<CSSInt>()?;
+	if i32::from(index) < 0 {
+		let c: Cursor = index.into();
+		Err(diagnostics::NumberTooSmall(0.0, c.into()))?
+	}
+	Ok(index)
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +261,44 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(FontPaletteValuesRule, 0);
+		assert_size!(FontPaletteValuesRule, 88);
+		assert_size!(FontPaletteValuesRuleStyleValue, 40);
+		assert_size!(BasePalette, 16);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(FontPaletteValuesRule, "@font-palette-values --cooler {}");
+		assert_parse!(FontPaletteValuesRule, "@font-palette-values --cooler{}");
+		assert_parse!(FontPaletteValuesRule, "@font-palette-values --cooler{font-family:Bixa;base-palette:1;}");
+		assert_parse!(
+			FontPaletteValuesRule,
+			"@font-palette-values --dark{font-family:'Bungee Spice';base-palette:dark}"
+		);
+		assert_parse!(
+			FontPaletteValuesRule,
+			"@font-palette-values --cooler{font-family:Bixa;override-colors:1 #7EB7E4,3 rgb(0 0 255);}",
+			"@font-palette-values --cooler{font-family:Bixa;override-colors:1#7EB7E4,3 rgb(0 0 255);}"
+		);
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values{}");
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values cooler{}");
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values --cooler{base-palette:-1}");
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values --cooler{base-palette:red}");
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values --cooler{override-colors:1 currentcolor}");
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values --cooler{color:red}");
+		assert_parse_error!(FontPaletteValuesRule, "@font-palette-values --cooler{base-palette:1!important}");
+	}
+
+	#[test]
+	fn test_undashed_name() {
+		let allocator = bumpalo::Bump::default();
+		let source_text = "@font-palette-values cooler{font-family:Bixa}";
+		let result = Parser::new(&allocator, source_text, hdx_parser::Features::default())
+			.parse_entirely::<crate::css::StyleSheet>();
+		assert!(matches!(result.errors[..], [ref error] if error.to_string().contains("<dashed-ident>")));
+		assert!(matches!(result.output.unwrap().rules[..], [crate::css::Rule::FontPaletteValuesRule(_)]));
 	}
 }
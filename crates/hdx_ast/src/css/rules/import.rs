@@ -1,7 +1,225 @@
-use crate::Todo;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{diagnostics, CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
+
+use crate::css::{
+	properties::Property,
+	rules::{LayerName, MediaQueryList, SupportsCondition},
+	Visit, VisitMut, Visitable, VisitableMut,
+};
+
+mod func {
+	use hdx_parser::custom_function;
+	custom_function!(Url, atom!("url"));
+	custom_function!(Layer, atom!("layer"));
+	custom_function!(Supports, atom!("supports"));
+}
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(Layer, atom!("layer"));
+}
 
 // https://drafts.csswg.org/css-cascade-5/#at-ruledef-import
-pub type ImportRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
+#[visit]
+pub struct ImportRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub url: ImportUrl,
+	pub layer: Option<ImportLayer<'a>>,
+	pub supports: Option<ImportSupports<'a>>,
+	pub media: Option<MediaQueryList<'a>>,
+	pub semicolon: Option<T![;]>,
+}
+
+// @import [ <url> | <string> ] [ layer | layer(<layer-name>) ]? <import-conditions> ;
+// <import-conditions> = [ supports( [ <supports-condition> | <declaration> ] ) ]? <media-query-list>?
+impl<'a> Parse<'a> for ImportRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let at_keyword = p.parse::<T![AtKeyword]>()?;
+		let c: Cursor = at_keyword.into();
+		let atom = p.parse_atom_lower(c);
+		if atom != atom!("import") {
+			Err(diagnostics::UnexpectedAtRule(atom, c.into()))?;
+		}
+		let url = p.parse::<ImportUrl>()?;
+		let layer = p.parse_if_peek::<ImportLayer>()?;
+		let supports = p.parse_if_peek::<ImportSupports>()?;
+		let media = if p.at_end() || p.peek::<T![;]>() { None } else { Some(p.parse::<MediaQueryList>()?) };
+		let semicolon = p.parse_if_peek::<T![;]>()?;
+		Ok(Self { at_keyword, url, layer, supports, media, semicolon })
+	}
+}
+
+impl ToCursors for ImportRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		ToCursors::to_cursors(&self.url, s);
+		if let Some(layer) = &self.layer {
+			ToCursors::to_cursors(layer, s);
+		}
+		if let Some(supports) = &self.supports {
+			ToCursors::to_cursors(supports, s);
+		}
+		if let Some(media) = &self.media {
+			ToCursors::to_cursors(media, s);
+		}
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for ImportRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_import_rule(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for ImportRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_import_rule(self);
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum ImportUrl {
+	Url(T![Url]),
+	UrlFunction(func::Url, T![String], T![')']),
+	String(T![String]),
+}
+
+impl ImportUrl {
+	/// The Cursor holding the URL, which can be read with [Cursor::parse_str].
+	pub fn cursor(&self) -> Cursor {
+		match self {
+			Self::Url(url) => url.into(),
+			Self::UrlFunction(_, string, _) | Self::String(string) => string.into(),
+		}
+	}
+}
+
+impl<'a> Parse<'a> for ImportUrl {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![Url]>() {
+			return Ok(Self::Url(p.parse::<T![Url]>()?));
+		}
+		if p.peek::<func::Url>() {
+			let func = p.parse::<func::Url>()?;
+			let string = p.parse::<T![String]>()?;
+			let close = p.parse::<T![')']>()?;
+			return Ok(Self::UrlFunction(func, string, close));
+		}
+		p.parse::<T![String]>().map(Self::String)
+	}
+}
+
+impl ToCursors for ImportUrl {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Url(url) => s.append(url.into()),
+			Self::UrlFunction(func, string, close) => {
+				s.append(func.into());
+				s.append(string.into());
+				s.append(close.into());
+			}
+			Self::String(string) => s.append(string.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum ImportLayer<'a> {
+	Anonymous(kw::Layer),
+	Named(func::Layer, LayerName<'a>, T![')']),
+}
+
+impl<'a> Peek<'a> for ImportLayer<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Layer>() || p.peek::<func::Layer>()
+	}
+}
+
+impl<'a> Parse<'a> for ImportLayer<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<kw::Layer>() {
+			return Ok(Self::Anonymous(p.parse::<kw::Layer>()?));
+		}
+		let func = p.parse::<func::Layer>()?;
+		let name = p.parse::<LayerName>()?;
+		let close = p.parse::<T![')']>()?;
+		Ok(Self::Named(func, name, close))
+	}
+}
+
+impl ToCursors for ImportLayer<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Anonymous(layer) => s.append(layer.into()),
+			Self::Named(func, name, close) => {
+				s.append(func.into());
+				ToCursors::to_cursors(name, s);
+				s.append(close.into());
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ImportSupports<'a> {
+	pub function: func::Supports,
+	pub condition: ImportSupportsCondition<'a>,
+	pub close: T![')'],
+}
+
+impl<'a> Peek<'a> for ImportSupports<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<func::Supports>()
+	}
+}
+
+impl<'a> Parse<'a> for ImportSupports<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let function = p.parse::<func::Supports>()?;
+		// A bare declaration starts with its name, while conditions start with `(`, `not`, or a function.
+		let condition = if p.peek::<T![Ident]>() && p.peek_n(2) == hdx_lexer::Kind::Colon {
+			ImportSupportsCondition::Declaration(Box::new(p.parse::<Property>()?))
+		} else {
+			ImportSupportsCondition::Condition(Box::new(p.parse::<SupportsCondition>()?))
+		};
+		let close = p.parse::<T![')']>()?;
+		Ok(Self { function, condition, close })
+	}
+}
+
+impl ToCursors for ImportSupports<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		ToCursors::to_cursors(&self.condition, s);
+		s.append(self.close.into());
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum ImportSupportsCondition<'a> {
+	Condition(Box<SupportsCondition<'a>>),
+	Declaration(Box<Property<'a>>),
+}
+
+impl ToCursors for ImportSupportsCondition<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Condition(condition) => ToCursors::to_cursors(condition.as_ref(), s),
+			Self::Declaration(property) => ToCursors::to_cursors(property.as_ref(), s),
+		}
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +228,39 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(ImportRule, 0);
+		assert_size!(ImportRule, 224);
+		assert_size!(ImportUrl, 40);
+		assert_size!(ImportLayer, 72);
+		assert_size!(ImportSupports, 40);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(ImportRule, "@import \"foo.css\"");
+		assert_parse!(ImportRule, "@import\"foo.css\";");
+		assert_parse!(ImportRule, "@import url(foo.css);");
+		assert_parse!(ImportRule, "@import url(\"foo.css\");");
+		assert_parse!(ImportRule, "@import\"foo.css\"layer;");
+		assert_parse!(ImportRule, "@import\"foo.css\"layer(base.reset);");
+		assert_parse!(ImportRule, "@import\"foo.css\"supports(display:grid);");
+		assert_parse!(ImportRule, "@import\"foo.css\"supports((display:grid));");
+		assert_parse!(ImportRule, "@import\"foo.css\"print;");
+		assert_parse!(ImportRule, "@import\"foo.css\"print,screen;");
+		assert_parse!(ImportRule, "@import url(foo.css)layer(base)supports(display:grid)screen;");
+		assert_parse!(ImportRule, "@import url(foo.css)layer(y)(min-width:1px);");
+		assert_parse!(ImportRule, "@import\"c.css\"supports(not (display:grid));");
+		assert_parse!(
+			ImportRule,
+			"@import\"c.css\"supports((color:red) or (color:blue));",
+			"@import\"c.css\"supports((color:red)or (color:blue));"
+		);
+		assert_parse!(ImportRule, "@import\"foo.css\"screen and (min-resolution:2dppx);");
+		assert_parse!(ImportRule, "@import\"foo.css\"");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ImportRule, "@import;");
+		assert_parse_error!(ImportRule, "@import foo;");
+		assert_parse_error!(ImportRule, "@import\"foo.css\"layer();");
 	}
 }
@@ -7,7 +7,7 @@ use hdx_parser::{
 use hdx_proc_macro::visit;
 
 use crate::{
-	css::{properties::Property, Visit, Visitable},
+	css::{properties::Property, Visit, VisitMut, Visitable, VisitableMut},
 	syntax::BadDeclaration,
 };
 
@@ -22,9 +22,9 @@ pub mod kw {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
 #[visit]
 pub struct KeyframesRule<'a> {
-	at_keyword: T![AtKeyword],
-	name: Option<KeyframesName>,
-	block: KeyframesBlock<'a>,
+	pub at_keyword: T![AtKeyword],
+	pub name: Option<KeyframesName>,
+	pub block: KeyframesBlock<'a>,
 }
 
 impl<'a> AtRule<'a> for KeyframesRule<'a> {
@@ -56,6 +56,13 @@ impl<'a> Visitable<'a> for KeyframesRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for KeyframesRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_keyframes_rule(self);
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum KeyframesName {
@@ -142,12 +149,20 @@ impl<'a> Visitable<'a> for KeyframesBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for KeyframesBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for rule in &mut self.keyframes {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
 pub struct Keyframe<'a> {
-	selectors: KeyframeSelectors<'a>,
-	block: KeyframeBlock<'a>,
+	pub selectors: KeyframeSelectors<'a>,
+	pub block: KeyframeBlock<'a>,
 }
 
 impl<'a> QualifiedRule<'a> for Keyframe<'a> {
@@ -178,6 +193,14 @@ impl<'a> Visitable<'a> for Keyframe<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for Keyframe<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_keyframe(self);
+		VisitableMut::accept_mut(&mut self.selectors, v);
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct KeyframeSelectors<'a>(pub Vec<'a, (KeyframeSelector, Option<T![,]>)>);
@@ -211,12 +234,20 @@ impl<'a> Visitable<'a> for KeyframeSelectors<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for KeyframeSelectors<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for (selector, _) in &mut self.0 {
+			VisitableMut::accept_mut(selector, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct KeyframeBlock<'a> {
-	open: T!['{'],
-	properties: Vec<'a, Property<'a>>,
-	close: Option<T!['}']>,
+	pub open: T!['{'],
+	pub properties: Vec<'a, Property<'a>>,
+	pub close: Option<T!['}']>,
 }
 
 impl<'a> DeclarationList<'a> for KeyframeBlock<'a> {
@@ -250,6 +281,14 @@ impl<'a> Visitable<'a> for KeyframeBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for KeyframeBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for property in &mut self.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
@@ -308,6 +347,12 @@ impl<'a> Visitable<'a> for KeyframeSelector {
 	}
 }
 
+impl<'a> VisitableMut<'a> for KeyframeSelector {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_keyframe_selector(self);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
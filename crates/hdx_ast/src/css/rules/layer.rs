@@ -1,12 +1,13 @@
 use bumpalo::collections::Vec;
 use hdx_atom::atom;
-use hdx_lexer::Cursor;
+use hdx_lexer::{Cursor, SourceOffset};
 use hdx_parser::{
-	diagnostics, AtRule, CursorSink, Parse, Parser, PreludeCommaList, Result as ParserResult, RuleList, ToCursors, T,
+	diagnostics, start_offset, AtRule, Block, CursorSink, Parse, Parser, PreludeCommaList, Result as ParserResult,
+	RuleList, State, ToCursors, T,
 };
 use hdx_proc_macro::visit;
 
-use crate::css::{stylesheet::Rule, Visit, Visitable};
+use crate::css::{properties::Property, stylesheet::Rule, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-cascade-5/#layering
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -57,6 +58,16 @@ impl<'a> Visitable<'a> for LayerRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for LayerRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_layer_rule(self);
+		if let Some(names) = &mut self.names {
+			VisitableMut::accept_mut(names, v);
+		}
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct LayerNameList<'a>(pub Vec<'a, (LayerName<'a>, Option<T![,]>)>);
@@ -90,6 +101,14 @@ impl<'a> Visitable<'a> for LayerNameList<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for LayerNameList<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for (name, _) in &mut self.0 {
+			VisitableMut::accept_mut(name, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
@@ -127,6 +146,12 @@ impl<'a> Visitable<'a> for LayerName<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for LayerName<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_layer_name(self);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum OptionalLayerRuleBlock<'a> {
@@ -163,10 +188,22 @@ impl<'a> Visitable<'a> for OptionalLayerRuleBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for OptionalLayerRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		if let Self::Block(block) = self {
+			VisitableMut::accept_mut(block, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
 pub struct LayerRuleBlock<'a> {
 	pub open: T!['{'],
+	// https://drafts.csswg.org/css-nesting/#conditionals
+	// Declarations are only parsed when nested inside a style rule, where they apply to the parent rule's selector.
+	#[cfg_attr(feature = "serde", serde(borrow))]
+	pub declarations: Vec<'a, Property<'a>>,
 	#[cfg_attr(feature = "serde", serde(borrow))]
 	pub rules: Vec<'a, Rule<'a>>,
 	pub close: Option<T!['}']>,
@@ -174,8 +211,12 @@ pub struct LayerRuleBlock<'a> {
 
 impl<'a> Parse<'a> for LayerRuleBlock<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.is(State::Nested) {
+			let (open, declarations, rules, close) = Self::parse_block(p)?;
+			return Ok(Self { open, declarations, rules, close });
+		}
 		let (open, rules, close) = Self::parse_rule_list(p)?;
-		Ok(Self { open, rules, close })
+		Ok(Self { open, declarations: Vec::new_in(p.bump()), rules, close })
 	}
 }
 
@@ -183,12 +224,28 @@ impl<'a> RuleList<'a> for LayerRuleBlock<'a> {
 	type Rule = Rule<'a>;
 }
 
+impl<'a> Block<'a> for LayerRuleBlock<'a> {
+	type Declaration = Property<'a>;
+	type Rule = Rule<'a>;
+}
+
 impl<'a> ToCursors for LayerRuleBlock<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		s.append(self.open.into());
+		// Declarations & rules may be interleaved, so each declaration is written before the first rule after it.
+		let mut declarations = self.declarations.iter().peekable();
 		for rule in &self.rules {
+			if declarations.peek().is_some() {
+				let start = start_offset(rule).unwrap_or(SourceOffset::DUMMY);
+				while let Some(declaration) = declarations.next_if(|d| start_offset(*d).is_some_and(|d| d < start)) {
+					ToCursors::to_cursors(declaration, s);
+				}
+			}
 			ToCursors::to_cursors(rule, s);
 		}
+		for declaration in declarations {
+			ToCursors::to_cursors(declaration, s);
+		}
 		if let Some(close) = self.close {
 			s.append(close.into());
 		}
@@ -197,12 +254,26 @@ impl<'a> ToCursors for LayerRuleBlock<'a> {
 
 impl<'a> Visitable<'a> for LayerRuleBlock<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		for declaration in &self.declarations {
+			Visitable::accept(declaration, v);
+		}
 		for rule in &self.rules {
 			Visitable::accept(rule, v);
 		}
 	}
 }
 
+impl<'a> VisitableMut<'a> for LayerRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for declaration in &mut self.declarations {
+			VisitableMut::accept_mut(declaration, v);
+		}
+		for rule in &mut self.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -210,11 +281,11 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(LayerRule, 112);
+		assert_size!(LayerRule, 144);
 		assert_size!(LayerNameList, 32);
 		assert_size!(LayerName, 48);
-		assert_size!(OptionalLayerRuleBlock, 64);
-		assert_size!(LayerRuleBlock, 64);
+		assert_size!(OptionalLayerRuleBlock, 96);
+		assert_size!(LayerRuleBlock, 96);
 	}
 
 	#[test]
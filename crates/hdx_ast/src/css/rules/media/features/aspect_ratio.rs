@@ -1,3 +1,31 @@
-use crate::Todo;
+use crate::css::types::Ratio;
+use hdx_parser::ranged_feature;
 
-pub type AspectRatioMediaFeature = Todo;
+ranged_feature!(AspectRatioMediaFeature[atom!("aspect-ratio")], Ratio);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(AspectRatioMediaFeature, 164);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(AspectRatioMediaFeature, "aspect-ratio:16/9");
+		assert_parse!(AspectRatioMediaFeature, "min-aspect-ratio:4/3");
+		assert_parse!(AspectRatioMediaFeature, "max-aspect-ratio:2");
+		assert_parse!(AspectRatioMediaFeature, "aspect-ratio>=1/1");
+		assert_parse!(AspectRatioMediaFeature, "1/1<aspect-ratio<16/9");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(AspectRatioMediaFeature, "aspect-ratio:");
+		assert_parse_error!(AspectRatioMediaFeature, "aspect-ratio:16px/9px");
+		assert_parse_error!(AspectRatioMediaFeature, "max-aspect-ratio>1/1");
+	}
+}
@@ -1,3 +1,29 @@
-use crate::Todo;
+use crate::css::types::Ratio;
+use hdx_parser::ranged_feature;
 
-pub type DeviceAspectRatioMediaFeature = Todo;
+ranged_feature!(DeviceAspectRatioMediaFeature[atom!("device-aspect-ratio")], Ratio);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(DeviceAspectRatioMediaFeature, 164);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(DeviceAspectRatioMediaFeature, "device-aspect-ratio:16/9");
+		assert_parse!(DeviceAspectRatioMediaFeature, "min-device-aspect-ratio:4/3");
+		assert_parse!(DeviceAspectRatioMediaFeature, "max-device-aspect-ratio:16/10");
+		assert_parse!(DeviceAspectRatioMediaFeature, "device-aspect-ratio<=16/9");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(DeviceAspectRatioMediaFeature, "device-aspect-ratio:");
+		assert_parse_error!(DeviceAspectRatioMediaFeature, "aspect-ratio:16/9");
+	}
+}
@@ -1,7 +1,7 @@
 use crate::css::units::Length;
 use hdx_parser::ranged_feature;
 
-ranged_feature!(DeviceHeightMediaFeature[atom!("device-height")], Length);
+ranged_feature!(DeviceHeightMediaFeature<'a>[atom!("device-height")], Length<'a>);
 
 #[cfg(test)]
 mod tests {
@@ -10,7 +10,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(DeviceHeightMediaFeature, 100);
+		assert_size!(DeviceHeightMediaFeature, 104);
 	}
 
 	#[test]
@@ -1,7 +1,7 @@
 use crate::css::units::Length;
 use hdx_parser::ranged_feature;
 
-ranged_feature!(DeviceWidthMediaFeature[atom!("device-width")], Length);
+ranged_feature!(DeviceWidthMediaFeature<'a>[atom!("device-width")], Length<'a>);
 
 #[cfg(test)]
 mod tests {
@@ -10,7 +10,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(DeviceWidthMediaFeature, 100);
+		assert_size!(DeviceWidthMediaFeature, 104);
 	}
 
 	#[test]
@@ -1,7 +1,7 @@
 use crate::css::units::Length;
 use hdx_parser::ranged_feature;
 
-ranged_feature!(HeightMediaFeature[atom!("height")], Length);
+ranged_feature!(HeightMediaFeature<'a>[atom!("height")], Length<'a>);
 
 #[cfg(test)]
 mod tests {
@@ -10,7 +10,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(HeightMediaFeature, 100);
+		assert_size!(HeightMediaFeature, 104);
 	}
 
 	#[test]
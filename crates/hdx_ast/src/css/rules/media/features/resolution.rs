@@ -1,3 +1,78 @@
-use crate::Todo;
+use crate::css::units::Resolution;
+use hdx_lexer::Cursor;
+use hdx_parser::{ranged_feature, Parse, Parser, Peek, Result as ParserResult};
 
-pub type ResolutionMediaFeature = Todo;
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(Infinite, atom!("infinite"));
+}
+
+ranged_feature!(ResolutionMediaFeature[atom!("resolution")], ResolutionMediaValue);
+
+// https://drafts.csswg.org/mediaqueries/#resolution
+// <resolution> | infinite
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
+pub enum ResolutionMediaValue {
+	Resolution(Resolution),
+	Infinite(kw::Infinite),
+}
+
+impl<'a> Peek<'a> for ResolutionMediaValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<Resolution>() || p.peek::<kw::Infinite>()
+	}
+}
+
+impl<'a> Parse<'a> for ResolutionMediaValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<kw::Infinite>() {
+			return p.parse::<kw::Infinite>().map(Self::Infinite);
+		}
+		p.parse::<Resolution>().map(Self::Resolution)
+	}
+}
+
+impl From<ResolutionMediaValue> for Cursor {
+	fn from(value: ResolutionMediaValue) -> Self {
+		match value {
+			ResolutionMediaValue::Resolution(c) => c.into(),
+			ResolutionMediaValue::Infinite(c) => c.into(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(ResolutionMediaFeature, 100);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(ResolutionMediaFeature, "resolution:2dppx");
+		assert_parse!(ResolutionMediaFeature, "resolution:118dpcm");
+		assert_parse!(ResolutionMediaFeature, "min-resolution:2dppx");
+		assert_parse!(ResolutionMediaFeature, "max-resolution:192dpi");
+		assert_parse!(ResolutionMediaFeature, "resolution>=2dppx");
+		assert_parse!(ResolutionMediaFeature, "1dppx<resolution<=3dppx");
+		assert_parse!(ResolutionMediaFeature, "resolution:infinite");
+		assert_parse!(ResolutionMediaFeature, "max-resolution:infinite");
+		assert_parse!(ResolutionMediaFeature, "2dppx<resolution<infinite");
+		assert_parse!(ResolutionMediaFeature, "resolution>1x");
+		assert_parse!(ResolutionMediaFeature, "min-resolution:2x");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ResolutionMediaFeature, "resolution:");
+		assert_parse_error!(ResolutionMediaFeature, "resolution:2px");
+		assert_parse_error!(ResolutionMediaFeature, "resolution:2");
+		assert_parse_error!(ResolutionMediaFeature, "min-resolution>2dppx");
+		assert_parse_error!(ResolutionMediaFeature, "resolution:auto");
+	}
+}
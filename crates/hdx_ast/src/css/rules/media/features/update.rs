@@ -1,3 +1,32 @@
-use crate::Todo;
+use hdx_parser::discrete_feature;
 
-pub type UpdateMediaFeature = Todo;
+discrete_feature!(UpdateMediaFeature[atom!("update")] {
+	None: atom!("none"),
+	Slow: atom!("slow"),
+	Fast: atom!("fast"),
+});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(UpdateMediaFeature, 40);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(UpdateMediaFeature, "update");
+		assert_parse!(UpdateMediaFeature, "update:none");
+		assert_parse!(UpdateMediaFeature, "update:slow");
+		assert_parse!(UpdateMediaFeature, "update:fast");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(UpdateMediaFeature, "update:");
+		assert_parse_error!(UpdateMediaFeature, "update:instant");
+	}
+}
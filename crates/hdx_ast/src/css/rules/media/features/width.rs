@@ -1,7 +1,7 @@
 use crate::css::units::Length;
 use hdx_parser::ranged_feature;
 
-ranged_feature!(WidthMediaFeature[atom!("width")], Length);
+ranged_feature!(WidthMediaFeature<'a>[atom!("width")], Length<'a>);
 
 #[cfg(test)]
 mod tests {
@@ -10,7 +10,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(WidthMediaFeature, 100);
+		assert_size!(WidthMediaFeature, 104);
 	}
 
 	#[test]
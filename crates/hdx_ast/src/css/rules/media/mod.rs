@@ -1,12 +1,13 @@
 use bumpalo::collections::Vec;
 use hdx_atom::atom;
-use hdx_lexer::{Cursor, Kind, Span};
+use hdx_lexer::{Cursor, Kind, SourceOffset, Span};
 use hdx_parser::{
-	diagnostics, keyword_typedef, AtRule, Build, ConditionalAtRule, CursorSink, Is, Parse, Parser, PreludeList,
-	Result as ParserResult, RuleList, ToCursors, T,
+	diagnostics, keyword_typedef, start_offset, AtRule, Block, Build, ConditionalAtRule, CursorSink, Is, Parse, Parser,
+	Result as ParserResult, RuleList, State, ToCursors, T,
 };
+use hdx_proc_macro::visit;
 
-use crate::css::{stylesheet::Rule, Visit, Visitable};
+use crate::css::{properties::Property, stylesheet::Rule, Visit, VisitMut, Visitable, VisitableMut};
 
 mod features;
 use features::*;
@@ -21,6 +22,7 @@ mod kw {
 // https://drafts.csswg.org/mediaqueries-4/
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
+#[visit]
 pub struct MediaRule<'a> {
 	pub at_keyword: T![AtKeyword],
 	pub query: MediaQueryList<'a>,
@@ -55,7 +57,25 @@ impl<'a> ToCursors for MediaRule<'a> {
 
 impl<'a> Visitable<'a> for MediaRule<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
-		todo!();
+		v.visit_media_rule(self);
+		for declaration in &self.block.declarations {
+			Visitable::accept(declaration, v);
+		}
+		for rule in &self.block.rules {
+			Visitable::accept(rule, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for MediaRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_media_rule(self);
+		for declaration in &mut self.block.declarations {
+			VisitableMut::accept_mut(declaration, v);
+		}
+		for rule in &mut self.block.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
 	}
 }
 
@@ -63,14 +83,21 @@ impl<'a> Visitable<'a> for MediaRule<'a> {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct MediaRules<'a> {
 	pub open: T!['{'],
+	// https://drafts.csswg.org/css-nesting/#conditionals
+	// Declarations are only parsed when nested inside a style rule, where they apply to the parent rule's selector.
+	pub declarations: Vec<'a, Property<'a>>,
 	pub rules: Vec<'a, Rule<'a>>,
 	pub close: Option<T!['}']>,
 }
 
 impl<'a> Parse<'a> for MediaRules<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.is(State::Nested) {
+			let (open, declarations, rules, close) = Self::parse_block(p)?;
+			return Ok(Self { open, declarations, rules, close });
+		}
 		let (open, rules, close) = Self::parse_rule_list(p)?;
-		Ok(Self { open, rules, close })
+		Ok(Self { open, declarations: Vec::new_in(p.bump()), rules, close })
 	}
 }
 
@@ -78,12 +105,29 @@ impl<'a> RuleList<'a> for MediaRules<'a> {
 	type Rule = Rule<'a>;
 }
 
+impl<'a> Block<'a> for MediaRules<'a> {
+	type Declaration = Property<'a>;
+	type Rule = Rule<'a>;
+}
+
 impl<'a> ToCursors for MediaRules<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		s.append(self.open.into());
+		// Declarations & rules are parsed into separate lists, but may be interleaved, e.g. `@media x{b{} color:red}`,
+		// so each declaration is written before the first rule which came after it.
+		let mut declarations = self.declarations.iter().peekable();
 		for rule in &self.rules {
+			if declarations.peek().is_some() {
+				let start = start_offset(rule).unwrap_or(SourceOffset::DUMMY);
+				while let Some(declaration) = declarations.next_if(|d| start_offset(*d).is_some_and(|d| d < start)) {
+					ToCursors::to_cursors(declaration, s);
+				}
+			}
 			ToCursors::to_cursors(rule, s);
 		}
+		for declaration in declarations {
+			ToCursors::to_cursors(declaration, s);
+		}
 		if let Some(close) = self.close {
 			s.append(close.into());
 		}
@@ -92,22 +136,31 @@ impl<'a> ToCursors for MediaRules<'a> {
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct MediaQueryList<'a>(pub Vec<'a, MediaQuery<'a>>);
-
-impl<'a> PreludeList<'a> for MediaQueryList<'a> {
-	type PreludeItem = MediaQuery<'a>;
-}
+pub struct MediaQueryList<'a>(pub Vec<'a, (MediaQuery<'a>, Option<T![,]>)>);
 
+// https://drafts.csswg.org/mediaqueries-4/#typedef-media-query-list
 impl<'a> Parse<'a> for MediaQueryList<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		Ok(Self(Self::parse_prelude_list(p)?))
+		let mut queries = Vec::new_in(p.bump());
+		loop {
+			let query = p.parse::<MediaQuery>()?;
+			let comma = p.parse_if_peek::<T![,]>()?;
+			let done = comma.is_none();
+			queries.push((query, comma));
+			if done {
+				return Ok(Self(queries));
+			}
+		}
 	}
 }
 
 impl<'a> ToCursors for MediaQueryList<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
-		for query in &self.0 {
+		for (query, comma) in &self.0 {
 			ToCursors::to_cursors(query, s);
+			if let Some(comma) = comma {
+				s.append(comma.into());
+			}
 		}
 	}
 }
@@ -163,6 +216,7 @@ impl From<&MediaType> for Cursor {
 pub struct MediaQuery<'a> {
 	precondition: Option<MediaPreCondition>,
 	media_type: Option<MediaType>,
+	and: Option<kw::And>,
 	condition: Option<MediaCondition<'a>>,
 }
 
@@ -170,10 +224,11 @@ impl<'a> Parse<'a> for MediaQuery<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let mut precondition = None;
 		let mut media_type = None;
+		let mut and = None;
 		let mut condition = None;
 		if p.peek::<T!['(']>() {
 			condition = Some(p.parse::<MediaCondition<'a>>()?);
-			return Ok(Self { precondition, media_type, condition });
+			return Ok(Self { precondition, media_type, and, condition });
 		}
 		let ident = p.parse::<T![Ident]>()?;
 		let c: Cursor = ident.into();
@@ -193,10 +248,13 @@ impl<'a> Parse<'a> for MediaQuery<'a> {
 				Err(diagnostics::UnexpectedIdent(p.parse_atom(c), c.into()))?
 			}
 		}
-		if p.peek::<kw::And>() || media_type.is_none() {
+		if media_type.is_none() {
+			condition = Some(p.parse::<MediaCondition>()?);
+		} else if p.peek::<kw::And>() {
+			and = Some(p.parse::<kw::And>()?);
 			condition = Some(p.parse::<MediaCondition>()?);
 		}
-		Ok(Self { precondition, media_type, condition })
+		Ok(Self { precondition, media_type, and, condition })
 	}
 }
 
@@ -208,6 +266,9 @@ impl<'a> ToCursors for MediaQuery<'a> {
 		if let Some(media_type) = &self.media_type {
 			s.append(media_type.into());
 		}
+		if let Some(and) = self.and {
+			s.append(and.into());
+		}
 		if let Some(condition) = &self.condition {
 			ToCursors::to_cursors(condition, s);
 		}
@@ -217,25 +278,29 @@ impl<'a> ToCursors for MediaQuery<'a> {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 pub enum MediaCondition<'a> {
-	Is(MediaFeature),
-	Not(Box<MediaCondition<'a>>),
-	And(Vec<'a, MediaFeature>),
-	Or(Vec<'a, MediaFeature>),
+	Is(MediaFeature<'a>),
+	Not(T![Ident], Box<MediaCondition<'a>>),
+	And(Vec<'a, (MediaFeature<'a>, Option<T![Ident]>)>),
+	Or(Vec<'a, (MediaFeature<'a>, Option<T![Ident]>)>),
+	Parens(T!['('], Box<MediaCondition<'a>>, T![')']),
 }
 
 impl<'a> ConditionalAtRule<'a> for MediaCondition<'a> {
-	type Feature = MediaFeature;
-	fn new_is(feature: MediaFeature) -> Self {
+	type Feature = MediaFeature<'a>;
+	fn new_is(feature: MediaFeature<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn new_not(condition: MediaCondition<'a>) -> Self {
-		Self::Not(Box::new(condition))
+	fn new_not(keyword: T![Ident], condition: MediaCondition<'a>) -> Self {
+		Self::Not(keyword, Box::new(condition))
 	}
-	fn new_and(feature: Vec<'a, MediaFeature>) -> Self {
-		Self::And(feature)
+	fn new_and(features: Vec<'a, (MediaFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::And(features)
 	}
-	fn new_or(feature: Vec<'a, MediaFeature>) -> Self {
-		Self::Or(feature)
+	fn new_or(features: Vec<'a, (MediaFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::Or(features)
+	}
+	fn new_parens(open: T!['('], condition: MediaCondition<'a>, close: T![')']) -> Self {
+		Self::Parens(open, Box::new(condition), close)
 	}
 }
 
@@ -248,68 +313,77 @@ impl<'a> Parse<'a> for MediaCondition<'a> {
 impl<'a> ToCursors for MediaCondition<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
-			Self::Is(c) => ToCursors::to_cursors(c, s),
-			Self::Not(c) => ToCursors::to_cursors(c.as_ref(), s),
-			Self::And(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
-				}
+			Self::Is(feature) => ToCursors::to_cursors(feature, s),
+			Self::Not(keyword, condition) => {
+				s.append(keyword.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
 			}
-			Self::Or(cs) => {
-				for c in cs {
-					ToCursors::to_cursors(c, s);
+			Self::And(features) | Self::Or(features) => {
+				for (feature, keyword) in features {
+					ToCursors::to_cursors(feature, s);
+					if let Some(keyword) = keyword {
+						s.append(keyword.into());
+					}
 				}
 			}
+			Self::Parens(open, condition, close) => {
+				s.append(open.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
+				s.append(close.into());
+			}
 		}
 	}
 }
 
 macro_rules! media_feature {
-	( $($name: ident($typ: ident): atom!($atom: tt)$(| $alts:pat)*,)+) => {
+	( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt)$(| $alts:pat)*,)+) => {
 		// https://drafts.csswg.org/mediaqueries-5/#media-descriptor-table
 		#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-		#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
-		pub enum MediaFeature {
-			$($name($typ),)+
-			Hack(HackMediaFeature),
+		#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
+		pub enum MediaFeature<'a> {
+			$($name(T!['('], $typ$(<$lt>)?, T![')']),)+
+			Hack(T!['('], HackMediaFeature, T![')']),
 		}
 	}
 }
 
 apply_medias!(media_feature);
 
-impl<'a> Parse<'a> for MediaFeature {
+impl<'a> Parse<'a> for MediaFeature<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		p.parse::<T![LeftParen]>()?;
+		let open = p.parse::<T![LeftParen]>()?;
 		let checkpoint = p.checkpoint();
 		let mut c = p.peek_n(1);
 		macro_rules! match_media {
-			( $($name: ident($typ: ident): atom!($atom: tt)$(| $alts:pat)*,)+) => {
+			( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt)$(| $alts:pat)*,)+) => {
 				// Only peek at the token as the underlying media feature parser needs to parse the leading atom.
 				{
 					match p.parse_atom_lower(c) {
-						$(atom!($atom)$(| $alts)* => $typ::parse(p).map(Self::$name),)+
+						$(atom!($atom)$(| $alts)* => $typ::parse(p).and_then(|value| {
+							Ok(Self::$name(open, value, p.parse::<T![')']>()?))
+						}),)+
 						atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
 					}
 				}
 			}
 		}
 		if c == Kind::Ident {
-			let value = apply_medias!(match_media).or_else(|err| {
+			apply_medias!(match_media).or_else(|err| {
 				p.rewind(checkpoint);
 				if let Ok(hack) = p.parse::<HackMediaFeature>() {
-					Ok(Self::Hack(hack))
+					Ok(Self::Hack(open, hack, p.parse::<T![')']>()?))
 				} else {
 					Err(err)
 				}
-			})?;
-			p.parse::<T![')']>()?;
-			Ok(value)
+			})
 		} else {
-			// Styles like (1em < width < 1em) or (1em <= width <= 1em)
-			c = p.peek_n(3);
-			if c != Kind::Ident {
-				c = p.peek_n(4)
+			// Styles like (1em < width < 1em) or (1em <= width <= 1em), where the value may be a ratio such as
+			// (16/9 <= aspect-ratio), so the name is up to 6 tokens in.
+			for n in 3..=6 {
+				c = p.peek_n(n);
+				if c == Kind::Ident {
+					break;
+				}
 			}
 			if c != Kind::Ident {
 				c = p.parse::<T![Any]>()?.into();
@@ -320,13 +394,21 @@ impl<'a> Parse<'a> for MediaFeature {
 	}
 }
 
-impl ToCursors for MediaFeature {
+impl ToCursors for MediaFeature<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		macro_rules! match_media {
-			( $($name: ident($typ: ident): atom!($atom: tt)$(| $alts:pat)*,)+) => {
+			( $($name: ident($typ: ident$(<$lt: lifetime>)?): atom!($atom: tt)$(| $alts:pat)*,)+) => {
 				match self {
-					$(Self::$name(c) => ToCursors::to_cursors(c, s),)+
-					Self::Hack(hack) => ToCursors::to_cursors(hack, s),
+					$(Self::$name(open, c, close) => {
+						s.append(open.into());
+						ToCursors::to_cursors(c, s);
+						s.append(close.into());
+					})+
+					Self::Hack(open, hack, close) => {
+						s.append(open.into());
+						ToCursors::to_cursors(hack, s);
+						s.append(close.into());
+					}
 				}
 			};
 		}
@@ -346,14 +428,14 @@ macro_rules! apply_medias {
 			ColorGamut(ColorGamutMediaFeature): atom!("color-gamut"),
 			ColorIndex(ColorIndexMediaFeature): atom!("color-index") | atom!("max-color-index") | atom!("min-color-index"),
 			DeviceAspectRatio(DeviceAspectRatioMediaFeature): atom!("device-aspect-ratio") | atom!("max-device-aspect-ratio") | atom!("min-device-aspect-ratio"),
-			DeviceHeight(DeviceHeightMediaFeature): atom!("device-height") | atom!("max-device-height") | atom!("min-device-height"),
-			DeviceWidth(DeviceWidthMediaFeature): atom!("device-width") | atom!("max-device-width") | atom!("min-device-width"),
+			DeviceHeight(DeviceHeightMediaFeature<'a>): atom!("device-height") | atom!("max-device-height") | atom!("min-device-height"),
+			DeviceWidth(DeviceWidthMediaFeature<'a>): atom!("device-width") | atom!("max-device-width") | atom!("min-device-width"),
 			DisplayMode(DisplayModeMediaFeature): atom!("display-mode"),
 			DynamicRange(DynamicRangeMediaFeature): atom!("dynamic-range"),
 			EnvironmentBlending(EnvironmentBlendingMediaFeature): atom!("environment-blending"),
 			ForcedColors(ForcedColorsMediaFeature): atom!("forced-colors"),
 			Grid(GridMediaFeature): atom!("grid"),
-			Height(HeightMediaFeature): atom!("height") | atom!("max-height") | atom!("min-height"),
+			Height(HeightMediaFeature<'a>): atom!("height") | atom!("max-height") | atom!("min-height"),
 			HorizontalViewportSegments(HorizontalViewportSegmentsMediaFeature): atom!("horizontal-viewport-segments") | atom!("max-horizontal-viewport-segments") | atom!("min-horizontal-viewport-segments"),
 			Hover(HoverMediaFeature): atom!("hover"),
 			InvertedColors(InvertedColorsMediaFeature): atom!("inverted-colors"),
@@ -375,7 +457,7 @@ macro_rules! apply_medias {
 			VerticalViewportSegments(VerticalViewportSegmentsMediaFeature): atom!("vertical-viewport-segments") | atom!("max-vertical-viewport-segments") | atom!("min-vertical-viewport-segments"),
 			VideoColorGamut(VideoColorGamutMediaFeature): atom!("video-color-gamut"),
 			VideoDynamicRange(VideoDynamicRangeMediaFeature): atom!("video-dynamic-range"),
-			Width(WidthMediaFeature): atom!("width") | atom!("max-width") | atom!("min-width"),
+			Width(WidthMediaFeature<'a>): atom!("width") | atom!("max-width") | atom!("min-width"),
 
 			// https://searchfox.org/wubkat/source/Source/WebCore/css/query/MediaQueryFeatures.cpp#192
 			WebkitAnimationMediaFeature(WebkitAnimationMediaFeature): atom!("-webkit-animation"),
@@ -414,10 +496,10 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(MediaRule, 112);
+		assert_size!(MediaRule, 144);
 		assert_size!(MediaQueryList, 32);
-		assert_size!(MediaQuery, 136);
-		assert_size!(MediaCondition, 104);
+		assert_size!(MediaQuery, 240);
+		assert_size!(MediaCondition, 192);
 	}
 
 	#[test]
@@ -425,7 +507,25 @@ mod tests {
 		assert_parse!(MediaQuery, "print");
 		assert_parse!(MediaQuery, "not embossed");
 		assert_parse!(MediaQuery, "only screen");
-		assert_parse!(MediaFeature, "(grid)", "grid");
+		assert_parse!(MediaFeature, "(grid)");
+		assert_parse!(MediaFeature, "(min-resolution:2dppx)");
+		assert_parse!(MediaFeature, "(aspect-ratio:16/9)");
+		assert_parse!(MediaFeature, "(max-device-aspect-ratio:16/10)");
+		assert_parse!(MediaFeature, "(update:slow)");
+		assert_parse!(MediaFeature, "(1dppx<resolution<=3dppx)");
+		assert_parse!(MediaFeature, "(max-resolution:infinite)");
+		assert_parse!(MediaFeature, "(resolution>1x)");
+		assert_parse!(MediaFeature, "(1/1 < aspect-ratio)", "(1/1<aspect-ratio)");
+		assert_parse!(MediaFeature, "(1/1 < aspect-ratio < 16/9)", "(1/1<aspect-ratio<16/9)");
+		assert_parse!(MediaFeature, "(16/9<=device-aspect-ratio)");
+		assert_parse!(MediaRule, "@media (1/1 < aspect-ratio){a{color:red}}", "@media(1/1<aspect-ratio){a{color:red}}");
+		assert_parse!(MediaQuery, "(min-width:1px)");
+		assert_parse!(MediaQuery, "screen and (grid)");
+		assert_parse!(MediaQuery, "screen and (hover) and (pointer)", "screen and (hover)and (pointer)");
+		assert_parse!(MediaQuery, "(hover) and (pointer)", "(hover)and (pointer)");
+		assert_parse!(MediaQuery, "(hover) or (pointer)", "(hover)or (pointer)");
+		assert_parse!(MediaQuery, "not ((hover) or (pointer))", "not ((hover)or (pointer))");
+		assert_parse!(MediaQuery, "((hover) and (pointer))", "((hover)and (pointer))");
 		// assert_parse!(MediaQuery, "screen and (grid)");
 		// assert_parse!(MediaQuery, "screen and (hover) and (pointer)");
 		// assert_parse!(MediaQuery, "screen and (orientation: landscape)");
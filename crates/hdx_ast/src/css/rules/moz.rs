@@ -3,7 +3,7 @@ use hdx_lexer::Cursor;
 use hdx_parser::{diagnostics, AtRule, CursorSink, Parse, Parser, Result as ParserResult, ToCursors, T};
 use hdx_proc_macro::visit;
 
-use crate::css::{Visit, Visitable};
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
 
 use super::{DocumentMatcherList, DocumentRuleBlock};
 
@@ -48,6 +48,12 @@ impl<'a> Visitable<'a> for MozDocumentRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for MozDocumentRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_moz_document_rule(self);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
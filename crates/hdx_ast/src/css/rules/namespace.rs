@@ -1,7 +1,61 @@
-use crate::Todo;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{diagnostics, CursorSink, Parse, Parser, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
+
+use crate::css::{rules::ImportUrl, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-namespaces/#at-ruledef-namespace
-pub type NamespaceRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
+#[visit]
+pub struct NamespaceRule {
+	pub at_keyword: T![AtKeyword],
+	pub prefix: Option<T![Ident]>,
+	pub url: ImportUrl,
+	pub semicolon: Option<T![;]>,
+}
+
+// @namespace <namespace-prefix>? [ <string> | <url> ] ;
+impl<'a> Parse<'a> for NamespaceRule {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let at_keyword = p.parse::<T![AtKeyword]>()?;
+		let c: Cursor = at_keyword.into();
+		let atom = p.parse_atom_lower(c);
+		if atom != atom!("namespace") {
+			Err(diagnostics::UnexpectedAtRule(atom, c.into()))?;
+		}
+		let prefix = p.parse_if_peek::<T![Ident]>()?;
+		let url = p.parse::<ImportUrl>()?;
+		let semicolon = p.parse_if_peek::<T![;]>()?;
+		Ok(Self { at_keyword, prefix, url, semicolon })
+	}
+}
+
+impl ToCursors for NamespaceRule {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		if let Some(prefix) = self.prefix {
+			s.append(prefix.into());
+		}
+		ToCursors::to_cursors(&self.url, s);
+		if let Some(semicolon) = self.semicolon {
+			s.append(semicolon.into());
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for NamespaceRule {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_namespace_rule(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for NamespaceRule {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_namespace_rule(self);
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +64,26 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(NamespaceRule, 0);
+		assert_size!(NamespaceRule, 84);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(NamespaceRule, "@namespace");
+		assert_parse!(NamespaceRule, "@namespace url(http://www.w3.org/1999/xhtml);");
+		assert_parse!(NamespaceRule, "@namespace svg url(http://www.w3.org/2000/svg);");
+		assert_parse!(
+			NamespaceRule,
+			"@namespace svg 'http://www.w3.org/2000/svg';",
+			"@namespace svg'http://www.w3.org/2000/svg';"
+		);
+		assert_parse!(NamespaceRule, "@namespace math url('http://www.w3.org/1998/Math/MathML')");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(NamespaceRule, "@namespace;");
+		assert_parse_error!(NamespaceRule, "@namespace svg;");
+		assert_parse_error!(NamespaceRule, "@namespace svg math url(x);");
+		assert_parse_error!(NamespaceRule, "@import url(x);");
 	}
 }
@@ -8,7 +8,7 @@ use hdx_parser::{
 use hdx_proc_macro::visit;
 
 use crate::{
-	css::{properties::Property, Visit, Visitable},
+	css::{properties::Property, Visit, VisitMut, Visitable, VisitableMut},
 	specificity::{Specificity, ToSpecificity},
 };
 
@@ -64,6 +64,16 @@ impl<'a> Visitable<'a> for PageRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for PageRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_page_rule(self);
+		if let Some(selectors) = &mut self.selectors {
+			VisitableMut::accept_mut(selectors, v);
+		}
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct PageSelectorList<'a>(pub Vec<'a, (PageSelector<'a>, Option<T![,]>)>);
@@ -97,6 +107,14 @@ impl<'a> Visitable<'a> for PageSelectorList<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for PageSelectorList<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for (selector, _) in &mut self.0 {
+			VisitableMut::accept_mut(selector, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
 #[visit]
@@ -147,6 +165,12 @@ impl<'a> Visitable<'a> for PageSelector<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for PageSelector<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_page_selector(self);
+	}
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
 pub enum PagePseudoClass {
@@ -261,6 +285,17 @@ impl<'a> Visitable<'a> for PageRuleBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for PageRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for property in &mut self.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+		for rule in &mut self.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 // https://drafts.csswg.org/cssom-1/#cssmarginrule
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
@@ -319,6 +354,13 @@ impl<'a> Visitable<'a> for MarginRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for MarginRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_margin_rule(self);
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
 pub struct MarginRuleBlock<'a> {
@@ -359,6 +401,14 @@ impl<'a> Visitable<'a> for MarginRuleBlock<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for MarginRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for property in &mut self.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
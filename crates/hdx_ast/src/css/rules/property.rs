@@ -8,7 +8,7 @@ use hdx_parser::{
 use hdx_proc_macro::visit;
 
 use crate::{
-	css::{Visit, Visitable},
+	css::{Visit, VisitMut, Visitable, VisitableMut},
 	syntax::ComponentValues,
 };
 
@@ -58,6 +58,15 @@ impl<'a> Visitable<'a> for PropertyRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for PropertyRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_property_rule(self);
+		for property in &mut self.block.properties {
+			VisitableMut::accept_mut(property, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct PropertyRuleBlock<'a> {
@@ -132,6 +141,12 @@ impl<'a> Visitable<'a> for PropertyRuleProperty<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for PropertyRuleProperty<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_property_rule_property(self);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum PropertyRuleStyleValue<'a> {
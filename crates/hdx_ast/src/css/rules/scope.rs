@@ -1,7 +1,112 @@
-use crate::Todo;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{diagnostics, CursorSink, Parse, Parser, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
+
+use crate::css::{selector::SelectorList, stylerule::StyleDeclaration, Visit, VisitMut, Visitable, VisitableMut};
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(To, atom!("to"));
+}
 
 // https://drafts.csswg.org/css-cascade-6/#at-ruledef-scope
-pub type ScopeRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
+#[visit]
+pub struct ScopeRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub start: Option<ScopeBoundary<'a>>,
+	pub end: Option<(kw::To, ScopeBoundary<'a>)>,
+	pub block: StyleDeclaration<'a>,
+}
+
+// https://drafts.csswg.org/css-cascade-6/#scope-syntax
+// @scope [(<scope-start>)]? [to (<scope-end>)]? {
+//   <block-contents>
+// }
+impl<'a> Parse<'a> for ScopeRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let at_keyword = p.parse::<T![AtKeyword]>()?;
+		let c: Cursor = at_keyword.into();
+		let atom = p.parse_atom_lower(c);
+		if atom != atom!("scope") {
+			Err(diagnostics::UnexpectedAtRule(atom, c.into()))?;
+		}
+		let start = if p.peek::<T!['(']>() { Some(p.parse::<ScopeBoundary>()?) } else { None };
+		let end = if p.peek::<kw::To>() { Some((p.parse::<kw::To>()?, p.parse::<ScopeBoundary>()?)) } else { None };
+		let block = p.parse::<StyleDeclaration>()?;
+		Ok(Self { at_keyword, start, end, block })
+	}
+}
+
+impl ToCursors for ScopeRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		if let Some(start) = &self.start {
+			ToCursors::to_cursors(start, s);
+		}
+		if let Some((to, end)) = &self.end {
+			s.append(to.into());
+			ToCursors::to_cursors(end, s);
+		}
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for ScopeRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_scope_rule(self);
+		if let Some(start) = &self.start {
+			Visitable::accept(&start.selectors, v);
+		}
+		if let Some((_, end)) = &self.end {
+			Visitable::accept(&end.selectors, v);
+		}
+		Visitable::accept(&self.block, v);
+	}
+}
+
+impl<'a> VisitableMut<'a> for ScopeRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_scope_rule(self);
+		if let Some(start) = &mut self.start {
+			VisitableMut::accept_mut(&mut start.selectors, v);
+		}
+		if let Some((_, end)) = &mut self.end {
+			VisitableMut::accept_mut(&mut end.selectors, v);
+		}
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
+// The parenthesised <scope-start> or <scope-end> of a ScopeRule prelude.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ScopeBoundary<'a> {
+	pub open: T!['('],
+	pub selectors: SelectorList<'a>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Parse<'a> for ScopeBoundary<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let open = p.parse::<T!['(']>()?;
+		let selectors = p.parse::<SelectorList>()?;
+		let close = p.parse_if_peek::<T![')']>()?;
+		Ok(Self { open, selectors, close })
+	}
+}
+
+impl ToCursors for ScopeBoundary<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		ToCursors::to_cursors(&self.selectors, s);
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +115,27 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(ScopeRule, 0);
+		assert_size!(ScopeRule, 256);
+		assert_size!(ScopeBoundary, 64);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(ScopeRule, "@scope");
+		assert_parse!(ScopeRule, "@scope{}");
+		assert_parse!(ScopeRule, "@scope(.card){color:red;}");
+		assert_parse!(ScopeRule, "@scope to (.content){color:red;}");
+		assert_parse!(
+			ScopeRule,
+			"@scope(.card) to (.content){img{border:1px solid black;}}",
+			"@scope(.card)to (.content){img{border:1px solid black;}}"
+		);
+		assert_parse!(ScopeRule, "@scope(.card,.panel){:scope{color:red;}}");
+		assert_parse!(ScopeRule, "@scope(.card){color:red;& .title{color:blue;}}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ScopeRule, "@scope(.card) to{}");
+		assert_parse_error!(ScopeRule, "@media(.card){}");
 	}
 }
@@ -1,7 +1,110 @@
-use crate::Todo;
+use bumpalo::collections::Vec;
+use hdx_atom::atom;
+use hdx_parser::{
+	AtRule, Block, CursorSink, NoPreludeAllowed, Parse, Parser, Result as ParserResult, RuleList, State, ToCursors, T,
+};
+use hdx_proc_macro::visit;
+
+use crate::css::{properties::Property, stylesheet::Rule, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/css-transitions-2/#at-ruledef-starting-style
-pub type StartingStyleRule = Todo;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
+#[visit]
+pub struct StartingStyleRule<'a> {
+	pub at_keyword: T![AtKeyword],
+	pub block: StartingStyleRules<'a>,
+}
+
+impl<'a> Parse<'a> for StartingStyleRule<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let (at_keyword, _, block) = Self::parse_at_rule(p, Some(atom!("starting-style")))?;
+		Ok(Self { at_keyword, block })
+	}
+}
+
+impl<'a> AtRule<'a> for StartingStyleRule<'a> {
+	type Prelude = NoPreludeAllowed;
+	type Block = StartingStyleRules<'a>;
+}
+
+impl ToCursors for StartingStyleRule<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.at_keyword.into());
+		ToCursors::to_cursors(&self.block, s);
+	}
+}
+
+impl<'a> Visitable<'a> for StartingStyleRule<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_starting_style_rule(self);
+		for declaration in &self.block.declarations {
+			Visitable::accept(declaration, v);
+		}
+		for rule in &self.block.rules {
+			Visitable::accept(rule, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for StartingStyleRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_starting_style_rule(self);
+		for declaration in &mut self.block.declarations {
+			VisitableMut::accept_mut(declaration, v);
+		}
+		for rule in &mut self.block.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct StartingStyleRules<'a> {
+	pub open: T!['{'],
+	// https://drafts.csswg.org/css-transitions-2/#defining-before-change-style
+	// At the top level the rule holds style rules, but when nested inside a style rule it holds declarations which
+	// apply to the parent rule's selector.
+	pub declarations: Vec<'a, Property<'a>>,
+	pub rules: Vec<'a, Rule<'a>>,
+	pub close: Option<T!['}']>,
+}
+
+impl<'a> Parse<'a> for StartingStyleRules<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.is(State::Nested) {
+			let (open, declarations, rules, close) = Self::parse_block(p)?;
+			return Ok(Self { open, declarations, rules, close });
+		}
+		let (open, rules, close) = Self::parse_rule_list(p)?;
+		Ok(Self { open, declarations: Vec::new_in(p.bump()), rules, close })
+	}
+}
+
+impl<'a> RuleList<'a> for StartingStyleRules<'a> {
+	type Rule = Rule<'a>;
+}
+
+impl<'a> Block<'a> for StartingStyleRules<'a> {
+	type Declaration = Property<'a>;
+	type Rule = Rule<'a>;
+}
+
+impl ToCursors for StartingStyleRules<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for declaration in &self.declarations {
+			ToCursors::to_cursors(declaration, s);
+		}
+		for rule in &self.rules {
+			ToCursors::to_cursors(rule, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -10,11 +113,20 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(StartingStyleRule, 0);
+		assert_size!(StartingStyleRule, 112);
+		assert_size!(StartingStyleRules, 96);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(StartingStyleRule, "@starting-style");
+		assert_parse!(StartingStyleRule, "@starting-style{}");
+		assert_parse!(StartingStyleRule, "@starting-style{.dialog{opacity:0;}}");
+		assert_parse!(StartingStyleRule, "@starting-style{.dialog{opacity:0;}.popover{translate:0 20px;}}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(StartingStyleRule, "@starting-style .dialog{}");
+		assert_parse_error!(StartingStyleRule, "@media{}");
 	}
 }
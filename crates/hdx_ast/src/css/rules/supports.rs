@@ -1,12 +1,17 @@
-use crate::{
-	css::{properties::Property, selector::ComplexSelector, stylesheet::Rule, Visit, Visitable},
-	syntax::ComponentValues,
+use crate::css::{
+	properties::Property,
+	selector::ComplexSelector,
+	stylesheet::Rule,
+	types::{FontFormat, FontTech},
+	Visit, VisitMut, Visitable, VisitableMut,
 };
+use crate::syntax::{ComponentValue, Function};
 use bumpalo::collections::Vec;
 use hdx_atom::atom;
-use hdx_lexer::Span;
+use hdx_lexer::{Kind, Span};
 use hdx_parser::{
-	diagnostics, AtRule, ConditionalAtRule, CursorSink, Parse, Parser, Result as ParserResult, RuleList, ToCursors, T,
+	diagnostics, AtRule, Block, ConditionalAtRule, CursorSink, Parse, Parser, Result as ParserResult, RuleList, State,
+	ToCursors, T,
 };
 use hdx_proc_macro::visit;
 
@@ -61,18 +66,32 @@ impl<'a> Visitable<'a> for SupportsRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for SupportsRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_supports_rule(self);
+		VisitableMut::accept_mut(&mut self.block, v);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct SupportsRuleBlock<'a> {
 	pub open: T!['{'],
+	// https://drafts.csswg.org/css-nesting/#conditionals
+	// Declarations are only parsed when nested inside a style rule, where they apply to the parent rule's selector.
+	pub declarations: Vec<'a, Property<'a>>,
 	pub rules: Vec<'a, Rule<'a>>,
 	pub close: Option<T!['}']>,
 }
 
 impl<'a> Parse<'a> for SupportsRuleBlock<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.is(State::Nested) {
+			let (open, declarations, rules, close) = Self::parse_block(p)?;
+			return Ok(Self { open, declarations, rules, close });
+		}
 		let (open, rules, close) = Self::parse_rule_list(p)?;
-		Ok(Self { open, rules, close })
+		Ok(Self { open, declarations: Vec::new_in(p.bump()), rules, close })
 	}
 }
 
@@ -80,9 +99,17 @@ impl<'a> RuleList<'a> for SupportsRuleBlock<'a> {
 	type Rule = Rule<'a>;
 }
 
+impl<'a> Block<'a> for SupportsRuleBlock<'a> {
+	type Declaration = Property<'a>;
+	type Rule = Rule<'a>;
+}
+
 impl<'a> ToCursors for SupportsRuleBlock<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		s.append(self.open.into());
+		for declaration in &self.declarations {
+			ToCursors::to_cursors(declaration, s);
+		}
 		for rule in &self.rules {
 			ToCursors::to_cursors(rule, s);
 		}
@@ -94,19 +121,34 @@ impl<'a> ToCursors for SupportsRuleBlock<'a> {
 
 impl<'a> Visitable<'a> for SupportsRuleBlock<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		for declaration in &self.declarations {
+			Visitable::accept(declaration, v);
+		}
 		for rule in &self.rules {
 			Visitable::accept(rule, v);
 		}
 	}
 }
 
+impl<'a> VisitableMut<'a> for SupportsRuleBlock<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		for declaration in &mut self.declarations {
+			VisitableMut::accept_mut(declaration, v);
+		}
+		for rule in &mut self.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 pub enum SupportsCondition<'a> {
 	Is(SupportsFeature<'a>),
-	Not(Box<SupportsCondition<'a>>),
-	And(Vec<'a, SupportsFeature<'a>>),
-	Or(Vec<'a, SupportsFeature<'a>>),
+	Not(T![Ident], Box<SupportsCondition<'a>>),
+	And(Vec<'a, (SupportsFeature<'a>, Option<T![Ident]>)>),
+	Or(Vec<'a, (SupportsFeature<'a>, Option<T![Ident]>)>),
+	Parens(T!['('], Box<SupportsCondition<'a>>, T![')']),
 }
 
 impl<'a> ConditionalAtRule<'a> for SupportsCondition<'a> {
@@ -114,22 +156,22 @@ impl<'a> ConditionalAtRule<'a> for SupportsCondition<'a> {
 	fn new_is(feature: SupportsFeature<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn new_not(feature: SupportsCondition<'a>) -> Self {
-		Self::Not(Box::new(feature))
+	fn new_not(keyword: T![Ident], condition: SupportsCondition<'a>) -> Self {
+		Self::Not(keyword, Box::new(condition))
+	}
+	fn new_and(features: Vec<'a, (SupportsFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::And(features)
 	}
-	fn new_and(feature: Vec<'a, SupportsFeature<'a>>) -> Self {
-		Self::And(feature)
+	fn new_or(features: Vec<'a, (SupportsFeature<'a>, Option<T![Ident]>)>) -> Self {
+		Self::Or(features)
 	}
-	fn new_or(feature: Vec<'a, SupportsFeature<'a>>) -> Self {
-		Self::Or(feature)
+	fn new_parens(open: T!['('], condition: SupportsCondition<'a>, close: T![')']) -> Self {
+		Self::Parens(open, Box::new(condition), close)
 	}
 }
 
 impl<'a> Parse<'a> for SupportsCondition<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		if p.peek::<T![Function]>() || p.peek::<T!['(']>() {
-			return Ok(Self::Is(p.parse::<SupportsFeature>()?));
-		}
 		Self::parse_condition(p)
 	}
 }
@@ -138,17 +180,23 @@ impl<'a> ToCursors for SupportsCondition<'a> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
 			Self::Is(feature) => ToCursors::to_cursors(feature, s),
-			Self::Not(feature) => ToCursors::to_cursors(feature.as_ref(), s),
-			Self::And(features) => {
-				for feature in features {
-					ToCursors::to_cursors(feature, s);
-				}
+			Self::Not(keyword, condition) => {
+				s.append(keyword.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
 			}
-			Self::Or(features) => {
-				for feature in features {
+			Self::And(features) | Self::Or(features) => {
+				for (feature, keyword) in features {
 					ToCursors::to_cursors(feature, s);
+					if let Some(keyword) = keyword {
+						s.append(keyword.into());
+					}
 				}
 			}
+			Self::Parens(open, condition, close) => {
+				s.append(open.into());
+				ToCursors::to_cursors(condition.as_ref(), s);
+				s.append(close.into());
+			}
 		}
 	}
 }
@@ -157,14 +205,9 @@ impl<'a> Visitable<'a> for SupportsCondition<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		match self {
 			Self::Is(feature) => Visitable::accept(feature, v),
-			Self::Not(feature) => Visitable::accept(feature.as_ref(), v),
-			Self::And(features) => {
-				for feature in features {
-					Visitable::accept(feature, v);
-				}
-			}
-			Self::Or(features) => {
-				for feature in features {
+			Self::Not(_, condition) | Self::Parens(_, condition, _) => Visitable::accept(condition.as_ref(), v),
+			Self::And(features) | Self::Or(features) => {
+				for (feature, _) in features {
 					Visitable::accept(feature, v);
 				}
 			}
@@ -175,10 +218,14 @@ impl<'a> Visitable<'a> for SupportsCondition<'a> {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum SupportsFeature<'a> {
-	FontTech(Option<T!['(']>, T![Function], ComponentValues<'a>, T![')'], Option<T![')']>),
-	FontFormat(Option<T!['(']>, T![Function], ComponentValues<'a>, T![')'], Option<T![')']>),
+	FontTech(Option<T!['(']>, T![Function], FontTech, T![')'], Option<T![')']>),
+	FontFormat(Option<T!['(']>, T![Function], FontFormat, T![')'], Option<T![')']>),
 	Selector(Option<T!['(']>, T![Function], ComplexSelector<'a>, T![')'], Option<T![')']>),
 	Property(T!['('], Property<'a>, Option<T![')']>),
+	// https://drafts.csswg.org/mediaqueries-4/#typedef-general-enclosed
+	// Unknown functions, and font-tech() or font-format() with a value which isn't known, are valid but never
+	// supported, so the condition is kept & evaluates to false.
+	GeneralEnclosed(Option<T!['(']>, Function<'a>, Option<T![')']>),
 }
 
 impl<'a> Parse<'a> for SupportsFeature<'a> {
@@ -195,13 +242,27 @@ impl<'a> Parse<'a> for SupportsFeature<'a> {
 					let open_close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
 					Ok(Self::Selector(open, function, selector, close, open_close))
 				}
-				atom!("font-tech") => {
-					todo!();
+				atom!("font-tech") if p.peek::<FontTech>() && p.peek_n(2) == Kind::RightParen => {
+					let tech = p.parse::<FontTech>()?;
+					let close = p.parse::<T![')']>()?;
+					let open_close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
+					Ok(Self::FontTech(open, function, tech, close, open_close))
 				}
-				atom!("font-format") => {
-					todo!();
+				atom!("font-format") if p.peek::<FontFormat>() && p.peek_n(2) == Kind::RightParen => {
+					let format = p.parse::<FontFormat>()?;
+					let close = p.parse::<T![')']>()?;
+					let open_close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
+					Ok(Self::FontFormat(open, function, format, close, open_close))
+				}
+				_ => {
+					let mut values = Vec::new_in(p.bump());
+					while !p.at_end() && !p.peek::<T![')']>() {
+						values.push(p.parse::<ComponentValue>()?);
+					}
+					let close_paren = Some(p.parse::<T![')']>()?);
+					let open_close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
+					Ok(Self::GeneralEnclosed(open, Function { name: function, values, close_paren }, open_close))
 				}
-				atom => Err(diagnostics::UnexpectedFunction(atom, c.into()))?,
 			}
 		} else if let Some(open) = open {
 			let property = p.parse::<Property>()?;
@@ -222,7 +283,7 @@ impl<'a> ToCursors for SupportsFeature<'a> {
 					s.append(open.into());
 				}
 				s.append(function.into());
-				ToCursors::to_cursors(feature, s);
+				s.append((*feature).into());
 				s.append(close.into());
 				if let Some(open_close) = open_close {
 					s.append(open_close.into());
@@ -233,7 +294,7 @@ impl<'a> ToCursors for SupportsFeature<'a> {
 					s.append(open.into());
 				}
 				s.append(function.into());
-				ToCursors::to_cursors(feature, s);
+				s.append((*feature).into());
 				s.append(close.into());
 				if let Some(open_close) = open_close {
 					s.append(open_close.into());
@@ -257,6 +318,15 @@ impl<'a> ToCursors for SupportsFeature<'a> {
 					s.append(close.into());
 				}
 			}
+			Self::GeneralEnclosed(open, function, open_close) => {
+				if let Some(open) = open {
+					s.append(open.into());
+				}
+				ToCursors::to_cursors(function, s);
+				if let Some(open_close) = open_close {
+					s.append(open_close.into());
+				}
+			}
 		}
 	}
 }
@@ -264,8 +334,8 @@ impl<'a> ToCursors for SupportsFeature<'a> {
 impl<'a> Visitable<'a> for SupportsFeature<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
 		match self {
-			Self::FontTech(_, _, _, _, _) => todo!(),
-			Self::FontFormat(_, _, _, _, _) => todo!(),
+			// Font technologies and formats are plain keywords, with nothing further to visit.
+			Self::FontTech(_, _, _, _, _) | Self::FontFormat(_, _, _, _, _) | Self::GeneralEnclosed(_, _, _) => {}
 			Self::Selector(_, _, selector, _, _) => Visitable::accept(selector, v),
 			Self::Property(_, property, _) => Visitable::accept(property, v),
 		}
@@ -279,15 +349,41 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(SupportsRule, 512);
-		assert_size!(SupportsCondition, 432);
-		assert_size!(SupportsRuleBlock, 64);
+		assert_size!(SupportsRule, 576);
+		assert_size!(SupportsCondition, 464);
+		assert_size!(SupportsRuleBlock, 96);
 	}
 
 	#[test]
 	fn test_writes() {
 		assert_parse!(SupportsRule, "@supports(color:black){}");
 		assert_parse!(SupportsRule, "@supports(width:1px){body{width:1px}}");
+		assert_parse!(SupportsRule, "@supports font-tech(color-COLRv1){}");
+		assert_parse!(SupportsRule, "@supports font-format(woff2){@font-face{font-family:x;}}");
+		assert_parse!(SupportsRule, "@supports(font-format(opentype)){}");
+		assert_parse!(SupportsRule, "@supports not (display:grid){}");
+		assert_parse!(SupportsRule, "@supports(color:red) or (color:blue){}", "@supports(color:red)or (color:blue){}");
+		assert_parse!(
+			SupportsRule,
+			"@supports(color:red) and (display:grid){}",
+			"@supports(color:red)and (display:grid){}"
+		);
+		assert_parse!(
+			SupportsRule,
+			"@supports not ((color:red) or (color:blue)){}",
+			"@supports not ((color:red)or (color:blue)){}"
+		);
+		assert_parse!(SupportsRule, "@supports not selector(h2>p){}");
+		assert_parse!(SupportsRule, "@supports font-format(bogus){a{color:red}}");
+		assert_parse!(SupportsRule, "@supports font-tech(woff2){}");
+		assert_parse!(SupportsRule, "@supports font-format('woff2'){}");
+		assert_parse!(SupportsRule, "@supports(font-format(woff2 woff)){}");
+		assert_parse!(SupportsRule, "@supports not foo(bar){}");
+		assert_parse!(
+			SupportsRule,
+			"@supports font-format(bogus) or (display:grid){}",
+			"@supports font-format(bogus)or (display:grid){}"
+		);
 		// assert_parse!(SupportsRule, "@supports not (width:1--foo){}");
 		// assert_parse!(SupportsRule, "@supports(width: 1--foo) or (width: 1foo) {\n\n}");
 		// assert_parse!(SupportsRule, "@supports(width: 1--foo) and (width: 1foo) {\n\n}");
@@ -299,4 +395,10 @@ mod tests {
 		// assert_parse!(SupportsRule, "@supports not selector(h2 > p) {\n\n}");
 		// assert_parse!(SupportsRule, "@supports not (selector(h2 > p)) {}", "@supports not selector(h2 > p) {\n\n}");
 	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(SupportsRule, "@supports font-format(woff2{}");
+		assert_parse_error!(SupportsRule, "@supports woff2{}");
+	}
 }
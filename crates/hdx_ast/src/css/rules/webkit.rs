@@ -3,7 +3,7 @@ use hdx_lexer::Span;
 use hdx_parser::{diagnostics, AtRule, CursorSink, Parse, Parser, Result as ParserResult, ToCursors, T};
 use hdx_proc_macro::visit;
 
-use crate::css::{Visit, Visitable};
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
 
 use super::{KeyframesBlock, KeyframesName};
 
@@ -12,9 +12,9 @@ use super::{KeyframesBlock, KeyframesName};
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
 #[visit]
 pub struct WebkitKeyframesRule<'a> {
-	at_keyword: T![AtKeyword],
-	name: KeyframesName,
-	block: KeyframesBlock<'a>,
+	pub at_keyword: T![AtKeyword],
+	pub name: KeyframesName,
+	pub block: KeyframesBlock<'a>,
 }
 
 impl<'a> Parse<'a> for WebkitKeyframesRule<'a> {
@@ -44,7 +44,15 @@ impl<'a> ToCursors for WebkitKeyframesRule<'a> {
 
 impl<'a> Visitable<'a> for WebkitKeyframesRule<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
-		todo!();
+		v.visit_webkit_keyframes_rule(self);
+		Visitable::accept(&self.block, v);
+	}
+}
+
+impl<'a> VisitableMut<'a> for WebkitKeyframesRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_webkit_keyframes_rule(self);
+		VisitableMut::accept_mut(&mut self.block, v);
 	}
 }
 
@@ -64,7 +64,7 @@ impl<'a> Parse<'a> for FunctionalPseudoClass<'a> {
 			atom!("is") => {
 				let value = p.parse::<ForgivingSelector>()?;
 				let close = p.parse_if_peek::<T![')']>()?;
-				Self::HostContext(HostContextPseudoFunction { colon, function, value, close })
+				Self::Is(IsPseudoFunction { colon, function, value, close })
 			}
 			atom!("lang") => {
 				let mut value = Vec::new_in(p.bump());
@@ -151,7 +151,23 @@ impl<'a> ToCursors for FunctionalPseudoClass<'a> {
 
 impl<'a> Visitable<'a> for FunctionalPseudoClass<'a> {
 	fn accept<V: Visit<'a>>(&self, v: &mut V) {
-		todo!();
+		match self {
+			Self::Has(c) => Visitable::accept(&c.value, v),
+			Self::Host(c) => Visitable::accept(&c.value, v),
+			Self::HostContext(c) => Visitable::accept(&c.value, v),
+			Self::Is(c) => Visitable::accept(&c.value, v),
+			Self::Not(c) => Visitable::accept(&c.value, v),
+			Self::Where(c) => Visitable::accept(&c.value, v),
+			Self::Dir(_)
+			| Self::Lang(_)
+			| Self::NthChild(_)
+			| Self::NthCol(_)
+			| Self::NthLastChild(_)
+			| Self::NthLastCol(_)
+			| Self::NthLastOfType(_)
+			| Self::NthOfType(_)
+			| Self::State(_) => {}
+		}
 	}
 }
 
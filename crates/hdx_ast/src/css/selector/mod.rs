@@ -36,7 +36,7 @@ pub use pseudo_element::*;
 pub use tag::*;
 pub use webkit::*;
 
-use super::{Visit, Visitable};
+use super::{Visit, VisitMut, Visitable, VisitableMut};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
@@ -70,6 +70,15 @@ impl<'a> Visitable<'a> for SelectorList<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for SelectorList<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_selector_list(self);
+		for selector in &mut self.0 {
+			VisitableMut::accept_mut(selector, v);
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
@@ -109,6 +118,12 @@ impl<'a> Visitable<'a> for CompoundSelector<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for CompoundSelector<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_compound_selector(self);
+	}
+}
+
 pub type ComplexSelector<'a> = SelectorList<'a>;
 pub type ForgivingSelector<'a> = SelectorList<'a>;
 pub type RelativeSelector<'a> = SelectorList<'a>;
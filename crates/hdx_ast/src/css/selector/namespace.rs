@@ -1,10 +1,13 @@
 use hdx_lexer::{Cursor, KindSet, Span};
-use hdx_parser::{Build, CursorSink, Is, Parse, Parser, Result as ParserResult, ToCursors, T};
+use hdx_parser::{diagnostics, Build, CursorSink, Error, Is, Parse, Parser, Result as ParserResult, ToCursors, Vec, T};
 use hdx_proc_macro::visit;
 
-use crate::css::{Visit, Visitable};
+use crate::css::{
+	rules::{ImportUrl, NamespaceRule},
+	Visit, Visitable,
+};
 
-use super::Tag;
+use super::{Attribute, Tag};
 
 // https://drafts.csswg.org/selectors/#combinators
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -164,6 +167,83 @@ impl From<&NamespaceTag> for Span {
 	}
 }
 
+// https://drafts.csswg.org/css-namespaces/#declaration
+// The namespaces declared by a StyleSheet's @namespace rules. Later declarations of the same prefix (or of the default
+// namespace) replace earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct NamespaceTable<'a> {
+	pub default: Option<ImportUrl>,
+	pub prefixes: Vec<'a, (T![Ident], ImportUrl)>,
+}
+
+impl<'a> NamespaceTable<'a> {
+	pub fn new_in(p: &Parser<'a>) -> Self {
+		Self { default: None, prefixes: Vec::new_in(p.bump()) }
+	}
+
+	pub fn declare(&mut self, p: &Parser<'a>, rule: &NamespaceRule) {
+		if let Some(prefix) = rule.prefix {
+			if let Some(index) = self.position(p, prefix.into()) {
+				self.prefixes[index] = (prefix, rule.url);
+			} else {
+				self.prefixes.push((prefix, rule.url));
+			}
+		} else {
+			self.default = Some(rule.url);
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.default.is_none() && self.prefixes.is_empty()
+	}
+
+	// Namespace prefixes are case-sensitive, so this compares the raw source text.
+	pub fn get(&self, p: &Parser<'a>, prefix: Cursor) -> Option<ImportUrl> {
+		self.position(p, prefix).map(|index| self.prefixes[index].1)
+	}
+
+	fn position(&self, p: &Parser<'a>, prefix: Cursor) -> Option<usize> {
+		let name = p.parse_raw_str(prefix);
+		self.prefixes.iter().position(|(ident, _)| p.parse_raw_str(ident.into()) == name)
+	}
+
+	// https://drafts.csswg.org/selectors/#type-nmsp
+	// A type or attribute selector with a prefix that hasn't been declared is invalid. This returns an error for each
+	// such prefix within the given node.
+	pub fn check<T: Visitable<'a>>(&self, p: &Parser<'a>, node: &T) -> std::vec::Vec<Error> {
+		let mut visitor = NamespacePrefixes::default();
+		node.accept(&mut visitor);
+		visitor
+			.0
+			.into_iter()
+			.filter(|prefix| self.get(p, (*prefix).into()).is_none())
+			.map(|prefix| {
+				let c: Cursor = prefix.into();
+				diagnostics::UndeclaredNamespacePrefix(p.parse_atom(c), c.into()).into()
+			})
+			.collect()
+	}
+}
+
+// Collects the named prefixes of every type and attribute selector.
+#[derive(Default)]
+struct NamespacePrefixes(std::vec::Vec<T![Ident]>);
+
+impl Visit<'_> for NamespacePrefixes {
+	fn visit_namespace(&mut self, namespace: &Namespace) {
+		if let Some(NamespacePrefix::Name(ident, _)) = namespace.prefix {
+			self.0.push(ident);
+		}
+	}
+
+	fn visit_attribute(&mut self, attribute: &Attribute) {
+		if let Some(NamespacePrefix::Name(ident, _)) = attribute.namespace_prefix {
+			self.0.push(ident);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -119,7 +119,7 @@ impl<'a> Parse<'a> for PseudoClass {
 			atom!("required") => Self::Active(colon, ident),
 			atom!("right") => Self::Active(colon, ident),
 			atom!("root") => Self::Active(colon, ident),
-			atom!("scope") => Self::Active(colon, ident),
+			atom!("scope") => Self::Scope(colon, ident),
 			atom!("target") => Self::Active(colon, ident),
 			atom!("target-within") => Self::Active(colon, ident),
 			atom!("valid") => Self::Active(colon, ident),
@@ -7,7 +7,7 @@ use hdx_lexer::Cursor;
 use hdx_parser::{Block, CursorSink, Parse, Parser, QualifiedRule, Result as ParserResult, ToCursors, Vec, T};
 use hdx_proc_macro::visit;
 
-use super::{rules, UnknownAtRule, UnknownQualifiedRule, Visit, Visitable};
+use super::{rules, UnknownAtRule, UnknownQualifiedRule, Visit, VisitMut, Visitable, VisitableMut};
 
 // https://drafts.csswg.org/cssom-1/#the-cssstylerule-interface
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -47,6 +47,14 @@ impl<'a> Visitable<'a> for StyleRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for StyleRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_style_rule(self);
+		VisitableMut::accept_mut(&mut self.selectors, v);
+		VisitableMut::accept_mut(&mut self.style, v);
+	}
+}
+
 // https://drafts.csswg.org/cssom-1/#the-cssstylerule-interface
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename = "style-declaration"))]
@@ -97,6 +105,18 @@ impl<'a> Visitable<'a> for StyleDeclaration<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for StyleDeclaration<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_style_declaration(self);
+		for declaration in &mut self.declarations {
+			VisitableMut::accept_mut(declaration, v);
+		}
+		for rule in &mut self.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 // https://drafts.csswg.org/css-nesting/#conditionals
 macro_rules! apply_rules {
 	($macro: ident) => {
@@ -104,7 +124,8 @@ macro_rules! apply_rules {
 			ContainerRule<'a>: atom!("container"),
 			LayerRule<'a>: atom!("layer"),
 			MediaRule<'a>: atom!("media"),
-			ScopeRule: atom!("scope"),
+			ScopeRule<'a>: atom!("scope"),
+			StartingStyleRule<'a>: atom!("starting-style"),
 			SupportsRule<'a>: atom!("supports"),
 		}
 	};
@@ -198,6 +219,24 @@ impl<'a> Visitable<'a> for NestedGroupRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for NestedGroupRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		macro_rules! match_rule {
+			( $(
+				$name: ident$(<$a: lifetime>)?: $atom: pat,
+			)+ ) => {
+				match self {
+					$(Self::$name(r) => VisitableMut::accept_mut(r, v),)+
+					Self::UnknownAt(r) => VisitableMut::accept_mut(r, v),
+					Self::Style(r) => VisitableMut::accept_mut(r, v),
+					Self::Unknown(r) => VisitableMut::accept_mut(r, v),
+				};
+			}
+		}
+		apply_rules!(match_rule);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -217,7 +256,14 @@ mod tests {
 		assert_parse!(StyleRule, ".foo *{}", ".foo *{}");
 		assert_parse!(StyleRule, ":nth-child(1){opacity:0;}");
 		assert_parse!(StyleRule, ".foo{--bar:(baz);}");
-		assert_parse!(StyleRule, ".foo{width: calc(1px + (var(--foo)) + 1px);}");
+		assert_parse!(
+			StyleRule,
+			".foo{width: calc(1px + (var(--foo)) + 1px);}",
+			".foo{width:calc(1px + (var(--foo)) + 1px);}"
+		);
 		assert_parse!(StyleRule, ".foo{--bar:1}");
+		assert_parse!(StyleRule, ".foo{@media print{color:red;}}");
+		assert_parse!(StyleRule, ".foo{@supports(display:grid){color:red;&:hover{color:blue;}}}");
+		assert_parse!(StyleRule, ".dialog{opacity:1;@starting-style{opacity:0;}}");
 	}
 }
@@ -4,7 +4,7 @@ use hdx_parser::{CursorSink, Parse, Parser, Result as ParserResult, StyleSheet a
 use hdx_proc_macro::visit;
 
 use crate::{
-	css::{rules, stylerule::StyleRule, Visit, Visitable},
+	css::{rules, selector::NamespaceTable, stylerule::StyleRule, Visit, VisitMut, Visitable, VisitableMut},
 	syntax::{AtRule, QualifiedRule},
 };
 
@@ -14,6 +14,10 @@ use crate::{
 #[visit]
 pub struct StyleSheet<'a> {
 	pub rules: Vec<'a, Rule<'a>>,
+	// Derived from the @namespace rules above, so it is left out of the serialized output. Most StyleSheets have no
+	// @namespace rules, so this is only allocated for those which do.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub namespaces: Option<&'a NamespaceTable<'a>>,
 }
 
 // A StyleSheet represents the root node of a CSS-like language.
@@ -22,7 +26,26 @@ pub struct StyleSheet<'a> {
 // AtRules vs QualifiedRules are differentiated by two different functions.
 impl<'a> Parse<'a> for StyleSheet<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		Ok(Self { rules: Self::parse_stylesheet(p)? })
+		let rules = Self::parse_stylesheet(p)?;
+		// https://drafts.csswg.org/css-namespaces/#syntax
+		// @namespace rules are only valid after any @charset and @import rules, and before all other rules.
+		let mut namespaces = NamespaceTable::new_in(p);
+		for rule in rules
+			.iter()
+			.take_while(|rule| matches!(rule, Rule::CharsetRule(_) | Rule::ImportRule(_) | Rule::NamespaceRule(_)))
+		{
+			if let Rule::NamespaceRule(rule) = rule {
+				namespaces.declare(p, rule);
+			}
+		}
+		// Every namespace prefix is followed by a `|`, so a StyleSheet without one can't have any prefixes to check.
+		if p.source_text().contains('|') {
+			for error in namespaces.check(p, &rules) {
+				p.push_error(error);
+			}
+		}
+		let namespaces = if namespaces.is_empty() { None } else { Some(&*p.bump().alloc(namespaces)) };
+		Ok(Self { rules, namespaces })
 	}
 }
 
@@ -47,25 +70,34 @@ impl<'a> Visitable<'a> for StyleSheet<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for StyleSheet<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_style_sheet(self);
+		for rule in &mut self.rules {
+			VisitableMut::accept_mut(rule, v);
+		}
+	}
+}
+
 macro_rules! apply_rules {
 	($macro: ident) => {
 		$macro! {
 			CharsetRule: atom!("charset"),
-			ColorProfileRule: atom!("color-profile"),
+			ColorProfileRule<'a>: atom!("color-profile"),
 			ContainerRule<'a>: atom!("container"),
-			CounterStyleRule: atom!("counter-style"),
+			CounterStyleRule<'a>: atom!("counter-style"),
 			FontFaceRule<'a>: atom!("font-face"),
-			FontFeatureValuesRule: atom!("font-feature-values"),
-			FontPaletteValuesRule: atom!("font-palette-values"),
-			ImportRule: atom!("import"),
+			FontFeatureValuesRule<'a>: atom!("font-feature-values"),
+			FontPaletteValuesRule<'a>: atom!("font-palette-values"),
+			ImportRule<'a>: atom!("import"),
 			KeyframesRule<'a>: atom!("keyframes"),
 			LayerRule<'a>: atom!("layer"),
 			MediaRule<'a>: atom!("media"),
 			NamespaceRule: atom!("namespace"),
 			PageRule<'a>: atom!("page"),
 			PropertyRule<'a>: atom!("property"),
-			ScopeRule: atom!("scope"),
-			StartingStyleRule: atom!("starting-style"),
+			ScopeRule<'a>: atom!("scope"),
+			StartingStyleRule<'a>: atom!("starting-style"),
 			SupportsRule<'a>: atom!("supports"),
 
 			// Deprecated Rules
@@ -103,6 +135,12 @@ impl<'a> Visitable<'a> for UnknownAtRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for UnknownAtRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_unknown_at_rule(self);
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
@@ -126,6 +164,12 @@ impl<'a> Visitable<'a> for UnknownQualifiedRule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for UnknownQualifiedRule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_unknown_qualified_rule(self);
+	}
+}
+
 macro_rules! rule {
     ( $(
         $name: ident$(<$a: lifetime>)?: $atom: pat,
@@ -215,6 +259,24 @@ impl<'a> Visitable<'a> for Rule<'a> {
 	}
 }
 
+impl<'a> VisitableMut<'a> for Rule<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		macro_rules! match_rule {
+			( $(
+				$name: ident$(<$a: lifetime>)?: $atom: pat,
+			)+ ) => {
+				match self {
+					$(Self::$name(r) => VisitableMut::accept_mut(r, v),)+
+					Self::UnknownAt(r) => VisitableMut::accept_mut(r, v),
+					Self::Style(r) => VisitableMut::accept_mut(r, v),
+					Self::Unknown(r) => VisitableMut::accept_mut(r, v),
+				};
+			}
+		}
+		apply_rules!(match_rule);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -222,8 +284,8 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(StyleSheet, 32);
-		assert_size!(Rule, 512);
+		assert_size!(StyleSheet, 40);
+		assert_size!(Rule, 576);
 	}
 
 	#[test]
@@ -232,5 +294,142 @@ mod tests {
 		assert_parse!(StyleSheet, "body,tr:nth-child(n-1){}");
 		assert_parse!(StyleSheet, "body{width:1px;}");
 		assert_parse!(StyleSheet, "body{width:1px;}.a{width:2px;}");
+		assert_parse!(StyleSheet, "@namespace svg url(http://www.w3.org/2000/svg);svg|a{}");
+		assert_parse!(StyleSheet, "@namespace xlink url(http://www.w3.org/1999/xlink);[xlink|href]{}");
+		assert_parse!(StyleSheet, "@namespace svg url(x);@media print{:is(svg|rect,*|a){}}");
+		assert_parse!(StyleSheet, "a{@media print{b{}color:red;}}");
+		assert_parse!(StyleSheet, "a{@media print{color:red;b{}width:1px;c{}}}");
+		assert_parse!(StyleSheet, "a{@container(width>1px){color:red;b{}}}");
+		assert_parse!(StyleSheet, "a{@layer x{color:red;}}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(StyleSheet, "svg|a{}");
+		assert_parse_error!(StyleSheet, "[xlink|href]{}");
+		assert_parse_error!(StyleSheet, "@namespace SVG url(x);svg|a{}");
+		assert_parse_error!(StyleSheet, "a{}@namespace svg url(x);svg|a{}");
+		assert_parse_error!(StyleSheet, "@media print{:not(svg|a){}}");
+	}
+
+	#[test]
+	fn test_undeclared_namespace_is_a_warning() {
+		let allocator = bumpalo::Bump::default();
+		let result =
+			Parser::new(&allocator, "svg|a{}b{}", hdx_parser::Features::default()).parse_entirely::<StyleSheet>();
+		assert_eq!(result.output.unwrap().rules.len(), 2);
+		assert!(matches!(result.errors[..], [ref error] if error.severity() == Some(miette::Severity::Warning)));
+	}
+
+	#[test]
+	fn test_pretty() {
+		assert_pretty!(StyleSheet, "body{}", "body {}\n");
+		assert_pretty!(
+			StyleSheet,
+			"a,b>c{width:1px;color:red!important}",
+			"a, b > c {\n\twidth: 1px;\n\tcolor: red !important;\n}\n"
+		);
+		assert_pretty!(StyleSheet, "a{width:1px}b{width:2px}", "a {\n\twidth: 1px;\n}\n\nb {\n\twidth: 2px;\n}\n");
+		assert_pretty!(
+			StyleSheet,
+			"a{b{width:calc( 1px + 2px )}}",
+			"a {\n\tb {\n\t\twidth: calc(1px + 2px);\n\t}\n}\n"
+		);
+		assert_pretty!(
+			StyleSheet,
+			"@media (resolution>2dppx) and (1px<width<=2px){a[b=c]{color:red}}",
+			"@media (resolution > 2dppx) and (1px < width <= 2px) {\n\ta[b=c] {\n\t\tcolor: red;\n\t}\n}\n"
+		);
+		assert_pretty!(
+			StyleSheet,
+			"/* a */\na{width:1px /* b */;}\n/* c */",
+			"/* a */\na {\n\twidth: 1px /* b */;\n}\n/* c */\n"
+		);
+		assert_pretty!(
+			StyleSheet,
+			"a{width:1px;b{color:red}}c{}",
+			"a { width:1px; b { color:red } }\nc {}\n",
+			hdx_parser::PrettyOptions {
+				indent_style: hdx_parser::IndentStyle::Spaces(2),
+				newline_per_declaration: false,
+				space_after_colon: false,
+				blank_lines_between_rules: 0,
+			}
+		);
+		assert_pretty!(
+			StyleSheet,
+			"a{b{color:red}}",
+			"a {\n  b {\n    color: red;\n  }\n}\n",
+			hdx_parser::PrettyOptions { indent_style: hdx_parser::IndentStyle::Spaces(2), ..Default::default() }
+		);
+	}
+
+	#[test]
+	fn test_source_map() {
+		let allocator = bumpalo::Bump::default();
+		let source_text = "a{color:red}\nb{}";
+		let result =
+			Parser::new(&allocator, source_text, hdx_parser::Features::default()).parse_entirely::<StyleSheet>();
+		let mut writer = hdx_parser::SourceMapWriter::new(String::new()).with_source("a.css", source_text);
+		result
+			.write_pretty_with_map(&mut hdx_parser::CursorStream::new(&allocator), &Default::default(), &mut writer)
+			.unwrap();
+		let (str, map) = writer.finish();
+		assert_eq!(str, "a {\n\tcolor: red;\n}\n\nb {}\n");
+		assert_eq!(
+			map.mappings()
+				.iter()
+				.map(|m| (m.generated_line, m.generated_column, m.original_line, m.original_column))
+				.collect::<std::vec::Vec<_>>(),
+			vec![
+				(0, 0, 0, 0),
+				(0, 2, 0, 1),
+				(1, 1, 0, 2),
+				(1, 6, 0, 7),
+				(1, 8, 0, 8),
+				(2, 0, 0, 11),
+				(4, 0, 1, 0),
+				(4, 2, 1, 1),
+				(4, 3, 1, 2)
+			]
+		);
+		assert_eq!(map.encode_mappings(), "AAAA,EAAC;CAAC,KAAK,EAAC;AAAG;;AACX,EAAC,CAAC");
+	}
+
+	#[test]
+	fn test_input_source_map() {
+		use hdx_parser::{Mapping, SourceMap, SourceMapWriter};
+		// `a{color:red}` compiled from `a.scss`, where the declaration came from line 3.
+		let mut input = SourceMap::new();
+		input.add_source("a.scss", Some("a {\n\n  color: red;\n}"));
+		for (generated_column, original_line, original_column) in [(0, 0, 0), (2, 2, 2)] {
+			input.add_mapping(Mapping {
+				generated_line: 0,
+				generated_column,
+				source: 0,
+				original_line,
+				original_column,
+			});
+		}
+		assert_eq!(SourceMap::from_json(&input.to_json()).unwrap(), input);
+		let source_text = format!("a{{color:red}}\n/*# sourceMappingURL={} */", input.to_data_url());
+		let allocator = bumpalo::Bump::default();
+		let result =
+			Parser::new(&allocator, &source_text, hdx_parser::Features::default()).parse_entirely::<StyleSheet>();
+		let (_, url) = result.source_mapping_url().unwrap();
+		assert!(url.starts_with("data:application/json"));
+		let mut writer = SourceMapWriter::new(String::new()).with_source("a.css", &source_text);
+		result.write_with_map(&mut hdx_parser::CursorStream::new(&allocator), &mut writer).unwrap();
+		let (str, mut map) = writer.finish();
+		assert_eq!(str, "a{color:red}");
+		map.compose(0, &SourceMap::from_data_url(url).unwrap());
+		assert_eq!(map.sources, vec!["a.scss"]);
+		assert_eq!(
+			map.mappings()
+				.iter()
+				.map(|m| (m.generated_column, m.original_line, m.original_column))
+				.collect::<std::vec::Vec<_>>(),
+			vec![(0, 0, 0), (1, 0, 1), (2, 2, 2), (7, 2, 7), (8, 2, 8), (11, 2, 11)]
+		);
 	}
 }
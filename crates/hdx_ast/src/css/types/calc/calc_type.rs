@@ -0,0 +1,180 @@
+// https://drafts.csswg.org/css-values-4/#css-type
+// The base types a math expression can resolve to. A <number> has no base type at all.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CalcBaseType {
+	Length,
+	Angle,
+	Time,
+	Frequency,
+	Resolution,
+	Flex,
+	Percent,
+}
+
+impl CalcBaseType {
+	const ALL: [Self; 7] =
+		[Self::Length, Self::Angle, Self::Time, Self::Frequency, Self::Resolution, Self::Flex, Self::Percent];
+}
+
+// https://drafts.csswg.org/css-values-4/#css-type
+// A map of each base type to its exponent, so `1px * 1px` has a Length of 2, and `1px / 1s` has a Length of 1 and a
+// Time of -1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CalcType {
+	exponents: [i8; 7],
+	percent_hint: Option<CalcBaseType>,
+	// Set for substitutions like var(), which cannot be typed until they have been substituted. These are compatible
+	// with any other type.
+	unknown: bool,
+}
+
+impl CalcType {
+	pub const NUMBER: Self = Self { exponents: [0; 7], percent_hint: None, unknown: false };
+	pub const UNKNOWN: Self = Self { exponents: [0; 7], percent_hint: None, unknown: true };
+
+	pub const fn new(base: CalcBaseType) -> Self {
+		let mut exponents = [0; 7];
+		exponents[base as usize] = 1;
+		Self { exponents, percent_hint: None, unknown: false }
+	}
+
+	#[inline]
+	pub fn exponent(&self, base: CalcBaseType) -> i8 {
+		self.exponents[base as usize]
+	}
+
+	#[inline]
+	pub fn percent_hint(&self) -> Option<CalcBaseType> {
+		self.percent_hint
+	}
+
+	#[inline]
+	pub fn is_unknown(&self) -> bool {
+		self.unknown
+	}
+
+	// https://drafts.csswg.org/css-values-4/#css-match
+	pub fn is_number(&self) -> bool {
+		self.unknown || (self.exponents == [0; 7] && self.percent_hint.is_none())
+	}
+
+	// https://drafts.csswg.org/css-values-4/#css-match
+	pub fn matches(&self, base: CalcBaseType) -> bool {
+		self.unknown || (*self == Self::new(base))
+	}
+
+	// https://drafts.csswg.org/css-values-4/#css-match
+	// Matches a type like <length-percentage>, where percentages resolve against the given base type.
+	pub fn matches_with_percentage(&self, base: CalcBaseType) -> bool {
+		if self.unknown || *self == Self::new(CalcBaseType::Percent) {
+			return true;
+		}
+		self.exponents == Self::new(base).exponents && self.percent_hint.is_none_or(|hint| hint == base)
+	}
+
+	// https://drafts.csswg.org/css-values-4/#apply-the-percent-hint
+	fn apply_percent_hint(&mut self, hint: CalcBaseType) {
+		let percent = CalcBaseType::Percent as usize;
+		self.exponents[hint as usize] += self.exponents[percent];
+		self.exponents[percent] = 0;
+		self.percent_hint = Some(hint);
+	}
+
+	fn unify_percent_hints(&mut self, other: &mut Self) -> Option<()> {
+		match (self.percent_hint, other.percent_hint) {
+			(Some(a), Some(b)) if a != b => return None,
+			(Some(hint), None) => other.apply_percent_hint(hint),
+			(None, Some(hint)) => self.apply_percent_hint(hint),
+			_ => {}
+		}
+		Some(())
+	}
+
+	// https://drafts.csswg.org/css-values-4/#css-add-two-types
+	pub fn add_type(self, other: Self) -> Option<Self> {
+		if self.unknown || other.unknown {
+			return Some(Self::UNKNOWN);
+		}
+		let (mut a, mut b) = (self, other);
+		a.unify_percent_hints(&mut b)?;
+		if a.exponents == b.exponents {
+			return Some(a);
+		}
+		// If one side has a percentage and the other has some other base type, the percentage may resolve against that
+		// base type, for example `50% + 1px` resolves to a Length.
+		let percent = CalcBaseType::Percent as usize;
+		if a.exponents[percent] == 0 && b.exponents[percent] == 0 {
+			return None;
+		}
+		for hint in CalcBaseType::ALL {
+			if hint == CalcBaseType::Percent || (a.exponent(hint) == 0 && b.exponent(hint) == 0) {
+				continue;
+			}
+			let (mut a, mut b) = (a, b);
+			a.apply_percent_hint(hint);
+			b.apply_percent_hint(hint);
+			if a.exponents == b.exponents {
+				return Some(a);
+			}
+		}
+		None
+	}
+
+	// https://drafts.csswg.org/css-values-4/#css-multiply-two-types
+	pub fn multiply_type(self, other: Self) -> Option<Self> {
+		if self.unknown || other.unknown {
+			return Some(Self::UNKNOWN);
+		}
+		let (mut a, mut b) = (self, other);
+		a.unify_percent_hints(&mut b)?;
+		for (exponent, other) in a.exponents.iter_mut().zip(b.exponents) {
+			*exponent += other;
+		}
+		Some(a)
+	}
+
+	// https://drafts.csswg.org/css-values-4/#invert-a-type
+	pub fn invert(self) -> Self {
+		let mut inverted = self;
+		for exponent in inverted.exponents.iter_mut() {
+			*exponent = -*exponent;
+		}
+		inverted
+	}
+}
+
+impl From<CalcBaseType> for CalcType {
+	fn from(value: CalcBaseType) -> Self {
+		Self::new(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_add() {
+		let length = CalcType::new(CalcBaseType::Length);
+		let percent = CalcType::new(CalcBaseType::Percent);
+		let time = CalcType::new(CalcBaseType::Time);
+		assert_eq!(length.add_type(length), Some(length));
+		assert!(length.add_type(percent).unwrap().matches_with_percentage(CalcBaseType::Length));
+		assert!(!length.add_type(percent).unwrap().matches(CalcBaseType::Length));
+		assert_eq!(length.add_type(time), None);
+		assert_eq!(length.add_type(CalcType::NUMBER), None);
+		assert_eq!(length.add_type(CalcType::UNKNOWN), Some(CalcType::UNKNOWN));
+	}
+
+	#[test]
+	fn test_multiply() {
+		let length = CalcType::new(CalcBaseType::Length);
+		let time = CalcType::new(CalcBaseType::Time);
+		assert_eq!(length.multiply_type(CalcType::NUMBER), Some(length));
+		assert_eq!(length.multiply_type(length).unwrap().exponent(CalcBaseType::Length), 2);
+		assert!(length.multiply_type(length.invert()).unwrap().is_number());
+		let speed = length.multiply_type(time.invert()).unwrap();
+		assert_eq!(speed.exponent(CalcBaseType::Length), 1);
+		assert_eq!(speed.exponent(CalcBaseType::Time), -1);
+	}
+}
@@ -0,0 +1,525 @@
+mod calc_type;
+
+use bumpalo::collections::Vec;
+use hdx_atom::{atom, Atom};
+use hdx_lexer::{Cursor, Kind, Span};
+use hdx_parser::{diagnostics, keyword_typedef, CursorSink, Is, Parse, Parser, Result as ParserResult, ToCursors, T};
+
+use crate::{
+	css::units::{Angle, CSSNumber, Flex, Frequency, Length, Resolution, Time},
+	syntax::Function,
+};
+
+pub use calc_type::*;
+
+// https://drafts.csswg.org/css-values-4/#math-function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
+pub enum MathFunctionName {
+	Calc,
+	Min,
+	Max,
+	Clamp,
+	Round,
+	Mod,
+	Rem,
+	Sin,
+	Cos,
+	Tan,
+	Asin,
+	Acos,
+	Atan,
+	Atan2,
+	Pow,
+	Sqrt,
+	Hypot,
+	Log,
+	Exp,
+	Abs,
+	Sign,
+}
+
+impl MathFunctionName {
+	pub fn from_atom(atom: &Atom) -> Option<Self> {
+		Some(match *atom {
+			atom!("calc") => Self::Calc,
+			atom!("min") => Self::Min,
+			atom!("max") => Self::Max,
+			atom!("clamp") => Self::Clamp,
+			atom!("round") => Self::Round,
+			atom!("mod") => Self::Mod,
+			atom!("rem") => Self::Rem,
+			atom!("sin") => Self::Sin,
+			atom!("cos") => Self::Cos,
+			atom!("tan") => Self::Tan,
+			atom!("asin") => Self::Asin,
+			atom!("acos") => Self::Acos,
+			atom!("atan") => Self::Atan,
+			atom!("atan2") => Self::Atan2,
+			atom!("pow") => Self::Pow,
+			atom!("sqrt") => Self::Sqrt,
+			atom!("hypot") => Self::Hypot,
+			atom!("log") => Self::Log,
+			atom!("exp") => Self::Exp,
+			atom!("abs") => Self::Abs,
+			atom!("sign") => Self::Sign,
+			_ => return None,
+		})
+	}
+
+	// The minimum & maximum number of <calc-sum> arguments each function takes.
+	fn arity(&self) -> (usize, usize) {
+		match self {
+			Self::Min | Self::Max | Self::Hypot => (1, usize::MAX),
+			Self::Clamp => (3, 3),
+			Self::Round | Self::Log => (1, 2),
+			Self::Mod | Self::Rem | Self::Atan2 | Self::Pow => (2, 2),
+			_ => (1, 1),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-values-4/#math
+// <calc()> = calc( <calc-sum> ), <min()> = min( <calc-sum># ), <round()> = round( <rounding-strategy>?, <calc-sum>, <calc-sum>? ) ...
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct MathFunction<'a> {
+	pub function: T![Function],
+	pub name: MathFunctionName,
+	// Only present for round()
+	pub strategy: Option<(RoundingStrategy, T![,])>,
+	pub arguments: Vec<'a, (CalcNode<'a>, Option<T![,]>)>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Is<'a> for MathFunction<'a> {
+	fn is(p: &Parser<'a>, c: Cursor) -> bool {
+		<T![Function]>::is(p, c) && MathFunctionName::from_atom(&p.parse_atom_lower(c)).is_some()
+	}
+}
+
+impl<'a> Parse<'a> for MathFunction<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let function = p.parse::<T![Function]>()?;
+		let c: Cursor = function.into();
+		let atom = p.parse_atom_lower(c);
+		let Some(name) = MathFunctionName::from_atom(&atom) else {
+			Err(diagnostics::UnexpectedFunction(atom, c.into()))?
+		};
+		let strategy = if name == MathFunctionName::Round && p.peek::<RoundingStrategy>() {
+			Some((p.parse::<RoundingStrategy>()?, p.parse::<T![,]>()?))
+		} else {
+			None
+		};
+		let mut arguments = Vec::new_in(p.bump());
+		loop {
+			let node = p.parse::<CalcNode>()?;
+			let comma = p.parse_if_peek::<T![,]>()?;
+			let done = comma.is_none();
+			arguments.push((node, comma));
+			if done {
+				break;
+			}
+		}
+		let close = if p.at_end() { None } else { Some(p.parse::<T![')']>()?) };
+		let span = close.map_or(c.span(), |close| c.span() + Cursor::from(close).span());
+		// The function is still understood if it has the wrong number of arguments, or can't be resolved, so these are
+		// reported without failing the parse, which would otherwise throw away the whole declaration.
+		let (min, max) = name.arity();
+		let value = Self { function, name, strategy, arguments, close };
+		if value.arguments.len() < min || value.arguments.len() > max {
+			p.push_error(diagnostics::UnexpectedMathFunctionArguments(atom, value.arguments.len(), span).into());
+		} else if value.resolve_type().is_none() {
+			p.push_error(diagnostics::UnresolvableMathFunction(span).into());
+		}
+		Ok(value)
+	}
+}
+
+impl<'a> MathFunction<'a> {
+	/// Whether the next math function resolves to a <number>. Values which accept both a <number> and a dimension use
+	/// this to avoid reporting a type mismatch on the dimension for something like `line-height: calc(1 + 1)`.
+	pub fn peek_number(p: &mut Parser<'a>) -> bool {
+		let checkpoint = p.checkpoint();
+		let number = p
+			.parse::<MathFunction>()
+			.ok()
+			.and_then(|function| function.resolve_type())
+			.is_some_and(|ty| ty.is_number());
+		p.rewind(checkpoint);
+		number
+	}
+
+	// https://drafts.csswg.org/css-values-4/#calc-type-checking
+	// Returns None if the arguments can never resolve to a single type, for example `calc(1px + 2s)`.
+	pub fn resolve_type(&self) -> Option<CalcType> {
+		let mut types = self.arguments.iter().map(|(node, _)| node.resolve_type());
+		let first = types.next()??;
+		// The type of each argument added together, for functions whose arguments must all be "consistent".
+		let consistent = |types: &mut dyn Iterator<Item = Option<CalcType>>| {
+			let mut ty = first;
+			for next in types {
+				ty = ty.add_type(next?)?;
+			}
+			Some(ty)
+		};
+		match self.name {
+			MathFunctionName::Calc | MathFunctionName::Abs => Some(first),
+			MathFunctionName::Min
+			| MathFunctionName::Max
+			| MathFunctionName::Clamp
+			| MathFunctionName::Hypot
+			| MathFunctionName::Mod
+			| MathFunctionName::Rem => consistent(&mut types),
+			// The step of round() may only be omitted if the value is a <number>, as it defaults to 1.
+			MathFunctionName::Round if self.arguments.len() == 1 => first.is_number().then_some(first),
+			MathFunctionName::Round => consistent(&mut types),
+			MathFunctionName::Sin | MathFunctionName::Cos | MathFunctionName::Tan => {
+				(first.is_number() || first.matches(CalcBaseType::Angle)).then_some(CalcType::NUMBER)
+			}
+			MathFunctionName::Asin | MathFunctionName::Acos | MathFunctionName::Atan => {
+				first.is_number().then_some(CalcType::new(CalcBaseType::Angle))
+			}
+			MathFunctionName::Atan2 => consistent(&mut types).map(|_| CalcType::new(CalcBaseType::Angle)),
+			MathFunctionName::Pow | MathFunctionName::Sqrt | MathFunctionName::Log | MathFunctionName::Exp => {
+				(first.is_number() && types.all(|ty| ty.is_some_and(|ty| ty.is_number()))).then_some(CalcType::NUMBER)
+			}
+			MathFunctionName::Sign => Some(CalcType::NUMBER),
+		}
+	}
+}
+
+impl ToCursors for MathFunction<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		if let Some((strategy, comma)) = self.strategy {
+			s.append(strategy.into());
+			s.append(comma.into());
+		}
+		for (node, comma) in &self.arguments {
+			ToCursors::to_cursors(node, s);
+			if let Some(comma) = comma {
+				s.append(comma.into());
+			}
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+impl From<&MathFunction<'_>> for Span {
+	fn from(value: &MathFunction<'_>) -> Self {
+		let span = Cursor::from(value.function).span();
+		match value.close {
+			Some(close) => span + Cursor::from(close).span(),
+			None => span,
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-values-4/#typedef-rounding-strategy
+keyword_typedef!(RoundingStrategy {
+	Nearest: atom!("nearest"),
+	Up: atom!("up"),
+	Down: atom!("down"),
+	ToZero: atom!("to-zero"),
+});
+
+// https://drafts.csswg.org/css-values-4/#typedef-calc-keyword
+keyword_typedef!(CalcConstant {
+	E: atom!("e"),
+	Pi: atom!("pi"),
+	Infinity: atom!("infinity"),
+	NegativeInfinity: atom!("-infinity"),
+	NaN: atom!("nan"),
+});
+
+// https://drafts.csswg.org/css-values-4/#calc-syntax
+// <calc-sum> = <calc-product> [ [ '+' | '-' ] <calc-product> ]*
+// <calc-product> = <calc-value> [ [ '*' | '/' ] <calc-value> ]*
+// <calc-value> = <number> | <dimension> | <percentage> | <calc-keyword> | ( <calc-sum> )
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum CalcNode<'a> {
+	Number(CSSNumber),
+	Percentage(T![Dimension::%]),
+	Length(Length<'a>),
+	Angle(Angle),
+	Time(Time),
+	Frequency(Frequency),
+	Resolution(Resolution),
+	Flex(Flex),
+	Constant(CalcConstant),
+	Function(&'a MathFunction<'a>),
+	// var(), env() or attr()
+	Substitution(&'a Function<'a>),
+	Parenthesized(T!['('], &'a CalcNode<'a>, Option<T![')']>),
+	Operation(&'a CalcNode<'a>, CalcOperator, &'a CalcNode<'a>),
+}
+
+impl<'a> Parse<'a> for CalcNode<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut node = Self::parse_product(p)?;
+		loop {
+			let operator = if p.peek::<T![+]>() || p.peek::<T![-]>() {
+				p.parse::<CalcOperator>()?
+			} else if Self::peek_signed(p) {
+				let c = p.peek_n(1);
+				p.push_error(diagnostics::MathOperatorWithoutWhitespace(c.into()).into());
+				CalcOperator::Sign
+			} else {
+				break;
+			};
+			let right = Self::parse_product(p)?;
+			node = Self::Operation(p.bump().alloc(node), operator, p.bump().alloc(right));
+		}
+		Ok(node)
+	}
+}
+
+impl<'a> CalcNode<'a> {
+	// A signed number directly after a value, such as the `+2px` in `calc(1px+2px)`, can't be an operator as the sign
+	// is part of the number. This isn't valid, but it's clear what was meant.
+	fn peek_signed(p: &Parser<'a>) -> bool {
+		let c = p.peek_n(1);
+		matches!(c.token().kind(), Kind::Number | Kind::Dimension) && c.token().has_sign()
+	}
+
+	fn parse_product(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut node = Self::parse_value(p)?;
+		while p.peek::<T![*]>() || p.peek::<T![/]>() {
+			let operator = p.parse::<CalcOperator>()?;
+			let right = Self::parse_value(p)?;
+			node = Self::Operation(p.bump().alloc(node), operator, p.bump().alloc(right));
+		}
+		Ok(node)
+	}
+
+	fn parse_value(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T!['(']>() {
+			let open = p.parse::<T!['(']>()?;
+			let node = p.parse::<CalcNode>()?;
+			let close = p.parse_if_peek::<T![')']>()?;
+			return Ok(Self::Parenthesized(open, p.bump().alloc(node), close));
+		}
+		if p.peek::<MathFunction>() {
+			let function = p.parse::<MathFunction>()?;
+			return Ok(Self::Function(p.bump().alloc(function)));
+		}
+		if p.peek::<T![Function]>() {
+			let c = p.peek_n(1);
+			return match p.parse_atom_lower(c) {
+				atom!("var") | atom!("env") | atom!("attr") => {
+					let function = p.parse::<Function>()?;
+					Ok(Self::Substitution(p.bump().alloc(function)))
+				}
+				atom => Err(diagnostics::UnexpectedFunction(atom, c.into()))?,
+			};
+		}
+		if p.peek::<CalcConstant>() {
+			return p.parse::<CalcConstant>().map(Self::Constant);
+		}
+		// A unitless zero is a <number> in math functions, so this must be checked before Length.
+		if p.peek::<CSSNumber>() {
+			return p.parse::<CSSNumber>().map(Self::Number);
+		}
+		if p.peek::<T![Dimension::%]>() {
+			return p.parse::<T![Dimension::%]>().map(Self::Percentage);
+		}
+		if p.peek::<Length>() {
+			return p.parse::<Length>().map(Self::Length);
+		}
+		if p.peek::<Angle>() {
+			return p.parse::<Angle>().map(Self::Angle);
+		}
+		if p.peek::<Time>() {
+			return p.parse::<Time>().map(Self::Time);
+		}
+		if p.peek::<Frequency>() {
+			return p.parse::<Frequency>().map(Self::Frequency);
+		}
+		if p.peek::<Resolution>() {
+			return p.parse::<Resolution>().map(Self::Resolution);
+		}
+		if p.peek::<Flex>() {
+			return p.parse::<Flex>().map(Self::Flex);
+		}
+		let c = p.next();
+		Err(diagnostics::Unexpected(c.into(), c.into()))?
+	}
+
+	// https://drafts.csswg.org/css-values-4/#determine-the-type-of-a-calculation
+	pub fn resolve_type(&self) -> Option<CalcType> {
+		Some(match self {
+			Self::Number(_) | Self::Constant(_) => CalcType::NUMBER,
+			Self::Percentage(_) => CalcType::new(CalcBaseType::Percent),
+			Self::Length(_) => CalcType::new(CalcBaseType::Length),
+			Self::Angle(_) => CalcType::new(CalcBaseType::Angle),
+			Self::Time(_) => CalcType::new(CalcBaseType::Time),
+			Self::Frequency(_) => CalcType::new(CalcBaseType::Frequency),
+			Self::Resolution(_) => CalcType::new(CalcBaseType::Resolution),
+			Self::Flex(_) => CalcType::new(CalcBaseType::Flex),
+			Self::Substitution(_) => CalcType::UNKNOWN,
+			Self::Function(function) => return function.resolve_type(),
+			Self::Parenthesized(_, node, _) => return node.resolve_type(),
+			Self::Operation(left, operator, right) => {
+				let (left, right) = (left.resolve_type()?, right.resolve_type()?);
+				return match operator {
+					CalcOperator::Plus(_) | CalcOperator::Minus(_) | CalcOperator::Sign => left.add_type(right),
+					CalcOperator::Multiply(_) => left.multiply_type(right),
+					CalcOperator::Divide(_) => left.multiply_type(right.invert()),
+				};
+			}
+		})
+	}
+}
+
+impl ToCursors for CalcNode<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Number(n) => s.append((*n).into()),
+			Self::Percentage(n) => s.append(n.into()),
+			Self::Length(n) => ToCursors::to_cursors(n, s),
+			Self::Angle(n) => s.append(n.into()),
+			Self::Time(n) => s.append((*n).into()),
+			Self::Frequency(n) => s.append((*n).into()),
+			Self::Resolution(n) => s.append((*n).into()),
+			Self::Flex(n) => s.append((*n).into()),
+			Self::Constant(n) => s.append(n.into()),
+			Self::Function(function) => ToCursors::to_cursors(*function, s),
+			Self::Substitution(function) => ToCursors::to_cursors(*function, s),
+			Self::Parenthesized(open, node, close) => {
+				s.append(open.into());
+				ToCursors::to_cursors(*node, s);
+				if let Some(close) = close {
+					s.append(close.into());
+				}
+			}
+			Self::Operation(left, operator, right) => {
+				ToCursors::to_cursors(*left, s);
+				ToCursors::to_cursors(operator, s);
+				ToCursors::to_cursors(*right, s);
+			}
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum CalcOperator {
+	Plus(T![+]),
+	Minus(T![-]),
+	Multiply(T![*]),
+	Divide(T![/]),
+	// The sign of a value written straight after another, as in `calc(1px+2px)`. It has no Cursor of its own.
+	Sign,
+}
+
+impl<'a> Parse<'a> for CalcOperator {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![+]>() {
+			p.parse::<T![+]>().map(Self::Plus)
+		} else if p.peek::<T![-]>() {
+			p.parse::<T![-]>().map(Self::Minus)
+		} else if p.peek::<T![*]>() {
+			p.parse::<T![*]>().map(Self::Multiply)
+		} else {
+			p.parse::<T![/]>().map(Self::Divide)
+		}
+	}
+}
+
+impl ToCursors for CalcOperator {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Plus(t) => s.append(t.into()),
+			Self::Minus(t) => s.append(t.into()),
+			Self::Multiply(t) => s.append(t.into()),
+			Self::Divide(t) => s.append(t.into()),
+			Self::Sign => {}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(MathFunction, 96);
+		assert_size!(CalcNode, 40);
+		assert_size!(CalcOperator, 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(MathFunction, "calc(1px)");
+		assert_parse!(MathFunction, "calc(1px + 2px)");
+		assert_parse!(MathFunction, "calc(100% - 10px)");
+		assert_parse!(MathFunction, "calc(1px + 2px*3)");
+		assert_parse!(MathFunction, "calc((1px + 2px)*3)");
+		assert_parse!(MathFunction, "calc(1px*3/2)");
+		assert_parse!(MathFunction, "calc(2*pi*1rad)");
+		assert_parse!(MathFunction, "calc(var(--x)*2)");
+		assert_parse!(MathFunction, "calc(100% - var(--gutter))");
+		assert_parse!(MathFunction, "calc(1px + calc(2em/3))");
+		assert_parse!(MathFunction, "calc(1px - (2px + -3px))");
+		assert_parse!(MathFunction, "max(1px - 2em,3px)");
+		assert_parse!(MathFunction, "min(1px,2%,3em)");
+		assert_parse!(MathFunction, "max(10vw,min(1px,2px))");
+		assert_parse!(MathFunction, "clamp(1rem,2.5vw,2rem)");
+		assert_parse!(MathFunction, "round(up,10.5px,1px)");
+		assert_parse!(MathFunction, "round(1.5)");
+		assert_parse!(MathFunction, "mod(18px,5px)");
+		assert_parse!(MathFunction, "sin(45deg)");
+		assert_parse!(MathFunction, "atan2(1px,2px)");
+		assert_parse!(MathFunction, "pow(2,3)");
+		assert_parse!(MathFunction, "hypot(3px,4px)");
+		assert_parse!(MathFunction, "calc(1s*2)");
+		assert_parse!(MathFunction, "calc(1px*1px/1px)");
+		assert_parse!(MathFunction, "calc(1fr)");
+	}
+
+	#[test]
+	fn test_types() {
+		let allocator = bumpalo::Bump::default();
+		let resolve = |source| {
+			let mut parser = Parser::new(&allocator, source, hdx_parser::Features::default());
+			parser.parse_entirely::<MathFunction>().output.and_then(|f| f.resolve_type())
+		};
+		assert_eq!(resolve("calc(1px + 2em)"), Some(CalcType::new(CalcBaseType::Length)));
+		assert!(resolve("calc(100% - 10px)").unwrap().matches_with_percentage(CalcBaseType::Length));
+		assert_eq!(resolve("calc(1px/1px)"), Some(CalcType::NUMBER));
+		assert_eq!(resolve("atan(1)"), Some(CalcType::new(CalcBaseType::Angle)));
+		assert_eq!(resolve("sin(45deg)"), Some(CalcType::NUMBER));
+		assert_eq!(resolve("sign(-2s)"), Some(CalcType::NUMBER));
+		assert_eq!(resolve("calc(var(--x) + 1px)"), Some(CalcType::UNKNOWN));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(MathFunction, "calc(1px + 2s)");
+		assert_parse_error!(MathFunction, "calc(1px + 2)");
+		assert_parse_error!(MathFunction, "calc(1px+2px)");
+		assert_parse_error!(MathFunction, "calc(1 -2)");
+		assert_parse_error!(MathFunction, "calc(1px+2px)");
+		assert_parse_error!(MathFunction, "calc(1px 2px)");
+		assert_parse_error!(MathFunction, "calc()");
+		assert_parse_error!(MathFunction, "calc(1px,2px)");
+		assert_parse_error!(MathFunction, "clamp(1px,2px)");
+		assert_parse_error!(MathFunction, "round(1px)");
+		assert_parse_error!(MathFunction, "sin(1px)");
+		assert_parse_error!(MathFunction, "pow(2px,2)");
+		assert_parse_error!(MathFunction, "min(1px,2deg)");
+		assert_parse_error!(MathFunction, "calc(foo)");
+		assert_parse_error!(MathFunction, "calc(rgb(0 0 0))");
+		assert_parse_error!(MathFunction, "var(--x)");
+	}
+}
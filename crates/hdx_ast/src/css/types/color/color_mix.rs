@@ -0,0 +1,233 @@
+use hdx_lexer::Cursor;
+use hdx_parser::{diagnostics, keyword_typedef, CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+
+use super::Color;
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(In, atom!("in"));
+	custom_keyword!(Hue, atom!("hue"));
+}
+
+mod func {
+	use hdx_parser::custom_function;
+	custom_function!(ColorMix, atom!("color-mix"));
+}
+
+// https://drafts.csswg.org/css-color-5/#color-mix
+// color-mix() = color-mix( <color-interpolation-method> , [ <color> && <percentage [0,100]>? ]#{2})
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ColorMix<'a> {
+	pub function: func::ColorMix,
+	pub method: ColorInterpolationMethod,
+	pub comma: T![,],
+	pub first: ColorMixComponent<'a>,
+	pub separator: T![,],
+	pub second: ColorMixComponent<'a>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Peek<'a> for ColorMix<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<func::ColorMix>()
+	}
+}
+
+impl<'a> Parse<'a> for ColorMix<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let function = p.parse::<func::ColorMix>()?;
+		let method = p.parse::<ColorInterpolationMethod>()?;
+		let comma = p.parse::<T![,]>()?;
+		let first = p.parse::<ColorMixComponent>()?;
+		let separator = p.parse::<T![,]>()?;
+		let second = p.parse::<ColorMixComponent>()?;
+		let close = p.parse_if_peek::<T![')']>()?;
+		Ok(Self { function, method, comma, first, separator, second, close })
+	}
+}
+
+impl ToCursors for ColorMix<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		ToCursors::to_cursors(&self.method, s);
+		s.append(self.comma.into());
+		ToCursors::to_cursors(&self.first, s);
+		s.append(self.separator.into());
+		ToCursors::to_cursors(&self.second, s);
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// <color> && <percentage [0,100]>?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ColorMixComponent<'a> {
+	pub leading_percentage: Option<T![Dimension::%]>,
+	pub color: &'a Color<'a>,
+	pub percentage: Option<T![Dimension::%]>,
+}
+
+impl<'a> Parse<'a> for ColorMixComponent<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let leading_percentage = p.parse_if_peek::<T![Dimension::%]>()?;
+		let color = p.parse::<Color>()?;
+		let percentage = if leading_percentage.is_none() { p.parse_if_peek::<T![Dimension::%]>()? } else { None };
+		for percentage in [leading_percentage, percentage].into_iter().flatten() {
+			let value: f32 = percentage.into();
+			if !(0.0..=100.0).contains(&value) {
+				let c: Cursor = percentage.into();
+				Err(diagnostics::NumberOutOfBounds(value, format!("{:?}", 0.0..=100.0), c.into()))?
+			}
+		}
+		Ok(Self { leading_percentage, color: p.bump().alloc(color), percentage })
+	}
+}
+
+impl ToCursors for ColorMixComponent<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(percentage) = self.leading_percentage {
+			s.append(percentage.into());
+		}
+		ToCursors::to_cursors(self.color, s);
+		if let Some(percentage) = self.percentage {
+			s.append(percentage.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-color-4/#color-interpolation-method
+// <color-interpolation-method> = in [ <rectangular-color-space> | <polar-color-space> <hue-interpolation-method>? ]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ColorInterpolationMethod {
+	pub in_keyword: kw::In,
+	pub space: InterpolationColorSpace,
+	pub hue: Option<HueInterpolationMethod>,
+}
+
+impl<'a> Peek<'a> for ColorInterpolationMethod {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::In>()
+	}
+}
+
+impl<'a> Parse<'a> for ColorInterpolationMethod {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let in_keyword = p.parse::<kw::In>()?;
+		let space = p.parse::<InterpolationColorSpace>()?;
+		let hue = p.parse_if_peek::<HueInterpolationMethod>()?;
+		if let Some(hue) = hue {
+			if !space.is_polar() {
+				let c: Cursor = hue.direction.into();
+				Err(diagnostics::UnexpectedIdent(p.parse_atom_lower(c), c.into()))?
+			}
+		}
+		Ok(Self { in_keyword, space, hue })
+	}
+}
+
+impl ToCursors for ColorInterpolationMethod {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.in_keyword.into());
+		s.append(self.space.into());
+		if let Some(hue) = &self.hue {
+			ToCursors::to_cursors(hue, s);
+		}
+	}
+}
+
+// <rectangular-color-space> | <polar-color-space>
+keyword_typedef!(InterpolationColorSpace {
+	Srgb: atom!("srgb"),
+	SrgbLinear: atom!("srgb-linear"),
+	DisplayP3: atom!("display-p3"),
+	A98Rgb: atom!("a98-rgb"),
+	ProphotoRgb: atom!("prophoto-rgb"),
+	Rec2020: atom!("rec2020"),
+	Lab: atom!("lab"),
+	Oklab: atom!("oklab"),
+	Xyz: atom!("xyz"),
+	XyzD50: atom!("xyz-d50"),
+	XyzD65: atom!("xyz-d65"),
+	Hsl: atom!("hsl"),
+	Hwb: atom!("hwb"),
+	Lch: atom!("lch"),
+	Oklch: atom!("oklch"),
+});
+
+impl InterpolationColorSpace {
+	#[inline]
+	pub fn is_polar(&self) -> bool {
+		matches!(self, Self::Hsl(_) | Self::Hwb(_) | Self::Lch(_) | Self::Oklch(_))
+	}
+}
+
+// <hue-interpolation-method> = [ shorter | longer | increasing | decreasing ] hue
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct HueInterpolationMethod {
+	pub direction: HueInterpolationDirection,
+	pub hue: kw::Hue,
+}
+
+impl<'a> Peek<'a> for HueInterpolationMethod {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<HueInterpolationDirection>()
+	}
+}
+
+impl<'a> Parse<'a> for HueInterpolationMethod {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let direction = p.parse::<HueInterpolationDirection>()?;
+		let hue = p.parse::<kw::Hue>()?;
+		Ok(Self { direction, hue })
+	}
+}
+
+impl ToCursors for HueInterpolationMethod {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.direction.into());
+		s.append(self.hue.into());
+	}
+}
+
+keyword_typedef!(HueInterpolationDirection {
+	Shorter: atom!("shorter"),
+	Longer: atom!("longer"),
+	Increasing: atom!("increasing"),
+	Decreasing: atom!("decreasing"),
+});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(ColorMix, 176);
+		assert_size!(ColorMixComponent, 32);
+		assert_size!(ColorInterpolationMethod, 56);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(ColorMix, "color-mix(in srgb,red,blue)");
+		assert_parse!(ColorMix, "color-mix(in oklab,red 40%,#00f)");
+		assert_parse!(ColorMix, "color-mix(in lch longer hue,25% red,blue 75%)");
+		assert_parse!(ColorMix, "color-mix(in display-p3,color-mix(in srgb,red,blue),white)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ColorMix, "color-mix(red,blue)");
+		assert_parse_error!(ColorMix, "color-mix(in srgb,red)");
+		assert_parse_error!(ColorMix, "color-mix(in foo,red,blue)");
+		assert_parse_error!(ColorMix, "color-mix(in srgb longer hue,red,blue)");
+		assert_parse_error!(ColorMix, "color-mix(in srgb,red 120%,blue)");
+		assert_parse_error!(ColorMix, "color-mix(in srgb,10% red 20%,blue)");
+	}
+}
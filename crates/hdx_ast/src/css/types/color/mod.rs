@@ -1,44 +1,41 @@
 mod color_function;
+mod color_mix;
 mod named;
+mod relative;
 mod syntax;
 mod system;
 
 use std::str::Chars;
 
 use hdx_atom::atom;
-use hdx_parser::{diagnostics, todo, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+use hdx_parser::{diagnostics, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
+
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
 
 pub use color_function::*;
+pub use color_mix::*;
 pub use named::*;
+pub use relative::*;
 pub use syntax::*;
 pub use system::*;
 
-mod func {
-	use hdx_parser::custom_function;
-	custom_function!(ColorMix, atom!("color-mix"));
-}
-
+// https://drafts.csswg.org/css-color-5/#typedef-color
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum Color {
+#[visit]
+pub enum Color<'a> {
 	Currentcolor(T![Ident]),
 	Transparent(T![Ident]),
 	System(SystemColor),
 	Hex(T![Hash]),
 	Named(NamedColor),
 	Function(ColorFunction),
-	// TODO: need bumpalo::Box PartialEq, or bumpalo::Box serde
-	// Relative(Box<'a, Color<'a>>, ColorFunction),
-	// Mix(ColorMixSyntax, Box<'a, Color<'a>>, u8, Box<'a, Color<'a>>),
+	Relative(RelativeColor<'a>),
+	Mix(ColorMix<'a>),
 }
 
-impl Color {
-	// Alias CanvasText for #[initial()]
-	// #[allow(non_upper_case_globals)]
-	// pub const Canvastext: Color = Color::System(SystemColor::CanvasText);
-}
-
-impl<'a> ToCursors for Color {
+impl ToCursors for Color<'_> {
 	fn to_cursors(&self, s: &mut impl hdx_parser::CursorSink) {
 		match self {
 			Self::Currentcolor(t) => s.append((*t).into()),
@@ -47,17 +44,19 @@ impl<'a> ToCursors for Color {
 			Self::Hex(t) => s.append((*t).into()),
 			Self::Named(t) => s.append((*t).into()),
 			Self::Function(func) => ToCursors::to_cursors(func, s),
+			Self::Relative(func) => ToCursors::to_cursors(func, s),
+			Self::Mix(func) => ToCursors::to_cursors(func, s),
 		}
 	}
 }
 
-impl<'a> Peek<'a> for Color {
+impl<'a> Peek<'a> for Color<'a> {
 	fn peek(p: &Parser<'a>) -> bool {
-		p.peek::<T![Hash]>() || p.peek::<ColorFunction>() || p.peek::<func::ColorMix>() || p.peek::<T![Ident]>()
+		p.peek::<T![Hash]>() || p.peek::<ColorFunction>() || p.peek::<ColorMix>() || p.peek::<T![Ident]>()
 	}
 }
 
-impl<'a> Parse<'a> for Color {
+impl<'a> Parse<'a> for Color<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		if p.peek::<T![Hash]>() {
 			Ok(Self::Hex(p.parse::<T![Hash]>()?))
@@ -69,21 +68,35 @@ impl<'a> Parse<'a> for Color {
 				atom => {
 					if NamedColor::is(p, c) {
 						Ok(Self::Named(p.parse::<NamedColor>()?))
-					} else if NamedColor::is(p, c) {
+					} else if SystemColor::is(p, c) {
 						Ok(Self::System(p.parse::<SystemColor>()?))
 					} else {
 						Err(diagnostics::UnexpectedIdent(atom, c.into()))?
 					}
 				}
 			}
-		} else if p.peek::<func::ColorMix>() {
-			todo!(p)
+		} else if p.peek::<ColorMix>() {
+			p.parse::<ColorMix>().map(Self::Mix)
+		} else if p.peek::<RelativeColor>() {
+			p.parse::<RelativeColor>().map(Self::Relative)
 		} else {
 			p.parse::<ColorFunction>().map(Color::Function)
 		}
 	}
 }
 
+impl<'a> Visitable<'a> for Color<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_color(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for Color<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_color(self);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -93,7 +106,7 @@ mod tests {
 	fn size_test() {
 		assert_size!(Channel, 16);
 		assert_size!(ColorFunction, 160);
-		assert_size!(Color, 160);
+		assert_size!(Color, 176);
 	}
 
 	#[test]
@@ -112,6 +125,15 @@ mod tests {
 		assert_parse!(Color, "lab(63.673% 51.577 5.811)");
 		assert_parse!(Color, "hwb(740deg 20% 30%/50%)");
 		assert_parse!(Color, "lch(20% 30% 740deg/50%)");
+		assert_parse!(Color, "canvastext");
+		assert_parse!(Color, "AccentColor");
+		assert_parse!(Color, "color-mix(in srgb,red 40%,blue)");
+		assert_parse!(Color, "rgb(from var(--x) r g b/50%)", "rgb(from var(--x)r g b/50%)");
+		assert_parse!(
+			Color,
+			"color-mix(in oklch,oklch(from var(--brand) l c h),transparent)",
+			"color-mix(in oklch,oklch(from var(--brand)l c h),transparent)"
+		);
 	}
 
 	#[test]
@@ -142,5 +164,7 @@ mod tests {
 		assert_parse_error!(Color, "hsl(250, 255deg, 255)");
 		// Using degrees for wrong component in lch
 		assert_parse_error!(Color, "lch(250, 255deg, 255)");
+		// Not a named or system color
+		assert_parse_error!(Color, "notacolor");
 	}
 }
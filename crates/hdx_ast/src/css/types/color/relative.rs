@@ -0,0 +1,207 @@
+use hdx_atom::{atom, Atom};
+use hdx_lexer::{Cursor, Kind};
+use hdx_parser::{diagnostics, CursorSink, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+
+use crate::{css::units::Angle, syntax::Function};
+
+use super::{Channel, Color, ColorFunction, ColorSpace};
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(From, atom!("from"));
+}
+
+// https://drafts.csswg.org/css-color-5/#relative-colors
+// rgb() = rgb( [ from <color> ]? [ <number> | <percentage> | none]{3} [ / [<alpha-value> | none] ]?  )
+// ...and likewise for each of the other color functions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct RelativeColor<'a> {
+	pub function: T![Function],
+	pub from: kw::From,
+	pub origin: RelativeOrigin<'a>,
+	// Only present for color(), which names its color space ahead of the channels.
+	pub space: Option<ColorSpace>,
+	pub channels: [RelativeChannel; 3],
+	pub slash: Option<T![/]>,
+	pub alpha: Option<RelativeChannel>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Peek<'a> for RelativeColor<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		let c = p.peek_n(2);
+		p.peek::<ColorFunction>() && c == Kind::Ident && p.parse_atom_lower(c) == atom!("from")
+	}
+}
+
+impl<'a> Parse<'a> for RelativeColor<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let function = p.parse::<T![Function]>()?;
+		let c: Cursor = function.into();
+		if !ColorFunction::is(p, c) {
+			Err(diagnostics::UnexpectedFunction(p.parse_atom_lower(c), c.into()))?
+		}
+		let name = p.parse_atom_lower(c);
+		let from = p.parse::<kw::From>()?;
+		let origin = p.parse::<RelativeOrigin>()?;
+		let space = if name == atom!("color") { Some(p.parse::<ColorSpace>()?) } else { None };
+		let keywords = channel_keywords(&name, space);
+		let hue = match name {
+			atom!("hsl") | atom!("hsla") | atom!("hwb") => Some(0),
+			atom!("lch") | atom!("oklch") => Some(2),
+			_ => None,
+		};
+		let channels = [
+			parse_channel(p, &keywords, hue == Some(0))?,
+			parse_channel(p, &keywords, hue == Some(1))?,
+			parse_channel(p, &keywords, hue == Some(2))?,
+		];
+		let slash = p.parse_if_peek::<T![/]>()?;
+		let alpha = if slash.is_some() { Some(parse_channel(p, &keywords, false)?) } else { None };
+		let close = p.parse_if_peek::<T![')']>()?;
+		Ok(Self { function, from, origin, space, channels, slash, alpha, close })
+	}
+}
+
+impl ToCursors for RelativeColor<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		s.append(self.from.into());
+		ToCursors::to_cursors(&self.origin, s);
+		if let Some(space) = self.space {
+			s.append(space.into());
+		}
+		for channel in &self.channels {
+			s.append(channel.into());
+		}
+		if let Some(slash) = self.slash {
+			s.append(slash.into());
+		}
+		if let Some(alpha) = &self.alpha {
+			s.append(alpha.into());
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// The channel keywords each function (or color() space) exposes from its origin color, ending with alpha.
+fn channel_keywords(name: &Atom, space: Option<ColorSpace>) -> [Atom; 4] {
+	match *name {
+		atom!("hsl") | atom!("hsla") => [atom!("h"), atom!("s"), atom!("l"), atom!("alpha")],
+		atom!("hwb") => [atom!("h"), atom!("w"), atom!("b"), atom!("alpha")],
+		atom!("lab") | atom!("oklab") => [atom!("l"), atom!("a"), atom!("b"), atom!("alpha")],
+		atom!("lch") | atom!("oklch") => [atom!("l"), atom!("c"), atom!("h"), atom!("alpha")],
+		atom!("color") if matches!(space, Some(ColorSpace::Xyz(_) | ColorSpace::XyzD50(_) | ColorSpace::XyzD65(_))) => {
+			[atom!("x"), atom!("y"), atom!("z"), atom!("alpha")]
+		}
+		_ => [atom!("r"), atom!("g"), atom!("b"), atom!("alpha")],
+	}
+}
+
+fn parse_channel(p: &mut Parser, keywords: &[Atom; 4], allow_angle: bool) -> ParserResult<RelativeChannel> {
+	if p.peek::<Channel>() {
+		return p.parse::<Channel>().map(RelativeChannel::Channel);
+	}
+	if allow_angle && p.peek::<Angle>() {
+		return p.parse::<Angle>().map(RelativeChannel::Angle);
+	}
+	let ident = p.parse::<T![Ident]>()?;
+	let c: Cursor = ident.into();
+	let atom = p.parse_atom_lower(c);
+	if !keywords.contains(&atom) {
+		Err(diagnostics::UnexpectedIdent(atom, c.into()))?
+	}
+	Ok(RelativeChannel::Keyword(ident))
+}
+
+// The origin color may be any <color>, but is commonly a custom property such as `var(--accent)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum RelativeOrigin<'a> {
+	Color(&'a Color<'a>),
+	Var(&'a Function<'a>),
+}
+
+impl<'a> Parse<'a> for RelativeOrigin<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let c = p.peek_n(1);
+		if c == Kind::Function && p.parse_atom_lower(c) == atom!("var") {
+			let function = p.parse::<Function>()?;
+			return Ok(Self::Var(p.bump().alloc(function)));
+		}
+		let color = p.parse::<Color>()?;
+		Ok(Self::Color(p.bump().alloc(color)))
+	}
+}
+
+impl ToCursors for RelativeOrigin<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Color(color) => ToCursors::to_cursors(*color, s),
+			Self::Var(function) => ToCursors::to_cursors(*function, s),
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum RelativeChannel {
+	Keyword(T![Ident]),
+	Channel(Channel),
+	Angle(Angle),
+}
+
+impl From<RelativeChannel> for Cursor {
+	fn from(value: RelativeChannel) -> Self {
+		match value {
+			RelativeChannel::Keyword(c) => c.into(),
+			RelativeChannel::Channel(c) => c.into(),
+			RelativeChannel::Angle(c) => c.into(),
+		}
+	}
+}
+
+impl From<&RelativeChannel> for Cursor {
+	fn from(value: &RelativeChannel) -> Self {
+		(*value).into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(RelativeColor, 152);
+		assert_size!(RelativeOrigin, 16);
+		assert_size!(RelativeChannel, 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(RelativeColor, "rgb(from red r g b)");
+		assert_parse!(RelativeColor, "rgb(from var(--x) r g b/50%)", "rgb(from var(--x)r g b/50%)");
+		assert_parse!(RelativeColor, "hsl(from #fff h s 20%)", "hsl(from#fff h s 20%)");
+		assert_parse!(RelativeColor, "hsl(from #fff 120deg s l/alpha)", "hsl(from#fff 120deg s l/alpha)");
+		assert_parse!(RelativeColor, "oklch(from var(--accent) l c 180deg)", "oklch(from var(--accent)l c 180deg)");
+		assert_parse!(RelativeColor, "lab(from rgb(0 0 0) l none b)", "lab(from rgb(0 0 0)l none b)");
+		assert_parse!(RelativeColor, "color(from red display-p3 r g b)");
+		assert_parse!(RelativeColor, "color(from red xyz-d50 x y z/0.5)");
+		assert_parse!(RelativeColor, "rgb(from hsl(from red h s l) r g b)", "rgb(from hsl(from red h s l)r g b)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(RelativeColor, "rgb(from red h s l)");
+		assert_parse_error!(RelativeColor, "rgb(from red r g)");
+		assert_parse_error!(RelativeColor, "rgb(from red 10deg g b)");
+		assert_parse_error!(RelativeColor, "color(from red r g b)");
+		assert_parse_error!(RelativeColor, "color(from red srgb x y z)");
+		assert_parse_error!(RelativeColor, "rgb(from r g b)");
+	}
+}
@@ -0,0 +1,94 @@
+use bumpalo::collections::Vec;
+use hdx_parser::{CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+
+// https://drafts.csswg.org/css-fonts-4/#family-name-syntax
+// <family-name> = <string> | <custom-ident>+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum FamilyName<'a> {
+	String(T![String]),
+	Idents(Vec<'a, T![Ident]>),
+}
+
+impl<'a> Peek<'a> for FamilyName<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![String]>() || p.peek::<T![Ident]>()
+	}
+}
+
+impl<'a> Parse<'a> for FamilyName<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![String]>() {
+			return p.parse::<T![String]>().map(Self::String);
+		}
+		let mut idents = Vec::new_in(p.bump());
+		idents.push(p.parse::<T![Ident]>()?);
+		while let Some(ident) = p.parse_if_peek::<T![Ident]>()? {
+			idents.push(ident);
+		}
+		Ok(Self::Idents(idents))
+	}
+}
+
+impl ToCursors for FamilyName<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::String(string) => s.append(string.into()),
+			Self::Idents(idents) => {
+				for ident in idents {
+					s.append(ident.into());
+				}
+			}
+		}
+	}
+}
+
+// <family-name>#
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FamilyNameList<'a>(pub Vec<'a, (FamilyName<'a>, Option<T![,]>)>);
+
+impl<'a> Parse<'a> for FamilyNameList<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut names = Vec::new_in(p.bump());
+		loop {
+			let name = p.parse::<FamilyName>()?;
+			let comma = p.parse_if_peek::<T![,]>()?;
+			let done = comma.is_none();
+			names.push((name, comma));
+			if done {
+				return Ok(Self(names));
+			}
+		}
+	}
+}
+
+impl ToCursors for FamilyNameList<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for (name, comma) in &self.0 {
+			ToCursors::to_cursors(name, s);
+			if let Some(comma) = comma {
+				s.append(comma.into());
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(FamilyName, 32);
+		assert_size!(FamilyNameList, 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(FamilyName, "'Taisho Gothic'");
+		assert_parse!(FamilyName, "Taisho Gothic");
+		assert_parse!(FamilyNameList, "Bungee,'Bungee Color',Font Awesome");
+	}
+}
@@ -0,0 +1,36 @@
+use hdx_parser::keyword_typedef;
+
+// https://drafts.csswg.org/css-fonts-4/#font-format-values
+// <font-format> = [ collection | embedded-opentype | opentype | svg | truetype | woff | woff2 ]
+keyword_typedef!(FontFormat {
+	Collection: atom!("collection"),
+	EmbeddedOpentype: atom!("embedded-opentype"),
+	Opentype: atom!("opentype"),
+	Svg: atom!("svg"),
+	Truetype: atom!("truetype"),
+	Woff: atom!("woff"),
+	Woff2: atom!("woff2"),
+});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(FontFormat, 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(FontFormat, "woff2");
+		assert_parse!(FontFormat, "embedded-opentype");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(FontFormat, "woff3");
+		assert_parse_error!(FontFormat, "'woff2'");
+	}
+}
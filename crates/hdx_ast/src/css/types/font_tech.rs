@@ -0,0 +1,43 @@
+use hdx_parser::keyword_typedef;
+
+// https://drafts.csswg.org/css-fonts-4/#font-tech-values
+// <font-tech> = [ <font-features-tech> | <color-font-tech> | variations | palettes | incremental ]
+// <font-features-tech> = [ features-opentype | features-aat | features-graphite ]
+// <color-font-tech> = [ color-COLRv0 | color-COLRv1 | color-SVG | color-sbix | color-CBDT ]
+keyword_typedef!(FontTech {
+	FeaturesOpentype: atom!("features-opentype"),
+	FeaturesAat: atom!("features-aat"),
+	FeaturesGraphite: atom!("features-graphite"),
+	ColorColrv0: atom!("color-colrv0"),
+	ColorColrv1: atom!("color-colrv1"),
+	ColorSvg: atom!("color-svg"),
+	ColorSbix: atom!("color-sbix"),
+	ColorCbdt: atom!("color-cbdt"),
+	Variations: atom!("variations"),
+	Palettes: atom!("palettes"),
+	Incremental: atom!("incremental"),
+});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(FontTech, 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(FontTech, "features-opentype");
+		assert_parse!(FontTech, "color-COLRv1");
+		assert_parse!(FontTech, "incremental");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(FontTech, "color-woff");
+		assert_parse_error!(FontTech, "'variations'");
+	}
+}
@@ -34,38 +34,38 @@ mod func {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum Gradient<'a> {
-	Linear(func::LinearGradient, Option<LinearDirection>, Option<T![,]>, Vec<'a, ColorStopOrHint>, Option<T![')']>),
+	Linear(func::LinearGradient, Option<LinearDirection>, Option<T![,]>, Vec<'a, ColorStopOrHint<'a>>, Option<T![')']>),
 	RepeatingLinear(
 		func::RepeatingLinearGradient,
 		Option<LinearDirection>,
 		Option<T![,]>,
-		Vec<'a, ColorStopOrHint>,
+		Vec<'a, ColorStopOrHint<'a>>,
 		Option<T![')']>,
 	),
 	Radial(
 		func::RadialGradient,
-		Option<RadialSize>,
+		Option<RadialSize<'a>>,
 		Option<RadialShape>,
 		Option<kw::At>,
-		Option<Position>,
+		Option<Position<'a>>,
 		Option<T![,]>,
-		Vec<'a, ColorStopOrHint>,
+		Vec<'a, ColorStopOrHint<'a>>,
 		Option<T![')']>,
 	),
 	RepeatingRadial(
 		func::RepeatingRadialGradient,
-		Option<RadialSize>,
+		Option<RadialSize<'a>>,
 		Option<RadialShape>,
 		Option<kw::At>,
-		Option<Position>,
+		Option<Position<'a>>,
 		Option<T![,]>,
-		Vec<'a, ColorStopOrHint>,
+		Vec<'a, ColorStopOrHint<'a>>,
 		Option<T![')']>,
 	),
 }
 
 impl<'a> Gradient<'a> {
-	fn parse_stops(p: &mut Parser<'a>) -> ParserResult<Vec<'a, ColorStopOrHint>> {
+	fn parse_stops(p: &mut Parser<'a>) -> ParserResult<Vec<'a, ColorStopOrHint<'a>>> {
 		let mut stops = Vec::new_in(p.bump());
 		let mut allow_hint = false;
 		loop {
@@ -283,16 +283,16 @@ impl<'a> ToCursors for LinearDirection {
 // https://drafts.csswg.org/css-images-3/#typedef-rg-size
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum RadialSize {
+pub enum RadialSize<'a> {
 	ClosestCorner(kw::ClosestCorner),
 	ClosestSide(kw::ClosestSide),
 	FarthestCorner(kw::FarthestCorner),
 	FarthestSide(kw::FarthestSide),
-	Circular(Length),
-	Elliptical(LengthPercentage, LengthPercentage),
+	Circular(Length<'a>),
+	Elliptical(LengthPercentage<'a>, LengthPercentage<'a>),
 }
 
-impl<'a> Is<'a> for RadialSize {
+impl<'a> Is<'a> for RadialSize<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
 		LengthPercentage::is(p, c)
 			|| (<T![Ident]>::is(p, c)
@@ -303,7 +303,7 @@ impl<'a> Is<'a> for RadialSize {
 	}
 }
 
-impl<'a> Parse<'a> for RadialSize {
+impl<'a> Parse<'a> for RadialSize<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		if p.peek::<T![Ident]>() {
 			let c = p.next();
@@ -329,17 +329,17 @@ impl<'a> Parse<'a> for RadialSize {
 	}
 }
 
-impl<'a> ToCursors for RadialSize {
+impl ToCursors for RadialSize<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
 			Self::ClosestCorner(c) => s.append(c.into()),
 			Self::ClosestSide(c) => s.append(c.into()),
 			Self::FarthestCorner(c) => s.append(c.into()),
 			Self::FarthestSide(c) => s.append(c.into()),
-			Self::Circular(c) => s.append(c.into()),
+			Self::Circular(c) => ToCursors::to_cursors(c, s),
 			Self::Elliptical(a, b) => {
-				s.append(a.into());
-				s.append(b.into());
+				ToCursors::to_cursors(a, s);
+				ToCursors::to_cursors(b, s);
 			}
 		}
 	}
@@ -350,25 +350,25 @@ keyword_typedef!(RadialShape { Circle: atom!("circle"), Ellipse: atom!("ellipse"
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum ColorStopOrHint {
-	Stop(Color, Option<LengthPercentage>, Option<T![,]>),
-	Hint(LengthPercentage, T![,]),
+pub enum ColorStopOrHint<'a> {
+	Stop(Color<'a>, Option<LengthPercentage<'a>>, Option<T![,]>),
+	Hint(LengthPercentage<'a>, T![,]),
 }
 
-impl<'a> ToCursors for ColorStopOrHint {
+impl ToCursors for ColorStopOrHint<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		match self {
 			Self::Stop(c, l, comma) => {
 				ToCursors::to_cursors(c, s);
 				if let Some(l) = l {
-					s.append(l.into());
+					ToCursors::to_cursors(l, s);
 				}
 				if let Some(comma) = comma {
 					s.append(comma.into());
 				}
 			}
 			Self::Hint(l, comma) => {
-				s.append(l.into());
+				ToCursors::to_cursors(l, s);
 				s.append(comma.into());
 			}
 		}
@@ -385,7 +385,7 @@ mod tests {
 		assert_size!(Gradient, 208);
 		assert_size!(LinearDirection, 44);
 		assert_size!(RadialSize, 32);
-		assert_size!(ColorStopOrHint, 192);
+		assert_size!(ColorStopOrHint, 208);
 	}
 
 	#[test]
@@ -16,7 +16,7 @@ mod func {
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct Image1D<'a> {
 	pub function: func::Stripes,
-	pub stripes: Vec<'a, ColorStripe>,
+	pub stripes: Vec<'a, ColorStripe<'a>>,
 	pub close: T![')'],
 }
 
@@ -54,13 +54,13 @@ impl<'a> ToCursors for Image1D<'a> {
 // <color-stripe> = <color> && [ <length-percentage> | <flex> ]?
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct ColorStripe {
-	pub color: Color,
-	pub thickness: Option<LengthPercentageOrFlex>,
+pub struct ColorStripe<'a> {
+	pub color: Color<'a>,
+	pub thickness: Option<LengthPercentageOrFlex<'a>>,
 	pub comma: Option<T![,]>,
 }
 
-impl<'a> Parse<'a> for ColorStripe {
+impl<'a> Parse<'a> for ColorStripe<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let mut color = p.parse_if_peek::<Color>()?;
 		let thickness = p.parse_if_peek::<LengthPercentageOrFlex>()?;
@@ -72,11 +72,11 @@ impl<'a> Parse<'a> for ColorStripe {
 	}
 }
 
-impl<'a> ToCursors for ColorStripe {
+impl ToCursors for ColorStripe<'_> {
 	fn to_cursors(&self, s: &mut impl hdx_parser::CursorSink) {
 		ToCursors::to_cursors(&self.color, s);
-		if let Some(thickness) = self.thickness {
-			s.append(thickness.into());
+		if let Some(thickness) = &self.thickness {
+			ToCursors::to_cursors(thickness, s);
 		}
 		if let Some(comma) = self.comma {
 			s.append(comma.into());
@@ -92,7 +92,7 @@ mod tests {
 	#[test]
 	fn size_test() {
 		assert_size!(Image1D, 56);
-		assert_size!(ColorStripe, 192);
+		assert_size!(ColorStripe, 208);
 	}
 
 	#[test]
@@ -1,5 +1,9 @@
+mod calc;
 mod color;
 mod counter_style;
+mod family_name;
+mod font_format;
+mod font_tech;
 mod gradient;
 mod image;
 mod image_1d;
@@ -8,8 +12,12 @@ mod opacity_value;
 mod position;
 mod ratio;
 mod symbols;
+pub use calc::*;
 pub use color::*;
 pub use counter_style::*;
+pub use family_name::*;
+pub use font_format::*;
+pub use font_tech::*;
 pub use gradient::*;
 pub use image::*;
 pub use image_1d::*;
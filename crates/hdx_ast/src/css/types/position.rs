@@ -1,7 +1,7 @@
 use hdx_atom::atom;
-use hdx_lexer::{Cursor, Kind, Token};
+use hdx_lexer::Cursor;
 use hdx_parser::{
-	diagnostics, keyword_typedef, Build, CursorSink, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T,
+	diagnostics, keyword_typedef, CursorSink, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T,
 };
 
 use crate::css::units::LengthPercentage;
@@ -20,25 +20,26 @@ use crate::css::units::LengthPercentage;
 // ]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum Position {
-	SingleValue(PositionSingleValue),
-	TwoValue(PositionHorizontal, PositionVertical),
-	FourValue(PositionHorizontalKeyword, LengthPercentage, PositionVerticalKeyword, LengthPercentage),
+pub enum Position<'a> {
+	SingleValue(PositionSingleValue<'a>),
+	TwoValue(PositionHorizontal<'a>, PositionVertical<'a>),
+	FourValue(PositionHorizontalKeyword, LengthPercentage<'a>, PositionVerticalKeyword, LengthPercentage<'a>),
 }
 
-impl<'a> Peek<'a> for Position {
+impl<'a> Peek<'a> for Position<'a> {
 	fn peek(p: &Parser<'a>) -> bool {
 		p.peek::<PositionSingleValue>()
 	}
 }
 
-impl<'a> Parse<'a> for Position {
+impl<'a> Parse<'a> for Position<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let first = p.parse::<PositionSingleValue>()?;
 		// Single case
 		if !p.peek::<PositionSingleValue>() {
 			return Ok(Self::SingleValue(first));
 		}
+		let second_cursor = p.peek_n(1);
 		let second = p.parse::<PositionSingleValue>()?;
 		// Two value
 		if !p.peek::<PositionSingleValue>() {
@@ -50,8 +51,7 @@ impl<'a> Parse<'a> for Position {
 				if let Some(vertical) = first.to_vertical() {
 					return Ok(Self::TwoValue(horizontal, vertical));
 				} else {
-					let cursor: Cursor = second.into();
-					Err(diagnostics::Unexpected(cursor.into(), cursor.into()))?
+					Err(diagnostics::Unexpected(second_cursor.into(), second_cursor.into()))?
 				}
 			}
 		}
@@ -59,13 +59,12 @@ impl<'a> Parse<'a> for Position {
 		if matches!(first, PositionSingleValue::Center(_) | PositionSingleValue::LengthPercentage(_))
 			|| !matches!(&second, PositionSingleValue::LengthPercentage(_))
 		{
-			let cursor: Cursor = second.into();
-			Err(diagnostics::Unexpected(cursor.into(), cursor.into()))?
+			Err(diagnostics::Unexpected(second_cursor.into(), second_cursor.into()))?
 		}
+		let third_cursor = p.peek_n(1);
 		let third = p.parse::<PositionSingleValue>()?;
 		if third.to_horizontal_keyword().is_none() && third.to_vertical_keyword().is_none() {
-			let cursor: Cursor = third.into();
-			Err(diagnostics::UnexpectedIdent(p.parse_atom_lower(cursor), cursor.into()))?
+			Err(diagnostics::UnexpectedIdent(p.parse_atom_lower(third_cursor), third_cursor.into()))?
 		}
 		let fourth = p.parse::<LengthPercentage>()?;
 		if let PositionSingleValue::LengthPercentage(second) = second {
@@ -73,42 +72,38 @@ impl<'a> Parse<'a> for Position {
 				if let Some(vertical) = third.to_vertical_keyword() {
 					Ok(Self::FourValue(horizontal, second, vertical, fourth))
 				} else {
-					let cursor: Cursor = third.into();
-					Err(diagnostics::Unexpected(cursor.into(), cursor.into()))?
+					Err(diagnostics::Unexpected(third_cursor.into(), third_cursor.into()))?
 				}
 			} else if let Some(horizontal) = third.to_horizontal_keyword() {
 				if let Some(vertical) = first.to_vertical_keyword() {
 					Ok(Self::FourValue(horizontal, fourth, vertical, second))
 				} else {
-					let cursor: Cursor = third.into();
-					Err(diagnostics::Unexpected(cursor.into(), cursor.into()))?
+					Err(diagnostics::Unexpected(third_cursor.into(), third_cursor.into()))?
 				}
 			} else {
-				let cursor: Cursor = third.into();
-				Err(diagnostics::Unexpected(cursor.into(), cursor.into()))?
+				Err(diagnostics::Unexpected(third_cursor.into(), third_cursor.into()))?
 			}
 		} else {
-			let cursor: Cursor = second.into();
-			Err(diagnostics::Unexpected(cursor.into(), cursor.into()))?
+			Err(diagnostics::Unexpected(second_cursor.into(), second_cursor.into()))?
 		}
 	}
 }
 
-impl<'a> ToCursors for Position {
+impl ToCursors for Position<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
-		match *self {
+		match self {
 			Self::SingleValue(v) => {
-				s.append(v.into());
+				ToCursors::to_cursors(v, s);
 			}
 			Self::TwoValue(a, b) => {
-				s.append(a.into());
-				s.append(b.into());
+				ToCursors::to_cursors(a, s);
+				ToCursors::to_cursors(b, s);
 			}
 			Self::FourValue(a, b, c, d) => {
 				s.append(a.into());
-				s.append(b.into());
+				ToCursors::to_cursors(b, s);
 				s.append(c.into());
-				s.append(d.into());
+				ToCursors::to_cursors(d, s);
 			}
 		}
 	}
@@ -116,18 +111,18 @@ impl<'a> ToCursors for Position {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum PositionSingleValue {
+pub enum PositionSingleValue<'a> {
 	Left(T![Ident]),
 	Right(T![Ident]),
 	Center(T![Ident]),
 	Top(T![Ident]),
 	Bottom(T![Ident]),
-	LengthPercentage(LengthPercentage),
+	LengthPercentage(LengthPercentage<'a>),
 }
 
-impl PositionSingleValue {
+impl<'a> PositionSingleValue<'a> {
 	#[inline]
-	fn to_horizontal(self) -> Option<PositionHorizontal> {
+	pub(crate) fn to_horizontal(self) -> Option<PositionHorizontal<'a>> {
 		match self {
 			Self::Left(t) => Some(PositionHorizontal::Left(t)),
 			Self::Right(t) => Some(PositionHorizontal::Right(t)),
@@ -138,7 +133,7 @@ impl PositionSingleValue {
 	}
 
 	#[inline]
-	fn to_vertical(self) -> Option<PositionVertical> {
+	pub(crate) fn to_vertical(self) -> Option<PositionVertical<'a>> {
 		match self {
 			Self::Top(t) => Some(PositionVertical::Top(t)),
 			Self::Bottom(t) => Some(PositionVertical::Bottom(t)),
@@ -167,40 +162,20 @@ impl PositionSingleValue {
 	}
 }
 
-impl From<PositionSingleValue> for Token {
-	fn from(value: PositionSingleValue) -> Self {
-		match value {
-			PositionSingleValue::Left(v) => v.into(),
-			PositionSingleValue::Right(v) => v.into(),
-			PositionSingleValue::Center(v) => v.into(),
-			PositionSingleValue::Top(v) => v.into(),
-			PositionSingleValue::Bottom(v) => v.into(),
-			PositionSingleValue::LengthPercentage(v) => v.into(),
-		}
-	}
-}
-
-impl From<PositionSingleValue> for Cursor {
-	fn from(value: PositionSingleValue) -> Self {
-		match value {
-			PositionSingleValue::Left(v) => v.into(),
-			PositionSingleValue::Right(v) => v.into(),
-			PositionSingleValue::Center(v) => v.into(),
-			PositionSingleValue::Top(v) => v.into(),
-			PositionSingleValue::Bottom(v) => v.into(),
-			PositionSingleValue::LengthPercentage(v) => v.into(),
+impl ToCursors for PositionSingleValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Left(v) => s.append(v.into()),
+			Self::Right(v) => s.append(v.into()),
+			Self::Center(v) => s.append(v.into()),
+			Self::Top(v) => s.append(v.into()),
+			Self::Bottom(v) => s.append(v.into()),
+			Self::LengthPercentage(v) => ToCursors::to_cursors(v, s),
 		}
 	}
 }
 
-impl From<PositionSingleValue> for Kind {
-	fn from(value: PositionSingleValue) -> Self {
-		let t: Token = value.into();
-		t.into()
-	}
-}
-
-impl<'a> Is<'a> for PositionSingleValue {
+impl<'a> Is<'a> for PositionSingleValue<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
 		LengthPercentage::is(p, c)
 			|| (<T![Ident]>::is(p, c)
@@ -211,84 +186,60 @@ impl<'a> Is<'a> for PositionSingleValue {
 	}
 }
 
-impl<'a> Build<'a> for PositionSingleValue {
-	fn build(p: &Parser<'a>, c: Cursor) -> Self {
-		if <T![Ident]>::is(p, c) {
-			let ident = <T![Ident]>::build(p, c);
-			match p.parse_atom_lower(c) {
-				atom!("center") => Self::Center(ident),
-				atom!("left") => Self::Left(ident),
-				atom!("right") => Self::Right(ident),
-				atom!("top") => Self::Top(ident),
-				atom!("bottom") => Self::Bottom(ident),
-				_ => unreachable!(),
-			}
-		} else if LengthPercentage::is(p, c) {
-			Self::LengthPercentage(LengthPercentage::build(p, c))
-		} else {
-			unreachable!()
+impl<'a> Parse<'a> for PositionSingleValue<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if !p.peek::<T![Ident]>() {
+			return p.parse::<LengthPercentage>().map(Self::LengthPercentage);
 		}
+		let ident = p.parse::<T![Ident]>()?;
+		let c: Cursor = ident.into();
+		Ok(match p.parse_atom_lower(c) {
+			atom!("center") => Self::Center(ident),
+			atom!("left") => Self::Left(ident),
+			atom!("right") => Self::Right(ident),
+			atom!("top") => Self::Top(ident),
+			atom!("bottom") => Self::Bottom(ident),
+			atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
+		})
 	}
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum PositionHorizontal {
+pub enum PositionHorizontal<'a> {
 	Left(T![Ident]),
 	Right(T![Ident]),
 	Center(T![Ident]),
-	LengthPercentage(LengthPercentage),
+	LengthPercentage(LengthPercentage<'a>),
 }
 
-impl From<PositionHorizontal> for Token {
-	fn from(value: PositionHorizontal) -> Self {
-		match value {
-			PositionHorizontal::Left(v) => v.into(),
-			PositionHorizontal::Right(v) => v.into(),
-			PositionHorizontal::Center(v) => v.into(),
-			PositionHorizontal::LengthPercentage(v) => v.into(),
-		}
-	}
-}
-
-impl From<PositionHorizontal> for Cursor {
-	fn from(value: PositionHorizontal) -> Self {
-		match value {
-			PositionHorizontal::Left(v) => v.into(),
-			PositionHorizontal::Right(v) => v.into(),
-			PositionHorizontal::Center(v) => v.into(),
-			PositionHorizontal::LengthPercentage(v) => v.into(),
+impl ToCursors for PositionHorizontal<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Left(v) => s.append(v.into()),
+			Self::Right(v) => s.append(v.into()),
+			Self::Center(v) => s.append(v.into()),
+			Self::LengthPercentage(v) => ToCursors::to_cursors(v, s),
 		}
 	}
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub enum PositionVertical {
+pub enum PositionVertical<'a> {
 	Top(T![Ident]),
 	Bottom(T![Ident]),
 	Center(T![Ident]),
-	LengthPercentage(LengthPercentage),
+	LengthPercentage(LengthPercentage<'a>),
 }
 
-impl From<PositionVertical> for Token {
-	fn from(value: PositionVertical) -> Self {
-		match value {
-			PositionVertical::Top(v) => v.into(),
-			PositionVertical::Bottom(v) => v.into(),
-			PositionVertical::Center(v) => v.into(),
-			PositionVertical::LengthPercentage(v) => v.into(),
-		}
-	}
-}
-
-impl From<PositionVertical> for Cursor {
-	fn from(value: PositionVertical) -> Self {
-		match value {
-			PositionVertical::Top(v) => v.into(),
-			PositionVertical::Bottom(v) => v.into(),
-			PositionVertical::Center(v) => v.into(),
-			PositionVertical::LengthPercentage(v) => v.into(),
+impl ToCursors for PositionVertical<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Top(v) => s.append(v.into()),
+			Self::Bottom(v) => s.append(v.into()),
+			Self::Center(v) => s.append(v.into()),
+			Self::LengthPercentage(v) => ToCursors::to_cursors(v, s),
 		}
 	}
 }
@@ -63,12 +63,32 @@ impl<'a> ToCursors for Symbols<'a> {
 	}
 }
 
-// https://drafts.csswg.org/css-counter-styles-3/#funcdef-symbols
+// https://drafts.csswg.org/css-counter-styles-3/#typedef-symbol
+// The symbols() function only allows strings & images, but @counter-style descriptors also allow <custom-ident>.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub enum Symbol<'a> {
 	String(T![String]),
 	Image(Image<'a>),
+	Ident(T![Ident]),
+}
+
+impl<'a> Peek<'a> for Symbol<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![String]>() || p.peek::<T![Ident]>() || p.peek::<Image>()
+	}
+}
+
+impl<'a> Parse<'a> for Symbol<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![String]>() {
+			p.parse::<T![String]>().map(Self::String)
+		} else if p.peek::<T![Ident]>() {
+			p.parse::<T![Ident]>().map(Self::Ident)
+		} else {
+			p.parse::<Image>().map(Self::Image)
+		}
+	}
 }
 
 impl<'a> ToCursors for Symbol<'a> {
@@ -76,6 +96,7 @@ impl<'a> ToCursors for Symbol<'a> {
 		match self {
 			Self::String(c) => s.append(c.into()),
 			Self::Image(c) => ToCursors::to_cursors(c, s),
+			Self::Ident(c) => s.append(c.into()),
 		}
 	}
 }
@@ -105,5 +126,8 @@ mod tests {
 	fn test_writes() {
 		assert_parse!(Symbols, "symbols(symbolic'+')");
 		assert_parse!(Symbols, "symbols(symbolic'*''†''‡')");
+		assert_parse!(Symbol, "'*'");
+		assert_parse!(Symbol, "thumbs");
+		assert_parse!(Symbol, "url(thumbs.svg)");
 	}
 }
@@ -16,6 +16,17 @@ pub enum Angle {
 	Deg(T![Dimension::Deg]),
 }
 
+impl Angle {
+	pub fn as_degrees(&self) -> f32 {
+		match self {
+			Self::Grad(f) => Into::<f32>::into(*f) * DEG_GRAD,
+			Self::Rad(f) => Into::<f32>::into(*f) * DEG_RAD,
+			Self::Turn(f) => Into::<f32>::into(*f) * DEG_TURN,
+			Self::Deg(f) => (*f).into(),
+		}
+	}
+}
+
 impl Default for Angle {
 	fn default() -> Self {
 		Self::Deg(Default::default())
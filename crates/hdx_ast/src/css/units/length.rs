@@ -1,7 +1,13 @@
-use hdx_lexer::{Cursor, Token};
-use hdx_parser::{Build, Is, Parser, T};
+use hdx_atom::atom;
+use hdx_lexer::{Cursor, Span};
+use hdx_parser::{diagnostics, Build, CursorSink, Is, Parse, Parser, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
 
 use super::Flex;
+use crate::css::{
+	types::{CalcBaseType, MathFunction},
+	Visit, VisitMut, Visitable, VisitableMut,
+};
 
 // const PX_CM: f32 = PX_IN / 2.54;
 // const PX_MM: f32 = PX_IN / 25.4;
@@ -82,21 +88,26 @@ macro_rules! define_length {
 	( $($name: ident),+ $(,)* ) => {
 		#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 		#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value", rename_all = "kebab-case"))]
-		pub enum Length {
+		#[visit]
+		pub enum Length<'a> {
 			Zero(T![Number]),
 			$($name(T![Dimension::$name]),)+
+			Math(&'a MathFunction<'a>),
 		}
 	}
 }
 apply_lengths!(define_length);
 
-impl From<Length> for f32 {
-	fn from(val: Length) -> Self {
+impl Length<'_> {
+	/// The number this Length was written with, or None for a math function, which can only be resolved once the
+	/// value has been computed.
+	pub fn to_f32(&self) -> Option<f32> {
 		macro_rules! match_length {
 			( $($name: ident),+ $(,)* ) => {
-				match val {
-					Length::Zero(_) => 0.0,
-					$(Length::$name(f) => f.into()),+
+				match self {
+					Length::Zero(_) => Some(0.0),
+					Length::Math(_) => None,
+					$(Length::$name(f) => Some((*f).into()),)+
 				}
 			}
 		}
@@ -104,39 +115,54 @@ impl From<Length> for f32 {
 	}
 }
 
-impl From<Length> for Token {
-	fn from(value: Length) -> Self {
-		macro_rules! match_length {
-				( $($name: ident),+ $(,)* ) => {
-					match value {
-						Length::Zero(l) => l.into(),
-						$(Length::$name(l) => l.into(),)+
-					}
-				}
+impl<'a> Length<'a> {
+	/// Parses a Length which must not be negative. A math function can't be checked until it has been computed, at
+	/// which point it's clamped to the range.
+	pub(crate) fn parse_non_negative(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let start = p.offset();
+		let value = p.parse::<Self>()?;
+		if let Some(f) = value.to_f32() {
+			if f < 0.0 {
+				Err(diagnostics::NumberNotNegative(f, Span::new(start, p.offset())))?
 			}
-		apply_lengths!(match_length)
+		}
+		Ok(value)
 	}
 }
 
-impl From<&Length> for Token {
-	fn from(value: &Length) -> Self {
+impl<'a> Visitable<'a> for Length<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_length(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for Length<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_length(self);
+	}
+}
+
+impl From<&Length<'_>> for Span {
+	fn from(value: &Length<'_>) -> Self {
 		macro_rules! match_length {
-				( $($name: ident),+ $(,)* ) => {
-					match value {
-						Length::Zero(l) => l.into(),
-						$(Length::$name(l) => l.into(),)+
-					}
+			( $($name: ident),+ $(,)* ) => {
+				match value {
+					Length::Zero(t) => Cursor::from(t).span(),
+					Length::Math(function) => (*function).into(),
+					$(Length::$name(t) => Cursor::from(t).span(),)+
 				}
 			}
+		}
 		apply_lengths!(match_length)
 	}
 }
 
-impl<'a> Is<'a> for Length {
+impl<'a> Is<'a> for Length<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
 		macro_rules! is_checks {
 			( $($name: ident),+ $(,)* ) => {
-				(<T![Number]>::is(p, c) && c.token().value() == 0.0)
+				MathFunction::is(p, c)
+					|| (<T![Number]>::is(p, c) && c.token().value() == 0.0)
 					$(|| <T![Dimension::$name]>::is(p, c))+
 			}
 		}
@@ -144,8 +170,15 @@ impl<'a> Is<'a> for Length {
 	}
 }
 
-impl<'a> Build<'a> for Length {
-	fn build(p: &Parser<'a>, c: Cursor) -> Self {
+impl<'a> Parse<'a> for Length<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<MathFunction>() {
+			return parse_math_function(p, false).map(Self::Math);
+		}
+		let c = p.next();
+		if !Self::is(p, c) {
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
 		macro_rules! build_steps {
 			( $($name: ident),+ $(,)* ) => {
 				$(if <T![Dimension::$name]>::is(p, c) {
@@ -155,51 +188,71 @@ impl<'a> Build<'a> for Length {
 				}
 			}
 		}
-		apply_lengths!(build_steps)
+		Ok(apply_lengths!(build_steps))
 	}
 }
 
-impl From<Length> for Cursor {
-	fn from(value: Length) -> Self {
-		macro_rules! from_steps {
+impl ToCursors for Length<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		macro_rules! match_length {
 			( $($name: ident),+ $(,)* ) => {
-				match value {
-					$(Length::$name(t) => t.into(),)+
-					Length::Zero(t) => t.into(),
+				match self {
+					Length::Zero(t) => s.append(t.into()),
+					Length::Math(function) => ToCursors::to_cursors(*function, s),
+					$(Length::$name(t) => s.append(t.into()),)+
 				}
 			}
 		}
-		apply_lengths!(from_steps)
+		apply_lengths!(match_length)
 	}
 }
 
-impl From<&Length> for Cursor {
-	fn from(value: &Length) -> Self {
-		(*value).into()
+// https://drafts.csswg.org/css-values-4/#calc-type-checking
+// A math function is only valid where its type matches, but it's still understood, so rather than discarding the
+// declaration the mismatch is reported as an error.
+fn parse_math_function<'a>(p: &mut Parser<'a>, allow_percentage: bool) -> ParserResult<&'a MathFunction<'a>> {
+	let start = p.offset();
+	let function = p.parse::<MathFunction>()?;
+	if let Some(ty) = function.resolve_type() {
+		let matches = if allow_percentage {
+			ty.matches_with_percentage(CalcBaseType::Length)
+		} else {
+			ty.matches(CalcBaseType::Length)
+		};
+		if !matches {
+			let expected = if allow_percentage { atom!("length-percentage") } else { atom!("length") };
+			p.push_error(diagnostics::UnexpectedMathFunctionType(expected, Span::new(start, p.offset())).into());
+		}
 	}
+	Ok(p.bump().alloc(function))
 }
 
 macro_rules! define_length_percentage {
 	( $($name: ident),+ $(,)* ) => {
 		#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 		#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value", rename_all = "kebab-case"))]
-		pub enum LengthPercentage {
+		#[visit]
+		pub enum LengthPercentage<'a> {
 			Zero(T![Number]),
 			$($name(T![Dimension::$name]),)+
 			Percent(T![Dimension::%]),
+			Math(&'a MathFunction<'a>),
 		}
 	}
 }
 apply_lengths!(define_length_percentage);
 
-impl From<LengthPercentage> for f32 {
-	fn from(val: LengthPercentage) -> Self {
+impl LengthPercentage<'_> {
+	/// The number this LengthPercentage was written with, or None for a math function, which can only be resolved
+	/// once the value has been computed.
+	pub fn to_f32(&self) -> Option<f32> {
 		macro_rules! match_length {
 			( $($name: ident),+ $(,)* ) => {
-				match val {
-					LengthPercentage::Zero(_) => 0.0,
-					LengthPercentage::Percent(f) => f.into(),
-					$(LengthPercentage::$name(f) => f.into()),+
+				match self {
+					LengthPercentage::Zero(_) => Some(0.0),
+					LengthPercentage::Percent(f) => Some((*f).into()),
+					LengthPercentage::Math(_) => None,
+					$(LengthPercentage::$name(f) => Some((*f).into()),)+
 				}
 			}
 		}
@@ -207,63 +260,56 @@ impl From<LengthPercentage> for f32 {
 	}
 }
 
-impl From<LengthPercentage> for Token {
-	fn from(value: LengthPercentage) -> Self {
-		macro_rules! match_length {
-				( $($name: ident),+ $(,)* ) => {
-					match value {
-						LengthPercentage::Zero(l) => l.into(),
-						LengthPercentage::Percent(l) => l.into(),
-						$(LengthPercentage::$name(l) => l.into(),)+
-					}
-				}
+impl<'a> LengthPercentage<'a> {
+	/// Parses a LengthPercentage which must not be negative. A math function can't be checked until it has been computed, at
+	/// which point it's clamped to the range.
+	pub(crate) fn parse_non_negative(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let start = p.offset();
+		let value = p.parse::<Self>()?;
+		if let Some(f) = value.to_f32() {
+			if f < 0.0 {
+				Err(diagnostics::NumberNotNegative(f, Span::new(start, p.offset())))?
 			}
-		apply_lengths!(match_length)
+		}
+		Ok(value)
 	}
 }
 
-impl From<&LengthPercentage> for Token {
-	fn from(value: &LengthPercentage) -> Self {
-		macro_rules! match_length {
-				( $($name: ident),+ $(,)* ) => {
-					match value {
-						LengthPercentage::Zero(l) => l.into(),
-						LengthPercentage::Percent(l) => l.into(),
-						$(LengthPercentage::$name(l) => l.into(),)+
-					}
-				}
-			}
-		apply_lengths!(match_length)
+impl<'a> Visitable<'a> for LengthPercentage<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_length_percentage(self);
+	}
+}
+
+impl<'a> VisitableMut<'a> for LengthPercentage<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_length_percentage(self);
 	}
 }
 
-impl From<LengthPercentage> for Cursor {
-	fn from(value: LengthPercentage) -> Self {
-		macro_rules! from_steps {
+impl From<&LengthPercentage<'_>> for Span {
+	fn from(value: &LengthPercentage<'_>) -> Self {
+		macro_rules! match_length {
 			( $($name: ident),+ $(,)* ) => {
 				match value {
-					$(LengthPercentage::$name(t) => t.into(),)+
-					LengthPercentage::Percent(t) => t.into(),
-					LengthPercentage::Zero(t) => t.into(),
+					LengthPercentage::Zero(t) => Cursor::from(t).span(),
+					LengthPercentage::Percent(t) => Cursor::from(t).span(),
+					LengthPercentage::Math(function) => (*function).into(),
+					$(LengthPercentage::$name(t) => Cursor::from(t).span(),)+
 				}
 			}
 		}
-		apply_lengths!(from_steps)
-	}
-}
-
-impl From<&LengthPercentage> for Cursor {
-	fn from(value: &LengthPercentage) -> Self {
-		(*value).into()
+		apply_lengths!(match_length)
 	}
 }
 
-impl<'a> Is<'a> for LengthPercentage {
+impl<'a> Is<'a> for LengthPercentage<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
 		macro_rules! is_checks {
 			( $($name: ident),+ $(,)* ) => {
-				(<T![Number]>::is(p, c) && c.token().value() == 0.0)
-				|| <T![Dimension::%]>::is(p, c)
+				MathFunction::is(p, c)
+					|| (<T![Number]>::is(p, c) && c.token().value() == 0.0)
+					|| <T![Dimension::%]>::is(p, c)
 					$(|| <T![Dimension::$name]>::is(p, c))+
 			}
 		}
@@ -271,8 +317,15 @@ impl<'a> Is<'a> for LengthPercentage {
 	}
 }
 
-impl<'a> Build<'a> for LengthPercentage {
-	fn build(p: &Parser<'a>, c: Cursor) -> Self {
+impl<'a> Parse<'a> for LengthPercentage<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<MathFunction>() {
+			return parse_math_function(p, true).map(Self::Math);
+		}
+		let c = p.next();
+		if !Self::is(p, c) {
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
 		macro_rules! build_steps {
 			( $($name: ident),+ $(,)* ) => {
 				$(if <T![Dimension::$name]>::is(p, c) {
@@ -284,110 +337,112 @@ impl<'a> Build<'a> for LengthPercentage {
 				}
 			}
 		}
-		apply_lengths!(build_steps)
+		Ok(apply_lengths!(build_steps))
+	}
+}
+
+impl ToCursors for LengthPercentage<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		macro_rules! match_length {
+			( $($name: ident),+ $(,)* ) => {
+				match self {
+					LengthPercentage::Zero(t) => s.append(t.into()),
+					LengthPercentage::Percent(t) => s.append(t.into()),
+					LengthPercentage::Math(function) => ToCursors::to_cursors(*function, s),
+					$(LengthPercentage::$name(t) => s.append(t.into()),)+
+				}
+			}
+		}
+		apply_lengths!(match_length)
 	}
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
-pub enum LengthPercentageOrAuto {
+pub enum LengthPercentageOrAuto<'a> {
 	Auto(kw::Auto),
-	LengthPercentage(LengthPercentage),
+	LengthPercentage(LengthPercentage<'a>),
 }
 
-impl<'a> Is<'a> for LengthPercentageOrAuto {
+impl<'a> Is<'a> for LengthPercentageOrAuto<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
 		<kw::Auto>::is(p, c) || LengthPercentage::is(p, c)
 	}
 }
 
-impl<'a> Build<'a> for LengthPercentageOrAuto {
-	fn build(p: &Parser<'a>, c: Cursor) -> Self {
-		if <kw::Auto>::is(p, c) {
-			Self::Auto(<kw::Auto>::build(p, c))
+impl<'a> Parse<'a> for LengthPercentageOrAuto<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<kw::Auto>() {
+			p.parse::<kw::Auto>().map(Self::Auto)
 		} else {
-			Self::LengthPercentage(LengthPercentage::build(p, c))
+			p.parse::<LengthPercentage>().map(Self::LengthPercentage)
 		}
 	}
 }
 
-impl From<LengthPercentageOrAuto> for Token {
-	fn from(value: LengthPercentageOrAuto) -> Self {
-		match value {
-			LengthPercentageOrAuto::Auto(l) => l.into(),
-			LengthPercentageOrAuto::LengthPercentage(l) => l.into(),
-		}
-	}
-}
-
-impl From<&LengthPercentageOrAuto> for Token {
-	fn from(value: &LengthPercentageOrAuto) -> Self {
-		match value {
-			LengthPercentageOrAuto::Auto(l) => l.into(),
-			LengthPercentageOrAuto::LengthPercentage(l) => l.into(),
-		}
-	}
-}
-
-impl From<LengthPercentageOrAuto> for Cursor {
-	fn from(value: LengthPercentageOrAuto) -> Self {
-		match value {
-			LengthPercentageOrAuto::Auto(t) => t.into(),
-			LengthPercentageOrAuto::LengthPercentage(t) => t.into(),
+impl ToCursors for LengthPercentageOrAuto<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Auto(t) => s.append(t.into()),
+			Self::LengthPercentage(t) => ToCursors::to_cursors(t, s),
 		}
 	}
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
-pub enum LengthPercentageOrFlex {
+pub enum LengthPercentageOrFlex<'a> {
 	Flex(Flex),
-	LengthPercentage(LengthPercentage),
-}
-
-impl<'a> Is<'a> for LengthPercentageOrFlex {
-	fn is(p: &Parser<'a>, c: Cursor) -> bool {
-		Flex::is(p, c) || LengthPercentage::is(p, c)
-	}
+	LengthPercentage(LengthPercentage<'a>),
 }
 
-impl<'a> Build<'a> for LengthPercentageOrFlex {
-	fn build(p: &Parser<'a>, c: Cursor) -> Self {
-		if <kw::Auto>::is(p, c) {
-			Self::Flex(Flex::build(p, c))
-		} else {
-			Self::LengthPercentage(LengthPercentage::build(p, c))
+impl LengthPercentageOrFlex<'_> {
+	/// The number this value was written with, or None for a math function.
+	pub fn to_f32(&self) -> Option<f32> {
+		match self {
+			Self::Flex(f) => Some((*f).into()),
+			Self::LengthPercentage(l) => l.to_f32(),
 		}
 	}
 }
 
-impl From<LengthPercentageOrFlex> for Token {
-	fn from(value: LengthPercentageOrFlex) -> Self {
-		match value {
-			LengthPercentageOrFlex::Flex(l) => l.into(),
-			LengthPercentageOrFlex::LengthPercentage(l) => l.into(),
+impl<'a> LengthPercentageOrFlex<'a> {
+	/// Parses a LengthPercentageOrFlex which must not be negative. A math function can't be checked until it has been
+	/// computed.
+	pub(crate) fn parse_non_negative(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let start = p.offset();
+		let value = p.parse::<Self>()?;
+		if let Some(f) = value.to_f32() {
+			if f < 0.0 {
+				Err(diagnostics::NumberNotNegative(f, Span::new(start, p.offset())))?
+			}
 		}
+		Ok(value)
 	}
 }
 
-impl From<&LengthPercentageOrFlex> for Token {
-	fn from(value: &LengthPercentageOrFlex) -> Self {
-		(*value).into()
+impl<'a> Is<'a> for LengthPercentageOrFlex<'a> {
+	fn is(p: &Parser<'a>, c: Cursor) -> bool {
+		Flex::is(p, c) || LengthPercentage::is(p, c)
 	}
 }
 
-impl From<LengthPercentageOrFlex> for Cursor {
-	fn from(value: LengthPercentageOrFlex) -> Self {
-		match value {
-			LengthPercentageOrFlex::Flex(l) => l.into(),
-			LengthPercentageOrFlex::LengthPercentage(l) => l.into(),
+impl<'a> Parse<'a> for LengthPercentageOrFlex<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<Flex>() {
+			p.parse::<Flex>().map(Self::Flex)
+		} else {
+			p.parse::<LengthPercentage>().map(Self::LengthPercentage)
 		}
 	}
 }
 
-impl From<&LengthPercentageOrFlex> for Cursor {
-	fn from(value: &LengthPercentageOrFlex) -> Self {
-		(*value).into()
+impl ToCursors for LengthPercentageOrFlex<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Flex(t) => ToCursors::to_cursors(t, s),
+			Self::LengthPercentage(t) => ToCursors::to_cursors(t, s),
+		}
 	}
 }
 
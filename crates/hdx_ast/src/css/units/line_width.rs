@@ -1,8 +1,10 @@
 use hdx_atom::atom;
 use hdx_lexer::Cursor;
-use hdx_parser::{Build, Is, Parser, T};
+use hdx_parser::{diagnostics, CursorSink, Is, Parse, Parser, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
 
 use super::Length;
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
 
 mod kw {
 	use hdx_parser::custom_keyword;
@@ -13,14 +15,15 @@ mod kw {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
-pub enum LineWidth {
+#[visit]
+pub enum LineWidth<'a> {
 	Thin(kw::Thin),
 	Medium(kw::Medium),
 	Thick(kw::Thick),
-	Length(Length),
+	Length(Length<'a>),
 }
 
-impl<'a> Is<'a> for LineWidth {
+impl<'a> Is<'a> for LineWidth<'a> {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
 		Length::is(p, c)
 			|| (<T![Ident]>::is(p, c)
@@ -28,28 +31,46 @@ impl<'a> Is<'a> for LineWidth {
 	}
 }
 
-impl<'a> Build<'a> for LineWidth {
-	fn build(p: &Parser<'a>, c: Cursor) -> Self {
-		if Length::is(p, c) {
-			Self::Length(Length::build(p, c))
-		} else {
-			match p.parse_atom_lower(c) {
-				atom!("medium") => Self::Medium(kw::Medium::build(p, c)),
-				atom!("thin") => Self::Thin(kw::Thin::build(p, c)),
-				atom!("thick") => Self::Thick(kw::Thick::build(p, c)),
-				_ => unreachable!(),
-			}
+impl<'a> Parse<'a> for LineWidth<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<Length>() {
+			return p.parse::<Length>().map(Self::Length);
+		}
+		let c = p.peek_n(1);
+		match p.parse_atom_lower(c) {
+			atom!("medium") => p.parse::<kw::Medium>().map(Self::Medium),
+			atom!("thin") => p.parse::<kw::Thin>().map(Self::Thin),
+			atom!("thick") => p.parse::<kw::Thick>().map(Self::Thick),
+			atom => Err(diagnostics::UnexpectedIdent(atom, c.into()))?,
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for LineWidth<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_line_width(self);
+		if let Self::Length(length) = self {
+			Visitable::accept(length, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for LineWidth<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_line_width(self);
+		if let Self::Length(length) = self {
+			VisitableMut::accept_mut(length, v);
 		}
 	}
 }
 
-impl From<LineWidth> for Cursor {
-	fn from(value: LineWidth) -> Self {
-		match value {
-			LineWidth::Thin(t) => t.into(),
-			LineWidth::Medium(t) => t.into(),
-			LineWidth::Thick(t) => t.into(),
-			LineWidth::Length(t) => t.into(),
+impl ToCursors for LineWidth<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Thin(t) => s.append(t.into()),
+			Self::Medium(t) => s.append(t.into()),
+			Self::Thick(t) => s.append(t.into()),
+			Self::Length(t) => ToCursors::to_cursors(t, s),
 		}
 	}
 }
@@ -0,0 +1,62 @@
+use hdx_lexer::Cursor;
+use hdx_parser::{Build, Is, Parser, T};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct CSSNumber(T![Number]);
+
+impl CSSNumber {
+	#[allow(non_upper_case_globals)]
+	pub const Zero: CSSNumber = CSSNumber(<T![Number]>::ZERO);
+}
+
+impl From<CSSNumber> for i32 {
+	fn from(value: CSSNumber) -> Self {
+		value.0.into()
+	}
+}
+
+impl From<CSSNumber> for f32 {
+	fn from(value: CSSNumber) -> Self {
+		value.0.into()
+	}
+}
+
+// https://drafts.csswg.org/css-values/#numbers
+// Unlike CSSFloat & CSSInt, a <number> may be written either with or without a fractional part.
+impl<'a> Is<'a> for CSSNumber {
+	fn is(p: &Parser<'a>, c: Cursor) -> bool {
+		<T![Number]>::is(p, c)
+	}
+}
+
+impl<'a> Build<'a> for CSSNumber {
+	fn build(p: &Parser<'a>, c: Cursor) -> Self {
+		Self(<T![Number]>::build(p, c))
+	}
+}
+
+impl From<CSSNumber> for Cursor {
+	fn from(value: CSSNumber) -> Self {
+		value.0.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(CSSNumber, 12);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(CSSNumber, "0.01");
+		assert_parse!(CSSNumber, "3.141");
+		assert_parse!(CSSNumber, "1");
+		assert_parse!(CSSNumber, "-2");
+	}
+}
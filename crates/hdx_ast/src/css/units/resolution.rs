@@ -12,6 +12,8 @@ pub enum Resolution {
 	Dpi(T![Dimension::Dpi]),
 	Dpcm(T![Dimension::Dpcm]),
 	Dppx(T![Dimension::Dppx]),
+	// An alias of dppx.
+	X(T![Dimension::X]),
 }
 
 impl Default for Resolution {
@@ -26,6 +28,7 @@ impl From<Resolution> for f32 {
 			Resolution::Dpi(r) => r.into(),
 			Resolution::Dpcm(r) => r.into(),
 			Resolution::Dppx(r) => r.into(),
+			Resolution::X(r) => r.into(),
 		}
 	}
 }
@@ -36,13 +39,15 @@ impl From<&Resolution> for f32 {
 			Resolution::Dpi(r) => r.into(),
 			Resolution::Dpcm(r) => r.into(),
 			Resolution::Dppx(r) => r.into(),
+			Resolution::X(r) => r.into(),
 		}
 	}
 }
 
 impl<'a> Is<'a> for Resolution {
 	fn is(p: &Parser<'a>, c: Cursor) -> bool {
-		<T![Dimension]>::is(p, c) && matches!(p.parse_atom_lower(c), atom!("dpi") | atom!("dpcm") | atom!("dppx"))
+		<T![Dimension]>::is(p, c)
+			&& matches!(p.parse_atom_lower(c), atom!("dpi") | atom!("dpcm") | atom!("dppx") | atom!("x"))
 	}
 }
 
@@ -52,6 +57,7 @@ impl<'a> Build<'a> for Resolution {
 			atom!("dpi") => Self::Dpi(<T![Dimension::Dpi]>::build(p, c)),
 			atom!("dpcm") => Self::Dpcm(<T![Dimension::Dpcm]>::build(p, c)),
 			atom!("dppx") => Self::Dppx(<T![Dimension::Dppx]>::build(p, c)),
+			atom!("x") => Self::X(<T![Dimension::X]>::build(p, c)),
 			_ => unreachable!(),
 		}
 	}
@@ -63,6 +69,7 @@ impl From<Resolution> for Cursor {
 			Resolution::Dpi(t) => t.into(),
 			Resolution::Dpcm(t) => t.into(),
 			Resolution::Dppx(t) => t.into(),
+			Resolution::X(t) => t.into(),
 		}
 	}
 }
@@ -80,5 +87,6 @@ mod tests {
 	#[test]
 	fn test_writes() {
 		assert_parse!(Resolution, "1dppx");
+		assert_parse!(Resolution, "2x");
 	}
 }
@@ -106,7 +106,7 @@ use impls::*;
 #[percentages("see § 8.3 percentages in gap properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum RowGapStyleValue {}
+pub enum RowGapStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-align-3/#column-gap
 #[value(" normal | <length-percentage [0,∞]> ")]
@@ -116,7 +116,7 @@ pub enum RowGapStyleValue {}
 #[percentages("see § 8.3 percentages in gap properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ColumnGapStyleValue {}
+pub enum ColumnGapStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-align-3/#gap
 #[value(" <'row-gap'> <'column-gap'>? ")]
@@ -126,4 +126,4 @@ pub enum ColumnGapStyleValue {}
 #[percentages("refer to corresponding dimension of the content area")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct GapStyleValue;
+pub struct GapStyleValue<'a>;
@@ -18,12 +18,12 @@ mod kw {
 )]
 pub enum SingleAnimationIterationCount {
 	Infinite(kw::Infinite),
-	Number(CSSFloat),
+	Number(CSSNumber),
 }
 
 impl<'a> Peek<'a> for SingleAnimationIterationCount {
 	fn peek(p: &Parser<'a>) -> bool {
-		p.peek::<kw::Infinite>() || p.peek::<CSSFloat>()
+		p.peek::<kw::Infinite>() || p.peek::<CSSNumber>()
 	}
 }
 
@@ -32,7 +32,7 @@ impl<'a> Parse<'a> for SingleAnimationIterationCount {
 		if p.peek::<kw::Infinite>() {
 			return Ok(Self::Infinite(p.parse::<kw::Infinite>()?));
 		}
-		let int = p.parse::<CSSFloat>()?;
+		let int = p.parse::<CSSNumber>()?;
 		let f: f32 = int.into();
 		if f < 0.0 {
 			let c: Cursor = int.into();
@@ -8,21 +8,21 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(BackgroundColorStyleValue, 160);
-		// assert_size!(BackgroundImageStyleValue, 1);
+		assert_size!(BackgroundColorStyleValue, 176);
+		assert_size!(BackgroundImageStyleValue, 32);
 		assert_size!(BackgroundRepeatStyleValue, 32);
 		assert_size!(BackgroundAttachmentStyleValue, 32);
-		// assert_size!(BackgroundPositionStyleValue, 1);
+		assert_size!(BackgroundPositionStyleValue, 32);
 		assert_size!(BackgroundClipStyleValue, 32);
 		assert_size!(BackgroundOriginStyleValue, 32);
-		// assert_size!(BackgroundSizeStyleValue, 1);
-		// assert_size!(BackgroundStyleValue, 1);
+		assert_size!(BackgroundSizeStyleValue, 32);
+		assert_size!(BackgroundStyleValue, 32);
 		assert_size!(BorderImageSourceStyleValue, 208);
-		// assert_size!(BorderImageSliceStyleValue, 1);
-		// assert_size!(BorderImageWidthStyleValue, 1);
-		// assert_size!(BorderImageOutsetStyleValue, 1);
-		// assert_size!(BorderImageRepeatStyleValue, 1);
-		// assert_size!(BorderImageStyleValue, 1);
+		assert_size!(BorderImageSliceStyleValue, 80);
+		assert_size!(BorderImageWidthStyleValue, 64);
+		assert_size!(BorderImageOutsetStyleValue, 64);
+		assert_size!(BorderImageRepeatStyleValue, 32);
+		assert_size!(BorderImageStyleValue, 288);
 		assert_size!(BackgroundRepeatXStyleValue, 32);
 		assert_size!(BackgroundRepeatYStyleValue, 32);
 		assert_size!(BackgroundRepeatBlockStyleValue, 32);
@@ -37,5 +37,57 @@ mod tests {
 	fn test_writes() {
 		assert_parse!(BackgroundRepeatStyleValue, "repeat-x");
 		assert_parse!(BackgroundRepeatStyleValue, "space round");
+		assert_parse!(BackgroundImageStyleValue, "none");
+		assert_parse!(BackgroundImageStyleValue, "url(foo.png), none", "url(foo.png),none");
+		assert_parse!(
+			BackgroundImageStyleValue,
+			"linear-gradient(red, blue), url(foo.png)",
+			"linear-gradient(red,blue),url(foo.png)"
+		);
+		assert_parse!(BackgroundPositionStyleValue, "left top");
+		assert_parse!(BackgroundPositionStyleValue, "center, 10px 20%", "center,10px 20%");
+		assert_parse!(BackgroundSizeStyleValue, "cover");
+		assert_parse!(BackgroundSizeStyleValue, "auto 50%, contain", "auto 50%,contain");
+		assert_parse!(BackgroundStyleValue, "red");
+		assert_parse!(BackgroundStyleValue, "url(foo.png) no-repeat", "url(foo.png)no-repeat");
+		assert_parse!(
+			BackgroundStyleValue,
+			"url(foo.png) center/cover no-repeat fixed padding-box content-box red",
+			"url(foo.png)center/cover no-repeat fixed padding-box content-box red"
+		);
+		assert_parse!(
+			BackgroundStyleValue,
+			"url(a.png) left top, url(b.png) repeat-x, blue",
+			"url(a.png)left top,url(b.png)repeat-x,blue"
+		);
+		assert_parse!(BackgroundStyleValue, "url(a.png) no-repeat center/cover", "url(a.png)no-repeat center/cover");
+		assert_parse!(BackgroundStyleValue, "red fixed url(a.png) border-box", "red fixed url(a.png)border-box");
+		assert_parse!(BorderImageSliceStyleValue, "10");
+		assert_parse!(BorderImageSliceStyleValue, "10% 20 30% 40 fill");
+		assert_parse!(BorderImageSliceStyleValue, "fill 30%", "30% fill");
+		assert_parse!(BorderImageWidthStyleValue, "1 auto 10px 5%");
+		assert_parse!(BorderImageOutsetStyleValue, "1px 2");
+		assert_parse!(BorderImageRepeatStyleValue, "stretch round");
+		assert_parse!(BorderImageStyleValue, "url(foo.png)");
+		assert_parse!(BorderImageStyleValue, "url(foo.png) 30 round", "url(foo.png)30 round");
+		assert_parse!(
+			BorderImageStyleValue,
+			"url(foo.png) 10% fill/2px/1 stretch repeat",
+			"url(foo.png)10% fill/2px/1 stretch repeat"
+		);
+		assert_parse!(BorderImageStyleValue, "url(foo.png) 30//2px", "url(foo.png)30//2px");
+		assert_parse!(BorderImageStyleValue, "round 30/2px url(foo.png)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(BackgroundSizeStyleValue, "-10px");
+		assert_parse_error!(BackgroundStyleValue, "red, url(foo.png)");
+		assert_parse_error!(BackgroundStyleValue, "url(a.png) blue, url(b.png)");
+		assert_parse_error!(BorderImageSliceStyleValue, "-10");
+		assert_parse_error!(BorderImageSliceStyleValue, "fill");
+		assert_parse_error!(BorderImageWidthStyleValue, "-1px");
+		assert_parse_error!(BorderImageOutsetStyleValue, "10%");
+		assert_parse_error!(BorderImageStyleValue, "url(foo.png) 30/");
 	}
 }
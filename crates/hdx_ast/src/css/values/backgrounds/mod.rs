@@ -16,17 +16,17 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BackgroundColorStyleValue;
+pub struct BackgroundColorStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#background-image
-// #[value(" <bg-image># ")]
-// #[initial("none")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("discrete")]
-// pub struct BackgroundImageStyleValue<'a>;
+// https://drafts.csswg.org/css-backgrounds-4/#background-image
+#[value(" <bg-image># ")]
+#[initial("none")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("discrete")]
+pub struct BackgroundImageStyleValue<'a>;
 
 // https://drafts.csswg.org/css-backgrounds-4/#background-repeat
 #[value(" <repeat-style># ")]
@@ -48,15 +48,16 @@ pub struct BackgroundRepeatStyleValue<'a>;
 #[animation_type("discrete")]
 pub struct BackgroundAttachmentStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#background-position
-// #[value(" <bg-position># ")]
-// #[initial("0% 0%")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("refer to size of background positioning area minus size of background image; see text")]
-// #[canonical_order("per grammar")]
-// #[animation_type("repeatable list")]
-// pub struct BackgroundPositionStyleValue<'a>;
+// https://drafts.csswg.org/css-backgrounds-4/#background-position
+// <bg-position>#
+#[value(" <position># ")]
+#[initial("0% 0%")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("refer to size of background positioning area minus size of background image; see text")]
+#[canonical_order("per grammar")]
+#[animation_type("repeatable list")]
+pub struct BackgroundPositionStyleValue<'a>;
 
 // https://drafts.csswg.org/css-backgrounds-4/#background-clip
 #[value(" <bg-clip># ")]
@@ -78,25 +79,26 @@ pub struct BackgroundClipStyleValue<'a>;
 #[animation_type("repeatable list")]
 pub struct BackgroundOriginStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#background-size
-// #[value(" <bg-size># ")]
-// #[initial("auto")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("see text")]
-// #[canonical_order("per grammar")]
-// #[animation_type("repeatable list")]
-// pub struct BackgroundSizeStyleValue<'a>;
+// https://drafts.csswg.org/css-backgrounds-4/#background-size
+#[value(" <bg-size># ")]
+#[initial("auto")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("see text")]
+#[canonical_order("per grammar")]
+#[animation_type("repeatable list")]
+pub struct BackgroundSizeStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#background
-// #[value(" <bg-layer>#? , <final-bg-layer> ")]
-// #[initial("see individual properties")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("see individual properties")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BackgroundStyleValue<'a>;
+// https://drafts.csswg.org/css-backgrounds-4/#background
+// <bg-layer>#? , <final-bg-layer>
+#[value(" <background> ")]
+#[initial("see individual properties")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("see individual properties")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BackgroundStyleValue<'a>;
 
 // https://drafts.csswg.org/css-backgrounds-4/#border-image-source
 #[value(" none | <image> ")]
@@ -108,55 +110,60 @@ pub struct BackgroundOriginStyleValue<'a>;
 #[animation_type("discrete")]
 pub enum BorderImageSourceStyleValue<'a> {}
 
-// // https://drafts.csswg.org/css-backgrounds-4/#border-image-slice
-// #[value(" [<number [0,∞]> | <percentage [0,∞]>]{1,4} && fill? ")]
-// #[initial("100%")]
-// #[applies_to("All elements, except internal table elements when border-collapse is collapse")]
-// #[inherited("no")]
-// #[percentages("refer to size of the border image")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderImageSliceStyleValue {}
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-slice
+// [<number [0,∞]> | <percentage [0,∞]>]{1,4} && fill?
+#[value(" <border-image-slice> ")]
+#[initial("100%")]
+#[applies_to("All elements, except internal table elements when border-collapse is collapse")]
+#[inherited("no")]
+#[percentages("refer to size of the border image")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderImageSliceStyleValue;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#border-image-width
-// #[value(" [ <length-percentage [0,∞]> | <number [0,∞]> | auto ]{1,4} ")]
-// #[initial("1")]
-// #[applies_to("All elements,except internal table elements when border-collapse is collapse")]
-// #[inherited("no")]
-// #[percentages("relative to width/height of the border image area")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderImageWidthStyleValue {}
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-width
+// [ <length-percentage [0,∞]> | <number [0,∞]> | auto ]{1,4}
+#[value(" <border-image-width-value>{1,4} ")]
+#[initial("1")]
+#[applies_to("All elements,except internal table elements when border-collapse is collapse")]
+#[inherited("no")]
+#[percentages("relative to width/height of the border image area")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderImageWidthStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#border-image-outset
-// #[value(" [ <length [0,∞]> | <number [0,∞]> ]{1,4} ")]
-// #[initial("0")]
-// #[applies_to("All elements, except internal table elements when border-collapse is collapse")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderImageOutsetStyleValue {}
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-outset
+// [ <length [0,∞]> | <number [0,∞]> ]{1,4}
+#[value(" <border-image-outset-value>{1,4} ")]
+#[initial("0")]
+#[applies_to("All elements, except internal table elements when border-collapse is collapse")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderImageOutsetStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#border-image-repeat
-// #[value(" [ stretch | repeat | round | space ]{1,2} ")]
-// #[initial("stretch")]
-// #[applies_to("All elements, except internal table elements when border-collapse is collapse")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("discrete")]
-// pub enum BorderImageRepeatStyleValue {}
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-repeat
+// [ stretch | repeat | round | space ]{1,2}
+#[value(" <border-image-repeat-value>{1,2} ")]
+#[initial("stretch")]
+#[applies_to("All elements, except internal table elements when border-collapse is collapse")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("discrete")]
+pub struct BorderImageRepeatStyleValue;
 
-// // https://drafts.csswg.org/css-backgrounds-4/#border-image
-// #[value(" <'border-image-source'> || <'border-image-slice'> [ / <'border-image-width'> | / <'border-image-width'>? / <'border-image-outset'> ]? || <'border-image-repeat'> ")]
-// #[initial("See individual properties")]
-// #[applies_to("See individual properties")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub enum BorderImageStyleValue {}
+// https://drafts.csswg.org/css-backgrounds-4/#border-image
+// <'border-image-source'> || <'border-image-slice'> [ / <'border-image-width'> | / <'border-image-width'>? / <'border-image-outset'> ]? || <'border-image-repeat'>
+#[value(" <border-image> ")]
+#[initial("See individual properties")]
+#[applies_to("See individual properties")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderImageStyleValue<'a>;
 
 // https://drafts.csswg.org/css-backgrounds-4/#background-repeat-x
 #[value(" <repetition># ")]
@@ -1,19 +1,46 @@
+use bumpalo::collections::Vec;
 use hdx_atom::atom;
-use hdx_lexer::Cursor;
+use hdx_lexer::{Cursor, Span};
 use hdx_parser::{
 	diagnostics, keyword_typedef, Build, CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors, T,
 };
+use hdx_proc_macro::visit;
 
 pub(crate) use crate::css::types::*;
+pub(crate) use crate::css::units::*;
 pub(crate) use crate::css::values::r#box::types::VisualBox;
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
+
+// Re-expose stylevalues for shorthands
+pub(crate) use super::{
+	BorderImageOutsetStyleValue, BorderImageRepeatStyleValue, BorderImageSliceStyleValue, BorderImageSourceStyleValue,
+	BorderImageWidthStyleValue,
+};
 
 mod kw {
 	use hdx_parser::custom_keyword;
+	custom_keyword!(Contain, atom!("contain"));
+	custom_keyword!(Cover, atom!("cover"));
+	custom_keyword!(Fill, atom!("fill"));
 	custom_keyword!(None, atom!("none"));
 	custom_keyword!(RepeatX, atom!("repeat-x"));
 	custom_keyword!(RepeatY, atom!("repeat-y"));
 }
 
+// Parses a <length-percentage [0,∞]>, <length [0,∞]> or <number [0,∞]>.
+fn parse_non_negative<'a, T>(p: &mut Parser<'a>) -> ParserResult<T>
+where
+	T: Parse<'a> + Copy + Into<f32> + Into<Cursor>,
+{
+	let value = p.parse::<T>()?;
+	let f: f32 = value.into();
+	if f < 0.0 {
+		let c: Cursor = value.into();
+		Err(diagnostics::NumberNotNegative(f, c.into()))?
+	}
+	Ok(value)
+}
+
 // https://drafts.csswg.org/css-backgrounds/#typedef-bg-image
 // <bg-image> = <image> | none
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -40,6 +67,15 @@ impl<'a> Parse<'a> for BgImage<'a> {
 	}
 }
 
+impl ToCursors for BgImage<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::None(ident) => s.append(ident.into()),
+			Self::Image(image) => ToCursors::to_cursors(image, s),
+		}
+	}
+}
+
 // https://drafts.csswg.org/css-backgrounds-4/#background-repeat
 // <repeat-style> = repeat-x | repeat-y | <repetition>{1,2}
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -112,3 +148,563 @@ keyword_typedef!(BgClip {
 	BorderArea: atom!("border-area"),
 	Text: atom!("text"),
 });
+
+// https://drafts.csswg.org/css-backgrounds-4/#typedef-bg-size
+// <bg-size> = [ <length-percentage [0,∞]> | auto ]{1,2} | cover | contain
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
+pub enum BgSize<'a> {
+	Cover(kw::Cover),
+	Contain(kw::Contain),
+	Size(LengthPercentageOrAuto<'a>, Option<LengthPercentageOrAuto<'a>>),
+}
+
+fn parse_bg_size_value<'a>(p: &mut Parser<'a>) -> ParserResult<LengthPercentageOrAuto<'a>> {
+	if p.peek::<LengthPercentage>() {
+		return LengthPercentage::parse_non_negative(p).map(LengthPercentageOrAuto::LengthPercentage);
+	}
+	p.parse::<LengthPercentageOrAuto>()
+}
+
+impl<'a> Peek<'a> for BgSize<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Cover>() || p.peek::<kw::Contain>() || p.peek::<LengthPercentageOrAuto>()
+	}
+}
+
+impl<'a> Parse<'a> for BgSize<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<kw::Cover>()? {
+			return Ok(Self::Cover(keyword));
+		}
+		if let Some(keyword) = p.parse_if_peek::<kw::Contain>()? {
+			return Ok(Self::Contain(keyword));
+		}
+		let width = parse_bg_size_value(p)?;
+		let height = if p.peek::<LengthPercentageOrAuto>() { Some(parse_bg_size_value(p)?) } else { None };
+		Ok(Self::Size(width, height))
+	}
+}
+
+impl ToCursors for BgSize<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Cover(keyword) => s.append(keyword.into()),
+			Self::Contain(keyword) => s.append(keyword.into()),
+			Self::Size(width, height) => {
+				ToCursors::to_cursors(width, s);
+				if let Some(height) = height {
+					ToCursors::to_cursors(height, s);
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-backgrounds-4/#typedef-bg-layer
+// <bg-layer> = <bg-image> || <bg-position> [ / <bg-size> ]? || <repeat-style> || <attachment> || <visual-box> || <visual-box>
+// <final-bg-layer> = <bg-layer> || <'background-color'>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct BgLayer<'a> {
+	pub image: Option<BgImage<'a>>,
+	pub position: Option<Position<'a>>,
+	pub size: Option<(T![/], BgSize<'a>)>,
+	pub repeat: Option<RepeatStyle>,
+	pub attachment: Option<Attachment>,
+	pub origin: Option<VisualBox>,
+	pub clip: Option<VisualBox>,
+	pub color: Option<Color<'a>>,
+	// The components may appear in any order, so this records the order they were parsed in, to write them back out
+	// in that order.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub order: [Option<BgLayerComponent>; 7],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BgLayerComponent {
+	Image,
+	// Includes the size, which can only follow the position.
+	Position,
+	Repeat,
+	Attachment,
+	Origin,
+	Clip,
+	Color,
+}
+
+impl BgLayer<'_> {
+	fn push_order(&mut self, component: BgLayerComponent) {
+		if let Some(slot) = self.order.iter_mut().find(|slot| slot.is_none()) {
+			*slot = Some(component);
+		}
+	}
+}
+
+impl<'a> Peek<'a> for BgLayer<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<BgImage>()
+			|| p.peek::<Position>()
+			|| p.peek::<RepeatStyle>()
+			|| p.peek::<Attachment>()
+			|| p.peek::<VisualBox>()
+			|| p.peek::<Color>()
+	}
+}
+
+impl<'a> Parse<'a> for BgLayer<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut layer = Self {
+			image: None,
+			position: None,
+			size: None,
+			repeat: None,
+			attachment: None,
+			origin: None,
+			clip: None,
+			color: None,
+			order: [None; 7],
+		};
+		loop {
+			if layer.image.is_none() && p.peek::<BgImage>() {
+				layer.image = Some(p.parse::<BgImage>()?);
+				layer.push_order(BgLayerComponent::Image);
+			} else if layer.position.is_none() && p.peek::<Position>() {
+				layer.position = Some(p.parse::<Position>()?);
+				if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+					layer.size = Some((slash, p.parse::<BgSize>()?));
+				}
+				layer.push_order(BgLayerComponent::Position);
+			} else if layer.repeat.is_none() && p.peek::<RepeatStyle>() {
+				layer.repeat = Some(p.parse::<RepeatStyle>()?);
+				layer.push_order(BgLayerComponent::Repeat);
+			} else if layer.attachment.is_none() && p.peek::<Attachment>() {
+				layer.attachment = Some(p.parse::<Attachment>()?);
+				layer.push_order(BgLayerComponent::Attachment);
+			} else if layer.origin.is_none() && p.peek::<VisualBox>() {
+				layer.origin = Some(p.parse::<VisualBox>()?);
+				layer.push_order(BgLayerComponent::Origin);
+			} else if layer.clip.is_none() && p.peek::<VisualBox>() {
+				layer.clip = Some(p.parse::<VisualBox>()?);
+				layer.push_order(BgLayerComponent::Clip);
+			} else if layer.color.is_none() && p.peek::<Color>() {
+				layer.color = Some(p.parse::<Color>()?);
+				layer.push_order(BgLayerComponent::Color);
+			} else {
+				break;
+			}
+		}
+		if layer.image.is_none()
+			&& layer.position.is_none()
+			&& layer.repeat.is_none()
+			&& layer.attachment.is_none()
+			&& layer.origin.is_none()
+			&& layer.color.is_none()
+		{
+			let c: Cursor = p.parse::<T![Any]>()?.into();
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
+		Ok(layer)
+	}
+}
+
+impl ToCursors for BgLayer<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for component in self.order.iter().flatten() {
+			match component {
+				BgLayerComponent::Image => {
+					if let Some(image) = &self.image {
+						ToCursors::to_cursors(image, s);
+					}
+				}
+				BgLayerComponent::Position => {
+					if let Some(position) = &self.position {
+						ToCursors::to_cursors(position, s);
+					}
+					if let Some((slash, size)) = &self.size {
+						s.append(slash.into());
+						ToCursors::to_cursors(size, s);
+					}
+				}
+				BgLayerComponent::Repeat => {
+					if let Some(repeat) = &self.repeat {
+						ToCursors::to_cursors(repeat, s);
+					}
+				}
+				BgLayerComponent::Attachment => {
+					if let Some(attachment) = self.attachment {
+						s.append(attachment.into());
+					}
+				}
+				BgLayerComponent::Origin => {
+					if let Some(origin) = self.origin {
+						s.append(origin.into());
+					}
+				}
+				BgLayerComponent::Clip => {
+					if let Some(clip) = self.clip {
+						s.append(clip.into());
+					}
+				}
+				BgLayerComponent::Color => {
+					if let Some(color) = &self.color {
+						ToCursors::to_cursors(color, s);
+					}
+				}
+			}
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for BgLayer<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_bg_layer(self);
+		if let Some(color) = &self.color {
+			Visitable::accept(color, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for BgLayer<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_bg_layer(self);
+		if let Some(color) = &mut self.color {
+			VisitableMut::accept_mut(color, v);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-backgrounds-4/#background
+// <bg-layer>#? , <final-bg-layer>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct Background<'a>(pub Vec<'a, (BgLayer<'a>, Option<T![,]>)>);
+
+impl<'a> Peek<'a> for Background<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<BgLayer>()
+	}
+}
+
+impl<'a> Parse<'a> for Background<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut layers = Vec::new_in(p.bump());
+		loop {
+			let start = p.offset();
+			let layer = p.parse::<BgLayer>()?;
+			let span = Span::new(start, p.offset());
+			let comma = p.parse_if_peek::<T![,]>()?;
+			if comma.is_none() {
+				layers.push((layer, comma));
+				return Ok(Self(layers));
+			}
+			// Only the <final-bg-layer> may include a color
+			if layer.color.is_some() {
+				Err(diagnostics::BackgroundColorNotInFinalLayer(span))?
+			}
+			layers.push((layer, comma));
+		}
+	}
+}
+
+impl ToCursors for Background<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for (layer, comma) in &self.0 {
+			ToCursors::to_cursors(layer, s);
+			if let Some(comma) = comma {
+				s.append(comma.into());
+			}
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for Background<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_background(self);
+		for (layer, _) in &self.0 {
+			Visitable::accept(layer, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for Background<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_background(self);
+		for (layer, _) in &mut self.0 {
+			VisitableMut::accept_mut(layer, v);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-slice
+// <number [0,∞]> | <percentage [0,∞]>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum BorderImageSliceValue {
+	Number(CSSNumber),
+	Percentage(T![Dimension::%]),
+}
+
+impl<'a> Peek<'a> for BorderImageSliceValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CSSNumber>() || p.peek::<T![Dimension::%]>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderImageSliceValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![Dimension::%]>() {
+			parse_non_negative::<T![Dimension::%]>(p).map(Self::Percentage)
+		} else {
+			parse_non_negative::<CSSNumber>(p).map(Self::Number)
+		}
+	}
+}
+
+impl From<BorderImageSliceValue> for Cursor {
+	fn from(value: BorderImageSliceValue) -> Self {
+		match value {
+			BorderImageSliceValue::Number(c) => c.into(),
+			BorderImageSliceValue::Percentage(c) => c.into(),
+		}
+	}
+}
+
+// [<number [0,∞]> | <percentage [0,∞]>]{1,4} && fill?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct BorderImageSlice {
+	pub top: BorderImageSliceValue,
+	pub right: Option<BorderImageSliceValue>,
+	pub bottom: Option<BorderImageSliceValue>,
+	pub left: Option<BorderImageSliceValue>,
+	pub fill: Option<kw::Fill>,
+}
+
+impl<'a> Peek<'a> for BorderImageSlice {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<BorderImageSliceValue>() || p.peek::<kw::Fill>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderImageSlice {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut fill = p.parse_if_peek::<kw::Fill>()?;
+		let top = p.parse::<BorderImageSliceValue>()?;
+		let right = p.parse_if_peek::<BorderImageSliceValue>()?;
+		let bottom = if right.is_some() { p.parse_if_peek::<BorderImageSliceValue>()? } else { None };
+		let left = if bottom.is_some() { p.parse_if_peek::<BorderImageSliceValue>()? } else { None };
+		if fill.is_none() {
+			fill = p.parse_if_peek::<kw::Fill>()?;
+		}
+		Ok(Self { top, right, bottom, left, fill })
+	}
+}
+
+impl ToCursors for BorderImageSlice {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.top.into());
+		for value in [self.right, self.bottom, self.left].into_iter().flatten() {
+			s.append(value.into());
+		}
+		if let Some(fill) = self.fill {
+			s.append(fill.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-width
+// <length-percentage [0,∞]> | <number [0,∞]> | auto
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum BorderImageWidthValue<'a> {
+	LengthPercentage(LengthPercentage<'a>),
+	Number(CSSNumber),
+	Auto(T![Ident]),
+}
+
+impl<'a> Peek<'a> for BorderImageWidthValue<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CSSNumber>() || p.peek::<LengthPercentageOrAuto>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderImageWidthValue<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<CSSNumber>() {
+			return parse_non_negative::<CSSNumber>(p).map(Self::Number);
+		}
+		if p.peek::<T![Ident]>() && p.parse_atom_lower(p.peek_n(1)) == atom!("auto") {
+			return p.parse::<T![Ident]>().map(Self::Auto);
+		}
+		LengthPercentage::parse_non_negative(p).map(Self::LengthPercentage)
+	}
+}
+
+impl ToCursors for BorderImageWidthValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::LengthPercentage(c) => ToCursors::to_cursors(c, s),
+			Self::Number(c) => s.append((*c).into()),
+			Self::Auto(c) => s.append(c.into()),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-outset
+// <length [0,∞]> | <number [0,∞]>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum BorderImageOutsetValue<'a> {
+	Length(Length<'a>),
+	Number(CSSNumber),
+}
+
+impl<'a> Peek<'a> for BorderImageOutsetValue<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CSSNumber>() || p.peek::<Length>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderImageOutsetValue<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<CSSNumber>() {
+			parse_non_negative::<CSSNumber>(p).map(Self::Number)
+		} else {
+			Length::parse_non_negative(p).map(Self::Length)
+		}
+	}
+}
+
+impl ToCursors for BorderImageOutsetValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Length(c) => ToCursors::to_cursors(c, s),
+			Self::Number(c) => s.append((*c).into()),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-backgrounds-4/#border-image-repeat
+// stretch | repeat | round | space
+keyword_typedef!(BorderImageRepeatValue {
+	Stretch: atom!("stretch"),
+	Repeat: atom!("repeat"),
+	Round: atom!("round"),
+	Space: atom!("space"),
+});
+
+// https://drafts.csswg.org/css-backgrounds-4/#border-image
+// <'border-image-source'> || <'border-image-slice'> [ / <'border-image-width'> | / <'border-image-width'>? / <'border-image-outset'> ]? || <'border-image-repeat'>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct BorderImage<'a> {
+	// The source is bump allocated to keep BorderImage within the StyleValue size budget
+	pub source: Option<&'a BorderImageSourceStyleValue<'a>>,
+	pub slice: Option<BorderImageSliceStyleValue>,
+	pub width: Option<(T![/], Option<BorderImageWidthStyleValue<'a>>)>,
+	pub outset: Option<(T![/], BorderImageOutsetStyleValue<'a>)>,
+	pub repeat: Option<BorderImageRepeatStyleValue>,
+	// The components may appear in any order, so this records the order they were parsed in, to write them back out
+	// in that order.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub order: [Option<BorderImageComponent>; 3],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BorderImageComponent {
+	Source,
+	// Includes the width & outset, which can only follow the slice.
+	Slice,
+	Repeat,
+}
+
+impl BorderImage<'_> {
+	fn push_order(&mut self, component: BorderImageComponent) {
+		if let Some(slot) = self.order.iter_mut().find(|slot| slot.is_none()) {
+			*slot = Some(component);
+		}
+	}
+}
+
+impl<'a> Peek<'a> for BorderImage<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<BorderImageSourceStyleValue>()
+			|| p.peek::<BorderImageSliceStyleValue>()
+			|| p.peek::<BorderImageRepeatStyleValue>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderImage<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut value = Self { source: None, slice: None, width: None, outset: None, repeat: None, order: [None; 3] };
+		loop {
+			if value.source.is_none() && p.peek::<BgImage>() {
+				value.source = Some(p.bump().alloc(p.parse::<BorderImageSourceStyleValue>()?));
+				value.push_order(BorderImageComponent::Source);
+			} else if value.slice.is_none() && p.peek::<BorderImageSliceStyleValue>() {
+				value.slice = Some(p.parse::<BorderImageSliceStyleValue>()?);
+				if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+					let width = p.parse_if_peek::<BorderImageWidthStyleValue>()?;
+					let outset_slash = p.parse_if_peek::<T![/]>()?;
+					// `/ /` must be followed by an outset, and a lone `/` by a width
+					if width.is_none() && outset_slash.is_none() {
+						let c: Cursor = p.parse::<T![Any]>()?.into();
+						Err(diagnostics::Unexpected(c.into(), c.into()))?
+					}
+					value.width = Some((slash, width));
+					if let Some(outset_slash) = outset_slash {
+						value.outset = Some((outset_slash, p.parse::<BorderImageOutsetStyleValue>()?));
+					}
+				}
+				value.push_order(BorderImageComponent::Slice);
+			} else if value.repeat.is_none() && p.peek::<BorderImageRepeatValue>() {
+				value.repeat = Some(p.parse::<BorderImageRepeatStyleValue>()?);
+				value.push_order(BorderImageComponent::Repeat);
+			} else {
+				break;
+			}
+		}
+		if value.source.is_none() && value.slice.is_none() && value.repeat.is_none() {
+			let c: Cursor = p.parse::<T![Any]>()?.into();
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
+		Ok(value)
+	}
+}
+
+impl ToCursors for BorderImage<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for component in self.order.iter().flatten() {
+			match component {
+				BorderImageComponent::Source => {
+					if let Some(source) = self.source {
+						ToCursors::to_cursors(source, s);
+					}
+				}
+				BorderImageComponent::Slice => {
+					if let Some(slice) = &self.slice {
+						ToCursors::to_cursors(slice, s);
+					}
+					if let Some((slash, width)) = &self.width {
+						s.append(slash.into());
+						if let Some(width) = width {
+							ToCursors::to_cursors(width, s);
+						}
+					}
+					if let Some((slash, outset)) = &self.outset {
+						s.append(slash.into());
+						ToCursors::to_cursors(outset, s);
+					}
+				}
+				BorderImageComponent::Repeat => {
+					if let Some(repeat) = &self.repeat {
+						ToCursors::to_cursors(repeat, s);
+					}
+				}
+			}
+		}
+	}
+}
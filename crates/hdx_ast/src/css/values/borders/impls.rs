@@ -8,17 +8,17 @@ mod tests {
 
 	#[test]
 	pub fn size_test() {
-		assert_size!(BorderTopColorStyleValue, 160);
-		assert_size!(BorderRightColorStyleValue, 160);
-		assert_size!(BorderBottomColorStyleValue, 160);
-		assert_size!(BorderLeftColorStyleValue, 160);
-		assert_size!(BorderBlockStartColorStyleValue, 160);
-		assert_size!(BorderBlockEndColorStyleValue, 160);
-		assert_size!(BorderInlineStartColorStyleValue, 160);
-		assert_size!(BorderInlineEndColorStyleValue, 160);
+		assert_size!(BorderTopColorStyleValue, 176);
+		assert_size!(BorderRightColorStyleValue, 176);
+		assert_size!(BorderBottomColorStyleValue, 176);
+		assert_size!(BorderLeftColorStyleValue, 176);
+		assert_size!(BorderBlockStartColorStyleValue, 176);
+		assert_size!(BorderBlockEndColorStyleValue, 176);
+		assert_size!(BorderInlineStartColorStyleValue, 176);
+		assert_size!(BorderInlineEndColorStyleValue, 176);
 		// assert_size!(BorderColorStyleValue, 1);
-		assert_size!(BorderBlockColorStyleValue, 320);
-		assert_size!(BorderInlineColorStyleValue, 320);
+		assert_size!(BorderBlockColorStyleValue, 352);
+		assert_size!(BorderInlineColorStyleValue, 352);
 		assert_size!(BorderTopStyleStyleValue, 16);
 		assert_size!(BorderRightStyleStyleValue, 16);
 		assert_size!(BorderBottomStyleStyleValue, 16);
@@ -38,16 +38,17 @@ mod tests {
 		assert_size!(BorderInlineEndWidthStyleValue, 16);
 		assert_size!(BorderBlockWidthStyleValue, 32);
 		assert_size!(BorderInlineWidthStyleValue, 32);
-		assert_size!(BorderTopStyleValue, 192);
-		assert_size!(BorderRightStyleValue, 192);
-		assert_size!(BorderBottomStyleValue, 192);
-		assert_size!(BorderLeftStyleValue, 192);
-		assert_size!(BorderBlockStartStyleValue, 192);
-		assert_size!(BorderBlockEndStyleValue, 192);
-		assert_size!(BorderInlineStartStyleValue, 192);
-		assert_size!(BorderInlineEndStyleValue, 192);
-		assert_size!(BorderBlockStyleValue, 192);
-		assert_size!(BorderInlineStyleValue, 192);
+		assert_size!(BorderTopStyleValue, 208);
+		assert_size!(BorderRightStyleValue, 208);
+		assert_size!(BorderBottomStyleValue, 208);
+		assert_size!(BorderLeftStyleValue, 208);
+		assert_size!(BorderBlockStartStyleValue, 208);
+		assert_size!(BorderBlockEndStyleValue, 208);
+		assert_size!(BorderInlineStartStyleValue, 208);
+		assert_size!(BorderInlineEndStyleValue, 208);
+		assert_size!(BorderStyleValue, 208);
+		assert_size!(BorderBlockStyleValue, 208);
+		assert_size!(BorderInlineStyleValue, 208);
 		assert_size!(BorderTopLeftRadiusStyleValue, 32);
 		assert_size!(BorderTopRightRadiusStyleValue, 32);
 		assert_size!(BorderBottomRightRadiusStyleValue, 32);
@@ -56,33 +57,77 @@ mod tests {
 		assert_size!(BorderStartEndRadiusStyleValue, 32);
 		assert_size!(BorderEndStartRadiusStyleValue, 32);
 		assert_size!(BorderEndEndRadiusStyleValue, 32);
-		// assert_size!(BorderTopRadiusStyleValue, 1);
-		// assert_size!(BorderRightRadiusStyleValue, 1);
-		// assert_size!(BorderBottomRadiusStyleValue, 1);
-		// assert_size!(BorderLeftRadiusStyleValue, 1);
-		// assert_size!(BorderBlockStartRadiusStyleValue, 1);
-		// assert_size!(BorderBlockEndRadiusStyleValue, 1);
-		// assert_size!(BorderInlineStartRadiusStyleValue, 1);
-		// assert_size!(BorderInlineEndRadiusStyleValue, 1);
-		// assert_size!(BorderRadiusStyleValue, 1);
-		// assert_size!(CornerShapeStyleValue, 1);
-		// assert_size!(CornersStyleValue, 1);
+		assert_size!(BorderTopRadiusStyleValue, 80);
+		assert_size!(BorderRightRadiusStyleValue, 80);
+		assert_size!(BorderBottomRadiusStyleValue, 80);
+		assert_size!(BorderLeftRadiusStyleValue, 80);
+		assert_size!(BorderBlockStartRadiusStyleValue, 80);
+		assert_size!(BorderBlockEndRadiusStyleValue, 80);
+		assert_size!(BorderInlineStartRadiusStyleValue, 80);
+		assert_size!(BorderInlineEndRadiusStyleValue, 80);
+		assert_size!(BorderRadiusStyleValue, 144);
+		assert_size!(CornerShapeStyleValue, 160);
+		assert_size!(CornersStyleValue, 304);
 		// assert_size!(BorderLimitStyleValue, 1);
-		// assert_size!(BorderClipStyleValue, 1);
-		// assert_size!(BorderClipTopStyleValue, 1);
-		// assert_size!(BorderClipRightStyleValue, 1);
-		// assert_size!(BorderClipBottomStyleValue, 1);
-		// assert_size!(BorderClipLeftStyleValue, 1);
-		// assert_size!(BoxShadowColorStyleValue, 1);
-		// assert_size!(BoxShadowOffsetStyleValue, 1);
+		assert_size!(BorderClipStyleValue, 32);
+		assert_size!(BorderClipTopStyleValue, 32);
+		assert_size!(BorderClipRightStyleValue, 32);
+		assert_size!(BorderClipBottomStyleValue, 32);
+		assert_size!(BorderClipLeftStyleValue, 32);
+		assert_size!(BoxShadowColorStyleValue, 32);
+		assert_size!(BoxShadowOffsetStyleValue, 32);
 		assert_size!(BoxShadowBlurStyleValue, 32);
 		assert_size!(BoxShadowSpreadStyleValue, 32);
-		// assert_size!(BoxShadowPositionStyleValue, 1);
-		// assert_size!(BoxShadowStyleValue, 1);
+		assert_size!(BoxShadowPositionStyleValue, 32);
+		assert_size!(BoxShadowStyleValue, 32);
 	}
 
 	#[test]
 	fn test_writes() {
 		assert_parse!(BorderTopColorStyleValue, "red");
+		assert_parse!(BorderStyleValue, "1px solid red");
+		assert_parse!(BorderStyleValue, "thin");
+		assert_parse!(BorderTopRadiusStyleValue, "10px");
+		assert_parse!(BorderTopRadiusStyleValue, "10px 5% / 20px", "10px 5%/20px");
+		assert_parse!(BorderRadiusStyleValue, "0");
+		assert_parse!(BorderRadiusStyleValue, "10px 20px 30px 40px");
+		assert_parse!(BorderRadiusStyleValue, "50% / 10%", "50%/10%");
+		assert_parse!(BorderRadiusStyleValue, "1px 2px / 3px 4px 5px", "1px 2px/3px 4px 5px");
+		assert_parse!(CornerShapeStyleValue, "round");
+		assert_parse!(CornerShapeStyleValue, "round scoop bevel notch");
+		assert_parse!(CornerShapeStyleValue, "square squircle");
+		assert_parse!(CornerShapeStyleValue, "superellipse(2) round", "superellipse(2)round");
+		assert_parse!(CornerShapeStyleValue, "superellipse(-1.5)");
+		assert_parse!(CornersStyleValue, "bevel 10px");
+		assert_parse!(BorderClipStyleValue, "normal");
+		assert_parse!(BorderClipStyleValue, "10px 1fr 10px");
+		assert_parse!(BorderClipTopStyleValue, "1fr 20%");
+		assert_parse!(BoxShadowColorStyleValue, "red, blue", "red,blue");
+		assert_parse!(BoxShadowOffsetStyleValue, "none, 10px 5px", "none,10px 5px");
+		assert_parse!(BoxShadowPositionStyleValue, "inset, outset", "inset,outset");
+		assert_parse!(BoxShadowStyleValue, "none");
+		assert_parse!(BoxShadowStyleValue, "10px 5px");
+		assert_parse!(BoxShadowStyleValue, "10px 5px 5px red");
+		assert_parse!(BoxShadowStyleValue, "inset 0 0 4px 2px rgba(0, 0, 0, 0.5)", "inset 0 0 4px 2px rgba(0,0,0,0.5)");
+		assert_parse!(BoxShadowStyleValue, "red 1px 2px inset");
+		assert_parse!(BoxShadowStyleValue, "inset red 1px 2px");
+		assert_parse!(BoxShadowStyleValue, "1px 2px inset, red 3px 4px", "1px 2px inset,red 3px 4px");
+		assert_parse!(BoxShadowStyleValue, "1px 1px 2px black, 0 0 1em blue", "1px 1px 2px black,0 0 1em blue");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(BorderRadiusStyleValue, "-10px");
+		assert_parse_error!(BorderRadiusStyleValue, "10px /");
+		assert_parse_error!(BorderRadiusStyleValue, "1px 2px 3px 4px 5px");
+		assert_parse_error!(BorderTopRadiusStyleValue, "1px 2px 3px");
+		assert_parse_error!(CornerShapeStyleValue, "angle");
+		assert_parse_error!(CornerShapeStyleValue, "superellipse(1px)");
+		assert_parse_error!(BorderClipStyleValue, "normal 10px");
+		assert_parse_error!(BorderClipStyleValue, "-1fr");
+		assert_parse_error!(BoxShadowStyleValue, "red");
+		assert_parse_error!(BoxShadowStyleValue, "10px");
+		assert_parse_error!(BoxShadowStyleValue, "1px 2px -3px");
+		assert_parse_error!(BoxShadowStyleValue, "1px 2px, none");
 	}
 }
@@ -226,7 +226,7 @@ pub struct BorderInlineStyleStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderTopWidthStyleValue;
+pub struct BorderTopWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-right-width
 #[value(" <line-width> ")]
@@ -236,7 +236,7 @@ pub struct BorderTopWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderRightWidthStyleValue;
+pub struct BorderRightWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-bottom-width
 #[value(" <line-width> ")]
@@ -246,7 +246,7 @@ pub struct BorderRightWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderBottomWidthStyleValue;
+pub struct BorderBottomWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-left-width
 #[value(" <line-width> ")]
@@ -256,7 +256,7 @@ pub struct BorderBottomWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderLeftWidthStyleValue;
+pub struct BorderLeftWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-block-start-width
 #[value(" <line-width> ")]
@@ -266,7 +266,7 @@ pub struct BorderLeftWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderBlockStartWidthStyleValue;
+pub struct BorderBlockStartWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-block-end-width
 #[value(" <line-width> ")]
@@ -276,7 +276,7 @@ pub struct BorderBlockStartWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderBlockEndWidthStyleValue;
+pub struct BorderBlockEndWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-inline-start-width
 #[value(" <line-width> ")]
@@ -286,7 +286,7 @@ pub struct BorderBlockEndWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderInlineStartWidthStyleValue;
+pub struct BorderInlineStartWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-inline-end-width
 #[value(" <line-width> ")]
@@ -296,7 +296,7 @@ pub struct BorderInlineStartWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderInlineEndWidthStyleValue;
+pub struct BorderInlineEndWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-block-width
 #[value(" <'border-top-width'>{1,2} ")]
@@ -306,7 +306,7 @@ pub struct BorderInlineEndWidthStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderBlockWidthStyleValue;
+pub struct BorderBlockWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-inline-width
 #[value(" <'border-top-width'>{1,2} ")]
@@ -316,7 +316,7 @@ pub struct BorderBlockWidthStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderInlineWidthStyleValue;
+pub struct BorderInlineWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-top
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -326,7 +326,7 @@ pub struct BorderInlineWidthStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderTopStyleValue;
+pub struct BorderTopStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-right
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -336,7 +336,7 @@ pub struct BorderTopStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderRightStyleValue;
+pub struct BorderRightStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-bottom
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -346,7 +346,7 @@ pub struct BorderRightStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderBottomStyleValue;
+pub struct BorderBottomStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-left
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -356,7 +356,7 @@ pub struct BorderBottomStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderLeftStyleValue;
+pub struct BorderLeftStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-block-start
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -366,7 +366,7 @@ pub struct BorderLeftStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderBlockStartStyleValue;
+pub struct BorderBlockStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-block-end
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -376,7 +376,7 @@ pub struct BorderBlockStartStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderBlockEndStyleValue;
+pub struct BorderBlockEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-inline-start
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -386,7 +386,7 @@ pub struct BorderBlockEndStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderInlineStartStyleValue;
+pub struct BorderInlineStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-inline-end
 #[value(" <line-width> || <line-style> || <color> ")]
@@ -396,7 +396,17 @@ pub struct BorderInlineStartStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderInlineEndStyleValue;
+pub struct BorderInlineEndStyleValue<'a>;
+
+// https://drafts.csswg.org/css-borders-4/#border
+#[value(" <line-width> || <line-style> || <color> ")]
+#[initial("see individual properties")]
+#[applies_to("see individual properties")]
+#[inherited("no")]
+#[percentages("see individual properties")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-block
 #[value(" <'border-block-start'> ")]
@@ -406,7 +416,7 @@ pub struct BorderInlineEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderBlockStyleValue;
+pub struct BorderBlockStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-inline
 #[value(" <'border-block-start'> ")]
@@ -416,7 +426,7 @@ pub struct BorderBlockStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BorderInlineStyleValue;
+pub struct BorderInlineStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-top-left-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -426,7 +436,7 @@ pub struct BorderInlineStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderTopLeftRadiusStyleValue;
+pub struct BorderTopLeftRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-top-right-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -436,7 +446,7 @@ pub struct BorderTopLeftRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderTopRightRadiusStyleValue;
+pub struct BorderTopRightRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-bottom-right-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -446,7 +456,7 @@ pub struct BorderTopRightRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderBottomRightRadiusStyleValue;
+pub struct BorderBottomRightRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-bottom-left-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -456,7 +466,7 @@ pub struct BorderBottomRightRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderBottomLeftRadiusStyleValue;
+pub struct BorderBottomLeftRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-start-start-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -466,7 +476,7 @@ pub struct BorderBottomLeftRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderStartStartRadiusStyleValue;
+pub struct BorderStartStartRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-start-end-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -476,7 +486,7 @@ pub struct BorderStartStartRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderStartEndRadiusStyleValue;
+pub struct BorderStartEndRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-end-start-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -486,7 +496,7 @@ pub struct BorderStartEndRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderEndStartRadiusStyleValue;
+pub struct BorderEndStartRadiusStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#border-end-end-radius
 #[value(" <length-percentage [0,∞]>{1,2} ")]
@@ -496,117 +506,127 @@ pub struct BorderEndStartRadiusStyleValue;
 #[percentages("refer to corresponding dimension of the border box.")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct BorderEndEndRadiusStyleValue;
+pub struct BorderEndEndRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-top-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderTopRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-top-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderTopRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-right-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderRightRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-right-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderRightRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-bottom-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderBottomRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-bottom-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderBottomRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-left-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderLeftRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-left-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderLeftRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-block-start-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderBlockStartRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-block-start-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderBlockStartRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-block-end-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderBlockEndRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-block-end-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderBlockEndRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-inline-start-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderInlineStartRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-inline-start-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderInlineStartRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-inline-end-radius
-// #[value(" <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements (but see prose)")]
-// #[inherited("no")]
-// #[percentages("refer to corresponding dimension of the border box.")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderInlineEndRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-inline-end-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[value(" <border-side-radius> ")]
+#[initial("0")]
+#[applies_to("all elements (but see prose)")]
+#[inherited("no")]
+#[percentages("refer to corresponding dimension of the border box.")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderInlineEndRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-radius
-// #[value(" <length-percentage [0,∞]>{1,4} [ / <length-percentage [0,∞]>{1,4} ]? ")]
-// #[initial("0")]
-// #[applies_to("all elements, except table element when border-collapse is collapse")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BorderRadiusStyleValue;
+// https://drafts.csswg.org/css-borders-4/#border-radius
+// <length-percentage [0,∞]>{1,4} [ / <length-percentage [0,∞]>{1,4} ]?
+#[value(" <border-radius> ")]
+#[initial("0")]
+#[applies_to("all elements, except table element when border-collapse is collapse")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BorderRadiusStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#corner-shape
-// #[value(" [ round | angle ]{1,4} ")]
-// #[initial("round")]
-// #[applies_to("all elements, except table element when border-collapse is collapse")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("discrete")]
-// pub enum CornerShapeStyleValue {}
+// https://drafts.csswg.org/css-borders-4/#corner-shape
+// <corner-shape-value>{1,4}
+#[value(" <corner-shape-value>{1,4} ")]
+#[initial("round")]
+#[applies_to("all elements, except table element when border-collapse is collapse")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("discrete")]
+pub struct CornerShapeStyleValue;
 
-// // https://drafts.csswg.org/css-borders-4/#corners
-// #[value(" <'corner-shape'> || <'border-radius'> ")]
-// #[initial("see individual properties")]
-// #[applies_to("see individual properties")]
-// #[inherited("see individual properties")]
-// #[percentages("see individual properties")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct CornersStyleValue;
+// https://drafts.csswg.org/css-borders-4/#corners
+#[value(" <'corner-shape'> || <'border-radius'> ")]
+#[initial("see individual properties")]
+#[applies_to("see individual properties")]
+#[inherited("see individual properties")]
+#[percentages("see individual properties")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct CornersStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-borders-4/#border-limit
 // #[value(" all | [ sides | corners ] <length-percentage [0,∞]>? | [ top | right | bottom | left ] <length-percentage [0,∞]> ")]
@@ -618,75 +638,81 @@ pub struct BorderEndEndRadiusStyleValue;
 // #[animation_type("discrete")]
 // pub enum BorderLimitStyleValue {}
 
-// // https://drafts.csswg.org/css-borders-4/#border-clip
-// #[value(" normal | [ <length-percentage [0,∞]> | <flex> ]+ ")]
-// #[initial("normal")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("refer to length of border-edge side")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderClipStyleValue {}
+// https://drafts.csswg.org/css-borders-4/#border-clip
+// normal | [ <length-percentage [0,∞]> | <flex> ]+
+#[value(" <border-clip> ")]
+#[initial("normal")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("refer to length of border-edge side")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderClipStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-clip-top
-// #[value(" normal | [ <length-percentage [0,∞]> | <flex> ]+ ")]
-// #[initial("normal")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("refer to length of border-edge side")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderClipTopStyleValue {}
+// https://drafts.csswg.org/css-borders-4/#border-clip-top
+// normal | [ <length-percentage [0,∞]> | <flex> ]+
+#[value(" <border-clip> ")]
+#[initial("normal")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("refer to length of border-edge side")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderClipTopStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-clip-right
-// #[value(" normal | [ <length-percentage [0,∞]> | <flex> ]+ ")]
-// #[initial("normal")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("refer to length of border-edge side")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderClipRightStyleValue {}
+// https://drafts.csswg.org/css-borders-4/#border-clip-right
+// normal | [ <length-percentage [0,∞]> | <flex> ]+
+#[value(" <border-clip> ")]
+#[initial("normal")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("refer to length of border-edge side")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderClipRightStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-clip-bottom
-// #[value(" normal | [ <length-percentage [0,∞]> | <flex> ]+ ")]
-// #[initial("normal")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("refer to length of border-edge side")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderClipBottomStyleValue {}
+// https://drafts.csswg.org/css-borders-4/#border-clip-bottom
+// normal | [ <length-percentage [0,∞]> | <flex> ]+
+#[value(" <border-clip> ")]
+#[initial("normal")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("refer to length of border-edge side")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderClipBottomStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#border-clip-left
-// #[value(" normal | [ <length-percentage [0,∞]> | <flex> ]+ ")]
-// #[initial("normal")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("refer to length of border-edge side")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BorderClipLeftStyleValue {}
+// https://drafts.csswg.org/css-borders-4/#border-clip-left
+// normal | [ <length-percentage [0,∞]> | <flex> ]+
+#[value(" <border-clip> ")]
+#[initial("normal")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("refer to length of border-edge side")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BorderClipLeftStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#box-shadow-color
-// #[value(" <color># ")]
-// #[initial("currentcolor")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub struct BoxShadowColorStyleValue<'a>;
+// https://drafts.csswg.org/css-borders-4/#box-shadow-color
+#[value(" <color># ")]
+#[initial("currentcolor")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BoxShadowColorStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#box-shadow-offset
-// #[value(" [ none | <length>{2} ]# ")]
-// #[initial("none")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value, treating none as 0 0 when interpolated with non-none values.")]
-// pub enum BoxShadowOffsetStyleValue<'a> {}
+// https://drafts.csswg.org/css-borders-4/#box-shadow-offset
+// [ none | <length>{2} ]#
+#[value(" <shadow-offset># ")]
+#[initial("none")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value, treating none as 0 0 when interpolated with non-none values.")]
+pub struct BoxShadowOffsetStyleValue<'a>;
 
 // https://drafts.csswg.org/css-borders-4/#box-shadow-blur
 #[value(" <length [0,∞]># ")]
@@ -708,22 +734,24 @@ pub struct BoxShadowBlurStyleValue<'a>;
 #[animation_type("by computed value")]
 pub struct BoxShadowSpreadStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#box-shadow-position
-// #[value(" [ outset | inset ]# ")]
-// #[initial("outset")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("by computed value")]
-// pub enum BoxShadowPositionStyleValue<'a> {}
+// https://drafts.csswg.org/css-borders-4/#box-shadow-position
+// [ outset | inset ]#
+#[value(" <shadow-position># ")]
+#[initial("outset")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("by computed value")]
+pub struct BoxShadowPositionStyleValue<'a>;
 
-// // https://drafts.csswg.org/css-borders-4/#box-shadow
-// #[value(" <spread-shadow># ")]
-// #[initial("none")]
-// #[applies_to("all elements")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("see individual properties")]
-// pub struct BoxShadowStyleValue<'a>;
+// https://drafts.csswg.org/css-borders-4/#box-shadow
+// <spread-shadow>#
+#[value(" <box-shadow> ")]
+#[initial("none")]
+#[applies_to("all elements")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("see individual properties")]
+pub struct BoxShadowStyleValue<'a>;
@@ -1,7 +1,549 @@
+use bumpalo::collections::Vec;
+use hdx_lexer::Cursor;
+use hdx_parser::{diagnostics, keyword_typedef, CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+use hdx_proc_macro::visit;
+
 pub(crate) use crate::css::types::*;
 pub(crate) use crate::css::units::*;
 
+use crate::css::{Visit, VisitMut, Visitable, VisitableMut};
+
 // Re-expose stylevalues for shorthands
 pub(crate) use super::{
-	BorderBlockStartStyleValue, BorderTopColorStyleValue, BorderTopStyleStyleValue, BorderTopWidthStyleValue,
+	BorderBlockStartStyleValue, BorderRadiusStyleValue, BorderTopColorStyleValue, BorderTopStyleStyleValue,
+	BorderTopWidthStyleValue, CornerShapeStyleValue,
 };
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(Inset, atom!("inset"));
+	custom_keyword!(None, atom!("none"));
+	custom_keyword!(Normal, atom!("normal"));
+}
+
+mod func {
+	use hdx_parser::custom_function;
+	custom_function!(Superellipse, atom!("superellipse"));
+}
+
+// <length-percentage [0,∞]>{1,4}
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct CornerRadii<'a> {
+	pub top_left: LengthPercentage<'a>,
+	pub top_right: Option<LengthPercentage<'a>>,
+	pub bottom_right: Option<LengthPercentage<'a>>,
+	pub bottom_left: Option<LengthPercentage<'a>>,
+}
+
+impl<'a> CornerRadii<'a> {
+	// Expands omitted values, in the order top-left, top-right, bottom-right, bottom-left.
+	pub fn expand(&self) -> [LengthPercentage<'a>; 4] {
+		let top_right = self.top_right.unwrap_or(self.top_left);
+		let bottom_right = self.bottom_right.unwrap_or(self.top_left);
+		let bottom_left = self.bottom_left.unwrap_or(top_right);
+		[self.top_left, top_right, bottom_right, bottom_left]
+	}
+}
+
+impl<'a> Peek<'a> for CornerRadii<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<LengthPercentage>()
+	}
+}
+
+impl<'a> Parse<'a> for CornerRadii<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let top_left = LengthPercentage::parse_non_negative(p)?;
+		let mut rest = [None; 3];
+		for radius in rest.iter_mut() {
+			if !p.peek::<LengthPercentage>() {
+				break;
+			}
+			*radius = Some(LengthPercentage::parse_non_negative(p)?);
+		}
+		let [top_right, bottom_right, bottom_left] = rest;
+		Ok(Self { top_left, top_right, bottom_right, bottom_left })
+	}
+}
+
+impl ToCursors for CornerRadii<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.top_left, s);
+		for radius in [self.top_right, self.bottom_right, self.bottom_left].into_iter().flatten() {
+			ToCursors::to_cursors(&radius, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-borders-4/#border-radius
+// <length-percentage [0,∞]>{1,4} [ / <length-percentage [0,∞]>{1,4} ]?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct BorderRadius<'a> {
+	pub horizontal: CornerRadii<'a>,
+	pub vertical: Option<(T![/], CornerRadii<'a>)>,
+}
+
+impl<'a> BorderRadius<'a> {
+	// Returns the (horizontal, vertical) radii of each corner, in the order top-left, top-right, bottom-right,
+	// bottom-left.
+	pub fn corners(&self) -> [(LengthPercentage<'a>, LengthPercentage<'a>); 4] {
+		let horizontal = self.horizontal.expand();
+		let vertical = self.vertical.map(|(_, radii)| radii.expand()).unwrap_or(horizontal);
+		[0, 1, 2, 3].map(|i| (horizontal[i], vertical[i]))
+	}
+}
+
+impl<'a> Peek<'a> for BorderRadius<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CornerRadii>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderRadius<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let horizontal = p.parse::<CornerRadii>()?;
+		let vertical =
+			if let Some(slash) = p.parse_if_peek::<T![/]>()? { Some((slash, p.parse::<CornerRadii>()?)) } else { None };
+		Ok(Self { horizontal, vertical })
+	}
+}
+
+impl ToCursors for BorderRadius<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.horizontal, s);
+		if let Some((slash, radii)) = &self.vertical {
+			s.append(slash.into());
+			ToCursors::to_cursors(radii, s);
+		}
+	}
+}
+
+// <length-percentage [0,∞]>{1,2}
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct SideRadii<'a> {
+	pub start: LengthPercentage<'a>,
+	pub end: Option<LengthPercentage<'a>>,
+}
+
+impl<'a> Peek<'a> for SideRadii<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<LengthPercentage>()
+	}
+}
+
+impl<'a> Parse<'a> for SideRadii<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let start = LengthPercentage::parse_non_negative(p)?;
+		let end = if p.peek::<LengthPercentage>() { Some(LengthPercentage::parse_non_negative(p)?) } else { None };
+		Ok(Self { start, end })
+	}
+}
+
+impl ToCursors for SideRadii<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.start, s);
+		if let Some(end) = &self.end {
+			ToCursors::to_cursors(end, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-borders-4/#border-top-radius
+// <length-percentage [0,∞]>{1,2} [ / <length-percentage [0,∞]>{1,2} ]?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct BorderSideRadius<'a> {
+	pub horizontal: SideRadii<'a>,
+	pub vertical: Option<(T![/], SideRadii<'a>)>,
+}
+
+impl<'a> Peek<'a> for BorderSideRadius<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<SideRadii>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderSideRadius<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let horizontal = p.parse::<SideRadii>()?;
+		let vertical =
+			if let Some(slash) = p.parse_if_peek::<T![/]>()? { Some((slash, p.parse::<SideRadii>()?)) } else { None };
+		Ok(Self { horizontal, vertical })
+	}
+}
+
+impl ToCursors for BorderSideRadius<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.horizontal, s);
+		if let Some((slash, radii)) = &self.vertical {
+			s.append(slash.into());
+			ToCursors::to_cursors(radii, s);
+		}
+	}
+}
+
+keyword_typedef!(CornerShapeKeyword {
+	Round: atom!("round"),
+	Scoop: atom!("scoop"),
+	Bevel: atom!("bevel"),
+	Notch: atom!("notch"),
+	Square: atom!("square"),
+	Squircle: atom!("squircle"),
+});
+
+// https://drafts.csswg.org/css-borders-4/#typedef-corner-shape-value
+// round | scoop | bevel | notch | square | squircle | superellipse(<number>)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum CornerShapeValue {
+	Keyword(CornerShapeKeyword),
+	Superellipse(T![Function], CSSNumber, Option<T![')']>),
+}
+
+impl<'a> Peek<'a> for CornerShapeValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CornerShapeKeyword>() || p.peek::<func::Superellipse>()
+	}
+}
+
+impl<'a> Parse<'a> for CornerShapeValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<CornerShapeKeyword>()? {
+			return Ok(Self::Keyword(keyword));
+		}
+		if !p.peek::<func::Superellipse>() {
+			let c: Cursor = p.parse::<T![Any]>()?.into();
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
+		let function = p.parse::<T![Function]>()?;
+		let number = p.parse::<CSSNumber>()?;
+		let close = if p.at_end() { None } else { Some(p.parse::<T![')']>()?) };
+		Ok(Self::Superellipse(function, number, close))
+	}
+}
+
+impl ToCursors for CornerShapeValue {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Keyword(keyword) => s.append(keyword.into()),
+			Self::Superellipse(function, number, close) => {
+				s.append(function.into());
+				s.append((*number).into());
+				if let Some(close) = close {
+					s.append(close.into());
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-borders-4/#border-clip
+// normal | [ <length-percentage [0,∞]> | <flex> ]+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum BorderClip<'a> {
+	Normal(kw::Normal),
+	Segments(Vec<'a, LengthPercentageOrFlex<'a>>),
+}
+
+impl<'a> Peek<'a> for BorderClip<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Normal>() || p.peek::<LengthPercentageOrFlex>()
+	}
+}
+
+impl<'a> Parse<'a> for BorderClip<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(normal) = p.parse_if_peek::<kw::Normal>()? {
+			return Ok(Self::Normal(normal));
+		}
+		let mut segments = Vec::new_in(p.bump());
+		loop {
+			segments.push(LengthPercentageOrFlex::parse_non_negative(p)?);
+			if !p.peek::<LengthPercentageOrFlex>() {
+				return Ok(Self::Segments(segments));
+			}
+		}
+	}
+}
+
+impl ToCursors for BorderClip<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Normal(normal) => s.append(normal.into()),
+			Self::Segments(segments) => {
+				for segment in segments {
+					ToCursors::to_cursors(segment, s);
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-borders-4/#box-shadow-offset
+// none | <length>{2}
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ShadowOffset<'a> {
+	None(kw::None),
+	Offset(Length<'a>, Length<'a>),
+}
+
+impl<'a> Peek<'a> for ShadowOffset<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::None>() || p.peek::<Length>()
+	}
+}
+
+impl<'a> Parse<'a> for ShadowOffset<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(none) = p.parse_if_peek::<kw::None>()? {
+			return Ok(Self::None(none));
+		}
+		let x = p.parse::<Length>()?;
+		let y = p.parse::<Length>()?;
+		Ok(Self::Offset(x, y))
+	}
+}
+
+impl ToCursors for ShadowOffset<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::None(none) => s.append(none.into()),
+			Self::Offset(x, y) => {
+				ToCursors::to_cursors(x, s);
+				ToCursors::to_cursors(y, s);
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-borders-4/#box-shadow-position
+// outset | inset
+keyword_typedef!(ShadowPosition { Outset: atom!("outset"), Inset: atom!("inset") });
+
+// https://drafts.csswg.org/css-borders-4/#typedef-spread-shadow
+// <'box-shadow-color'>? && [ <'box-shadow-offset'> [ <'box-shadow-blur'> <'box-shadow-spread'>? ]? ] && <'box-shadow-position'>?
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct Shadow<'a> {
+	pub color: Option<Color<'a>>,
+	pub offset: (Length<'a>, Length<'a>),
+	pub blur: Option<Length<'a>>,
+	pub spread: Option<Length<'a>>,
+	pub inset: Option<kw::Inset>,
+	// The components may appear in any order, so this records the order they were parsed in, to write them back out
+	// in that order.
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub order: [Option<ShadowComponent>; 3],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShadowComponent {
+	Color,
+	// Includes the blur & spread, which can only follow the offset.
+	Offset,
+	Inset,
+}
+
+impl ShadowComponent {
+	fn push_order(self, order: &mut [Option<Self>; 3]) {
+		if let Some(slot) = order.iter_mut().find(|slot| slot.is_none()) {
+			*slot = Some(self);
+		}
+	}
+}
+
+impl<'a> Peek<'a> for Shadow<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<Length>() || p.peek::<kw::Inset>() || p.peek::<Color>()
+	}
+}
+
+impl<'a> Parse<'a> for Shadow<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut color = None;
+		let mut lengths = None;
+		let mut inset = None;
+		let mut order = [None; 3];
+		loop {
+			let component = if lengths.is_none() && p.peek::<Length>() {
+				let x = p.parse::<Length>()?;
+				let y = p.parse::<Length>()?;
+				let blur = if p.peek::<Length>() { Some(Length::parse_non_negative(p)?) } else { None };
+				let spread = if blur.is_some() { p.parse_if_peek::<Length>()? } else { None };
+				lengths = Some(((x, y), blur, spread));
+				ShadowComponent::Offset
+			} else if inset.is_none() && p.peek::<kw::Inset>() {
+				inset = Some(p.parse::<kw::Inset>()?);
+				ShadowComponent::Inset
+			} else if color.is_none() && p.peek::<Color>() {
+				color = Some(p.parse::<Color>()?);
+				ShadowComponent::Color
+			} else {
+				break;
+			};
+			component.push_order(&mut order);
+		}
+		let Some((offset, blur, spread)) = lengths else {
+			let c: Cursor = p.parse::<T![Any]>()?.into();
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		};
+		Ok(Self { color, offset, blur, spread, inset, order })
+	}
+}
+
+impl ToCursors for Shadow<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for component in self.order.iter().flatten() {
+			match component {
+				ShadowComponent::Color => {
+					if let Some(color) = &self.color {
+						ToCursors::to_cursors(color, s);
+					}
+				}
+				ShadowComponent::Offset => {
+					ToCursors::to_cursors(&self.offset.0, s);
+					ToCursors::to_cursors(&self.offset.1, s);
+					if let Some(blur) = &self.blur {
+						ToCursors::to_cursors(blur, s);
+					}
+					if let Some(spread) = &self.spread {
+						ToCursors::to_cursors(spread, s);
+					}
+				}
+				ShadowComponent::Inset => {
+					if let Some(inset) = self.inset {
+						s.append(inset.into());
+					}
+				}
+			}
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for Shadow<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_shadow(self);
+		Visitable::accept(&self.offset.0, v);
+		Visitable::accept(&self.offset.1, v);
+		if let Some(blur) = &self.blur {
+			Visitable::accept(blur, v);
+		}
+		if let Some(spread) = &self.spread {
+			Visitable::accept(spread, v);
+		}
+		if let Some(color) = &self.color {
+			Visitable::accept(color, v);
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for Shadow<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_shadow(self);
+		VisitableMut::accept_mut(&mut self.offset.0, v);
+		VisitableMut::accept_mut(&mut self.offset.1, v);
+		if let Some(blur) = &mut self.blur {
+			VisitableMut::accept_mut(blur, v);
+		}
+		if let Some(spread) = &mut self.spread {
+			VisitableMut::accept_mut(spread, v);
+		}
+		if let Some(color) = &mut self.color {
+			VisitableMut::accept_mut(color, v);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-borders-4/#box-shadow
+// none | <spread-shadow>#
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[visit]
+pub enum BoxShadow<'a> {
+	None(kw::None),
+	Shadows(Vec<'a, (Shadow<'a>, Option<T![,]>)>),
+}
+
+impl<'a> Peek<'a> for BoxShadow<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::None>() || p.peek::<Shadow>()
+	}
+}
+
+impl<'a> Parse<'a> for BoxShadow<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(none) = p.parse_if_peek::<kw::None>()? {
+			return Ok(Self::None(none));
+		}
+		let mut shadows = Vec::new_in(p.bump());
+		loop {
+			let shadow = p.parse::<Shadow>()?;
+			let comma = p.parse_if_peek::<T![,]>()?;
+			let done = comma.is_none();
+			shadows.push((shadow, comma));
+			if done {
+				return Ok(Self::Shadows(shadows));
+			}
+		}
+	}
+}
+
+impl ToCursors for BoxShadow<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::None(none) => s.append(none.into()),
+			Self::Shadows(shadows) => {
+				for (shadow, comma) in shadows {
+					ToCursors::to_cursors(shadow, s);
+					if let Some(comma) = comma {
+						s.append(comma.into());
+					}
+				}
+			}
+		}
+	}
+}
+
+impl<'a> Visitable<'a> for BoxShadow<'a> {
+	fn accept<V: Visit<'a>>(&self, v: &mut V) {
+		v.visit_box_shadow(self);
+		if let Self::Shadows(shadows) = self {
+			for (shadow, _) in shadows {
+				Visitable::accept(shadow, v);
+			}
+		}
+	}
+}
+
+impl<'a> VisitableMut<'a> for BoxShadow<'a> {
+	fn accept_mut<V: VisitMut<'a>>(&mut self, v: &mut V) {
+		v.visit_box_shadow(self);
+		if let Self::Shadows(shadows) = self {
+			for (shadow, _) in shadows {
+				VisitableMut::accept_mut(shadow, v);
+			}
+		}
+	}
+}
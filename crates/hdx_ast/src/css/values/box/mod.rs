@@ -16,7 +16,7 @@ use impls::*;
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum MarginTopStyleValue {}
+pub enum MarginTopStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-box-4/#margin-right
 #[value(" <length-percentage> | auto ")]
@@ -26,7 +26,7 @@ pub enum MarginTopStyleValue {}
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum MarginRightStyleValue {}
+pub enum MarginRightStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-box-4/#margin-bottom
 #[value(" <length-percentage> | auto ")]
@@ -36,7 +36,7 @@ pub enum MarginRightStyleValue {}
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum MarginBottomStyleValue {}
+pub enum MarginBottomStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-box-4/#margin-left
 #[value(" <length-percentage> | auto ")]
@@ -46,7 +46,7 @@ pub enum MarginBottomStyleValue {}
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum MarginLeftStyleValue {}
+pub enum MarginLeftStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-box-4/#margin
 #[value(" <'margin-top'>{1,4} ")]
@@ -56,7 +56,7 @@ pub enum MarginLeftStyleValue {}
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MarginStyleValue;
+pub struct MarginStyleValue<'a>;
 
 // https://drafts.csswg.org/css-box-4/#padding-top
 #[value(" <length-percentage [0,∞]> ")]
@@ -66,7 +66,7 @@ pub struct MarginStyleValue;
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingTopStyleValue;
+pub struct PaddingTopStyleValue<'a>;
 
 // https://drafts.csswg.org/css-box-4/#padding-right
 #[value(" <length-percentage [0,∞]> ")]
@@ -76,7 +76,7 @@ pub struct PaddingTopStyleValue;
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingRightStyleValue;
+pub struct PaddingRightStyleValue<'a>;
 
 // https://drafts.csswg.org/css-box-4/#padding-bottom
 #[value(" <length-percentage [0,∞]> ")]
@@ -86,7 +86,7 @@ pub struct PaddingRightStyleValue;
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingBottomStyleValue;
+pub struct PaddingBottomStyleValue<'a>;
 
 // https://drafts.csswg.org/css-box-4/#padding-left
 #[value(" <length-percentage [0,∞]> ")]
@@ -96,7 +96,7 @@ pub struct PaddingBottomStyleValue;
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingLeftStyleValue;
+pub struct PaddingLeftStyleValue<'a>;
 
 // https://drafts.csswg.org/css-box-4/#padding
 #[value(" <'padding-top'>{1,4} ")]
@@ -106,7 +106,7 @@ pub struct PaddingLeftStyleValue;
 #[percentages("refer to logical width of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingStyleValue;
+pub struct PaddingStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-box-4/#margin-trim
 // #[value(" none | [ block || inline ] | [ block-start || inline-start || block-end || inline-end ] ")]
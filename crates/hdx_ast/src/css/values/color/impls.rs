@@ -8,7 +8,7 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(ColorStyleValue, 160);
+		assert_size!(ColorStyleValue, 176);
 		assert_size!(OpacityStyleValue, 16);
 	}
 
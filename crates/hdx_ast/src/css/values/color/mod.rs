@@ -16,7 +16,7 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ColorStyleValue;
+pub struct ColorStyleValue<'a>;
 
 // https://drafts.csswg.org/css-color-6/#opacity
 #[value(" <opacity-value> ")]
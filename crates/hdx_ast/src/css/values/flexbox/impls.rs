@@ -14,7 +14,7 @@ mod tests {
 		// assert_size!(FlexStyleValue, 1);
 		assert_size!(FlexGrowStyleValue, 12);
 		assert_size!(FlexShrinkStyleValue, 12);
-		assert_size!(FlexBasisStyleValue, 44);
+		assert_size!(FlexBasisStyleValue, 48);
 		assert_size!(JustifyContentStyleValue, 16);
 		assert_size!(AlignItemsStyleValue, 16);
 		assert_size!(AlignSelfStyleValue, 16);
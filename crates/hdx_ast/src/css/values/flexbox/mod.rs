@@ -76,7 +76,7 @@ pub struct FlexShrinkStyleValue;
 #[percentages("relative to the flex container’s inner main size")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum FlexBasisStyleValue {}
+pub enum FlexBasisStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-flexbox-1/#justify-content
 #[value(" flex-start | flex-end | center | space-between | space-around ")]
@@ -1,4 +1,4 @@
-pub(crate) use crate::css::units::CSSFloat;
+pub(crate) use crate::css::units::CSSNumber;
 pub(crate) use crate::css::values::sizing::WidthStyleValue;
 
 // Re-expose stylevalues for shorthands
@@ -1,2 +1,117 @@
 pub(crate) use crate::traits::StyleValue;
 pub(crate) use hdx_proc_macro::*;
+
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(FontFamilyStyleValue, 32);
+		assert_size!(FontWeightStyleValue, 16);
+		assert_size!(FontStyleStyleValue, 24);
+		assert_size!(FontSizeStyleValue, 24);
+		assert_size!(FontStyleValue, 160);
+		assert_size!(FontVariantLigaturesStyleValue, 64);
+		assert_size!(FontVariantNumericStyleValue, 80);
+		assert_size!(FontVariantAlternatesStyleValue, 32);
+		assert_size!(FontVariantEastAsianStyleValue, 48);
+		assert_size!(FontVariantStyleValue, 272);
+		assert_size!(FontFeatureSettingsStyleValue, 32);
+		assert_size!(FontVariationSettingsStyleValue, 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(FontFamilyStyleValue, "serif");
+		assert_parse!(FontFamilyStyleValue, "Gill Sans Extrabold,sans-serif");
+		assert_parse!(FontFamilyStyleValue, "\"Goudy Bookletter 1911\",'serif',serif");
+		assert_parse!(FontFamilyStyleValue, "Times serif,monospace");
+		assert_parse!(FontWeightStyleValue, "normal");
+		assert_parse!(FontWeightStyleValue, "bold");
+		assert_parse!(FontWeightStyleValue, "bolder");
+		assert_parse!(FontWeightStyleValue, "650");
+		assert_parse!(FontStyleStyleValue, "italic");
+		assert_parse!(FontStyleStyleValue, "oblique");
+		assert_parse!(FontStyleStyleValue, "oblique 14deg");
+		assert_parse!(FontStyleStyleValue, "oblique -0.25turn");
+		assert_parse!(FontSizeStyleValue, "medium");
+		assert_parse!(FontSizeStyleValue, "xxx-large");
+		assert_parse!(FontSizeStyleValue, "smaller");
+		assert_parse!(FontSizeStyleValue, "1.2em");
+		assert_parse!(FontSizeStyleValue, "80%");
+		assert_parse!(FontSizeStyleValue, "math");
+		assert_parse!(FontStyleValue, "12px Arial");
+		assert_parse!(FontStyleValue, "menu");
+		assert_parse!(FontStyleValue, "bold italic large Palatino,serif", "italic bold large Palatino,serif");
+		assert_parse!(FontStyleValue, "italic small-caps bold condensed 16px/2 cursive");
+		assert_parse!(FontStyleValue, "normal small-caps 120%/120% fantasy");
+		assert_parse!(
+			FontStyleValue,
+			"normal normal 700 1em/1.5 \"Helvetica Neue\",sans-serif",
+			"normal normal 700 1em/1.5\"Helvetica Neue\",sans-serif"
+		);
+		assert_parse!(
+			FontStyleValue,
+			"oblique 10deg 300 x-small/normal 'Font Awesome'",
+			"oblique 10deg 300 x-small/normal'Font Awesome'"
+		);
+		assert_parse!(FontVariantLigaturesStyleValue, "none");
+		assert_parse!(FontVariantLigaturesStyleValue, "common-ligatures");
+		assert_parse!(
+			FontVariantLigaturesStyleValue,
+			"no-contextual historical-ligatures",
+			"historical-ligatures no-contextual"
+		);
+		assert_parse!(FontVariantNumericStyleValue, "normal");
+		assert_parse!(FontVariantNumericStyleValue, "slashed-zero tabular-nums", "tabular-nums slashed-zero");
+		assert_parse!(FontVariantNumericStyleValue, "oldstyle-nums proportional-nums diagonal-fractions ordinal");
+		assert_parse!(FontVariantAlternatesStyleValue, "historical-forms");
+		assert_parse!(FontVariantAlternatesStyleValue, "stylistic(alt-a)");
+		assert_parse!(
+			FontVariantAlternatesStyleValue,
+			"styleset(ss01,ss02) swash(flowing)",
+			"styleset(ss01,ss02)swash(flowing)"
+		);
+		assert_parse!(FontVariantEastAsianStyleValue, "ruby");
+		assert_parse!(FontVariantEastAsianStyleValue, "full-width jis78", "jis78 full-width");
+		assert_parse!(FontVariantStyleValue, "normal");
+		assert_parse!(FontVariantStyleValue, "none");
+		assert_parse!(FontVariantStyleValue, "small-caps");
+		assert_parse!(FontVariantStyleValue, "common-ligatures small-caps tabular-nums");
+		assert_parse!(FontVariantStyleValue, "super emoji swash(flowing) ruby", "swash(flowing)ruby super emoji");
+		assert_parse!(FontFeatureSettingsStyleValue, "normal");
+		assert_parse!(FontFeatureSettingsStyleValue, "\"liga\"");
+		assert_parse!(
+			FontFeatureSettingsStyleValue,
+			"\"smcp\" on,\"swsh\" 2,'dlig' off",
+			"\"smcp\"on,\"swsh\"2,'dlig'off"
+		);
+		assert_parse!(FontVariationSettingsStyleValue, "normal");
+		assert_parse!(FontVariationSettingsStyleValue, "\"wght\" 400,\"wdth\" 75.5", "\"wght\"400,\"wdth\"75.5");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(FontFamilyStyleValue, "");
+		assert_parse_error!(FontFamilyStyleValue, "Arial,");
+		assert_parse_error!(FontWeightStyleValue, "0");
+		assert_parse_error!(FontWeightStyleValue, "1001");
+		assert_parse_error!(FontStyleStyleValue, "oblique 91deg");
+		assert_parse_error!(FontSizeStyleValue, "-1px");
+		assert_parse_error!(FontStyleValue, "bold");
+		assert_parse_error!(FontStyleValue, "12px");
+		assert_parse_error!(FontStyleValue, "bold bold 12px serif");
+		assert_parse_error!(FontStyleValue, "normal normal normal normal normal 12px serif");
+		assert_parse_error!(FontVariantLigaturesStyleValue, "common-ligatures no-common-ligatures");
+		assert_parse_error!(FontVariantNumericStyleValue, "none");
+		assert_parse_error!(FontVariantAlternatesStyleValue, "stylistic(a,b)");
+		assert_parse_error!(FontVariantAlternatesStyleValue, "swash(a) swash(b)");
+		assert_parse_error!(FontVariantStyleValue, "small-caps titling-caps");
+		assert_parse_error!(FontFeatureSettingsStyleValue, "\"liga\" -1");
+		assert_parse_error!(FontFeatureSettingsStyleValue, "\"ligatures\"");
+		assert_parse_error!(FontFeatureSettingsStyleValue, "liga");
+		assert_parse_error!(FontVariationSettingsStyleValue, "\"wght\"");
+	}
+}
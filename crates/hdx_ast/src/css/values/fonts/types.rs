@@ -1 +1,1041 @@
-pub(crate) use crate::css::units::*;
+use std::mem::discriminant;
+
+use bumpalo::collections::Vec;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{
+	diagnostics, keyword_typedef, Build, CursorSink, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T,
+};
+
+pub use crate::css::{types::FamilyName, units::*};
+
+// Re-expose stylevalues for shorthands
+pub(crate) use super::{
+	FontSizeStyleValue, FontStyleStyleValue, FontVariantCapsStyleValue, FontVariantEmojiStyleValue,
+	FontVariantPositionStyleValue, FontWeightStyleValue,
+};
+pub(crate) use crate::css::values::inline::LineHeightStyleValue;
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(Bold, atom!("bold"));
+	custom_keyword!(HistoricalForms, atom!("historical-forms"));
+	custom_keyword!(Normal, atom!("normal"));
+	custom_keyword!(Oblique, atom!("oblique"));
+	custom_keyword!(Off, atom!("off"));
+	custom_keyword!(On, atom!("on"));
+	custom_keyword!(Ordinal, atom!("ordinal"));
+	custom_keyword!(Ruby, atom!("ruby"));
+	custom_keyword!(SlashedZero, atom!("slashed-zero"));
+}
+
+mod func {
+	use hdx_parser::custom_function;
+	custom_function!(Annotation, atom!("annotation"));
+	custom_function!(CharacterVariant, atom!("character-variant"));
+	custom_function!(Ornaments, atom!("ornaments"));
+	custom_function!(Styleset, atom!("styleset"));
+	custom_function!(Stylistic, atom!("stylistic"));
+	custom_function!(Swash, atom!("swash"));
+}
+
+// https://drafts.csswg.org/css-fonts-4/#generic-font-families
+keyword_typedef!(GenericFamily {
+	Serif: atom!("serif"),
+	SansSerif: atom!("sans-serif"),
+	Cursive: atom!("cursive"),
+	Fantasy: atom!("fantasy"),
+	Monospace: atom!("monospace"),
+	SystemUi: atom!("system-ui"),
+	Math: atom!("math"),
+	Emoji: atom!("emoji"),
+	Fangsong: atom!("fangsong"),
+	UiSerif: atom!("ui-serif"),
+	UiSansSerif: atom!("ui-sans-serif"),
+	UiMonospace: atom!("ui-monospace"),
+	UiRounded: atom!("ui-rounded"),
+});
+
+// https://drafts.csswg.org/css-fonts-4/#font-family-prop
+// <family-name> | <generic-family>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum FontFamily<'a> {
+	Generic(GenericFamily),
+	Named(FamilyName<'a>),
+}
+
+impl FontFamily<'_> {
+	/// Whether this family was written as a `<string>`, which is never treated as a generic family.
+	#[inline]
+	pub fn is_quoted(&self) -> bool {
+		matches!(self, Self::Named(FamilyName::String(_)))
+	}
+}
+
+impl<'a> Peek<'a> for FontFamily<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<FamilyName>()
+	}
+}
+
+impl<'a> Parse<'a> for FontFamily<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		// A generic family keyword only counts when it is the whole name; `serif Text` is a family name.
+		if p.peek::<GenericFamily>() && !<T![Ident]>::is(p, p.peek_n(2)) {
+			return p.parse::<GenericFamily>().map(Self::Generic);
+		}
+		p.parse::<FamilyName>().map(Self::Named)
+	}
+}
+
+impl ToCursors for FontFamily<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Generic(generic) => s.append(generic.into()),
+			Self::Named(name) => ToCursors::to_cursors(name, s),
+		}
+	}
+}
+
+// [ <family-name> | <generic-family> ]#
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontFamilyList<'a>(pub Vec<'a, (FontFamily<'a>, Option<T![,]>)>);
+
+impl<'a> Peek<'a> for FontFamilyList<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<FontFamily>()
+	}
+}
+
+impl<'a> Parse<'a> for FontFamilyList<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut families = Vec::new_in(p.bump());
+		loop {
+			let family = p.parse::<FontFamily>()?;
+			let comma = p.parse_if_peek::<T![,]>()?;
+			let done = comma.is_none();
+			families.push((family, comma));
+			if done {
+				return Ok(Self(families));
+			}
+		}
+	}
+}
+
+impl ToCursors for FontFamilyList<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for (family, comma) in &self.0 {
+			ToCursors::to_cursors(family, s);
+			if let Some(comma) = comma {
+				s.append(comma.into());
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-weight-absolute-values
+// <font-weight-absolute> = [ normal | bold | <number [1,1000]> ]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum FontWeightAbsolute {
+	Normal(kw::Normal),
+	Bold(kw::Bold),
+	Number(CSSNumber),
+}
+
+impl<'a> Peek<'a> for FontWeightAbsolute {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Normal>() || p.peek::<kw::Bold>() || p.peek::<CSSNumber>()
+	}
+}
+
+impl<'a> Parse<'a> for FontWeightAbsolute {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<kw::Normal>()? {
+			return Ok(Self::Normal(keyword));
+		}
+		if let Some(keyword) = p.parse_if_peek::<kw::Bold>()? {
+			return Ok(Self::Bold(keyword));
+		}
+		let number = p.parse::<CSSNumber>()?;
+		let f: f32 = number.into();
+		if !(1.0..=1000.0).contains(&f) {
+			let c: Cursor = number.into();
+			Err(diagnostics::NumberOutOfBounds(f, format!("{:?}", 1.0..=1000.0), c.into()))?
+		}
+		Ok(Self::Number(number))
+	}
+}
+
+impl From<FontWeightAbsolute> for Cursor {
+	fn from(value: FontWeightAbsolute) -> Self {
+		match value {
+			FontWeightAbsolute::Normal(c) => c.into(),
+			FontWeightAbsolute::Bold(c) => c.into(),
+			FontWeightAbsolute::Number(c) => c.into(),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-style-prop
+// oblique <angle [-90deg,90deg]>?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ObliqueAngle {
+	pub oblique: kw::Oblique,
+	pub angle: Option<Angle>,
+}
+
+impl<'a> Peek<'a> for ObliqueAngle {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Oblique>()
+	}
+}
+
+impl<'a> Parse<'a> for ObliqueAngle {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let oblique = p.parse::<kw::Oblique>()?;
+		let angle = p.parse_if_peek::<Angle>()?;
+		if let Some(angle) = angle {
+			let degrees = angle.as_degrees();
+			if !(-90.0..=90.0).contains(&degrees) {
+				let c: Cursor = angle.into();
+				Err(diagnostics::NumberOutOfBounds(degrees, format!("{:?}", -90.0..=90.0), c.into()))?
+			}
+		}
+		Ok(Self { oblique, angle })
+	}
+}
+
+impl ToCursors for ObliqueAngle {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.oblique.into());
+		if let Some(angle) = self.angle {
+			s.append(angle.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#absolute-size-mapping
+// <absolute-size> = [ xx-small | x-small | small | medium | large | x-large | xx-large | xxx-large ]
+keyword_typedef!(AbsoluteSize {
+	XxSmall: atom!("xx-small"),
+	XSmall: atom!("x-small"),
+	Small: atom!("small"),
+	Medium: atom!("medium"),
+	Large: atom!("large"),
+	XLarge: atom!("x-large"),
+	XxLarge: atom!("xx-large"),
+	XxxLarge: atom!("xxx-large"),
+});
+
+// https://drafts.csswg.org/css-fonts-4/#valdef-font-size-relative-size
+// <relative-size> = [ larger | smaller ]
+keyword_typedef!(RelativeSize { Larger: atom!("larger"), Smaller: atom!("smaller") });
+
+// https://drafts.csswg.org/css-fonts-4/#font-prop
+// <font-variant-css2> = [ normal | small-caps ]
+keyword_typedef!(FontVariantCss2 { Normal: atom!("normal"), SmallCaps: atom!("small-caps") });
+
+// <font-width-css3> = [ normal | ultra-condensed | extra-condensed | condensed | semi-condensed | semi-expanded | expanded | extra-expanded | ultra-expanded ]
+keyword_typedef!(FontWidthCss3 {
+	Normal: atom!("normal"),
+	UltraCondensed: atom!("ultra-condensed"),
+	ExtraCondensed: atom!("extra-condensed"),
+	Condensed: atom!("condensed"),
+	SemiCondensed: atom!("semi-condensed"),
+	SemiExpanded: atom!("semi-expanded"),
+	Expanded: atom!("expanded"),
+	ExtraExpanded: atom!("extra-expanded"),
+	UltraExpanded: atom!("ultra-expanded"),
+});
+
+// <system-family-name> = caption | icon | menu | message-box | small-caption | status-bar
+keyword_typedef!(SystemFamilyName {
+	Caption: atom!("caption"),
+	Icon: atom!("icon"),
+	Menu: atom!("menu"),
+	MessageBox: atom!("message-box"),
+	SmallCaption: atom!("small-caption"),
+	StatusBar: atom!("status-bar"),
+});
+
+// https://drafts.csswg.org/css-fonts-4/#font-prop
+// [ [ <'font-style'> || <font-variant-css2> || <'font-weight'> || <font-width-css3> ]? <'font-size'> [ / <'line-height'> ]? <'font-family'># ] | <system-family-name>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum Font<'a> {
+	System(SystemFamilyName),
+	Values {
+		style: Option<FontStyleStyleValue>,
+		variant: Option<FontVariantCss2>,
+		weight: Option<FontWeightStyleValue>,
+		width: Option<FontWidthCss3>,
+		size: FontSizeStyleValue<'a>,
+		line_height: Option<(T![/], LineHeightStyleValue<'a>)>,
+		family: FontFamilyList<'a>,
+	},
+}
+
+impl<'a> Peek<'a> for Font<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![Ident]>() || p.peek::<CSSNumber>() || p.peek::<LengthPercentage>()
+	}
+}
+
+impl<'a> Parse<'a> for Font<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(system) = p.parse_if_peek::<SystemFamilyName>()? {
+			return Ok(Self::System(system));
+		}
+		let mut style = None;
+		let mut variant = None;
+		let mut weight = None;
+		let mut width = None;
+		// `normal` is valid for any of the four leading values, so they are only assigned once the others are known.
+		let mut normals = std::vec::Vec::new();
+		loop {
+			if p.peek::<kw::Normal>() {
+				let c: Cursor = p.parse::<T![Ident]>()?.into();
+				normals.push(c);
+			} else if style.is_none() && p.peek::<T![Ident]>() && p.parse_atom_lower(p.peek_n(1)) == atom!("italic")
+				|| style.is_none() && p.peek::<ObliqueAngle>()
+			{
+				style = Some(p.parse::<FontStyleStyleValue>()?);
+			} else if variant.is_none() && p.peek::<FontVariantCss2>() {
+				variant = Some(p.parse::<FontVariantCss2>()?);
+			} else if weight.is_none()
+				&& (p.peek::<CSSNumber>()
+					|| p.peek::<kw::Bold>()
+					|| p.peek::<T![Ident]>()
+						&& matches!(p.parse_atom_lower(p.peek_n(1)), atom!("bolder") | atom!("lighter")))
+			{
+				weight = Some(p.parse::<FontWeightStyleValue>()?);
+			} else if width.is_none() && p.peek::<FontWidthCss3>() {
+				width = Some(p.parse::<FontWidthCss3>()?);
+			} else {
+				break;
+			}
+		}
+		let unset = [style.is_none(), variant.is_none(), weight.is_none(), width.is_none()];
+		if normals.len() > unset.iter().filter(|unset| **unset).count() {
+			let c = normals[normals.len() - 1];
+			Err(diagnostics::UnexpectedDuplicateIdent(atom!("normal"), c.into()))?
+		}
+		for c in normals {
+			if style.is_none() {
+				style = Some(FontStyleStyleValue::Normal(<T![Ident]>::build(p, c)));
+			} else if variant.is_none() {
+				variant = Some(FontVariantCss2::build(p, c));
+			} else if weight.is_none() {
+				weight =
+					Some(FontWeightStyleValue::FontWeightAbsolute(FontWeightAbsolute::Normal(kw::Normal::build(p, c))));
+			} else {
+				width = Some(FontWidthCss3::build(p, c));
+			}
+		}
+		let size = p.parse::<FontSizeStyleValue>()?;
+		let line_height = if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+			Some((slash, p.parse::<LineHeightStyleValue>()?))
+		} else {
+			None
+		};
+		let family = p.parse::<FontFamilyList>()?;
+		Ok(Self::Values { style, variant, weight, width, size, line_height, family })
+	}
+}
+
+impl ToCursors for Font<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::System(system) => s.append(system.into()),
+			Self::Values { style, variant, weight, width, size, line_height, family } => {
+				if let Some(style) = style {
+					ToCursors::to_cursors(style, s);
+				}
+				if let Some(variant) = variant {
+					s.append(variant.into());
+				}
+				if let Some(weight) = weight {
+					ToCursors::to_cursors(weight, s);
+				}
+				if let Some(width) = width {
+					s.append(width.into());
+				}
+				ToCursors::to_cursors(size, s);
+				if let Some((slash, line_height)) = line_height {
+					s.append(slash.into());
+					ToCursors::to_cursors(line_height, s);
+				}
+				ToCursors::to_cursors(family, s);
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-variant-ligatures-prop
+// <common-lig-values> = [ common-ligatures | no-common-ligatures ]
+keyword_typedef!(CommonLigValues {
+	CommonLigatures: atom!("common-ligatures"),
+	NoCommonLigatures: atom!("no-common-ligatures"),
+});
+
+// <discretionary-lig-values> = [ discretionary-ligatures | no-discretionary-ligatures ]
+keyword_typedef!(DiscretionaryLigValues {
+	DiscretionaryLigatures: atom!("discretionary-ligatures"),
+	NoDiscretionaryLigatures: atom!("no-discretionary-ligatures"),
+});
+
+// <historical-lig-values> = [ historical-ligatures | no-historical-ligatures ]
+keyword_typedef!(HistoricalLigValues {
+	HistoricalLigatures: atom!("historical-ligatures"),
+	NoHistoricalLigatures: atom!("no-historical-ligatures"),
+});
+
+// <contextual-alt-values> = [ contextual | no-contextual ]
+keyword_typedef!(ContextualAltValues { Contextual: atom!("contextual"), NoContextual: atom!("no-contextual") });
+
+// https://drafts.csswg.org/css-fonts-4/#font-variant-numeric-prop
+// <numeric-figure-values> = [ lining-nums | oldstyle-nums ]
+keyword_typedef!(NumericFigureValues { LiningNums: atom!("lining-nums"), OldstyleNums: atom!("oldstyle-nums") });
+
+// <numeric-spacing-values> = [ proportional-nums | tabular-nums ]
+keyword_typedef!(NumericSpacingValues {
+	ProportionalNums: atom!("proportional-nums"),
+	TabularNums: atom!("tabular-nums"),
+});
+
+// <numeric-fraction-values> = [ diagonal-fractions | stacked-fractions ]
+keyword_typedef!(NumericFractionValues {
+	DiagonalFractions: atom!("diagonal-fractions"),
+	StackedFractions: atom!("stacked-fractions"),
+});
+
+// https://drafts.csswg.org/css-fonts-4/#font-variant-east-asian-prop
+// <east-asian-variant-values> = [ jis78 | jis83 | jis90 | jis04 | simplified | traditional ]
+keyword_typedef!(EastAsianVariantValues {
+	Jis78: atom!("jis78"),
+	Jis83: atom!("jis83"),
+	Jis90: atom!("jis90"),
+	Jis04: atom!("jis04"),
+	Simplified: atom!("simplified"),
+	Traditional: atom!("traditional"),
+});
+
+// <east-asian-width-values> = [ full-width | proportional-width ]
+keyword_typedef!(EastAsianWidthValues {
+	FullWidth: atom!("full-width"),
+	ProportionalWidth: atom!("proportional-width"),
+});
+
+// Raises an error at the next token when a `||` group matched nothing.
+fn unexpected<T>(p: &mut Parser<'_>) -> ParserResult<T> {
+	let c: Cursor = p.parse::<T![Any]>()?.into();
+	Err(diagnostics::Unexpected(c.into(), c.into()))?
+}
+
+// [ <common-lig-values> || <discretionary-lig-values> || <historical-lig-values> || <contextual-alt-values> ]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct LigatureValues {
+	pub common: Option<CommonLigValues>,
+	pub discretionary: Option<DiscretionaryLigValues>,
+	pub historical: Option<HistoricalLigValues>,
+	pub contextual: Option<ContextualAltValues>,
+}
+
+impl LigatureValues {
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.common.is_none() && self.discretionary.is_none() && self.historical.is_none() && self.contextual.is_none()
+	}
+
+	fn parse_item(&mut self, p: &mut Parser<'_>) -> ParserResult<bool> {
+		if self.common.is_none() && p.peek::<CommonLigValues>() {
+			self.common = Some(p.parse::<CommonLigValues>()?);
+		} else if self.discretionary.is_none() && p.peek::<DiscretionaryLigValues>() {
+			self.discretionary = Some(p.parse::<DiscretionaryLigValues>()?);
+		} else if self.historical.is_none() && p.peek::<HistoricalLigValues>() {
+			self.historical = Some(p.parse::<HistoricalLigValues>()?);
+		} else if self.contextual.is_none() && p.peek::<ContextualAltValues>() {
+			self.contextual = Some(p.parse::<ContextualAltValues>()?);
+		} else {
+			return Ok(false);
+		}
+		Ok(true)
+	}
+}
+
+impl<'a> Peek<'a> for LigatureValues {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CommonLigValues>()
+			|| p.peek::<DiscretionaryLigValues>()
+			|| p.peek::<HistoricalLigValues>()
+			|| p.peek::<ContextualAltValues>()
+	}
+}
+
+impl<'a> Parse<'a> for LigatureValues {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut values = Self::default();
+		while values.parse_item(p)? {}
+		if values.is_empty() {
+			return unexpected(p);
+		}
+		Ok(values)
+	}
+}
+
+impl ToCursors for LigatureValues {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(common) = self.common {
+			s.append(common.into());
+		}
+		if let Some(discretionary) = self.discretionary {
+			s.append(discretionary.into());
+		}
+		if let Some(historical) = self.historical {
+			s.append(historical.into());
+		}
+		if let Some(contextual) = self.contextual {
+			s.append(contextual.into());
+		}
+	}
+}
+
+// [ <numeric-figure-values> || <numeric-spacing-values> || <numeric-fraction-values> || ordinal || slashed-zero ]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct NumericValues {
+	pub figure: Option<NumericFigureValues>,
+	pub spacing: Option<NumericSpacingValues>,
+	pub fraction: Option<NumericFractionValues>,
+	pub ordinal: Option<kw::Ordinal>,
+	pub slashed_zero: Option<kw::SlashedZero>,
+}
+
+impl NumericValues {
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.figure.is_none()
+			&& self.spacing.is_none()
+			&& self.fraction.is_none()
+			&& self.ordinal.is_none()
+			&& self.slashed_zero.is_none()
+	}
+
+	fn parse_item(&mut self, p: &mut Parser<'_>) -> ParserResult<bool> {
+		if self.figure.is_none() && p.peek::<NumericFigureValues>() {
+			self.figure = Some(p.parse::<NumericFigureValues>()?);
+		} else if self.spacing.is_none() && p.peek::<NumericSpacingValues>() {
+			self.spacing = Some(p.parse::<NumericSpacingValues>()?);
+		} else if self.fraction.is_none() && p.peek::<NumericFractionValues>() {
+			self.fraction = Some(p.parse::<NumericFractionValues>()?);
+		} else if self.ordinal.is_none() && p.peek::<kw::Ordinal>() {
+			self.ordinal = Some(p.parse::<kw::Ordinal>()?);
+		} else if self.slashed_zero.is_none() && p.peek::<kw::SlashedZero>() {
+			self.slashed_zero = Some(p.parse::<kw::SlashedZero>()?);
+		} else {
+			return Ok(false);
+		}
+		Ok(true)
+	}
+}
+
+impl<'a> Peek<'a> for NumericValues {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<NumericFigureValues>()
+			|| p.peek::<NumericSpacingValues>()
+			|| p.peek::<NumericFractionValues>()
+			|| p.peek::<kw::Ordinal>()
+			|| p.peek::<kw::SlashedZero>()
+	}
+}
+
+impl<'a> Parse<'a> for NumericValues {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut values = Self::default();
+		while values.parse_item(p)? {}
+		if values.is_empty() {
+			return unexpected(p);
+		}
+		Ok(values)
+	}
+}
+
+impl ToCursors for NumericValues {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(figure) = self.figure {
+			s.append(figure.into());
+		}
+		if let Some(spacing) = self.spacing {
+			s.append(spacing.into());
+		}
+		if let Some(fraction) = self.fraction {
+			s.append(fraction.into());
+		}
+		if let Some(ordinal) = self.ordinal {
+			s.append(ordinal.into());
+		}
+		if let Some(slashed_zero) = self.slashed_zero {
+			s.append(slashed_zero.into());
+		}
+	}
+}
+
+// [ <east-asian-variant-values> || <east-asian-width-values> || ruby ]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct EastAsianValues {
+	pub variant: Option<EastAsianVariantValues>,
+	pub width: Option<EastAsianWidthValues>,
+	pub ruby: Option<kw::Ruby>,
+}
+
+impl EastAsianValues {
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.variant.is_none() && self.width.is_none() && self.ruby.is_none()
+	}
+
+	fn parse_item(&mut self, p: &mut Parser<'_>) -> ParserResult<bool> {
+		if self.variant.is_none() && p.peek::<EastAsianVariantValues>() {
+			self.variant = Some(p.parse::<EastAsianVariantValues>()?);
+		} else if self.width.is_none() && p.peek::<EastAsianWidthValues>() {
+			self.width = Some(p.parse::<EastAsianWidthValues>()?);
+		} else if self.ruby.is_none() && p.peek::<kw::Ruby>() {
+			self.ruby = Some(p.parse::<kw::Ruby>()?);
+		} else {
+			return Ok(false);
+		}
+		Ok(true)
+	}
+}
+
+impl<'a> Peek<'a> for EastAsianValues {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<EastAsianVariantValues>() || p.peek::<EastAsianWidthValues>() || p.peek::<kw::Ruby>()
+	}
+}
+
+impl<'a> Parse<'a> for EastAsianValues {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut values = Self::default();
+		while values.parse_item(p)? {}
+		if values.is_empty() {
+			return unexpected(p);
+		}
+		Ok(values)
+	}
+}
+
+impl ToCursors for EastAsianValues {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(variant) = self.variant {
+			s.append(variant.into());
+		}
+		if let Some(width) = self.width {
+			s.append(width.into());
+		}
+		if let Some(ruby) = self.ruby {
+			s.append(ruby.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-variant-alternates-prop
+// A function naming values declared in @font-feature-values, such as `swash(<feature-value-name>)` or
+// `styleset(<feature-value-name>#)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FeatureValueNames<'a> {
+	pub function: T![Function],
+	pub names: Vec<'a, (T![Ident], Option<T![,]>)>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> FeatureValueNames<'a> {
+	fn parse_names(p: &mut Parser<'a>, multiple: bool) -> ParserResult<Self> {
+		let function = p.parse::<T![Function]>()?;
+		let mut names = Vec::new_in(p.bump());
+		loop {
+			let name = p.parse::<T![Ident]>()?;
+			let comma = if multiple { p.parse_if_peek::<T![,]>()? } else { None };
+			let done = comma.is_none();
+			names.push((name, comma));
+			if done {
+				break;
+			}
+		}
+		let close = if p.at_end() { None } else { Some(p.parse::<T![')']>()?) };
+		Ok(Self { function, names, close })
+	}
+}
+
+impl ToCursors for FeatureValueNames<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		for (name, comma) in &self.names {
+			s.append(name.into());
+			if let Some(comma) = comma {
+				s.append(comma.into());
+			}
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// stylistic(<feature-value-name>) | historical-forms | styleset(<feature-value-name>#) | character-variant(<feature-value-name>#) | swash(<feature-value-name>) | ornaments(<feature-value-name>) | annotation(<feature-value-name>)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum Alternate<'a> {
+	Stylistic(FeatureValueNames<'a>),
+	HistoricalForms(kw::HistoricalForms),
+	Styleset(FeatureValueNames<'a>),
+	CharacterVariant(FeatureValueNames<'a>),
+	Swash(FeatureValueNames<'a>),
+	Ornaments(FeatureValueNames<'a>),
+	Annotation(FeatureValueNames<'a>),
+}
+
+impl<'a> Peek<'a> for Alternate<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<func::Stylistic>()
+			|| p.peek::<kw::HistoricalForms>()
+			|| p.peek::<func::Styleset>()
+			|| p.peek::<func::CharacterVariant>()
+			|| p.peek::<func::Swash>()
+			|| p.peek::<func::Ornaments>()
+			|| p.peek::<func::Annotation>()
+	}
+}
+
+impl<'a> Parse<'a> for Alternate<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<kw::HistoricalForms>()? {
+			Ok(Self::HistoricalForms(keyword))
+		} else if p.peek::<func::Stylistic>() {
+			FeatureValueNames::parse_names(p, false).map(Self::Stylistic)
+		} else if p.peek::<func::Styleset>() {
+			FeatureValueNames::parse_names(p, true).map(Self::Styleset)
+		} else if p.peek::<func::CharacterVariant>() {
+			FeatureValueNames::parse_names(p, true).map(Self::CharacterVariant)
+		} else if p.peek::<func::Swash>() {
+			FeatureValueNames::parse_names(p, false).map(Self::Swash)
+		} else if p.peek::<func::Ornaments>() {
+			FeatureValueNames::parse_names(p, false).map(Self::Ornaments)
+		} else if p.peek::<func::Annotation>() {
+			FeatureValueNames::parse_names(p, false).map(Self::Annotation)
+		} else {
+			unexpected(p)
+		}
+	}
+}
+
+impl ToCursors for Alternate<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::HistoricalForms(keyword) => s.append(keyword.into()),
+			Self::Stylistic(names)
+			| Self::Styleset(names)
+			| Self::CharacterVariant(names)
+			| Self::Swash(names)
+			| Self::Ornaments(names)
+			| Self::Annotation(names) => ToCursors::to_cursors(names, s),
+		}
+	}
+}
+
+// [ stylistic(<feature-value-name>) || historical-forms || styleset(<feature-value-name>#) || character-variant(<feature-value-name>#) || swash(<feature-value-name>) || ornaments(<feature-value-name>) || annotation(<feature-value-name>) ]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct AlternateValues<'a>(pub Vec<'a, Alternate<'a>>);
+
+impl<'a> AlternateValues<'a> {
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	fn parse_item(&mut self, p: &mut Parser<'a>) -> ParserResult<bool> {
+		if !p.peek::<Alternate>() {
+			return Ok(false);
+		}
+		// Each alternate may only appear once, so a repeat ends the list.
+		let checkpoint = p.checkpoint();
+		let alternate = p.parse::<Alternate>()?;
+		if self.0.iter().any(|seen| discriminant(seen) == discriminant(&alternate)) {
+			p.rewind(checkpoint);
+			return Ok(false);
+		}
+		self.0.push(alternate);
+		Ok(true)
+	}
+}
+
+impl<'a> Peek<'a> for AlternateValues<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<Alternate>()
+	}
+}
+
+impl<'a> Parse<'a> for AlternateValues<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut values = Self(Vec::new_in(p.bump()));
+		while values.parse_item(p)? {}
+		if values.is_empty() {
+			return unexpected(p);
+		}
+		Ok(values)
+	}
+}
+
+impl ToCursors for AlternateValues<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for alternate in &self.0 {
+			ToCursors::to_cursors(alternate, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-variant-prop
+// [ <common-lig-values> || <discretionary-lig-values> || <historical-lig-values> || <contextual-alt-values> ] ||
+// [ small-caps | all-small-caps | petite-caps | all-petite-caps | unicase | titling-caps ] || <alternates> ||
+// [ <numeric-figure-values> || <numeric-spacing-values> || <numeric-fraction-values> || ordinal || slashed-zero ] ||
+// [ <east-asian-variant-values> || <east-asian-width-values> || ruby ] || [ sub | super ] || [ text | emoji | unicode ]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FontVariantValues<'a> {
+	pub ligatures: LigatureValues,
+	pub caps: Option<FontVariantCapsStyleValue>,
+	pub alternates: AlternateValues<'a>,
+	pub numeric: NumericValues,
+	pub east_asian: EastAsianValues,
+	pub position: Option<FontVariantPositionStyleValue>,
+	pub emoji: Option<FontVariantEmojiStyleValue>,
+}
+
+impl FontVariantValues<'_> {
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.ligatures.is_empty()
+			&& self.caps.is_none()
+			&& self.alternates.is_empty()
+			&& self.numeric.is_empty()
+			&& self.east_asian.is_empty()
+			&& self.position.is_none()
+			&& self.emoji.is_none()
+	}
+}
+
+impl<'a> Peek<'a> for FontVariantValues<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![Ident]>() || p.peek::<AlternateValues>()
+	}
+}
+
+impl<'a> Parse<'a> for FontVariantValues<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut values = Self {
+			ligatures: LigatureValues::default(),
+			caps: None,
+			alternates: AlternateValues(Vec::new_in(p.bump())),
+			numeric: NumericValues::default(),
+			east_asian: EastAsianValues::default(),
+			position: None,
+			emoji: None,
+		};
+		loop {
+			if values.ligatures.parse_item(p)?
+				|| values.alternates.parse_item(p)?
+				|| values.numeric.parse_item(p)?
+				|| values.east_asian.parse_item(p)?
+			{
+				continue;
+			}
+			if !p.peek::<T![Ident]>() {
+				break;
+			}
+			match p.parse_atom_lower(p.peek_n(1)) {
+				atom!("small-caps")
+				| atom!("all-small-caps")
+				| atom!("petite-caps")
+				| atom!("all-petite-caps")
+				| atom!("unicase")
+				| atom!("titling-caps")
+					if values.caps.is_none() =>
+				{
+					values.caps = Some(p.parse::<FontVariantCapsStyleValue>()?);
+				}
+				atom!("sub") | atom!("super") if values.position.is_none() => {
+					values.position = Some(p.parse::<FontVariantPositionStyleValue>()?);
+				}
+				atom!("text") | atom!("emoji") | atom!("unicode") if values.emoji.is_none() => {
+					values.emoji = Some(p.parse::<FontVariantEmojiStyleValue>()?);
+				}
+				_ => break,
+			}
+		}
+		if values.is_empty() {
+			return unexpected(p);
+		}
+		Ok(values)
+	}
+}
+
+impl ToCursors for FontVariantValues<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.ligatures, s);
+		if let Some(caps) = &self.caps {
+			ToCursors::to_cursors(caps, s);
+		}
+		ToCursors::to_cursors(&self.alternates, s);
+		ToCursors::to_cursors(&self.numeric, s);
+		ToCursors::to_cursors(&self.east_asian, s);
+		if let Some(position) = &self.position {
+			ToCursors::to_cursors(position, s);
+		}
+		if let Some(emoji) = &self.emoji {
+			ToCursors::to_cursors(emoji, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-feature-settings-prop
+// <opentype-tag> = <string>
+// A <string> of exactly four characters in the U+20–7E range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct OpentypeTag(T![String]);
+
+impl<'a> Peek<'a> for OpentypeTag {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![String]>()
+	}
+}
+
+impl<'a> Parse<'a> for OpentypeTag {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let string = p.parse::<T![String]>()?;
+		let c: Cursor = string.into();
+		let tag = p.parse_str(c);
+		if tag.len() != 4 || !tag.chars().all(|char| (' '..='~').contains(&char)) {
+			Err(diagnostics::InvalidOpentypeTag(c.into()))?
+		}
+		Ok(Self(string))
+	}
+}
+
+impl From<OpentypeTag> for Cursor {
+	fn from(value: OpentypeTag) -> Self {
+		value.0.into()
+	}
+}
+
+// <integer [0,∞]> | on | off
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum FeatureTagState {
+	Integer(CSSInt),
+	On(kw::On),
+	Off(kw::Off),
+}
+
+impl<'a> Peek<'a> for FeatureTagState {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CSSInt>() || p.peek::<kw::On>() || p.peek::<kw::Off>()
+	}
+}
+
+impl<'a> Parse<'a> for FeatureTagState {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<kw::On>()? {
+			return Ok(Self::On(keyword));
+		}
+		if let Some(keyword) = p.parse_if_peek::<kw::Off>()? {
+			return Ok(Self::Off(keyword));
+		}
+		let int = p.parse::<CSSInt>()?;
+		let i: i32 = int.into();
+		if i < 0 {
+			let c: Cursor = int.into();
+			Err(diagnostics::NumberNotNegative(i as f32, c.into()))?
+		}
+		Ok(Self::Integer(int))
+	}
+}
+
+impl From<FeatureTagState> for Cursor {
+	fn from(value: FeatureTagState) -> Self {
+		match value {
+			FeatureTagState::Integer(c) => c.into(),
+			FeatureTagState::On(c) => c.into(),
+			FeatureTagState::Off(c) => c.into(),
+		}
+	}
+}
+
+// <feature-tag-value> = <opentype-tag> [ <integer [0,∞]> | on | off ]?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct FeatureTagValue {
+	pub tag: OpentypeTag,
+	pub state: Option<FeatureTagState>,
+}
+
+impl<'a> Peek<'a> for FeatureTagValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<OpentypeTag>()
+	}
+}
+
+impl<'a> Parse<'a> for FeatureTagValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let tag = p.parse::<OpentypeTag>()?;
+		let state = p.parse_if_peek::<FeatureTagState>()?;
+		Ok(Self { tag, state })
+	}
+}
+
+impl ToCursors for FeatureTagValue {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.tag.into());
+		if let Some(state) = self.state {
+			s.append(state.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-fonts-4/#font-variation-settings-def
+// <opentype-tag> <number>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct VariationValue {
+	pub tag: OpentypeTag,
+	pub value: CSSNumber,
+}
+
+impl<'a> Peek<'a> for VariationValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<OpentypeTag>()
+	}
+}
+
+impl<'a> Parse<'a> for VariationValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let tag = p.parse::<OpentypeTag>()?;
+		let value = p.parse::<CSSNumber>()?;
+		Ok(Self { tag, value })
+	}
+}
+
+impl ToCursors for VariationValue {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.tag.into());
+		s.append(self.value.into());
+	}
+}
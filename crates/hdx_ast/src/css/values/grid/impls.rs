@@ -1,2 +1,142 @@
 pub(crate) use crate::traits::StyleValue;
 pub(crate) use hdx_proc_macro::*;
+
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(GridTemplateColumnsStyleValue, 56);
+		assert_size!(GridTemplateRowsStyleValue, 56);
+		assert_size!(GridTemplateAreasStyleValue, 32);
+		assert_size!(GridTemplateStyleValue, 128);
+		assert_size!(GridAutoColumnsStyleValue, 32);
+		assert_size!(GridAutoRowsStyleValue, 32);
+		assert_size!(GridAutoFlowStyleValue, 48);
+		assert_size!(GridStyleValue, 136);
+		assert_size!(GridRowStartStyleValue, 44);
+		assert_size!(GridColumnStartStyleValue, 44);
+		assert_size!(GridRowEndStyleValue, 44);
+		assert_size!(GridColumnEndStyleValue, 44);
+		assert_size!(GridRowStyleValue, 100);
+		assert_size!(GridColumnStyleValue, 100);
+		assert_size!(GridAreaStyleValue, 212);
+		assert_size!(MasonryDirectionStyleValue, 16);
+		assert_size!(MasonryFillStyleValue, 16);
+		assert_size!(MasonryFlowStyleValue, 32);
+		assert_size!(MasonryStyleValue, 104);
+		assert_size!(MasonryTemplateTracksStyleValue, 56);
+		assert_size!(MasonryTemplateAreasStyleValue, 16);
+		assert_size!(MasonryAutoTracksStyleValue, 32);
+		assert_size!(MasonrySlackStyleValue, 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(GridTemplateColumnsStyleValue, "none");
+		assert_parse!(GridTemplateColumnsStyleValue, "1fr 2fr");
+		assert_parse!(
+			GridTemplateColumnsStyleValue,
+			"[full-start] minmax(1em,1fr) [main-start] minmax(0,40em) [main-end] minmax(1em,1fr) [full-end]",
+			"[full-start]minmax(1em,1fr)[main-start]minmax(0,40em)[main-end]minmax(1em,1fr)[full-end]"
+		);
+		assert_parse!(GridTemplateColumnsStyleValue, "repeat(3,1fr)");
+		assert_parse!(GridTemplateColumnsStyleValue, "repeat(auto-fill,minmax(200px,1fr))");
+		assert_parse!(
+			GridTemplateColumnsStyleValue,
+			"[a] 100px repeat(auto-fit,[b] 50px [c]) 20%",
+			"[a]100px repeat(auto-fit,[b]50px[c])20%"
+		);
+		assert_parse!(
+			GridTemplateColumnsStyleValue,
+			"fit-content(40%) auto min-content max-content",
+			"fit-content(40%)auto min-content max-content"
+		);
+		assert_parse!(GridTemplateColumnsStyleValue, "subgrid");
+		assert_parse!(
+			GridTemplateColumnsStyleValue,
+			"subgrid [a] repeat(auto-fill,[b] [c])",
+			"subgrid[a]repeat(auto-fill,[b][c])"
+		);
+		assert_parse!(GridTemplateRowsStyleValue, "auto 1fr auto");
+		assert_parse!(GridTemplateRowsStyleValue, "masonry");
+		assert_parse!(GridTemplateColumnsStyleValue, "masonry");
+		assert_parse!(GridTemplateStyleValue, "masonry/repeat(3,1fr)");
+		assert_parse!(GridTemplateAreasStyleValue, "none");
+		assert_parse!(GridTemplateAreasStyleValue, "\"a a b\" \"a a b\" \". c c\"", "\"a a b\"\"a a b\"\". c c\"");
+		assert_parse!(
+			GridTemplateAreasStyleValue,
+			"\"head head\" \"nav  main\" \"... main\"",
+			"\"head head\"\"nav  main\"\"... main\""
+		);
+		assert_parse!(GridTemplateStyleValue, "none");
+		assert_parse!(GridTemplateStyleValue, "auto 1fr/auto 1fr auto");
+		assert_parse!(
+			GridTemplateStyleValue,
+			"[header-top] \"a a a\" [header-bottom] [main-top] \"b b b\" 1fr [main-bottom]/auto 1fr auto",
+			"[header-top]\"a a a\"[header-bottom][main-top]\"b b b\"1fr[main-bottom]/auto 1fr auto"
+		);
+		assert_parse!(GridAutoColumnsStyleValue, "auto");
+		assert_parse!(GridAutoColumnsStyleValue, "min-content minmax(100px,auto)");
+		assert_parse!(GridAutoFlowStyleValue, "row");
+		assert_parse!(GridAutoFlowStyleValue, "column dense");
+		assert_parse!(GridAutoFlowStyleValue, "dense row-reverse wrap-reverse", "row-reverse dense wrap-reverse");
+		assert_parse!(GridStyleValue, "none");
+		assert_parse!(GridStyleValue, "auto-flow/1fr 1fr 1fr");
+		assert_parse!(GridStyleValue, "auto-flow dense 40px 40px/1fr 1fr");
+		assert_parse!(GridStyleValue, "100px 300px/auto-flow 200px");
+		assert_parse!(GridStyleValue, "\"a b\" \"c d\"/100px 1fr", "\"a b\"\"c d\"/100px 1fr");
+		assert_parse!(GridRowStartStyleValue, "auto");
+		assert_parse!(GridRowStartStyleValue, "main");
+		assert_parse!(GridRowStartStyleValue, "-1");
+		assert_parse!(GridRowStartStyleValue, "2 main");
+		assert_parse!(GridRowStartStyleValue, "main 2", "2 main");
+		assert_parse!(GridColumnEndStyleValue, "span 2");
+		assert_parse!(GridColumnEndStyleValue, "span main 3", "span 3 main");
+		assert_parse!(GridRowStyleValue, "1/3");
+		assert_parse!(GridColumnStyleValue, "span 2/7");
+		assert_parse!(GridAreaStyleValue, "main");
+		assert_parse!(GridAreaStyleValue, "1/2/3/4");
+		assert_parse!(MasonryStyleValue, "column");
+		assert_parse!(MasonryStyleValue, "\"a b c\" repeat(3,1fr) row reverse", "\"a b c\"repeat(3,1fr)row reverse");
+		assert_parse!(MasonryTemplateTracksStyleValue, "repeat(auto-areas,auto)");
+		assert_parse!(
+			MasonryTemplateTracksStyleValue,
+			"100px repeat(auto-areas,1fr) 100px",
+			"100px repeat(auto-areas,1fr)100px"
+		);
+		assert_parse!(MasonryTemplateAreasStyleValue, "\"a b c\"");
+		assert_parse!(MasonryAutoTracksStyleValue, "auto");
+		assert_parse!(MasonrySlackStyleValue, "1em");
+		assert_parse!(MasonrySlackStyleValue, "infinite");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(GridTemplateColumnsStyleValue, "[a] [b] 1fr");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "[span] 1fr");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "-1px");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "minmax(1fr,100px)");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "repeat(0,1fr)");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "repeat(2,repeat(2,1fr))");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "repeat(auto-fill,1fr)");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "repeat(auto-fill,100px) 1fr");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "repeat(auto-fill,100px) repeat(auto-fit,100px)");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "repeat(auto-areas,100px)");
+		assert_parse_error!(GridTemplateColumnsStyleValue, "subgrid repeat(auto-fit,[a])");
+		assert_parse_error!(GridTemplateAreasStyleValue, "\"a b\" \"a\"");
+		assert_parse_error!(GridTemplateAreasStyleValue, "\"a b a\"");
+		assert_parse_error!(GridTemplateAreasStyleValue, "\"a b\" \"b a\"");
+		assert_parse_error!(GridTemplateAreasStyleValue, "\"a # b\"");
+		assert_parse_error!(GridTemplateAreasStyleValue, "\"\"");
+		assert_parse_error!(GridTemplateStyleValue, "\"a\" \"a b\"");
+		assert_parse_error!(GridTemplateStyleValue, "\"a\"/repeat(2,1fr)");
+		assert_parse_error!(GridRowStartStyleValue, "0");
+		assert_parse_error!(GridRowStartStyleValue, "span");
+		assert_parse_error!(GridRowStartStyleValue, "span -1");
+		assert_parse_error!(GridAreaStyleValue, "1/2/3/4/5");
+		assert_parse_error!(GridAutoFlowStyleValue, "row column");
+	}
+}
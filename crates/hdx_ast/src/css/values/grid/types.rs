@@ -1,2 +1,1297 @@
+use bumpalo::collections::Vec;
+use hdx_atom::{atom, Atom};
+use hdx_lexer::{Cursor, Span};
+use hdx_parser::{diagnostics, keyword_typedef, CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors, T};
+
+pub use crate::css::units::*;
+
 // Re-expose stylevalues for shorthands
-pub(crate) use super::{MasonryDirectionStyleValue, MasonryFillStyleValue};
+pub(crate) use super::{
+	GridAutoColumnsStyleValue, GridAutoRowsStyleValue, GridTemplateColumnsStyleValue, GridTemplateRowsStyleValue,
+	MasonryDirectionStyleValue, MasonryFillStyleValue, MasonryTemplateAreasStyleValue, MasonryTemplateTracksStyleValue,
+};
+
+mod kw {
+	use hdx_parser::custom_keyword;
+	custom_keyword!(Auto, atom!("auto"));
+	custom_keyword!(AutoAreas, atom!("auto-areas"));
+	custom_keyword!(AutoFill, atom!("auto-fill"));
+	custom_keyword!(AutoFit, atom!("auto-fit"));
+	custom_keyword!(AutoFlow, atom!("auto-flow"));
+	custom_keyword!(Dense, atom!("dense"));
+	custom_keyword!(MaxContent, atom!("max-content"));
+	custom_keyword!(MinContent, atom!("min-content"));
+	custom_keyword!(None, atom!("none"));
+	custom_keyword!(Normal, atom!("normal"));
+	custom_keyword!(Reverse, atom!("reverse"));
+	custom_keyword!(Span, atom!("span"));
+	custom_keyword!(Subgrid, atom!("subgrid"));
+	custom_keyword!(WrapReverse, atom!("wrap-reverse"));
+}
+
+mod func {
+	use hdx_parser::custom_function;
+	custom_function!(FitContent, atom!("fit-content"));
+	custom_function!(Minmax, atom!("minmax"));
+	custom_function!(Repeat, atom!("repeat"));
+}
+
+// Parses a <length-percentage [0,∞]> or a <flex [0,∞]>.
+fn parse_non_negative<'a, T>(p: &mut Parser<'a>) -> ParserResult<T>
+where
+	T: Parse<'a> + Copy + Into<f32> + Into<Cursor>,
+{
+	let value = p.parse::<T>()?;
+	let f: f32 = value.into();
+	if f < 0.0 {
+		let c: Cursor = value.into();
+		Err(diagnostics::NumberNotNegative(f, c.into()))?
+	}
+	Ok(value)
+}
+
+// Closes a function which must not be followed by anything other than its `)`.
+fn parse_close(p: &mut Parser<'_>) -> ParserResult<Option<T![')']>> {
+	if p.at_end() {
+		Ok(None)
+	} else {
+		Ok(Some(p.parse::<T![')']>()?))
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-line-names
+// <line-names> = '[' <custom-ident>* ']'
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct LineNames<'a> {
+	pub open: T!['['],
+	pub names: Vec<'a, T![Ident]>,
+	pub close: T![']'],
+}
+
+impl<'a> Peek<'a> for LineNames<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T!['[']>()
+	}
+}
+
+impl<'a> Parse<'a> for LineNames<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let open = p.parse::<T!['[']>()?;
+		let mut names = Vec::new_in(p.bump());
+		while let Some(name) = p.parse_if_peek::<T![Ident]>()? {
+			let c: Cursor = name.into();
+			let atom = p.parse_atom_lower(c);
+			if matches!(atom, atom!("span") | atom!("auto")) {
+				Err(diagnostics::UnexpectedIdent(atom, c.into()))?
+			}
+			names.push(name);
+		}
+		let close = p.parse::<T![']']>()?;
+		Ok(Self { open, names, close })
+	}
+}
+
+impl ToCursors for LineNames<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.open.into());
+		for name in &self.names {
+			s.append(name.into());
+		}
+		s.append(self.close.into());
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-track-breadth
+// <track-breadth> = <length-percentage [0,∞]> | <flex [0,∞]> | min-content | max-content | auto
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum TrackBreadth<'a> {
+	LengthPercentage(LengthPercentage<'a>),
+	Flex(Flex),
+	MinContent(kw::MinContent),
+	MaxContent(kw::MaxContent),
+	Auto(kw::Auto),
+}
+
+impl TrackBreadth<'_> {
+	// <fixed-breadth> = <length-percentage [0,∞]>
+	#[inline]
+	pub fn is_fixed(&self) -> bool {
+		matches!(self, Self::LengthPercentage(_))
+	}
+
+	#[inline]
+	pub fn is_flexible(&self) -> bool {
+		matches!(self, Self::Flex(_))
+	}
+}
+
+impl<'a> Peek<'a> for TrackBreadth<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<LengthPercentage>()
+			|| p.peek::<Flex>()
+			|| p.peek::<kw::MinContent>()
+			|| p.peek::<kw::MaxContent>()
+			|| p.peek::<kw::Auto>()
+	}
+}
+
+impl<'a> Parse<'a> for TrackBreadth<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<Flex>() {
+			parse_non_negative::<Flex>(p).map(Self::Flex)
+		} else if let Some(keyword) = p.parse_if_peek::<kw::MinContent>()? {
+			Ok(Self::MinContent(keyword))
+		} else if let Some(keyword) = p.parse_if_peek::<kw::MaxContent>()? {
+			Ok(Self::MaxContent(keyword))
+		} else if let Some(keyword) = p.parse_if_peek::<kw::Auto>()? {
+			Ok(Self::Auto(keyword))
+		} else {
+			LengthPercentage::parse_non_negative(p).map(Self::LengthPercentage)
+		}
+	}
+}
+
+impl ToCursors for TrackBreadth<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::LengthPercentage(c) => ToCursors::to_cursors(c, s),
+			Self::Flex(c) => s.append((*c).into()),
+			Self::MinContent(c) => s.append(c.into()),
+			Self::MaxContent(c) => s.append(c.into()),
+			Self::Auto(c) => s.append(c.into()),
+		}
+	}
+}
+
+impl From<&TrackBreadth<'_>> for Span {
+	fn from(value: &TrackBreadth<'_>) -> Self {
+		match value {
+			TrackBreadth::LengthPercentage(c) => c.into(),
+			TrackBreadth::Flex(c) => Cursor::from(*c).span(),
+			TrackBreadth::MinContent(c) => Cursor::from(c).span(),
+			TrackBreadth::MaxContent(c) => Cursor::from(c).span(),
+			TrackBreadth::Auto(c) => Cursor::from(c).span(),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-track-size
+// <track-size> = <track-breadth> | minmax( <inflexible-breadth> , <track-breadth> ) | fit-content( <length-percentage [0,∞]> )
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum TrackSize<'a> {
+	Breadth(TrackBreadth<'a>),
+	Minmax(func::Minmax, TrackBreadth<'a>, T![,], TrackBreadth<'a>, Option<T![')']>),
+	FitContent(func::FitContent, LengthPercentage<'a>, Option<T![')']>),
+}
+
+impl TrackSize<'_> {
+	// <fixed-size> = <fixed-breadth> | minmax( <fixed-breadth> , <track-breadth> ) | minmax( <inflexible-breadth> , <fixed-breadth> )
+	pub fn is_fixed(&self) -> bool {
+		match self {
+			Self::Breadth(breadth) => breadth.is_fixed(),
+			Self::Minmax(_, min, _, max, _) => min.is_fixed() || max.is_fixed(),
+			Self::FitContent(..) => false,
+		}
+	}
+}
+
+impl<'a> Peek<'a> for TrackSize<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<TrackBreadth>() || p.peek::<func::Minmax>() || p.peek::<func::FitContent>()
+	}
+}
+
+impl<'a> Parse<'a> for TrackSize<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(function) = p.parse_if_peek::<func::Minmax>()? {
+			let min = p.parse::<TrackBreadth>()?;
+			// <inflexible-breadth> = <length-percentage [0,∞]> | min-content | max-content | auto
+			if let TrackBreadth::Flex(flex) = min {
+				let c: Cursor = flex.into();
+				Err(diagnostics::UnexpectedDimension(atom!("fr"), c.into()))?
+			}
+			let comma = p.parse::<T![,]>()?;
+			let max = p.parse::<TrackBreadth>()?;
+			Ok(Self::Minmax(function, min, comma, max, parse_close(p)?))
+		} else if let Some(function) = p.parse_if_peek::<func::FitContent>()? {
+			let length = LengthPercentage::parse_non_negative(p)?;
+			Ok(Self::FitContent(function, length, parse_close(p)?))
+		} else {
+			p.parse::<TrackBreadth>().map(Self::Breadth)
+		}
+	}
+}
+
+impl ToCursors for TrackSize<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Breadth(breadth) => ToCursors::to_cursors(breadth, s),
+			Self::Minmax(function, min, comma, max, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(min, s);
+				s.append(comma.into());
+				ToCursors::to_cursors(max, s);
+				if let Some(close) = close {
+					s.append(close.into());
+				}
+			}
+			Self::FitContent(function, length, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(length, s);
+				if let Some(close) = close {
+					s.append(close.into());
+				}
+			}
+		}
+	}
+}
+
+impl From<&TrackSize<'_>> for Span {
+	fn from(value: &TrackSize<'_>) -> Self {
+		match value {
+			TrackSize::Breadth(breadth) => breadth.into(),
+			TrackSize::Minmax(function, _, _, max, close) => {
+				Cursor::from(function).span() + close.map(|close| Cursor::from(close).span()).unwrap_or(max.into())
+			}
+			TrackSize::FitContent(function, length, close) => {
+				Cursor::from(function).span() + close.map(|close| Cursor::from(close).span()).unwrap_or(length.into())
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-track-repeat
+// [ <integer [1,∞]> | auto-fill | auto-fit ], or auto-areas within a masonry track list.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum RepeatCount {
+	Count(CSSInt),
+	AutoFill(kw::AutoFill),
+	AutoFit(kw::AutoFit),
+	AutoAreas(kw::AutoAreas),
+}
+
+impl RepeatCount {
+	#[inline]
+	pub fn is_auto(&self) -> bool {
+		!matches!(self, Self::Count(_))
+	}
+}
+
+impl<'a> Peek<'a> for RepeatCount {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<CSSInt>() || p.peek::<kw::AutoFill>() || p.peek::<kw::AutoFit>() || p.peek::<kw::AutoAreas>()
+	}
+}
+
+impl<'a> Parse<'a> for RepeatCount {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<kw::AutoFill>()? {
+			Ok(Self::AutoFill(keyword))
+		} else if let Some(keyword) = p.parse_if_peek::<kw::AutoFit>()? {
+			Ok(Self::AutoFit(keyword))
+		} else if let Some(keyword) = p.parse_if_peek::<kw::AutoAreas>()? {
+			Ok(Self::AutoAreas(keyword))
+		} else {
+			let count = p.parse::<CSSInt>()?;
+			let i: i32 = count.into();
+			if i < 1 {
+				let c: Cursor = count.into();
+				Err(diagnostics::NumberTooSmall(1.0, c.into()))?
+			}
+			Ok(Self::Count(count))
+		}
+	}
+}
+
+impl From<RepeatCount> for Cursor {
+	fn from(value: RepeatCount) -> Self {
+		match value {
+			RepeatCount::Count(c) => c.into(),
+			RepeatCount::AutoFill(c) => c.into(),
+			RepeatCount::AutoFit(c) => c.into(),
+			RepeatCount::AutoAreas(c) => c.into(),
+		}
+	}
+}
+
+impl From<&RepeatCount> for Cursor {
+	fn from(value: &RepeatCount) -> Self {
+		(*value).into()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum TrackListItem<'a> {
+	LineNames(LineNames<'a>),
+	Size(TrackSize<'a>),
+	Repeat(TrackRepeat<'a>),
+}
+
+impl ToCursors for TrackListItem<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::LineNames(names) => ToCursors::to_cursors(names, s),
+			Self::Size(size) => ToCursors::to_cursors(size, s),
+			Self::Repeat(repeat) => ToCursors::to_cursors(repeat, s),
+		}
+	}
+}
+
+// [ <line-names>? [ <track-size> | <track-repeat> ] ]+ <line-names>?
+fn parse_track_items<'a>(p: &mut Parser<'a>, allow_repeat: bool) -> ParserResult<Vec<'a, TrackListItem<'a>>> {
+	let mut items = Vec::new_in(p.bump());
+	loop {
+		if p.peek::<LineNames>() {
+			let names = p.parse::<LineNames>()?;
+			if matches!(items.last(), Some(TrackListItem::LineNames(_))) {
+				let c: Cursor = names.open.into();
+				Err(diagnostics::Unexpected(c.into(), c.into()))?
+			}
+			items.push(TrackListItem::LineNames(names));
+		} else if allow_repeat && p.peek::<func::Repeat>() {
+			items.push(TrackListItem::Repeat(p.parse::<TrackRepeat>()?));
+		} else if p.peek::<TrackSize>() {
+			items.push(TrackListItem::Size(p.parse::<TrackSize>()?));
+		} else {
+			break;
+		}
+	}
+	if items.iter().all(|item| matches!(item, TrackListItem::LineNames(_))) {
+		let c: Cursor = p.parse::<T![Any]>()?.into();
+		Err(diagnostics::Unexpected(c.into(), c.into()))?
+	}
+	Ok(items)
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-track-repeat
+// <track-repeat> = repeat( [ <integer [1,∞]> ] , [ <line-names>? <track-size> ]+ <line-names>? )
+// <auto-repeat>  = repeat( [ auto-fill | auto-fit ] , [ <line-names>? <fixed-size> ]+ <line-names>? )
+// https://drafts.csswg.org/css-grid-3/#typedef-masonry-auto-repeat
+// <masonry-auto-repeat> = repeat( auto-areas , [ <line-names>? <track-size> ]+ <line-names>? )
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TrackRepeat<'a> {
+	pub function: func::Repeat,
+	pub count: RepeatCount,
+	pub comma: T![,],
+	pub tracks: Vec<'a, TrackListItem<'a>>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> TrackRepeat<'a> {
+	pub fn sizes(&self) -> impl Iterator<Item = &TrackSize<'a>> {
+		self.tracks.iter().filter_map(|item| match item {
+			TrackListItem::Size(size) => Some(size),
+			_ => None,
+		})
+	}
+}
+
+impl<'a> Peek<'a> for TrackRepeat<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<func::Repeat>()
+	}
+}
+
+impl<'a> Parse<'a> for TrackRepeat<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let function = p.parse::<func::Repeat>()?;
+		let count = p.parse::<RepeatCount>()?;
+		let comma = p.parse::<T![,]>()?;
+		let tracks = parse_track_items(p, false)?;
+		let repeat = Self { function, count, comma, tracks, close: parse_close(p)? };
+		if matches!(repeat.count, RepeatCount::AutoFill(_) | RepeatCount::AutoFit(_)) {
+			if let Some(size) = repeat.sizes().find(|size| !size.is_fixed()) {
+				Err(diagnostics::AutoRepeatWithIntrinsicSize(size.into()))?
+			}
+		}
+		Ok(repeat)
+	}
+}
+
+impl ToCursors for TrackRepeat<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		s.append(self.count.into());
+		s.append(self.comma.into());
+		for item in &self.tracks {
+			ToCursors::to_cursors(item, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-track-list
+// <track-list>      = [ <line-names>? [ <track-size> | <track-repeat> ] ]+ <line-names>?
+// <auto-track-list> = [ <line-names>? [ <fixed-size> | <fixed-repeat> ] ]* <line-names>? <auto-repeat>
+//                     [ <line-names>? [ <fixed-size> | <fixed-repeat> ] ]* <line-names>?
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TrackList<'a>(pub Vec<'a, TrackListItem<'a>>);
+
+impl<'a> TrackList<'a> {
+	/// The automatic repeat() of an `<auto-track-list>`, if there is one.
+	pub fn auto_repeat(&self) -> Option<&TrackRepeat<'a>> {
+		self.0.iter().find_map(|item| match item {
+			TrackListItem::Repeat(repeat) if repeat.count.is_auto() => Some(repeat),
+			_ => None,
+		})
+	}
+
+	// Masonry track lists may use repeat(auto-areas, ...) in place of repeat(auto-fill, ...) or repeat(auto-fit, ...).
+	fn parse_tracks(p: &mut Parser<'a>, masonry: bool) -> ParserResult<Self> {
+		let list = Self(parse_track_items(p, true)?);
+		let mut auto_repeats = list.0.iter().filter_map(|item| match item {
+			TrackListItem::Repeat(repeat) if repeat.count.is_auto() => Some(repeat),
+			_ => None,
+		});
+		let Some(auto_repeat) = auto_repeats.next() else {
+			return Ok(list);
+		};
+		if let Some(repeat) = auto_repeats.next() {
+			let c: Cursor = repeat.function.into();
+			Err(diagnostics::TooManyAutoRepeats(c.into()))?
+		}
+		if masonry != matches!(auto_repeat.count, RepeatCount::AutoAreas(_)) {
+			let c: Cursor = auto_repeat.count.into();
+			Err(diagnostics::UnexpectedIdent(p.parse_atom_lower(c), c.into()))?
+		}
+		// Every other track alongside an automatic repeat() must have a fixed size.
+		for item in &list.0 {
+			let size = match item {
+				TrackListItem::Size(size) if !size.is_fixed() => Some(size),
+				TrackListItem::Repeat(repeat) if !repeat.count.is_auto() => {
+					repeat.sizes().find(|size| !size.is_fixed())
+				}
+				_ => None,
+			};
+			if let Some(size) = size {
+				Err(diagnostics::AutoRepeatWithIntrinsicSize(size.into()))?
+			}
+		}
+		Ok(list)
+	}
+}
+
+impl<'a> Peek<'a> for TrackList<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<LineNames>() || p.peek::<TrackSize>() || p.peek::<func::Repeat>()
+	}
+}
+
+impl<'a> Parse<'a> for TrackList<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		Self::parse_tracks(p, false)
+	}
+}
+
+impl ToCursors for TrackList<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for item in &self.0 {
+			ToCursors::to_cursors(item, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-3/#typedef-masonry-auto-track-list
+// <track-list> | <masonry-auto-track-list>
+// <masonry-auto-track-list> = [ <line-names>? [ <fixed-size> | <fixed-repeat> ] ]* <line-names>? <masonry-auto-repeat>
+//                             [ <line-names>? [ <fixed-size> | <fixed-repeat> ] ]* <line-names>?
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct MasonryTrackList<'a>(pub TrackList<'a>);
+
+impl<'a> Peek<'a> for MasonryTrackList<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<TrackList>()
+	}
+}
+
+impl<'a> Parse<'a> for MasonryTrackList<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		TrackList::parse_tracks(p, true).map(Self)
+	}
+}
+
+impl ToCursors for MasonryTrackList<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.0, s);
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-line-name-list
+// subgrid <line-name-list>?
+// <line-name-list> = [ <line-names> | <name-repeat> ]+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct Subgrid<'a> {
+	pub subgrid: kw::Subgrid,
+	pub names: Vec<'a, LineNameListItem<'a>>,
+}
+
+impl<'a> Peek<'a> for Subgrid<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Subgrid>()
+	}
+}
+
+impl<'a> Parse<'a> for Subgrid<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let subgrid = p.parse::<kw::Subgrid>()?;
+		let mut names = Vec::new_in(p.bump());
+		let mut auto_fill = false;
+		loop {
+			if p.peek::<LineNames>() {
+				names.push(LineNameListItem::LineNames(p.parse::<LineNames>()?));
+			} else if p.peek::<NameRepeat>() {
+				let repeat = p.parse::<NameRepeat>()?;
+				if repeat.count.is_auto() {
+					if auto_fill {
+						let c: Cursor = repeat.function.into();
+						Err(diagnostics::TooManyAutoRepeats(c.into()))?
+					}
+					auto_fill = true;
+				}
+				names.push(LineNameListItem::Repeat(repeat));
+			} else {
+				break;
+			}
+		}
+		Ok(Self { subgrid, names })
+	}
+}
+
+impl ToCursors for Subgrid<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.subgrid.into());
+		for item in &self.names {
+			match item {
+				LineNameListItem::LineNames(names) => ToCursors::to_cursors(names, s),
+				LineNameListItem::Repeat(repeat) => ToCursors::to_cursors(repeat, s),
+			}
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum LineNameListItem<'a> {
+	LineNames(LineNames<'a>),
+	Repeat(NameRepeat<'a>),
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-name-repeat
+// <name-repeat> = repeat( [ <integer [1,∞]> | auto-fill ], <line-names>+)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct NameRepeat<'a> {
+	pub function: func::Repeat,
+	pub count: RepeatCount,
+	pub comma: T![,],
+	pub names: Vec<'a, LineNames<'a>>,
+	pub close: Option<T![')']>,
+}
+
+impl<'a> Peek<'a> for NameRepeat<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<func::Repeat>()
+	}
+}
+
+impl<'a> Parse<'a> for NameRepeat<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let function = p.parse::<func::Repeat>()?;
+		let count = p.parse::<RepeatCount>()?;
+		if matches!(count, RepeatCount::AutoFit(_) | RepeatCount::AutoAreas(_)) {
+			let c: Cursor = count.into();
+			Err(diagnostics::UnexpectedIdent(p.parse_atom_lower(c), c.into()))?
+		}
+		let comma = p.parse::<T![,]>()?;
+		let mut names = Vec::new_in(p.bump());
+		names.push(p.parse::<LineNames>()?);
+		while let Some(line_names) = p.parse_if_peek::<LineNames>()? {
+			names.push(line_names);
+		}
+		Ok(Self { function, count, comma, names, close: parse_close(p)? })
+	}
+}
+
+impl ToCursors for NameRepeat<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.function.into());
+		s.append(self.count.into());
+		s.append(self.comma.into());
+		for names in &self.names {
+			ToCursors::to_cursors(names, s);
+		}
+		if let Some(close) = self.close {
+			s.append(close.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#grid-template-areas-property
+// Tokenizes an area string into named cell tokens and null cell tokens (None), or returns None if the string contains
+// a trash token.
+fn area_cells(str: &str) -> Option<std::vec::Vec<Option<&str>>> {
+	let is_name = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_') || !c.is_ascii();
+	let mut cells = std::vec::Vec::new();
+	let mut chars = str.char_indices().peekable();
+	while let Some((start, char)) = chars.next() {
+		if matches!(char, ' ' | '\t' | '\n' | '\r' | '\x0C') {
+			continue;
+		}
+		if char == '.' {
+			while chars.next_if(|(_, c)| *c == '.').is_some() {}
+			cells.push(None);
+		} else if is_name(char) {
+			let mut end = start + char.len_utf8();
+			while let Some((i, c)) = chars.next_if(|(_, c)| is_name(*c)) {
+				end = i + c.len_utf8();
+			}
+			cells.push(Some(&str[start..end]));
+		} else {
+			return None;
+		}
+	}
+	Some(cells)
+}
+
+// Every row must have the same number of columns, and each named area must form a single filled-in rectangle.
+fn check_areas(p: &Parser<'_>, strings: impl Iterator<Item = Cursor>) -> ParserResult<()> {
+	let mut columns = None;
+	// Each area's name, bounds as [top, bottom, left, right], count of cells, and the string it was first seen in.
+	let mut areas: std::vec::Vec<(&str, [usize; 4], usize, Cursor)> = std::vec::Vec::new();
+	for (row, c) in strings.enumerate() {
+		let Some(cells) = area_cells(p.parse_str(c)).filter(|cells| !cells.is_empty()) else {
+			Err(diagnostics::InvalidGridAreaString(c.into()))?
+		};
+		let expected = *columns.get_or_insert(cells.len());
+		if cells.len() != expected {
+			Err(diagnostics::GridAreaColumnsMismatch(expected, cells.len(), c.into()))?
+		}
+		for (column, name) in cells.into_iter().enumerate() {
+			let Some(name) = name else {
+				continue;
+			};
+			if let Some((_, bounds, count, _)) = areas.iter_mut().find(|(area, ..)| *area == name) {
+				bounds[1] = row;
+				bounds[2] = bounds[2].min(column);
+				bounds[3] = bounds[3].max(column);
+				*count += 1;
+			} else {
+				areas.push((name, [row, row, column, column], 1, c));
+			}
+		}
+	}
+	for (name, [top, bottom, left, right], count, c) in areas {
+		if (bottom - top + 1) * (right - left + 1) != count {
+			Err(diagnostics::GridAreaNotRectangular(Atom::from(name), c.into()))?
+		}
+	}
+	Ok(())
+}
+
+// https://drafts.csswg.org/css-grid-2/#grid-template-areas-property
+// A <string> naming the areas of one row of the grid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TemplateAreaString(pub T![String]);
+
+impl<'a> Peek<'a> for TemplateAreaString {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![String]>()
+	}
+}
+
+impl<'a> Parse<'a> for TemplateAreaString {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let string = p.parse::<T![String]>()?;
+		check_areas(p, [string.into()].into_iter())?;
+		Ok(Self(string))
+	}
+}
+
+impl From<TemplateAreaString> for Cursor {
+	fn from(value: TemplateAreaString) -> Self {
+		value.0.into()
+	}
+}
+
+impl From<&TemplateAreaString> for Cursor {
+	fn from(value: &TemplateAreaString) -> Self {
+		value.0.into()
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#grid-template-areas-property
+// <string>+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TemplateAreas<'a>(pub Vec<'a, TemplateAreaString>);
+
+impl<'a> Peek<'a> for TemplateAreas<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<TemplateAreaString>()
+	}
+}
+
+impl<'a> Parse<'a> for TemplateAreas<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut strings = Vec::new_in(p.bump());
+		strings.push(p.parse::<TemplateAreaString>()?);
+		while let Some(string) = p.parse_if_peek::<TemplateAreaString>()? {
+			strings.push(string);
+		}
+		check_areas(p, strings.iter().map(Cursor::from))?;
+		Ok(Self(strings))
+	}
+}
+
+impl ToCursors for TemplateAreas<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for string in &self.0 {
+			s.append(string.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#grid-template
+// none | [ <'grid-template-rows'> / <'grid-template-columns'> ] |
+// [ <line-names>? <string> <track-size>? <line-names>? ]+ [ / <explicit-track-list> ]?
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum GridTemplate<'a> {
+	None(kw::None),
+	Tracks(GridTemplateRowsStyleValue<'a>, T![/], GridTemplateColumnsStyleValue<'a>),
+	Areas(Vec<'a, GridTemplateAreaRow<'a>>, Option<(T![/], TrackList<'a>)>),
+}
+
+impl<'a> Peek<'a> for GridTemplate<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::None>() || p.peek::<TemplateAreaString>() || p.peek::<TrackList>() || p.peek::<Subgrid>()
+	}
+}
+
+impl<'a> Parse<'a> for GridTemplate<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		// `none` on its own, rather than the rows of `none / <'grid-template-columns'>`.
+		if p.peek::<kw::None>() {
+			let checkpoint = p.checkpoint();
+			let none = p.parse::<kw::None>()?;
+			if !p.peek::<T![/]>() {
+				return Ok(Self::None(none));
+			}
+			p.rewind(checkpoint);
+		}
+		let checkpoint = p.checkpoint();
+		p.parse_if_peek::<LineNames>()?;
+		let is_areas = p.peek::<TemplateAreaString>();
+		p.rewind(checkpoint);
+		if !is_areas {
+			let rows = p.parse::<GridTemplateRowsStyleValue>()?;
+			let slash = p.parse::<T![/]>()?;
+			let columns = p.parse::<GridTemplateColumnsStyleValue>()?;
+			return Ok(Self::Tracks(rows, slash, columns));
+		}
+		let mut rows = Vec::new_in(p.bump());
+		while p.peek::<LineNames>() || p.peek::<TemplateAreaString>() {
+			rows.push(p.parse::<GridTemplateAreaRow>()?);
+		}
+		check_areas(p, rows.iter().map(|row| row.area.into()))?;
+		// <explicit-track-list> = [ <line-names>? <track-size> ]+ <line-names>?
+		let columns = if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+			Some((slash, TrackList(parse_track_items(p, false)?)))
+		} else {
+			None
+		};
+		Ok(Self::Areas(rows, columns))
+	}
+}
+
+impl ToCursors for GridTemplate<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::None(none) => s.append(none.into()),
+			Self::Tracks(rows, slash, columns) => {
+				ToCursors::to_cursors(rows, s);
+				s.append(slash.into());
+				ToCursors::to_cursors(columns, s);
+			}
+			Self::Areas(rows, columns) => {
+				for row in rows {
+					ToCursors::to_cursors(row, s);
+				}
+				if let Some((slash, columns)) = columns {
+					s.append(slash.into());
+					ToCursors::to_cursors(columns, s);
+				}
+			}
+		}
+	}
+}
+
+// <line-names>? <string> <track-size>? <line-names>?
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct GridTemplateAreaRow<'a> {
+	pub leading_names: Option<LineNames<'a>>,
+	pub area: TemplateAreaString,
+	pub size: Option<TrackSize<'a>>,
+	pub trailing_names: Option<LineNames<'a>>,
+}
+
+impl<'a> Parse<'a> for GridTemplateAreaRow<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let leading_names = p.parse_if_peek::<LineNames>()?;
+		let area = p.parse::<TemplateAreaString>()?;
+		let size = p.parse_if_peek::<TrackSize>()?;
+		let trailing_names = p.parse_if_peek::<LineNames>()?;
+		Ok(Self { leading_names, area, size, trailing_names })
+	}
+}
+
+impl ToCursors for GridTemplateAreaRow<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(names) = &self.leading_names {
+			ToCursors::to_cursors(names, s);
+		}
+		s.append(self.area.into());
+		if let Some(size) = &self.size {
+			ToCursors::to_cursors(size, s);
+		}
+		if let Some(names) = &self.trailing_names {
+			ToCursors::to_cursors(names, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#grid-auto-columns
+// <track-size>+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TrackSizeList<'a>(pub Vec<'a, TrackSize<'a>>);
+
+impl<'a> Peek<'a> for TrackSizeList<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<TrackSize>()
+	}
+}
+
+impl<'a> Parse<'a> for TrackSizeList<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut sizes = Vec::new_in(p.bump());
+		sizes.push(p.parse::<TrackSize>()?);
+		while let Some(size) = p.parse_if_peek::<TrackSize>()? {
+			sizes.push(size);
+		}
+		Ok(Self(sizes))
+	}
+}
+
+impl ToCursors for TrackSizeList<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for size in &self.0 {
+			ToCursors::to_cursors(size, s);
+		}
+	}
+}
+
+keyword_typedef!(AutoFlowDirection {
+	Row: atom!("row"),
+	Column: atom!("column"),
+	RowReverse: atom!("row-reverse"),
+	ColumnReverse: atom!("column-reverse"),
+});
+
+// https://drafts.csswg.org/css-grid-3/#grid-auto-flow
+// [ row | column | row-reverse | column-reverse ] || dense || wrap-reverse
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct AutoFlow {
+	pub direction: Option<AutoFlowDirection>,
+	pub dense: Option<kw::Dense>,
+	pub wrap_reverse: Option<kw::WrapReverse>,
+}
+
+impl<'a> Peek<'a> for AutoFlow {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<AutoFlowDirection>() || p.peek::<kw::Dense>() || p.peek::<kw::WrapReverse>()
+	}
+}
+
+impl<'a> Parse<'a> for AutoFlow {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut flow = Self { direction: None, dense: None, wrap_reverse: None };
+		loop {
+			if flow.direction.is_none() && p.peek::<AutoFlowDirection>() {
+				flow.direction = Some(p.parse::<AutoFlowDirection>()?);
+			} else if flow.dense.is_none() && p.peek::<kw::Dense>() {
+				flow.dense = Some(p.parse::<kw::Dense>()?);
+			} else if flow.wrap_reverse.is_none() && p.peek::<kw::WrapReverse>() {
+				flow.wrap_reverse = Some(p.parse::<kw::WrapReverse>()?);
+			} else {
+				break;
+			}
+		}
+		if flow == (Self { direction: None, dense: None, wrap_reverse: None }) {
+			let c: Cursor = p.parse::<T![Any]>()?.into();
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
+		Ok(flow)
+	}
+}
+
+impl ToCursors for AutoFlow {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(direction) = self.direction {
+			s.append(direction.into());
+		}
+		if let Some(dense) = self.dense {
+			s.append(dense.into());
+		}
+		if let Some(wrap_reverse) = self.wrap_reverse {
+			s.append(wrap_reverse.into());
+		}
+	}
+}
+
+// auto-flow && dense?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct AutoFlowKeywords {
+	pub auto_flow: kw::AutoFlow,
+	pub dense: Option<kw::Dense>,
+}
+
+impl<'a> Peek<'a> for AutoFlowKeywords {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<kw::AutoFlow>() || p.peek::<kw::Dense>()
+	}
+}
+
+impl<'a> Parse<'a> for AutoFlowKeywords {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let dense = p.parse_if_peek::<kw::Dense>()?;
+		let auto_flow = p.parse::<kw::AutoFlow>()?;
+		let dense = if dense.is_none() { p.parse_if_peek::<kw::Dense>()? } else { dense };
+		Ok(Self { auto_flow, dense })
+	}
+}
+
+impl ToCursors for AutoFlowKeywords {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.auto_flow.into());
+		if let Some(dense) = self.dense {
+			s.append(dense.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#grid-shorthand
+// <'grid-template'> |
+// <'grid-template-rows'> / [ auto-flow && dense? ] <'grid-auto-columns'>? |
+// [ auto-flow && dense? ] <'grid-auto-rows'>? / <'grid-template-columns'>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum Grid<'a> {
+	Template(GridTemplate<'a>),
+	AutoColumns(GridTemplateRowsStyleValue<'a>, T![/], AutoFlowKeywords, Option<GridAutoColumnsStyleValue<'a>>),
+	AutoRows(AutoFlowKeywords, Option<GridAutoRowsStyleValue<'a>>, T![/], GridTemplateColumnsStyleValue<'a>),
+}
+
+impl<'a> Peek<'a> for Grid<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<AutoFlowKeywords>() || p.peek::<GridTemplate>()
+	}
+}
+
+impl<'a> Parse<'a> for Grid<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(auto_flow) = p.parse_if_peek::<AutoFlowKeywords>()? {
+			let rows = p.parse_if_peek::<GridAutoRowsStyleValue>()?;
+			let slash = p.parse::<T![/]>()?;
+			let columns = p.parse::<GridTemplateColumnsStyleValue>()?;
+			return Ok(Self::AutoRows(auto_flow, rows, slash, columns));
+		}
+		let checkpoint = p.checkpoint();
+		if let Ok(rows) = p.parse::<GridTemplateRowsStyleValue>() {
+			if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+				if let Some(auto_flow) = p.parse_if_peek::<AutoFlowKeywords>()? {
+					let columns = p.parse_if_peek::<GridAutoColumnsStyleValue>()?;
+					return Ok(Self::AutoColumns(rows, slash, auto_flow, columns));
+				}
+			}
+		}
+		p.rewind(checkpoint);
+		p.parse::<GridTemplate>().map(Self::Template)
+	}
+}
+
+impl ToCursors for Grid<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Template(template) => ToCursors::to_cursors(template, s),
+			Self::AutoColumns(rows, slash, auto_flow, columns) => {
+				ToCursors::to_cursors(rows, s);
+				s.append(slash.into());
+				ToCursors::to_cursors(auto_flow, s);
+				if let Some(columns) = columns {
+					ToCursors::to_cursors(columns, s);
+				}
+			}
+			Self::AutoRows(auto_flow, rows, slash, columns) => {
+				ToCursors::to_cursors(auto_flow, s);
+				if let Some(rows) = rows {
+					ToCursors::to_cursors(rows, s);
+				}
+				s.append(slash.into());
+				ToCursors::to_cursors(columns, s);
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#typedef-grid-row-start-grid-line
+// <grid-line> = auto | <custom-ident> | [ [ <integer [-∞,-1]> | <integer [1,∞]> ] && <custom-ident>? ] |
+//               [ span && [ <integer [1,∞]> || <custom-ident> ] ]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub enum GridLine {
+	Auto(kw::Auto),
+	Area(T![Ident]),
+	Line(CSSInt, Option<T![Ident]>),
+	Span(kw::Span, Option<CSSInt>, Option<T![Ident]>),
+}
+
+impl<'a> Peek<'a> for GridLine {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![Ident]>() || p.peek::<CSSInt>()
+	}
+}
+
+impl<'a> Parse<'a> for GridLine {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(auto) = p.parse_if_peek::<kw::Auto>()? {
+			return Ok(Self::Auto(auto));
+		}
+		let mut span = None;
+		let mut integer = None;
+		let mut name = None;
+		loop {
+			if span.is_none() && p.peek::<kw::Span>() {
+				span = Some(p.parse::<kw::Span>()?);
+			} else if integer.is_none() && p.peek::<CSSInt>() {
+				integer = Some(p.parse::<CSSInt>()?);
+			} else if name.is_none() && p.peek::<T![Ident]>() {
+				let ident = p.parse::<T![Ident]>()?;
+				let c: Cursor = ident.into();
+				let atom = p.parse_atom_lower(c);
+				if matches!(atom, atom!("span") | atom!("auto")) {
+					Err(diagnostics::UnexpectedIdent(atom, c.into()))?
+				}
+				name = Some(ident);
+			} else {
+				break;
+			}
+		}
+		if let Some(integer) = integer {
+			let i: i32 = integer.into();
+			if i == 0 || (span.is_some() && i < 0) {
+				let c: Cursor = integer.into();
+				Err(diagnostics::NumberTooSmall(1.0, c.into()))?
+			}
+		}
+		match (span, integer, name) {
+			(None, None, Some(name)) => Ok(Self::Area(name)),
+			(None, Some(integer), name) => Ok(Self::Line(integer, name)),
+			(Some(span), integer, name) if integer.is_some() || name.is_some() => Ok(Self::Span(span, integer, name)),
+			_ => {
+				let c: Cursor = p.parse::<T![Any]>()?.into();
+				Err(diagnostics::Unexpected(c.into(), c.into()))?
+			}
+		}
+	}
+}
+
+impl ToCursors for GridLine {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Auto(auto) => s.append(auto.into()),
+			Self::Area(name) => s.append(name.into()),
+			Self::Line(integer, name) => {
+				s.append((*integer).into());
+				if let Some(name) = name {
+					s.append(name.into());
+				}
+			}
+			Self::Span(span, integer, name) => {
+				s.append(span.into());
+				if let Some(integer) = integer {
+					s.append((*integer).into());
+				}
+				if let Some(name) = name {
+					s.append(name.into());
+				}
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#propdef-grid-row
+// <grid-line> [ / <grid-line> ]?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct GridLines {
+	pub start: GridLine,
+	pub end: Option<(T![/], GridLine)>,
+}
+
+impl<'a> Peek<'a> for GridLines {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<GridLine>()
+	}
+}
+
+impl<'a> Parse<'a> for GridLines {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let start = p.parse::<GridLine>()?;
+		let end = parse_next_line(p)?;
+		Ok(Self { start, end })
+	}
+}
+
+impl ToCursors for GridLines {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.start, s);
+		write_next_line(&self.end, s);
+	}
+}
+
+// https://drafts.csswg.org/css-grid-2/#propdef-grid-area
+// <grid-line> [ / <grid-line> ]{0,3}
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct GridAreaLines {
+	pub row_start: GridLine,
+	pub column_start: Option<(T![/], GridLine)>,
+	pub row_end: Option<(T![/], GridLine)>,
+	pub column_end: Option<(T![/], GridLine)>,
+}
+
+impl<'a> Peek<'a> for GridAreaLines {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<GridLine>()
+	}
+}
+
+impl<'a> Parse<'a> for GridAreaLines {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let row_start = p.parse::<GridLine>()?;
+		let column_start = parse_next_line(p)?;
+		let row_end = if column_start.is_some() { parse_next_line(p)? } else { None };
+		let column_end = if row_end.is_some() { parse_next_line(p)? } else { None };
+		Ok(Self { row_start, column_start, row_end, column_end })
+	}
+}
+
+impl ToCursors for GridAreaLines {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.row_start, s);
+		write_next_line(&self.column_start, s);
+		write_next_line(&self.row_end, s);
+		write_next_line(&self.column_end, s);
+	}
+}
+
+// [ / <grid-line> ]?
+fn parse_next_line(p: &mut Parser<'_>) -> ParserResult<Option<(T![/], GridLine)>> {
+	if let Some(slash) = p.parse_if_peek::<T![/]>()? {
+		Ok(Some((slash, p.parse::<GridLine>()?)))
+	} else {
+		Ok(None)
+	}
+}
+
+fn write_next_line(line: &Option<(T![/], GridLine)>, s: &mut impl CursorSink) {
+	if let Some((slash, line)) = line {
+		s.append(slash.into());
+		ToCursors::to_cursors(line, s);
+	}
+}
+
+// https://drafts.csswg.org/css-grid-3/#masonry
+// <'masonry-template-areas'> || <'masonry-template-tracks'> || <'masonry-direction'> || <'masonry-fill'>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct Masonry<'a> {
+	pub areas: Option<MasonryTemplateAreasStyleValue>,
+	pub tracks: Option<MasonryTemplateTracksStyleValue<'a>>,
+	pub direction: Option<MasonryDirectionStyleValue>,
+	pub fill: Option<MasonryFillStyleValue>,
+}
+
+impl<'a> Masonry<'a> {
+	// The generated keyword style values peek any ident, so check for their keywords before parsing them.
+	fn peek_fill(p: &Parser<'a>) -> bool {
+		p.peek::<kw::Normal>() || p.peek::<kw::Reverse>()
+	}
+
+	fn peek_tracks(p: &Parser<'a>) -> bool {
+		p.peek::<kw::None>() || p.peek::<MasonryTrackList>() || p.peek::<Subgrid>()
+	}
+}
+
+impl<'a> Peek<'a> for Masonry<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<TemplateAreaString>() || p.peek::<AutoFlowDirection>() || Self::peek_fill(p) || Self::peek_tracks(p)
+	}
+}
+
+impl<'a> Parse<'a> for Masonry<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut masonry = Self { areas: None, tracks: None, direction: None, fill: None };
+		loop {
+			if masonry.areas.is_none() && (p.peek::<TemplateAreaString>() || p.peek::<kw::None>()) {
+				masonry.areas = Some(p.parse::<MasonryTemplateAreasStyleValue>()?);
+			} else if masonry.direction.is_none() && p.peek::<AutoFlowDirection>() {
+				masonry.direction = Some(p.parse::<MasonryDirectionStyleValue>()?);
+			} else if masonry.fill.is_none() && Self::peek_fill(p) {
+				masonry.fill = Some(p.parse::<MasonryFillStyleValue>()?);
+			} else if masonry.tracks.is_none() && Self::peek_tracks(p) {
+				masonry.tracks = Some(p.parse::<MasonryTemplateTracksStyleValue>()?);
+			} else {
+				break;
+			}
+		}
+		if masonry == (Self { areas: None, tracks: None, direction: None, fill: None }) {
+			let c: Cursor = p.parse::<T![Any]>()?.into();
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
+		Ok(masonry)
+	}
+}
+
+impl ToCursors for Masonry<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(areas) = &self.areas {
+			ToCursors::to_cursors(areas, s);
+		}
+		if let Some(tracks) = &self.tracks {
+			ToCursors::to_cursors(tracks, s);
+		}
+		if let Some(direction) = &self.direction {
+			ToCursors::to_cursors(direction, s);
+		}
+		if let Some(fill) = &self.fill {
+			ToCursors::to_cursors(fill, s);
+		}
+	}
+}
@@ -1,2 +1,26 @@
 pub(crate) use crate::traits::StyleValue;
 pub(crate) use hdx_proc_macro::*;
+
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(ObjectFitStyleValue, 32);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(ObjectFitStyleValue, "fill");
+		assert_parse!(ObjectFitStyleValue, "cover scale-down");
+		assert_parse!(ObjectFitStyleValue, "scale-down contain");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ObjectFitStyleValue, "fill scale-down");
+		assert_parse_error!(ObjectFitStyleValue, "contain cover");
+	}
+}
@@ -8,15 +8,15 @@ use impls::*;
  * CSS Images Module Level 5
  */
 
-// // https://drafts.csswg.org/css-images-5/#object-fit
-// #[value(" fill | none | [contain | cover] || scale-down ")]
-// #[initial("fill")]
-// #[applies_to("replaced elements")]
-// #[inherited("no")]
-// #[percentages("n/a")]
-// #[canonical_order("per grammar")]
-// #[animation_type("discrete")]
-// pub enum ObjectFitStyleValue {}
+// https://drafts.csswg.org/css-images-5/#object-fit
+#[value(" <object-fit> ")]
+#[initial("fill")]
+#[applies_to("replaced elements")]
+#[inherited("no")]
+#[percentages("n/a")]
+#[canonical_order("per grammar")]
+#[animation_type("discrete")]
+pub struct ObjectFitStyleValue;
 
 // // https://drafts.csswg.org/css-images-5/#object-position
 // #[value(" <position> ")]
@@ -1 +1,53 @@
+use hdx_lexer::Cursor;
+use hdx_parser::{diagnostics, keyword_typedef, CursorSink, Parse, Parser, Peek, Result as ParserResult, ToCursors};
 
+// https://drafts.csswg.org/css-images-5/#object-fit
+keyword_typedef!(ObjectFitKeyword {
+	Fill: atom!("fill"),
+	None: atom!("none"),
+	Contain: atom!("contain"),
+	Cover: atom!("cover"),
+	ScaleDown: atom!("scale-down"),
+});
+
+// https://drafts.csswg.org/css-images-5/#object-fit
+// fill | none | [contain | cover] || scale-down
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ObjectFit(pub ObjectFitKeyword, pub Option<ObjectFitKeyword>);
+
+impl<'a> Peek<'a> for ObjectFit {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<ObjectFitKeyword>()
+	}
+}
+
+impl<'a> Parse<'a> for ObjectFit {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let first = p.parse::<ObjectFitKeyword>()?;
+		if matches!(first, ObjectFitKeyword::Fill(_) | ObjectFitKeyword::None(_)) || !p.peek::<ObjectFitKeyword>() {
+			return Ok(Self(first, None));
+		}
+		let second = p.parse::<ObjectFitKeyword>()?;
+		// `scale-down` may only be paired with one of `contain` or `cover`, in either order.
+		match (first, second) {
+			(ObjectFitKeyword::ScaleDown(_), ObjectFitKeyword::Contain(_) | ObjectFitKeyword::Cover(_))
+			| (ObjectFitKeyword::Contain(_) | ObjectFitKeyword::Cover(_), ObjectFitKeyword::ScaleDown(_)) => {
+				Ok(Self(first, Some(second)))
+			}
+			_ => {
+				let c: Cursor = second.into();
+				Err(diagnostics::UnexpectedIdent(p.parse_atom_lower(c), c.into()))?
+			}
+		}
+	}
+}
+
+impl ToCursors for ObjectFit {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.0.into());
+		if let Some(second) = self.1 {
+			s.append(second.into());
+		}
+	}
+}
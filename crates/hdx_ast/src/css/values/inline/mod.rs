@@ -68,7 +68,7 @@ pub enum AlignmentBaselineStyleValue {}
 #[percentages("computed relative to 1em")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum LineHeightStyleValue {}
+pub enum LineHeightStyleValue<'a> {}
 
 // // https://drafts.csswg.org/css-inline-3/#line-fit-edge
 // #[value(" leading | <text-edge> ")]
@@ -148,4 +148,4 @@ pub enum InlineSizingStyleValue {}
 #[percentages("relative to logical width of (last fragment of) initial letter")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum InitialLetterWrapStyleValue {}
+pub enum InitialLetterWrapStyleValue<'a> {}
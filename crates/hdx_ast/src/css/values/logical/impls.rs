@@ -8,12 +8,12 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(BlockSizeStyleValue, 44);
-		assert_size!(InlineSizeStyleValue, 44);
-		assert_size!(MinBlockSizeStyleValue, 44);
-		assert_size!(MinInlineSizeStyleValue, 44);
-		assert_size!(MaxBlockSizeStyleValue, 44);
-		assert_size!(MaxInlineSizeStyleValue, 44);
+		assert_size!(BlockSizeStyleValue, 48);
+		assert_size!(InlineSizeStyleValue, 48);
+		assert_size!(MinBlockSizeStyleValue, 48);
+		assert_size!(MinInlineSizeStyleValue, 48);
+		assert_size!(MaxBlockSizeStyleValue, 48);
+		assert_size!(MaxInlineSizeStyleValue, 48);
 		assert_size!(MarginBlockStartStyleValue, 16);
 		assert_size!(MarginBlockEndStyleValue, 16);
 		assert_size!(MarginInlineStartStyleValue, 16);
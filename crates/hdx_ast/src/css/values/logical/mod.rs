@@ -16,7 +16,7 @@ use impls::*;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct BlockSizeStyleValue;
+pub struct BlockSizeStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#inline-size
 #[value(" <'width'> ")]
@@ -26,7 +26,7 @@ pub struct BlockSizeStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct InlineSizeStyleValue;
+pub struct InlineSizeStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#min-block-size
 #[value(" <'min-width'> ")]
@@ -36,7 +36,7 @@ pub struct InlineSizeStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MinBlockSizeStyleValue;
+pub struct MinBlockSizeStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#min-inline-size
 #[value(" <'min-width'> ")]
@@ -46,7 +46,7 @@ pub struct MinBlockSizeStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MinInlineSizeStyleValue;
+pub struct MinInlineSizeStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#max-block-size
 #[value(" <'max-width'> ")]
@@ -56,7 +56,7 @@ pub struct MinInlineSizeStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MaxBlockSizeStyleValue;
+pub struct MaxBlockSizeStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#max-inline-size
 #[value(" <'max-width'> ")]
@@ -66,7 +66,7 @@ pub struct MaxBlockSizeStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MaxInlineSizeStyleValue;
+pub struct MaxInlineSizeStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#margin-block-start
 #[value(" <'margin-top'> ")]
@@ -76,7 +76,7 @@ pub struct MaxInlineSizeStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MarginBlockStartStyleValue;
+pub struct MarginBlockStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#margin-block-end
 #[value(" <'margin-top'> ")]
@@ -86,7 +86,7 @@ pub struct MarginBlockStartStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MarginBlockEndStyleValue;
+pub struct MarginBlockEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#margin-inline-start
 #[value(" <'margin-top'> ")]
@@ -96,7 +96,7 @@ pub struct MarginBlockEndStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MarginInlineStartStyleValue;
+pub struct MarginInlineStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#margin-inline-end
 #[value(" <'margin-top'> ")]
@@ -106,7 +106,7 @@ pub struct MarginInlineStartStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct MarginInlineEndStyleValue;
+pub struct MarginInlineEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#margin-block
 #[value(" <'margin-top'>{1,2} ")]
@@ -116,7 +116,7 @@ pub struct MarginInlineEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct MarginBlockStyleValue;
+pub struct MarginBlockStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#margin-inline
 #[value(" <'margin-top'>{1,2} ")]
@@ -126,7 +126,7 @@ pub struct MarginBlockStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct MarginInlineStyleValue;
+pub struct MarginInlineStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#padding-block-start
 #[value(" <'padding-top'> ")]
@@ -136,7 +136,7 @@ pub struct MarginInlineStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingBlockStartStyleValue;
+pub struct PaddingBlockStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#padding-block-end
 #[value(" <'padding-top'> ")]
@@ -146,7 +146,7 @@ pub struct PaddingBlockStartStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingBlockEndStyleValue;
+pub struct PaddingBlockEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#padding-inline-start
 #[value(" <'padding-top'> ")]
@@ -156,7 +156,7 @@ pub struct PaddingBlockEndStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingInlineStartStyleValue;
+pub struct PaddingInlineStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#padding-inline-end
 #[value(" <'padding-top'> ")]
@@ -166,7 +166,7 @@ pub struct PaddingInlineStartStyleValue;
 #[percentages("as for the corresponding physical property")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct PaddingInlineEndStyleValue;
+pub struct PaddingInlineEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#padding-block
 #[value(" <'padding-top'>{1,2} ")]
@@ -176,7 +176,7 @@ pub struct PaddingInlineEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct PaddingBlockStyleValue;
+pub struct PaddingBlockStyleValue<'a>;
 
 // https://drafts.csswg.org/css-logical-1/#padding-inline
 #[value(" <'padding-top'>{1,2} ")]
@@ -186,4 +186,4 @@ pub struct PaddingBlockStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct PaddingInlineStyleValue;
+pub struct PaddingInlineStyleValue<'a>;
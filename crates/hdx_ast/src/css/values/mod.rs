@@ -116,3 +116,7 @@ pub use view_transitions::*;
 pub use viewport::*;
 pub use will_change::*;
 pub use writing_modes::*;
+
+// Types which aren't StyleValues themselves, but are visited within them.
+pub(crate) use backgrounds::types::{Background, BgLayer};
+pub(crate) use borders::types::{BoxShadow, Shadow};
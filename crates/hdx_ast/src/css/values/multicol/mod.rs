@@ -16,7 +16,7 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ColumnWidthStyleValue {}
+pub enum ColumnWidthStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-multicol-2/#column-count
 #[value(" auto | <integer [1,∞]> ")]
@@ -36,7 +36,7 @@ pub enum ColumnCountStyleValue {}
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct ColumnsStyleValue;
+pub struct ColumnsStyleValue<'a>;
 
 // https://drafts.csswg.org/css-multicol-2/#column-rule-color
 #[value(" <color> ")]
@@ -46,7 +46,7 @@ pub struct ColumnsStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ColumnRuleColorStyleValue;
+pub struct ColumnRuleColorStyleValue<'a>;
 
 // https://drafts.csswg.org/css-multicol-2/#column-rule-style
 #[value(" <line-style> ")]
@@ -66,7 +66,7 @@ pub struct ColumnRuleStyleStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ColumnRuleWidthStyleValue;
+pub struct ColumnRuleWidthStyleValue<'a>;
 
 // https://drafts.csswg.org/css-multicol-2/#column-rule
 #[value(" <'column-rule-width'> || <'column-rule-style'> || <'column-rule-color'> ")]
@@ -76,7 +76,7 @@ pub struct ColumnRuleWidthStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct ColumnRuleStyleValue;
+pub struct ColumnRuleStyleValue<'a>;
 
 // https://drafts.csswg.org/css-multicol-2/#column-span
 #[value(" none | <integer [1,∞]> | all | auto ")]
@@ -106,7 +106,7 @@ pub enum ScrollBehaviorStyleValue {}
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginTopStyleValue;
+pub struct OverflowClipMarginTopStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-right
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -116,7 +116,7 @@ pub struct OverflowClipMarginTopStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginRightStyleValue;
+pub struct OverflowClipMarginRightStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-bottom
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -126,7 +126,7 @@ pub struct OverflowClipMarginRightStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginBottomStyleValue;
+pub struct OverflowClipMarginBottomStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-left
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -136,7 +136,7 @@ pub struct OverflowClipMarginBottomStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginLeftStyleValue;
+pub struct OverflowClipMarginLeftStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-block-start
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -146,7 +146,7 @@ pub struct OverflowClipMarginLeftStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginBlockStartStyleValue;
+pub struct OverflowClipMarginBlockStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-inline-start
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -156,7 +156,7 @@ pub struct OverflowClipMarginBlockStartStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginInlineStartStyleValue;
+pub struct OverflowClipMarginInlineStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-block-end
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -166,7 +166,7 @@ pub struct OverflowClipMarginInlineStartStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginBlockEndStyleValue;
+pub struct OverflowClipMarginBlockEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-inline-end
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -176,7 +176,7 @@ pub struct OverflowClipMarginBlockEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-pub struct OverflowClipMarginInlineEndStyleValue;
+pub struct OverflowClipMarginInlineEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-inline
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -186,7 +186,7 @@ pub struct OverflowClipMarginInlineEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct OverflowClipMarginInlineStyleValue;
+pub struct OverflowClipMarginInlineStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin-block
 #[value(" <visual-box> || <length [0,∞]> ")]
@@ -196,7 +196,7 @@ pub struct OverflowClipMarginInlineStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct OverflowClipMarginBlockStyleValue;
+pub struct OverflowClipMarginBlockStyleValue<'a>;
 
 // https://drafts.csswg.org/css-overflow-5/#block-ellipsis
 #[value(" none | auto | <string> ")]
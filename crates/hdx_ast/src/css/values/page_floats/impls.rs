@@ -9,7 +9,7 @@ mod tests {
 	#[test]
 	fn size_test() {
 		assert_size!(FloatReferenceStyleValue, 16);
-		assert_size!(FloatStyleValue, 80);
+		assert_size!(FloatStyleValue, 88);
 		assert_size!(ClearStyleValue, 16);
 		assert_size!(FloatDeferStyleValue, 16);
 		assert_size!(FloatOffsetStyleValue, 16);
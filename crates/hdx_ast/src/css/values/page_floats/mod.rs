@@ -26,7 +26,7 @@ pub enum FloatReferenceStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum FloatStyleValue {}
+pub enum FloatStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-page-floats-3/#clear
 #[value(" inline-start | inline-end | block-start | block-end | left | right | top | bottom | both-inline | both-block | both | none ")]
@@ -56,4 +56,4 @@ pub enum FloatDeferStyleValue {}
 #[percentages("see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct FloatOffsetStyleValue;
+pub struct FloatOffsetStyleValue<'a>;
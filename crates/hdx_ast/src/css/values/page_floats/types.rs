@@ -13,21 +13,21 @@ mod func {
 // snap-block() = snap-block( <length> , [ start | end | near ]? )
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
-pub struct SnapBlock {
+pub struct SnapBlock<'a> {
 	pub function: func::SnapBlock,
-	pub length: LengthPercentage,
+	pub length: LengthPercentage<'a>,
 	pub comma: Option<T![,]>,
 	pub keyword: Option<SnapBlockKeyword>,
 	pub close: Option<T![')']>,
 }
 
-impl<'a> Peek<'a> for SnapBlock {
+impl<'a> Peek<'a> for SnapBlock<'a> {
 	fn peek(p: &Parser<'a>) -> bool {
 		p.peek::<func::SnapBlock>()
 	}
 }
 
-impl<'a> Parse<'a> for SnapBlock {
+impl<'a> Parse<'a> for SnapBlock<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let function = p.parse::<func::SnapBlock>()?;
 		let length = p.parse::<LengthPercentage>()?;
@@ -38,10 +38,10 @@ impl<'a> Parse<'a> for SnapBlock {
 	}
 }
 
-impl<'a> ToCursors for SnapBlock {
+impl ToCursors for SnapBlock<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		s.append(self.function.into());
-		s.append(self.length.into());
+		ToCursors::to_cursors(&self.length, s);
 		if let Some(comma) = self.comma {
 			s.append(comma.into());
 		}
@@ -60,21 +60,21 @@ keyword_typedef!(SnapBlockKeyword { Start: atom!("start"), End: atom!("end"), Ne
 // snap-inline() = snap-inline( <length> , [ left | right | near ]? )
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
-pub struct SnapInline {
+pub struct SnapInline<'a> {
 	pub function: func::SnapInline,
-	pub length: LengthPercentage,
+	pub length: LengthPercentage<'a>,
 	pub comma: Option<T![,]>,
 	pub keyword: Option<SnapInlineKeyword>,
 	pub close: Option<T![')']>,
 }
 
-impl<'a> Peek<'a> for SnapInline {
+impl<'a> Peek<'a> for SnapInline<'a> {
 	fn peek(p: &Parser<'a>) -> bool {
 		p.peek::<func::SnapInline>()
 	}
 }
 
-impl<'a> Parse<'a> for SnapInline {
+impl<'a> Parse<'a> for SnapInline<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let function = p.parse::<func::SnapInline>()?;
 		let length = p.parse::<LengthPercentage>()?;
@@ -85,10 +85,10 @@ impl<'a> Parse<'a> for SnapInline {
 	}
 }
 
-impl<'a> ToCursors for SnapInline {
+impl ToCursors for SnapInline<'_> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		s.append(self.function.into());
-		s.append(self.length.into());
+		ToCursors::to_cursors(&self.length, s);
 		if let Some(comma) = self.comma {
 			s.append(comma.into());
 		}
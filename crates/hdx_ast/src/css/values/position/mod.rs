@@ -26,7 +26,7 @@ pub enum PositionStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum TopStyleValue {}
+pub enum TopStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#right
 #[value(" auto | <length-percentage> ")]
@@ -36,7 +36,7 @@ pub enum TopStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum RightStyleValue {}
+pub enum RightStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#bottom
 #[value(" auto | <length-percentage> ")]
@@ -46,7 +46,7 @@ pub enum RightStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum BottomStyleValue {}
+pub enum BottomStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#left
 #[value(" auto | <length-percentage> ")]
@@ -56,7 +56,7 @@ pub enum BottomStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum LeftStyleValue {}
+pub enum LeftStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#inset-block-start
 #[value(" auto | <length-percentage> ")]
@@ -66,7 +66,7 @@ pub enum LeftStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum InsetBlockStartStyleValue {}
+pub enum InsetBlockStartStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#inset-inline-start
 #[value(" auto | <length-percentage> ")]
@@ -76,7 +76,7 @@ pub enum InsetBlockStartStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum InsetInlineStartStyleValue {}
+pub enum InsetInlineStartStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#inset-block-end
 #[value(" auto | <length-percentage> ")]
@@ -86,7 +86,7 @@ pub enum InsetInlineStartStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum InsetBlockEndStyleValue {}
+pub enum InsetBlockEndStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#inset-inline-end
 #[value(" auto | <length-percentage> ")]
@@ -96,7 +96,7 @@ pub enum InsetBlockEndStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum InsetInlineEndStyleValue {}
+pub enum InsetInlineEndStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-position-4/#inset-block
 #[value(" <'top'>{1,2} ")]
@@ -106,7 +106,7 @@ pub enum InsetInlineEndStyleValue {}
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct InsetBlockStyleValue;
+pub struct InsetBlockStyleValue<'a>;
 
 // https://drafts.csswg.org/css-position-4/#inset-inline
 #[value(" <'top'>{1,2} ")]
@@ -116,7 +116,7 @@ pub struct InsetBlockStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct InsetInlineStyleValue;
+pub struct InsetInlineStyleValue<'a>;
 
 // https://drafts.csswg.org/css-position-4/#inset
 #[value(" <'top'>{1,4} ")]
@@ -126,7 +126,7 @@ pub struct InsetInlineStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct InsetStyleValue;
+pub struct InsetStyleValue<'a>;
 
 // https://drafts.csswg.org/css-position-4/#overlay
 #[value(" none | auto ")]
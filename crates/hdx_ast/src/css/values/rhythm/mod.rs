@@ -16,7 +16,7 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum BlockStepSizeStyleValue {}
+pub enum BlockStepSizeStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-rhythm-1/#block-step-insert
 #[value(" margin-box | padding-box | content-box ")]
@@ -56,7 +56,7 @@ pub enum BlockStepRoundStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-pub struct BlockStepStyleValue;
+pub struct BlockStepStyleValue<'a>;
 
 // https://drafts.csswg.org/css-rhythm-1/#line-height-step
 #[value(" <length [0,∞]> ")]
@@ -66,4 +66,4 @@ pub struct BlockStepStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct LineHeightStepStyleValue;
+pub struct LineHeightStepStyleValue<'a>;
@@ -66,7 +66,7 @@ pub enum ScrollSnapStopStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingTopStyleValue {}
+pub enum ScrollPaddingTopStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-right
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -76,7 +76,7 @@ pub enum ScrollPaddingTopStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingRightStyleValue {}
+pub enum ScrollPaddingRightStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-bottom
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -86,7 +86,7 @@ pub enum ScrollPaddingRightStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingBottomStyleValue {}
+pub enum ScrollPaddingBottomStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-left
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -96,7 +96,7 @@ pub enum ScrollPaddingBottomStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingLeftStyleValue {}
+pub enum ScrollPaddingLeftStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-inline-start
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -106,7 +106,7 @@ pub enum ScrollPaddingLeftStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingInlineStartStyleValue {}
+pub enum ScrollPaddingInlineStartStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-block-start
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -116,7 +116,7 @@ pub enum ScrollPaddingInlineStartStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingBlockStartStyleValue {}
+pub enum ScrollPaddingBlockStartStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-inline-end
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -126,7 +126,7 @@ pub enum ScrollPaddingBlockStartStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingInlineEndStyleValue {}
+pub enum ScrollPaddingInlineEndStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-block-end
 #[value(" auto | <length-percentage [0,∞]> ")]
@@ -136,7 +136,7 @@ pub enum ScrollPaddingInlineEndStyleValue {}
 #[percentages("relative to the scroll container’s scrollport")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub enum ScrollPaddingBlockEndStyleValue {}
+pub enum ScrollPaddingBlockEndStyleValue<'a> {}
 
 // // https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding-block
 // #[value(" [ auto | <length-percentage [0,∞]> ]{1,2} ")]
@@ -166,7 +166,7 @@ pub enum ScrollPaddingBlockEndStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginTopStyleValue;
+pub struct ScrollMarginTopStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-right
 #[value(" <length> ")]
@@ -176,7 +176,7 @@ pub struct ScrollMarginTopStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginRightStyleValue;
+pub struct ScrollMarginRightStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-bottom
 #[value(" <length> ")]
@@ -186,7 +186,7 @@ pub struct ScrollMarginRightStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginBottomStyleValue;
+pub struct ScrollMarginBottomStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-left
 #[value(" <length> ")]
@@ -196,7 +196,7 @@ pub struct ScrollMarginBottomStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginLeftStyleValue;
+pub struct ScrollMarginLeftStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-block-start
 #[value(" <length> ")]
@@ -206,7 +206,7 @@ pub struct ScrollMarginLeftStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginBlockStartStyleValue;
+pub struct ScrollMarginBlockStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-inline-start
 #[value(" <length> ")]
@@ -216,7 +216,7 @@ pub struct ScrollMarginBlockStartStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginInlineStartStyleValue;
+pub struct ScrollMarginInlineStartStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-block-end
 #[value(" <length> ")]
@@ -226,7 +226,7 @@ pub struct ScrollMarginInlineStartStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginBlockEndStyleValue;
+pub struct ScrollMarginBlockEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-inline-end
 #[value(" <length> ")]
@@ -236,7 +236,7 @@ pub struct ScrollMarginBlockEndStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginInlineEndStyleValue;
+pub struct ScrollMarginInlineEndStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-block
 #[value(" <length>{1,2} ")]
@@ -246,7 +246,7 @@ pub struct ScrollMarginInlineEndStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginBlockStyleValue;
+pub struct ScrollMarginBlockStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-margin-inline
 #[value(" <length>{1,2} ")]
@@ -256,7 +256,7 @@ pub struct ScrollMarginBlockStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct ScrollMarginInlineStyleValue;
+pub struct ScrollMarginInlineStyleValue<'a>;
 
 // https://drafts.csswg.org/css-scroll-snap-2/#scroll-start-target
 #[value(" none | auto ")]
@@ -36,7 +36,7 @@ pub struct ShapeImageThresholdStyleValue;
 #[percentages("refer to the inline size of the containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct ShapeMarginStyleValue;
+pub struct ShapeMarginStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-shapes-2/#shape-inside
 // #[value(" auto | outside-shape | [ <basic-shape> || shape-box ] | <image> | display ")]
@@ -56,4 +56,4 @@ pub struct ShapeMarginStyleValue;
 #[percentages("refer to the inline size of the containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub struct ShapePaddingStyleValue;
+pub struct ShapePaddingStyleValue<'a>;
@@ -22,12 +22,12 @@ mod tests {
 
 	#[test]
 	fn size_test() {
-		assert_size!(WidthStyleValue, 44);
-		assert_size!(HeightStyleValue, 44);
-		assert_size!(MinWidthStyleValue, 44);
-		assert_size!(MinHeightStyleValue, 44);
-		assert_size!(MaxWidthStyleValue, 44);
-		assert_size!(MaxHeightStyleValue, 44);
+		assert_size!(WidthStyleValue, 48);
+		assert_size!(HeightStyleValue, 48);
+		assert_size!(MinWidthStyleValue, 48);
+		assert_size!(MinHeightStyleValue, 48);
+		assert_size!(MaxWidthStyleValue, 48);
+		assert_size!(MaxHeightStyleValue, 48);
 		assert_size!(BoxSizingStyleValue, 16);
 	}
 
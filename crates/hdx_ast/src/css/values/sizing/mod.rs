@@ -16,7 +16,7 @@ use impls::*;
 #[percentages("relative to width/height of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type, recursing into fit-content()")]
-pub enum WidthStyleValue {}
+pub enum WidthStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-sizing-4/#height
 #[value(" auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>) | <calc-size()> | stretch | fit-content ")]
@@ -26,7 +26,7 @@ pub enum WidthStyleValue {}
 #[percentages("relative to width/height of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type, recursing into fit-content()")]
-pub enum HeightStyleValue {}
+pub enum HeightStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-sizing-4/#min-width
 #[value(" auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>) | <calc-size()> | stretch | fit-content ")]
@@ -36,7 +36,7 @@ pub enum HeightStyleValue {}
 #[percentages("relative to width/height of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value, recursing into fit-content()")]
-pub enum MinWidthStyleValue {}
+pub enum MinWidthStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-sizing-4/#min-height
 #[value(" auto | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>) | <calc-size()> | stretch | fit-content ")]
@@ -46,7 +46,7 @@ pub enum MinWidthStyleValue {}
 #[percentages("relative to width/height of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value, recursing into fit-content()")]
-pub enum MinHeightStyleValue {}
+pub enum MinHeightStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-sizing-4/#max-width
 #[value(" none | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>) | <calc-size()> | stretch | fit-content ")]
@@ -56,7 +56,7 @@ pub enum MinHeightStyleValue {}
 #[percentages("relative to width/height of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value, recursing into fit-content()")]
-pub enum MaxWidthStyleValue {}
+pub enum MaxWidthStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-sizing-4/#max-height
 #[value(" none | <length-percentage [0,∞]> | min-content | max-content | fit-content(<length-percentage [0,∞]>) | <calc-size()> | stretch | fit-content ")]
@@ -66,7 +66,7 @@ pub enum MaxWidthStyleValue {}
 #[percentages("relative to width/height of containing block")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value, recursing into fit-content()")]
-pub enum MaxHeightStyleValue {}
+pub enum MaxHeightStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-sizing-4/#box-sizing
 #[value(" content-box | border-box ")]
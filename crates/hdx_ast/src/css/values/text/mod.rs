@@ -136,7 +136,7 @@ pub enum TextAlignLastStyleValue {}
 #[percentages("relative to computed font-size, i.e. 1em")]
 #[canonical_order("n/a")]
 #[animation_type("by computed value type")]
-pub enum WordSpacingStyleValue {}
+pub enum WordSpacingStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-text-4/#letter-spacing
 #[value(" normal | <length-percentage> ")]
@@ -146,7 +146,7 @@ pub enum WordSpacingStyleValue {}
 #[percentages("relative to computed font-size, i.e. 1em")]
 #[canonical_order("n/a")]
 #[animation_type("by computed value type")]
-pub enum LetterSpacingStyleValue {}
+pub enum LetterSpacingStyleValue<'a> {}
 
 // // https://drafts.csswg.org/css-text-4/#text-indent
 // #[value(" [ <length-percentage> ] && hanging? && each-line? ")]
@@ -276,7 +276,7 @@ pub enum HyphenateCharacterStyleValue {}
 #[percentages("refers to length of the line box")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct HyphenateLimitZoneStyleValue;
+pub struct HyphenateLimitZoneStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-text-4/#hyphenate-limit-chars
 // #[value(" [ auto | <integer> ]{1,3} ")]
@@ -326,7 +326,7 @@ pub enum TextGroupAlignStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct LinePaddingStyleValue;
+pub struct LinePaddingStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-text-4/#text-autospace
 // #[value(" normal | <autospace> | auto ")]
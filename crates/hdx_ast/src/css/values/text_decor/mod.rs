@@ -36,7 +36,7 @@ pub enum TextDecorationStyleStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct TextDecorationColorStyleValue;
+pub struct TextDecorationColorStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-text-decor-4/#text-decoration
 // #[value(" <'text-decoration-line'> || <'text-decoration-thickness'> || <'text-decoration-style'> || <'text-decoration-color'> ")]
@@ -76,7 +76,7 @@ pub struct TextDecorationColorStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-pub struct TextEmphasisColorStyleValue;
+pub struct TextEmphasisColorStyleValue<'a>;
 
 // // https://drafts.csswg.org/css-text-decor-4/#text-emphasis
 // #[value(" <'text-emphasis-style'> || <'text-emphasis-color'> ")]
@@ -116,7 +116,7 @@ pub struct TextEmphasisColorStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub enum TextDecorationThicknessStyleValue {}
+pub enum TextDecorationThicknessStyleValue<'a> {}
 
 // https://drafts.csswg.org/css-text-decor-4/#text-underline-offset
 #[value(" auto | <length-percentage> ")]
@@ -126,7 +126,7 @@ pub enum TextDecorationThicknessStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value")]
-pub enum TextUnderlineOffsetStyleValue {}
+pub enum TextUnderlineOffsetStyleValue<'a> {}
 
 // // https://drafts.csswg.org/css-text-decor-4/#text-decoration-trim
 // #[value(" <length>{1,2} | auto ")]
@@ -1,2 +1,90 @@
 pub(crate) use crate::traits::StyleValue;
 pub(crate) use hdx_proc_macro::*;
+
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_size!(TransformStyleValue, 32);
+		assert_size!(TransformOriginStyleValue, 80);
+		assert_size!(TransformBoxStyleValue, 16);
+		assert_size!(TranslateStyleValue, 48);
+		assert_size!(RotateStyleValue, 52);
+		assert_size!(ScaleStyleValue, 48);
+		assert_size!(TransformStyleStyleValue, 16);
+		assert_size!(PerspectiveStyleValue, 16);
+		assert_size!(PerspectiveOriginStyleValue, 64);
+		assert_size!(BackfaceVisibilityStyleValue, 16);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(TransformStyleValue, "none");
+		assert_parse!(TransformStyleValue, "translate(10px)");
+		assert_parse!(TransformStyleValue, "translate(10px, 50%) rotate(45deg)", "translate(10px,50%)rotate(45deg)");
+		assert_parse!(
+			TransformStyleValue,
+			"translateX(10px) translateY(1em) translateZ(0)",
+			"translateX(10px)translateY(1em)translateZ(0)"
+		);
+		assert_parse!(TransformStyleValue, "translate3d(1px, 2px, 3px)", "translate3d(1px,2px,3px)");
+		assert_parse!(
+			TransformStyleValue,
+			"scale(2) scale(50%, 1.5) scaleX(0.5) scale3d(1, 2, 3)",
+			"scale(2)scale(50%,1.5)scaleX(0.5)scale3d(1,2,3)"
+		);
+		assert_parse!(
+			TransformStyleValue,
+			"rotate(0) rotateZ(1turn) rotate3d(1, 0, 0, 90deg)",
+			"rotate(0)rotateZ(1turn)rotate3d(1,0,0,90deg)"
+		);
+		assert_parse!(
+			TransformStyleValue,
+			"skew(10deg, 0) skewX(5deg) skewY(-5deg)",
+			"skew(10deg,0)skewX(5deg)skewY(-5deg)"
+		);
+		assert_parse!(TransformStyleValue, "matrix(1, 0, 0, 1, 10, 20)", "matrix(1,0,0,1,10,20)");
+		assert_parse!(
+			TransformStyleValue,
+			"matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1)",
+			"matrix3d(1,0,0,0,0,1,0,0,0,0,1,0,0,0,0,1)"
+		);
+		assert_parse!(
+			TransformStyleValue,
+			"perspective(none) perspective(100px)",
+			"perspective(none)perspective(100px)"
+		);
+		assert_parse!(TransformOriginStyleValue, "center");
+		assert_parse!(TransformOriginStyleValue, "left top");
+		assert_parse!(TransformOriginStyleValue, "top left", "left top");
+		assert_parse!(TransformOriginStyleValue, "10px 20% 5px");
+		assert_parse!(TranslateStyleValue, "none");
+		assert_parse!(TranslateStyleValue, "10px 20% 30px");
+		assert_parse!(RotateStyleValue, "45deg");
+		assert_parse!(RotateStyleValue, "x 90deg");
+		assert_parse!(RotateStyleValue, "90deg 1 1 0", "1 1 0 90deg");
+		assert_parse!(ScaleStyleValue, "none");
+		assert_parse!(ScaleStyleValue, "1.5 50%");
+		assert_parse!(PerspectiveStyleValue, "none");
+		assert_parse!(PerspectiveStyleValue, "500px");
+		assert_parse!(PerspectiveOriginStyleValue, "left bottom");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(TransformStyleValue, "translate(10px) none");
+		assert_parse_error!(TransformStyleValue, "rotate(10px)");
+		assert_parse_error!(TransformStyleValue, "rotate(1)");
+		assert_parse_error!(TransformStyleValue, "translate3d(1px, 2px, 3%)");
+		assert_parse_error!(TransformStyleValue, "matrix(1, 0, 0, 1, 10)");
+		assert_parse_error!(TransformStyleValue, "foo(1)");
+		assert_parse_error!(TransformOriginStyleValue, "top 10px");
+		assert_parse_error!(TranslateStyleValue, "10px 20px 30%");
+		assert_parse_error!(RotateStyleValue, "x y 10deg");
+		assert_parse_error!(ScaleStyleValue, "1 2 3 4");
+		assert_parse_error!(PerspectiveStyleValue, "-10px");
+	}
+}
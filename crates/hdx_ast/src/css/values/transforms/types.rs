@@ -1 +1,735 @@
+use bumpalo::collections::Vec;
+use hdx_atom::atom;
+use hdx_lexer::Cursor;
+use hdx_parser::{
+	diagnostics, keyword_typedef, Build, CursorSink, Is, Parse, Parser, Peek, Result as ParserResult, ToCursors, T,
+};
 
+pub(crate) use crate::css::types::*;
+pub(crate) use crate::css::units::*;
+
+// Re-expose stylevalues for shorthands
+pub(crate) use super::PerspectiveStyleValue;
+
+mod func {
+	use hdx_parser::custom_function;
+	custom_function!(Matrix, atom!("matrix"));
+	custom_function!(Matrix3d, atom!("matrix3d"));
+	custom_function!(Perspective, atom!("perspective"));
+	custom_function!(Rotate, atom!("rotate"));
+	custom_function!(Rotate3d, atom!("rotate3d"));
+	custom_function!(RotateX, atom!("rotatex"));
+	custom_function!(RotateY, atom!("rotatey"));
+	custom_function!(RotateZ, atom!("rotatez"));
+	custom_function!(Scale, atom!("scale"));
+	custom_function!(Scale3d, atom!("scale3d"));
+	custom_function!(ScaleX, atom!("scalex"));
+	custom_function!(ScaleY, atom!("scaley"));
+	custom_function!(ScaleZ, atom!("scalez"));
+	custom_function!(Skew, atom!("skew"));
+	custom_function!(SkewX, atom!("skewx"));
+	custom_function!(SkewY, atom!("skewy"));
+	custom_function!(Translate, atom!("translate"));
+	custom_function!(Translate3d, atom!("translate3d"));
+	custom_function!(TranslateX, atom!("translatex"));
+	custom_function!(TranslateY, atom!("translatey"));
+	custom_function!(TranslateZ, atom!("translatez"));
+}
+
+// Closes a function which must not be followed by anything other than its `)`.
+fn parse_close(p: &mut Parser<'_>) -> ParserResult<Option<T![')']>> {
+	if p.at_end() {
+		Ok(None)
+	} else {
+		Ok(Some(p.parse::<T![')']>()?))
+	}
+}
+
+// <number>#{N}
+fn parse_numbers<const N: usize>(p: &mut Parser<'_>) -> ParserResult<[(CSSNumber, Option<T![,]>); N]> {
+	let mut values = [(CSSNumber::Zero, None); N];
+	for (i, (value, comma)) in values.iter_mut().enumerate() {
+		*value = p.parse::<CSSNumber>()?;
+		if i < N - 1 {
+			*comma = Some(p.parse::<T![,]>()?);
+		}
+	}
+	Ok(values)
+}
+
+// https://drafts.csswg.org/css-values-4/#zero-value
+// [ <angle> | <zero> ]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
+pub enum AngleOrZero {
+	Angle(Angle),
+	Zero(T![Number]),
+}
+
+impl AngleOrZero {
+	pub fn as_degrees(&self) -> f32 {
+		match self {
+			Self::Angle(angle) => angle.as_degrees(),
+			Self::Zero(_) => 0.0,
+		}
+	}
+}
+
+impl<'a> Is<'a> for AngleOrZero {
+	fn is(p: &Parser<'a>, c: Cursor) -> bool {
+		Angle::is(p, c) || (<T![Number]>::is(p, c) && c.token().value() == 0.0)
+	}
+}
+
+impl<'a> Build<'a> for AngleOrZero {
+	fn build(p: &Parser<'a>, c: Cursor) -> Self {
+		if Angle::is(p, c) {
+			Self::Angle(Angle::build(p, c))
+		} else {
+			Self::Zero(<T![Number]>::build(p, c))
+		}
+	}
+}
+
+impl From<AngleOrZero> for Cursor {
+	fn from(value: AngleOrZero) -> Self {
+		match value {
+			AngleOrZero::Angle(angle) => angle.into(),
+			AngleOrZero::Zero(zero) => zero.into(),
+		}
+	}
+}
+
+// [ <number> | <percentage> ]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
+pub enum NumberOrPercentage {
+	Number(CSSNumber),
+	Percentage(T![Dimension::%]),
+}
+
+impl NumberOrPercentage {
+	// Resolves the value as a scale factor, where 100% is 1.
+	pub fn as_factor(&self) -> f32 {
+		match self {
+			Self::Number(number) => (*number).into(),
+			Self::Percentage(percentage) => Into::<f32>::into(*percentage) / 100.0,
+		}
+	}
+}
+
+impl<'a> Is<'a> for NumberOrPercentage {
+	fn is(p: &Parser<'a>, c: Cursor) -> bool {
+		CSSNumber::is(p, c) || <T![Dimension::%]>::is(p, c)
+	}
+}
+
+impl<'a> Build<'a> for NumberOrPercentage {
+	fn build(p: &Parser<'a>, c: Cursor) -> Self {
+		if CSSNumber::is(p, c) {
+			Self::Number(CSSNumber::build(p, c))
+		} else {
+			Self::Percentage(<T![Dimension::%]>::build(p, c))
+		}
+	}
+}
+
+impl From<NumberOrPercentage> for Cursor {
+	fn from(value: NumberOrPercentage) -> Self {
+		match value {
+			NumberOrPercentage::Number(number) => number.into(),
+			NumberOrPercentage::Percentage(percentage) => percentage.into(),
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-transforms-2/#transform-functions
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TransformFunction<'a> {
+	// matrix( <number>#{6} )
+	Matrix(func::Matrix, [(CSSNumber, Option<T![,]>); 6], Option<T![')']>),
+	// matrix3d( <number>#{16} )
+	// The values are bump allocated to keep the size of each function in check
+	Matrix3d(func::Matrix3d, &'a [(CSSNumber, Option<T![,]>); 16], Option<T![')']>),
+	// translate( <length-percentage> , <length-percentage>? )
+	Translate(func::Translate, LengthPercentage<'a>, Option<(T![,], LengthPercentage<'a>)>, Option<T![')']>),
+	// translate3d( <length-percentage> , <length-percentage> , <length> )
+	Translate3d(
+		func::Translate3d,
+		LengthPercentage<'a>,
+		T![,],
+		LengthPercentage<'a>,
+		T![,],
+		Length<'a>,
+		Option<T![')']>,
+	),
+	// translateX( <length-percentage> )
+	TranslateX(func::TranslateX, LengthPercentage<'a>, Option<T![')']>),
+	// translateY( <length-percentage> )
+	TranslateY(func::TranslateY, LengthPercentage<'a>, Option<T![')']>),
+	// translateZ( <length> )
+	TranslateZ(func::TranslateZ, Length<'a>, Option<T![')']>),
+	// scale( [ <number> | <percentage> ]#{1,2} )
+	Scale(func::Scale, NumberOrPercentage, Option<(T![,], NumberOrPercentage)>, Option<T![')']>),
+	// scale3d( [ <number> | <percentage> ]#{3} )
+	Scale3d(func::Scale3d, NumberOrPercentage, T![,], NumberOrPercentage, T![,], NumberOrPercentage, Option<T![')']>),
+	// scaleX( [ <number> | <percentage> ] )
+	ScaleX(func::ScaleX, NumberOrPercentage, Option<T![')']>),
+	// scaleY( [ <number> | <percentage> ] )
+	ScaleY(func::ScaleY, NumberOrPercentage, Option<T![')']>),
+	// scaleZ( [ <number> | <percentage> ] )
+	ScaleZ(func::ScaleZ, NumberOrPercentage, Option<T![')']>),
+	// rotate( [ <angle> | <zero> ] )
+	Rotate(func::Rotate, AngleOrZero, Option<T![')']>),
+	// rotate3d( <number> , <number> , <number> , [ <angle> | <zero> ] )
+	Rotate3d(func::Rotate3d, [(CSSNumber, Option<T![,]>); 3], T![,], AngleOrZero, Option<T![')']>),
+	// rotateX( [ <angle> | <zero> ] )
+	RotateX(func::RotateX, AngleOrZero, Option<T![')']>),
+	// rotateY( [ <angle> | <zero> ] )
+	RotateY(func::RotateY, AngleOrZero, Option<T![')']>),
+	// rotateZ( [ <angle> | <zero> ] )
+	RotateZ(func::RotateZ, AngleOrZero, Option<T![')']>),
+	// skew( [ <angle> | <zero> ] , [ <angle> | <zero> ]? )
+	Skew(func::Skew, AngleOrZero, Option<(T![,], AngleOrZero)>, Option<T![')']>),
+	// skewX( [ <angle> | <zero> ] )
+	SkewX(func::SkewX, AngleOrZero, Option<T![')']>),
+	// skewY( [ <angle> | <zero> ] )
+	SkewY(func::SkewY, AngleOrZero, Option<T![')']>),
+	// perspective( [ <length [0,∞]> | none ] )
+	Perspective(func::Perspective, PerspectiveStyleValue<'a>, Option<T![')']>),
+}
+
+impl<'a> Peek<'a> for TransformFunction<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<T![Function]>()
+			&& matches!(
+				p.parse_atom_lower(p.peek_n(1)),
+				atom!("matrix")
+					| atom!("matrix3d")
+					| atom!("translate")
+					| atom!("translate3d")
+					| atom!("translatex")
+					| atom!("translatey")
+					| atom!("translatez")
+					| atom!("scale")
+					| atom!("scale3d")
+					| atom!("scalex")
+					| atom!("scaley")
+					| atom!("scalez")
+					| atom!("rotate")
+					| atom!("rotate3d")
+					| atom!("rotatex")
+					| atom!("rotatey")
+					| atom!("rotatez")
+					| atom!("skew") | atom!("skewx")
+					| atom!("skewy")
+					| atom!("perspective")
+			)
+	}
+}
+
+impl<'a> Parse<'a> for TransformFunction<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let c = p.peek_n(1);
+		Ok(match p.parse_atom_lower(c) {
+			atom!("matrix") => {
+				let function = p.parse::<func::Matrix>()?;
+				Self::Matrix(function, parse_numbers::<6>(p)?, parse_close(p)?)
+			}
+			atom!("matrix3d") => {
+				let function = p.parse::<func::Matrix3d>()?;
+				Self::Matrix3d(function, p.bump().alloc(parse_numbers::<16>(p)?), parse_close(p)?)
+			}
+			atom!("translate") => {
+				let function = p.parse::<func::Translate>()?;
+				let x = p.parse::<LengthPercentage>()?;
+				let y = if let Some(comma) = p.parse_if_peek::<T![,]>()? {
+					Some((comma, p.parse::<LengthPercentage>()?))
+				} else {
+					None
+				};
+				Self::Translate(function, x, y, parse_close(p)?)
+			}
+			atom!("translate3d") => {
+				let function = p.parse::<func::Translate3d>()?;
+				let x = p.parse::<LengthPercentage>()?;
+				let comma_x = p.parse::<T![,]>()?;
+				let y = p.parse::<LengthPercentage>()?;
+				let comma_y = p.parse::<T![,]>()?;
+				let z = p.parse::<Length>()?;
+				Self::Translate3d(function, x, comma_x, y, comma_y, z, parse_close(p)?)
+			}
+			atom!("translatex") => {
+				let function = p.parse::<func::TranslateX>()?;
+				Self::TranslateX(function, p.parse::<LengthPercentage>()?, parse_close(p)?)
+			}
+			atom!("translatey") => {
+				let function = p.parse::<func::TranslateY>()?;
+				Self::TranslateY(function, p.parse::<LengthPercentage>()?, parse_close(p)?)
+			}
+			atom!("translatez") => {
+				let function = p.parse::<func::TranslateZ>()?;
+				Self::TranslateZ(function, p.parse::<Length>()?, parse_close(p)?)
+			}
+			atom!("scale") => {
+				let function = p.parse::<func::Scale>()?;
+				let x = p.parse::<NumberOrPercentage>()?;
+				let y = if let Some(comma) = p.parse_if_peek::<T![,]>()? {
+					Some((comma, p.parse::<NumberOrPercentage>()?))
+				} else {
+					None
+				};
+				Self::Scale(function, x, y, parse_close(p)?)
+			}
+			atom!("scale3d") => {
+				let function = p.parse::<func::Scale3d>()?;
+				let x = p.parse::<NumberOrPercentage>()?;
+				let comma_x = p.parse::<T![,]>()?;
+				let y = p.parse::<NumberOrPercentage>()?;
+				let comma_y = p.parse::<T![,]>()?;
+				let z = p.parse::<NumberOrPercentage>()?;
+				Self::Scale3d(function, x, comma_x, y, comma_y, z, parse_close(p)?)
+			}
+			atom!("scalex") => {
+				let function = p.parse::<func::ScaleX>()?;
+				Self::ScaleX(function, p.parse::<NumberOrPercentage>()?, parse_close(p)?)
+			}
+			atom!("scaley") => {
+				let function = p.parse::<func::ScaleY>()?;
+				Self::ScaleY(function, p.parse::<NumberOrPercentage>()?, parse_close(p)?)
+			}
+			atom!("scalez") => {
+				let function = p.parse::<func::ScaleZ>()?;
+				Self::ScaleZ(function, p.parse::<NumberOrPercentage>()?, parse_close(p)?)
+			}
+			atom!("rotate") => {
+				let function = p.parse::<func::Rotate>()?;
+				Self::Rotate(function, p.parse::<AngleOrZero>()?, parse_close(p)?)
+			}
+			atom!("rotate3d") => {
+				let function = p.parse::<func::Rotate3d>()?;
+				let axis = parse_numbers::<3>(p)?;
+				let comma = p.parse::<T![,]>()?;
+				let angle = p.parse::<AngleOrZero>()?;
+				Self::Rotate3d(function, axis, comma, angle, parse_close(p)?)
+			}
+			atom!("rotatex") => {
+				let function = p.parse::<func::RotateX>()?;
+				Self::RotateX(function, p.parse::<AngleOrZero>()?, parse_close(p)?)
+			}
+			atom!("rotatey") => {
+				let function = p.parse::<func::RotateY>()?;
+				Self::RotateY(function, p.parse::<AngleOrZero>()?, parse_close(p)?)
+			}
+			atom!("rotatez") => {
+				let function = p.parse::<func::RotateZ>()?;
+				Self::RotateZ(function, p.parse::<AngleOrZero>()?, parse_close(p)?)
+			}
+			atom!("skew") => {
+				let function = p.parse::<func::Skew>()?;
+				let x = p.parse::<AngleOrZero>()?;
+				let y = if let Some(comma) = p.parse_if_peek::<T![,]>()? {
+					Some((comma, p.parse::<AngleOrZero>()?))
+				} else {
+					None
+				};
+				Self::Skew(function, x, y, parse_close(p)?)
+			}
+			atom!("skewx") => {
+				let function = p.parse::<func::SkewX>()?;
+				Self::SkewX(function, p.parse::<AngleOrZero>()?, parse_close(p)?)
+			}
+			atom!("skewy") => {
+				let function = p.parse::<func::SkewY>()?;
+				Self::SkewY(function, p.parse::<AngleOrZero>()?, parse_close(p)?)
+			}
+			atom!("perspective") => {
+				let function = p.parse::<func::Perspective>()?;
+				Self::Perspective(function, p.parse::<PerspectiveStyleValue>()?, parse_close(p)?)
+			}
+			atom => Err(diagnostics::UnexpectedFunction(atom, c.into()))?,
+		})
+	}
+}
+
+impl ToCursors for TransformFunction<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		fn numbers(values: &[(CSSNumber, Option<T![,]>)], s: &mut impl CursorSink) {
+			for (value, comma) in values {
+				s.append((*value).into());
+				if let Some(comma) = comma {
+					s.append(comma.into());
+				}
+			}
+		}
+		fn pair<T: ToCursors>(first: &T, second: &Option<(T![,], T)>, s: &mut impl CursorSink) {
+			ToCursors::to_cursors(first, s);
+			if let Some((comma, second)) = second {
+				s.append(comma.into());
+				ToCursors::to_cursors(second, s);
+			}
+		}
+		let close = match self {
+			Self::Matrix(function, values, close) => {
+				s.append(function.into());
+				numbers(values, s);
+				close
+			}
+			Self::Matrix3d(function, values, close) => {
+				s.append(function.into());
+				numbers(*values, s);
+				close
+			}
+			Self::Translate(function, x, y, close) => {
+				s.append(function.into());
+				pair(x, y, s);
+				close
+			}
+			Self::Translate3d(function, x, comma_x, y, comma_y, z, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(x, s);
+				s.append(comma_x.into());
+				ToCursors::to_cursors(y, s);
+				s.append(comma_y.into());
+				ToCursors::to_cursors(z, s);
+				close
+			}
+			Self::TranslateX(function, x, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(x, s);
+				close
+			}
+			Self::TranslateY(function, y, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(y, s);
+				close
+			}
+			Self::TranslateZ(function, z, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(z, s);
+				close
+			}
+			Self::Scale(function, x, y, close) => {
+				s.append(function.into());
+				pair(x, y, s);
+				close
+			}
+			Self::Scale3d(function, x, comma_x, y, comma_y, z, close) => {
+				s.append(function.into());
+				s.append((*x).into());
+				s.append(comma_x.into());
+				s.append((*y).into());
+				s.append(comma_y.into());
+				s.append((*z).into());
+				close
+			}
+			Self::ScaleX(function, x, close) => {
+				s.append(function.into());
+				s.append((*x).into());
+				close
+			}
+			Self::ScaleY(function, y, close) => {
+				s.append(function.into());
+				s.append((*y).into());
+				close
+			}
+			Self::ScaleZ(function, z, close) => {
+				s.append(function.into());
+				s.append((*z).into());
+				close
+			}
+			Self::Rotate3d(function, axis, comma, angle, close) => {
+				s.append(function.into());
+				numbers(axis, s);
+				s.append(comma.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::Rotate(function, angle, close) => {
+				s.append(function.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::RotateX(function, angle, close) => {
+				s.append(function.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::RotateY(function, angle, close) => {
+				s.append(function.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::RotateZ(function, angle, close) => {
+				s.append(function.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::Skew(function, x, y, close) => {
+				s.append(function.into());
+				pair(x, y, s);
+				close
+			}
+			Self::SkewX(function, angle, close) => {
+				s.append(function.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::SkewY(function, angle, close) => {
+				s.append(function.into());
+				s.append((*angle).into());
+				close
+			}
+			Self::Perspective(function, value, close) => {
+				s.append(function.into());
+				ToCursors::to_cursors(value, s);
+				close
+			}
+		};
+		if let Some(close) = close {
+			s.append(close.into());
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-transforms-1/#typedef-transform-list
+// <transform-list> = <transform-function>+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TransformList<'a>(pub Vec<'a, TransformFunction<'a>>);
+
+impl<'a> Peek<'a> for TransformList<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<TransformFunction>()
+	}
+}
+
+impl<'a> Parse<'a> for TransformList<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let mut functions = Vec::new_in(p.bump());
+		loop {
+			functions.push(p.parse::<TransformFunction>()?);
+			if !p.peek::<TransformFunction>() {
+				return Ok(Self(functions));
+			}
+		}
+	}
+}
+
+impl ToCursors for TransformList<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		for function in &self.0 {
+			ToCursors::to_cursors(function, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-transforms-2/#transform-origin
+// [ left | center | right | top | bottom | <length-percentage> ]
+// | [ left | center | right | <length-percentage> ] [ top | center | bottom | <length-percentage> ] <length>?
+// | [[ center | left | right ] && [ center | top | bottom ]] <length>?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TransformOrigin<'a> {
+	pub position: Position<'a>,
+	pub z: Option<Length<'a>>,
+}
+
+impl<'a> Peek<'a> for TransformOrigin<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<PositionSingleValue>()
+	}
+}
+
+impl<'a> Parse<'a> for TransformOrigin<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let first = p.parse::<PositionSingleValue>()?;
+		if !p.peek::<PositionSingleValue>() {
+			return Ok(Self { position: Position::SingleValue(first), z: None });
+		}
+		let c = p.peek_n(1);
+		let second = p.parse::<PositionSingleValue>()?;
+		let position = match (first.to_horizontal(), second.to_vertical()) {
+			(Some(horizontal), Some(vertical)) => Position::TwoValue(horizontal, vertical),
+			// Keywords may be given in either order
+			_ => match (second.to_horizontal(), first.to_vertical()) {
+				(Some(horizontal), Some(vertical))
+					if !matches!(first, PositionSingleValue::LengthPercentage(_))
+						&& !matches!(second, PositionSingleValue::LengthPercentage(_)) =>
+				{
+					Position::TwoValue(horizontal, vertical)
+				}
+				_ => Err(diagnostics::Unexpected(c.into(), c.into()))?,
+			},
+		};
+		let z = p.parse_if_peek::<Length>()?;
+		Ok(Self { position, z })
+	}
+}
+
+impl ToCursors for TransformOrigin<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.position, s);
+		if let Some(z) = &self.z {
+			ToCursors::to_cursors(z, s);
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-transforms-2/#translate
+// <length-percentage> [ <length-percentage> <length>? ]?
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct TranslateValue<'a> {
+	pub x: LengthPercentage<'a>,
+	pub y: Option<LengthPercentage<'a>>,
+	pub z: Option<Length<'a>>,
+}
+
+impl<'a> Peek<'a> for TranslateValue<'a> {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<LengthPercentage>()
+	}
+}
+
+impl<'a> Parse<'a> for TranslateValue<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let x = p.parse::<LengthPercentage>()?;
+		let y = p.parse_if_peek::<LengthPercentage>()?;
+		let z = if y.is_some() { p.parse_if_peek::<Length>()? } else { None };
+		Ok(Self { x, y, z })
+	}
+}
+
+impl ToCursors for TranslateValue<'_> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		ToCursors::to_cursors(&self.x, s);
+		if let Some(y) = &self.y {
+			ToCursors::to_cursors(y, s);
+		}
+		if let Some(z) = &self.z {
+			ToCursors::to_cursors(z, s);
+		}
+	}
+}
+
+// x | y | z
+keyword_typedef!(RotateAxisKeyword { X: atom!("x"), Y: atom!("y"), Z: atom!("z") });
+
+// [ x | y | z | <number>{3} ]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize),
+	serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum RotateAxis {
+	Keyword(RotateAxisKeyword),
+	Vector(CSSNumber, CSSNumber, CSSNumber),
+}
+
+impl<'a> Peek<'a> for RotateAxis {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<RotateAxisKeyword>() || p.peek::<CSSNumber>()
+	}
+}
+
+impl<'a> Parse<'a> for RotateAxis {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(keyword) = p.parse_if_peek::<RotateAxisKeyword>()? {
+			return Ok(Self::Keyword(keyword));
+		}
+		let x = p.parse::<CSSNumber>()?;
+		let y = p.parse::<CSSNumber>()?;
+		let z = p.parse::<CSSNumber>()?;
+		Ok(Self::Vector(x, y, z))
+	}
+}
+
+impl ToCursors for RotateAxis {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Keyword(keyword) => s.append(keyword.into()),
+			Self::Vector(x, y, z) => {
+				s.append((*x).into());
+				s.append((*y).into());
+				s.append((*z).into());
+			}
+		}
+	}
+}
+
+// https://drafts.csswg.org/css-transforms-2/#rotate
+// <angle> | [ x | y | z | <number>{3} ] && <angle>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct RotateValue {
+	pub axis: Option<RotateAxis>,
+	pub angle: Angle,
+}
+
+impl<'a> Peek<'a> for RotateValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<Angle>() || p.peek::<RotateAxis>()
+	}
+}
+
+impl<'a> Parse<'a> for RotateValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if let Some(angle) = p.parse_if_peek::<Angle>()? {
+			let axis = p.parse_if_peek::<RotateAxis>()?;
+			return Ok(Self { axis, angle });
+		}
+		let axis = Some(p.parse::<RotateAxis>()?);
+		let angle = p.parse::<Angle>()?;
+		Ok(Self { axis, angle })
+	}
+}
+
+impl ToCursors for RotateValue {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		if let Some(axis) = &self.axis {
+			ToCursors::to_cursors(axis, s);
+		}
+		s.append(self.angle.into());
+	}
+}
+
+// https://drafts.csswg.org/css-transforms-2/#scale
+// [ <number> | <percentage> ]{1,3}
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct ScaleValue {
+	pub x: NumberOrPercentage,
+	pub y: Option<NumberOrPercentage>,
+	pub z: Option<NumberOrPercentage>,
+}
+
+impl<'a> Peek<'a> for ScaleValue {
+	fn peek(p: &Parser<'a>) -> bool {
+		p.peek::<NumberOrPercentage>()
+	}
+}
+
+impl<'a> Parse<'a> for ScaleValue {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let x = p.parse::<NumberOrPercentage>()?;
+		let y = p.parse_if_peek::<NumberOrPercentage>()?;
+		let z = if y.is_some() { p.parse_if_peek::<NumberOrPercentage>()? } else { None };
+		Ok(Self { x, y, z })
+	}
+}
+
+impl ToCursors for ScaleValue {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		s.append(self.x.into());
+		for value in [self.y, self.z].into_iter().flatten() {
+			s.append(value.into());
+		}
+	}
+}
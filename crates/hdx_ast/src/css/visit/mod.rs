@@ -1,7 +1,10 @@
 include!(concat!(env!("OUT_DIR"), "/css_node_kind.rs"));
 include!(concat!(env!("OUT_DIR"), "/css_apply_visit_methods.rs"));
 
-use crate::css::*;
+use crate::css::{
+	values::{Background, BgLayer, BoxShadow, Shadow},
+	*,
+};
 
 macro_rules! visit_mut_trait {
 	( $(
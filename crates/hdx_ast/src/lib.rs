@@ -8,7 +8,7 @@ pub mod test_helpers;
 
 use css::{Visit, Visitable};
 use hdx_parser::{CursorSink, Parse, Parser, Result as ParserResult, ToCursors};
-pub use traits::StyleValue;
+pub use traits::{Initial, StyleValue};
 
 // TODO! - delete this when we're done ;)
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
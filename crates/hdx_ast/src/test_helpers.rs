@@ -44,6 +44,49 @@ macro_rules! assert_parse {
 #[cfg(test)]
 pub(crate) use assert_parse;
 
+#[cfg(test)]
+pub fn test_pretty<'a, T: Parse<'a> + ToCursors>(
+	allocator: &'a Bump,
+	source_text: &'a str,
+	expected: &'a str,
+	options: &hdx_parser::PrettyOptions,
+	file: &str,
+	line: u32,
+) {
+	let mut parser = Parser::new(allocator, source_text, Features::default());
+	let result = parser.parse_entirely::<T>();
+	if !result.errors.is_empty() {
+		panic!("\n\nParse on {}:{} failed. ({:?}) saw error {:?}", file, line, source_text, result.errors[0]);
+	}
+	let mut actual = bumpalo::collections::String::new_in(allocator);
+	let mut cursors = hdx_parser::CursorStream::new(&allocator);
+	result.write_pretty(&mut cursors, options, &mut actual).unwrap();
+	if expected != actual {
+		panic!("\n\nPretty print on {}:{} failed: did not match expected format:\n\n   parser input: {:?}\n  parser output: {:?}\n       expected: {:?}\n", file, line, source_text, actual, expected);
+	}
+}
+
+#[cfg(test)]
+macro_rules! assert_pretty {
+	($ty: ty, $str: literal, $str2: literal, $options: expr) => {
+		let allocator = bumpalo::Bump::default();
+		$crate::test_helpers::test_pretty::<$ty>(&allocator, $str, $str2, &$options, file!(), line!());
+	};
+	($ty: ty, $str: literal, $str2: literal) => {
+		let allocator = bumpalo::Bump::default();
+		$crate::test_helpers::test_pretty::<$ty>(
+			&allocator,
+			$str,
+			$str2,
+			&hdx_parser::PrettyOptions::default(),
+			file!(),
+			line!(),
+		);
+	};
+}
+#[cfg(test)]
+pub(crate) use assert_pretty;
+
 #[cfg(test)]
 pub fn test_error<'a, T: Parse<'a> + ToCursors>(allocator: &'a Bump, source_text: &'a str, file: &str, line: u32) {
 	let mut parser = Parser::new(allocator, source_text, Features::default());
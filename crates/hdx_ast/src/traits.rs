@@ -1,9 +1,25 @@
-pub trait StyleValue: PartialEq + Sized + Clone {
-	// fn initial() -> Self {
-	// 	Self::default()
-	// }
+use bumpalo::Bump;
+use hdx_parser::{Features, Parse, Parser, ToCursors};
 
+pub trait StyleValue: PartialEq + Sized + Clone {
 	fn inherits() -> bool {
 		false
 	}
 }
+
+pub trait Initial<'a>: Parse<'a> + ToCursors {
+	/// The initial value, as written in the specification. Shorthands don't have an initial value of their own, as
+	/// they're made up of the initial values of their longhands.
+	const INITIAL: Option<&'static str>;
+
+	/// Parses [Initial::INITIAL]. Cursors within the returned value point into [Initial::INITIAL] rather than the
+	/// source text of a StyleSheet, so it must be used as the source text when writing the value.
+	fn initial(allocator: &'a Bump) -> Option<Self> {
+		let result = Parser::new(allocator, Self::INITIAL?, Features::default()).parse_entirely::<Self>();
+		if result.errors.is_empty() {
+			result.output
+		} else {
+			None
+		}
+	}
+}
@@ -83,7 +83,11 @@ impl Cursor {
 			Kind::Dimension => match self.token().dimension_unit() {
 				DimensionUnit::Unknown => f.write_str(self.str_slice(str))?,
 				d => {
-					f.write_str(&self.token().value().to_string())?;
+					if self.token().has_sign() {
+						write!(f, "{:+}", self.token().value())?;
+					} else {
+						write!(f, "{}", self.token().value())?;
+					}
 					f.write_str(&d.to_atom())?;
 				}
 			},
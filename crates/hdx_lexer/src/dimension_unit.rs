@@ -135,6 +135,21 @@ impl DimensionUnit {
 			| Self::Cqmin => 5,
 		}
 	}
+
+	// https://drafts.csswg.org/css-values/#lengths
+	pub fn is_length(&self) -> bool {
+		!matches!(
+			self,
+			Self::Unknown
+				| Self::Deg | Self::Dpcm
+				| Self::Dpi | Self::Dppx
+				| Self::Fr | Self::Grad
+				| Self::Hz | Self::Khz
+				| Self::Ms | Self::Percent
+				| Self::Rad | Self::S
+				| Self::Turn | Self::X
+		)
+	}
 }
 
 impl From<u8> for DimensionUnit {
@@ -127,7 +127,7 @@ impl Token {
 		Token((((Kind::Whitespace as u32) | (WhitespaceStyle::Tab as u32) << 5) << 24) & KIND_MASK, 1);
 	pub const NEWLINE: Token =
 		Token((((Kind::Whitespace as u32) | (WhitespaceStyle::Newline as u32) << 5) << 24) & KIND_MASK | (1 << 12), 1);
-	pub const NUMBER_ZERO: Token = Token((((Kind::Number as u32) | 0b100_00000) << 24) & KIND_MASK, 1);
+	pub const NUMBER_ZERO: Token = Token((((Kind::Number as u32) << 24) & KIND_MASK) | 1, 0);
 	pub const COLON: Token = Token((((Kind::Colon as u32) | 0b001_00000) << 24) & KIND_MASK, ':' as u32);
 	pub const SEMICOLON: Token = Token((((Kind::Semicolon as u32) | 0b001_00000) << 24) & KIND_MASK, ';' as u32);
 	pub const COMMA: Token = Token((((Kind::Comma as u32) | 0b001_00000) << 24) & KIND_MASK, ',' as u32);
@@ -0,0 +1,30 @@
+use hdx_lexer::Span;
+use miette::{self, Diagnostic};
+use thiserror::{self, Error};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("This rule has no declarations or nested rules, so it has no effect.")]
+#[diagnostic(severity(Warning), help("Consider removing this rule."), code(hdx_lint::NoEmptyRules))]
+pub struct EmptyRule(#[label("This rule is empty")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The property '{0}' is declared more than once in this block.")]
+#[diagnostic(
+	severity(Warning),
+	help("Only the last declaration will apply. Consider removing the earlier one."),
+	code(hdx_lint::NoDuplicateProperties)
+)]
+pub struct DuplicateProperty(
+	pub String,
+	#[label("It was first declared here")] pub Span,
+	#[label("This overrides the earlier declaration")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The at-rule '{0}' isn't a known at-rule.")]
+#[diagnostic(
+	severity(Warning),
+	help("This may be a typo, or an at-rule which browsers do not support."),
+	code(hdx_lint::NoUnknownAtRules)
+)]
+pub struct UnknownAtRule(pub String, #[label("This at-rule isn't recognised")] pub Span);
@@ -0,0 +1,169 @@
+use hdx_ast::css::{visit::Visitable, StyleSheet, Visit};
+use hdx_lexer::{Cursor, SourceOffset, Span};
+use hdx_parser::{CursorSink, Error, ToCursors};
+
+pub mod diagnostics;
+mod rules;
+#[cfg(test)]
+mod test_helpers;
+
+pub use rules::*;
+
+// A Lint is a Visitor over a StyleSheet which collects diagnostics for any nodes that it considers problematic.
+// Each Lint has a stable name (used to enable or disable it) and reports diagnostics with stable codes, so that
+// they can be referenced in CI logs and configuration.
+pub trait Lint<'a>: Visit<'a> {
+	const NAME: &'static str;
+
+	fn new(source_text: &'a str) -> Self;
+
+	fn into_diagnostics(self) -> Vec<Error>;
+
+	fn lint(stylesheet: &StyleSheet<'a>, source_text: &'a str) -> Vec<Error> {
+		let mut lint = Self::new(source_text);
+		stylesheet.accept(&mut lint);
+		lint.into_diagnostics()
+	}
+}
+
+macro_rules! apply_lints {
+	($macro: ident) => {
+		$macro! {
+			NoDuplicateProperties,
+			NoEmptyRules,
+			NoUnknownAtRules,
+		}
+	};
+}
+
+macro_rules! lint_names {
+	( $( $name: ident, )+ ) => {
+		/// The names of every available lint, which can be passed to [Linter::disable].
+		pub const LINTS: &[&str] = &[$($name::NAME,)+];
+	}
+}
+apply_lints!(lint_names);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Linter {
+	disabled: Vec<String>,
+}
+
+impl Linter {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn disable(mut self, name: impl Into<String>) -> Self {
+		self.disabled.push(name.into());
+		self
+	}
+
+	pub fn is_enabled(&self, name: &str) -> bool {
+		!self.disabled.iter().any(|disabled| disabled == name)
+	}
+
+	pub fn run<'a>(&self, stylesheet: &StyleSheet<'a>, source_text: &'a str) -> Vec<Error> {
+		let mut diagnostics = vec![];
+		macro_rules! run_lints {
+			( $( $name: ident, )+ ) => {
+				$(
+					if self.is_enabled($name::NAME) {
+						diagnostics.extend($name::lint(stylesheet, source_text));
+					}
+				)+
+			}
+		}
+		apply_lints!(run_lints);
+		diagnostics
+	}
+}
+
+// Collects the Span which covers all Cursors of a node, so that diagnostics can label whole nodes.
+#[derive(Default)]
+pub(crate) struct SpanSink {
+	first: Option<Cursor>,
+	span: Option<Span>,
+}
+
+impl SpanSink {
+	pub(crate) fn span_of(node: &impl ToCursors) -> Span {
+		let mut sink = Self::default();
+		node.to_cursors(&mut sink);
+		sink.span.or(sink.first.map(|c| c.span())).unwrap_or_else(Span::dummy)
+	}
+
+	pub(crate) fn first_cursor(node: &impl ToCursors) -> Option<Cursor> {
+		let mut sink = Self::default();
+		node.to_cursors(&mut sink);
+		sink.first
+	}
+}
+
+impl CursorSink for SpanSink {
+	fn append(&mut self, c: Cursor) {
+		self.first.get_or_insert(c);
+		// Cursors which were added by the parser (rather than lexed) have no place in the source text.
+		if c.offset() == SourceOffset::DUMMY {
+			return;
+		}
+		self.span = Some(match self.span {
+			Some(span) => span + c.span(),
+			None => c.span(),
+		});
+	}
+
+	fn iter_cursors(&self) -> impl Iterator<Item = &Cursor> {
+		self.first.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use hdx_parser::{Features, Parser};
+
+	#[test]
+	fn test_lint_names() {
+		assert_eq!(LINTS, &["no-duplicate-properties", "no-empty-rules", "no-unknown-at-rules"]);
+	}
+
+	#[test]
+	fn test_linter_runs_enabled_lints() {
+		let bump = Bump::default();
+		let source_text = "a{}b{color:red;color:blue}";
+		let result = Parser::new(&bump, source_text, Features::default()).parse_entirely::<StyleSheet>();
+		let stylesheet = result.output.unwrap();
+		assert_eq!(Linter::new().run(&stylesheet, source_text).len(), 2);
+		assert_eq!(Linter::new().disable("no-empty-rules").run(&stylesheet, source_text).len(), 1);
+	}
+
+	#[test]
+	fn test_linter_visits_conditional_rules() {
+		let bump = Bump::default();
+		let source_text = "@media print{a{}b{color:red;color:blue}}@supports (color:red){@media screen{c{}}}";
+		let result = Parser::new(&bump, source_text, Features::default()).parse_entirely::<StyleSheet>();
+		let stylesheet = result.output.unwrap();
+		assert_eq!(Linter::new().run(&stylesheet, source_text).len(), 3);
+	}
+
+	#[test]
+	fn test_span_of_skips_dummy_cursors() {
+		use hdx_lexer::Token;
+
+		struct Node(Vec<Cursor>);
+		impl ToCursors for Node {
+			fn to_cursors(&self, s: &mut impl CursorSink) {
+				for c in &self.0 {
+					s.append(*c);
+				}
+			}
+		}
+
+		let ident = Cursor::new(SourceOffset(2), Token::new_ident(false, false, false, 3));
+		let node = Node(vec![Cursor::dummy(Token::SPACE), ident, Cursor::dummy(Token::SEMICOLON)]);
+		assert_eq!(SpanSink::span_of(&node), Span::new(SourceOffset(2), SourceOffset(5)));
+		assert_eq!(SpanSink::span_of(&Node(vec![Cursor::dummy(Token::SEMICOLON)])), Span::dummy());
+	}
+}
@@ -0,0 +1,7 @@
+mod no_duplicate_properties;
+mod no_empty_rules;
+mod no_unknown_at_rules;
+
+pub use no_duplicate_properties::*;
+pub use no_empty_rules::*;
+pub use no_unknown_at_rules::*;
@@ -0,0 +1,64 @@
+use hdx_ast::css::{StyleDeclaration, Visit};
+use hdx_lexer::Cursor;
+use hdx_parser::Error;
+
+use crate::{diagnostics, Lint};
+
+// Declaring the same property twice within one block means the earlier declaration is overridden, which is most
+// likely a mistake left behind after editing.
+#[derive(Debug, Default)]
+pub struct NoDuplicateProperties<'a> {
+	source_text: &'a str,
+	diagnostics: Vec<Error>,
+}
+
+impl<'a> Lint<'a> for NoDuplicateProperties<'a> {
+	const NAME: &'static str = "no-duplicate-properties";
+
+	fn new(source_text: &'a str) -> Self {
+		Self { source_text, diagnostics: vec![] }
+	}
+
+	fn into_diagnostics(self) -> Vec<Error> {
+		self.diagnostics
+	}
+}
+
+impl<'a> Visit<'a> for NoDuplicateProperties<'a> {
+	fn visit_style_declaration(&mut self, rule: &StyleDeclaration<'a>) {
+		for (i, property) in rule.declarations.iter().enumerate() {
+			let c: Cursor = property.name.into();
+			let name = c.str_slice(self.source_text);
+			// Custom properties are case-sensitive, while all other properties are ASCII case-insensitive.
+			let earlier = rule.declarations[..i].iter().find(|earlier| {
+				let other = Cursor::from(earlier.name).str_slice(self.source_text);
+				if property.name.is_dashed_ident() {
+					other == name
+				} else {
+					other.eq_ignore_ascii_case(name)
+				}
+			});
+			if let Some(earlier) = earlier {
+				self.diagnostics.push(
+					diagnostics::DuplicateProperty(name.to_string(), earlier.name.into(), property.name.into()).into(),
+				);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_lints() {
+		assert_lints!(NoDuplicateProperties, "a{color:red;color:blue}", 1);
+		assert_lints!(NoDuplicateProperties, "a{color:red;COLOR:blue}", 1);
+		assert_lints!(NoDuplicateProperties, "a{color:red;color:blue;color:green}", 2);
+		assert_lints!(NoDuplicateProperties, "a{color:red;b{color:blue}}", 0);
+		assert_lints!(NoDuplicateProperties, "a{--foo:red;--FOO:blue}", 0);
+		assert_lints!(NoDuplicateProperties, "a{color:red}b{color:blue}", 0);
+	}
+}
@@ -0,0 +1,46 @@
+use hdx_ast::css::{StyleRule, Visit};
+use hdx_parser::Error;
+
+use crate::{diagnostics, Lint, SpanSink};
+
+// Style rules without any declarations or nested rules can be removed without changing the meaning of the StyleSheet.
+#[derive(Debug, Default)]
+pub struct NoEmptyRules {
+	diagnostics: Vec<Error>,
+}
+
+impl<'a> Lint<'a> for NoEmptyRules {
+	const NAME: &'static str = "no-empty-rules";
+
+	fn new(_source_text: &'a str) -> Self {
+		Self::default()
+	}
+
+	fn into_diagnostics(self) -> Vec<Error> {
+		self.diagnostics
+	}
+}
+
+impl<'a> Visit<'a> for NoEmptyRules {
+	fn visit_style_rule(&mut self, rule: &StyleRule<'a>) {
+		if rule.style.declarations.is_empty() && rule.style.rules.is_empty() {
+			self.diagnostics.push(diagnostics::EmptyRule(SpanSink::span_of(rule)).into());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_lints() {
+		assert_lints!(NoEmptyRules, "a{}", 1);
+		assert_lints!(NoEmptyRules, "a{}b{}", 2);
+		assert_lints!(NoEmptyRules, "a{b{}}", 1);
+		assert_lints!(NoEmptyRules, "a{color:red}", 0);
+		assert_lints!(NoEmptyRules, "a{b{color:red}}", 0);
+		assert_lints!(NoEmptyRules, "@media print{a{}}:is(a,b){}@-webkit-keyframes x{from{}}", 2);
+	}
+}
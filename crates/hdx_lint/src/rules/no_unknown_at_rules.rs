@@ -0,0 +1,50 @@
+use hdx_ast::css::{UnknownAtRule, Visit};
+use hdx_lexer::Cursor;
+use hdx_parser::Error;
+
+use crate::{diagnostics, Lint, SpanSink};
+
+// At-rules which hdx doesn't recognise are very likely to be typos, or non-standard rules that browsers will drop.
+#[derive(Debug, Default)]
+pub struct NoUnknownAtRules<'a> {
+	source_text: &'a str,
+	diagnostics: Vec<Error>,
+}
+
+impl<'a> Lint<'a> for NoUnknownAtRules<'a> {
+	const NAME: &'static str = "no-unknown-at-rules";
+
+	fn new(source_text: &'a str) -> Self {
+		Self { source_text, diagnostics: vec![] }
+	}
+
+	fn into_diagnostics(self) -> Vec<Error> {
+		self.diagnostics
+	}
+}
+
+impl<'a> Visit<'a> for NoUnknownAtRules<'a> {
+	fn visit_unknown_at_rule(&mut self, rule: &UnknownAtRule<'a>) {
+		let name = SpanSink::first_cursor(rule).map(|c: Cursor| c.str_slice(self.source_text)).unwrap_or_default();
+		self.diagnostics.push(diagnostics::UnknownAtRule(name.to_string(), SpanSink::span_of(rule)).into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_lints() {
+		assert_lints!(NoUnknownAtRules, "@foo{}", 1);
+		assert_lints!(NoUnknownAtRules, "@foo{}@baz bar{}", 2);
+		assert_lints!(NoUnknownAtRules, "a{@foo{}}", 1);
+		assert_lints!(NoUnknownAtRules, "@page{margin:1cm}", 0);
+		assert_lints!(NoUnknownAtRules, "@media print{@foo{}}", 1);
+		assert_lints!(NoUnknownAtRules, "@container sidebar (width:2px){@foo{}}", 1);
+		assert_lints!(NoUnknownAtRules, "@container style(--theme:dark){@foo{}}", 1);
+		assert_lints!(NoUnknownAtRules, "@container scroll-state((stuck:top) and (snapped:x)){@foo{}}", 1);
+		assert_lints!(NoUnknownAtRules, "@supports font-tech(color-COLRv1) and font-format(woff2){@foo{}}", 1);
+	}
+}
@@ -0,0 +1,33 @@
+use bumpalo::Bump;
+use hdx_ast::css::StyleSheet;
+use hdx_parser::{Features, Parser};
+
+use crate::Lint;
+
+pub fn test_lint<'a, T: Lint<'a>>(allocator: &'a Bump, source_text: &'a str, expected: usize, file: &str, line: u32) {
+	let result = Parser::new(allocator, source_text, Features::default()).parse_entirely::<StyleSheet>();
+	if !result.errors.is_empty() {
+		panic!("\n\nParse on {}:{} failed. ({:?}) saw error {:?}", file, line, source_text, result.errors[0]);
+	}
+	let diagnostics = T::lint(&result.output.unwrap(), source_text);
+	if diagnostics.len() != expected {
+		panic!(
+			"\n\nLint {} on {}:{} failed: expected {} diagnostics but saw {}.\n\n   input: {:?}\n  diagnostics: {:#?}\n",
+			T::NAME,
+			file,
+			line,
+			expected,
+			diagnostics.len(),
+			source_text,
+			diagnostics
+		);
+	}
+}
+
+macro_rules! assert_lints {
+	($ty: ty, $str: literal, $count: literal) => {
+		let allocator = bumpalo::Bump::default();
+		$crate::test_helpers::test_lint::<$ty>(&allocator, $str, $count, file!(), line!());
+	};
+}
+pub(crate) use assert_lints;
@@ -2,25 +2,47 @@ use bumpalo::Bump;
 use dashmap::DashMap;
 use hdx_ast::css::{StyleSheet, Visitable};
 use hdx_highlight::{SemanticKind, SemanticModifier, TokenHighlighter};
-use hdx_parser::{Features, Parser};
+use hdx_parser::{CursorStream, Features, Parser};
+use hdx_transform::{Pipeline, TransformOptions};
 use itertools::Itertools;
 use lsp_types::{
 	notification::{DidChangeTextDocument, DidOpenTextDocument},
-	request::{Initialize, SemanticTokensFullRequest},
+	request::{Initialize, Request, SemanticTokensFullRequest},
 	InitializeResult, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
 	SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, SemanticTokensResult,
-	SemanticTokensServerCapabilities, ServerCapabilities, ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind,
-	TextDocumentSyncOptions, TextDocumentSyncSaveOptions, Uri, WorkDoneProgressOptions,
+	SemanticTokensServerCapabilities, ServerCapabilities, ServerInfo, TextDocumentIdentifier,
+	TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, TextDocumentSyncSaveOptions, Uri,
+	WorkDoneProgressOptions,
 };
+use serde::{Deserialize, Serialize};
 use std::{io, sync::Arc};
 use strum::VariantNames;
 use tracing::trace;
 
 use crate::Server;
 
+/// Runs transforms over an open document, responding with the transformed source text. Responds with `null` if the
+/// document isn't open or fails to parse.
+pub enum TransformRequest {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformParams {
+	pub text_document: TextDocumentIdentifier,
+	#[serde(default)]
+	pub options: TransformOptions,
+}
+
+impl Request for TransformRequest {
+	type Params = TransformParams;
+	type Result = Option<String>;
+	const METHOD: &'static str = "hdx/transform";
+}
+
 pub fn server_with_handlers(version: &'static str) -> Server {
 	let files = Arc::new(DashMap::<Uri, String>::new());
 	let files_for_semantic_tokens = files.clone();
+	let files_for_transform = files.clone();
 	let files_for_open_doc = files.clone();
 	let files_for_change_doc = files.clone();
 	Server::new()
@@ -127,6 +149,23 @@ pub fn server_with_handlers(version: &'static str) -> Server {
 			}
 			Ok(None)
 		})
+		.handle::<TransformRequest>(move |params| -> Result<Option<String>, io::Error> {
+			let Some(source_text) = files_for_transform.get(&params.text_document.uri) else { return Ok(None) };
+			let allocator = Bump::default();
+			let mut result =
+				Parser::new(&allocator, source_text.as_str(), Features::default()).parse_entirely::<StyleSheet>();
+			if !result.errors.is_empty() {
+				return Ok(None);
+			}
+			if result.output.is_none() {
+				return Ok(None);
+			}
+			let report = Pipeline::from_options(&params.options).run(&mut result);
+			trace!("Transformed {:?}: {}", &params.text_document.uri, report);
+			let mut str = String::new();
+			result.write(&mut CursorStream::new(&allocator), &mut str).map_err(io::Error::other)?;
+			Ok(Some(str))
+		})
 		.on::<DidOpenTextDocument>(move |params| -> Result<(), io::Error> {
 			let uri = params.text_document.uri;
 			let source_text = params.text_document.text;
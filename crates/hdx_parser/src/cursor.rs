@@ -59,6 +59,13 @@ impl<'a> Parser<'a> {
 		}
 	}
 
+	/// Moves the Parser forward to the given offset, without parsing any of the source text before it. This allows text
+	/// which has been appended to an already parsed source text to be parsed into Cursors which are valid for the whole
+	/// of the source text.
+	pub fn hop(&mut self, offset: SourceOffset) {
+		self.lexer.hop(Cursor::new(offset, Token::default()));
+	}
+
 	#[inline]
 	pub fn checkpoint(&self) -> ParserCheckpoint {
 		ParserCheckpoint {
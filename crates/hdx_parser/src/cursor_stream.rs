@@ -1,5 +1,9 @@
+use core::fmt;
+
 use bumpalo::{collections::Vec, Bump};
-use hdx_lexer::Cursor;
+use hdx_lexer::{Cursor, SourceOffset};
+
+use crate::ToCursors;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CursorStream<'a> {
@@ -25,3 +29,51 @@ pub trait CursorSink {
 	fn append(&mut self, c: Cursor);
 	fn iter_cursors(&self) -> impl Iterator<Item = &Cursor>;
 }
+
+/// The offset of the first Cursor a node writes which came from the source text. Nodes which were parsed into separate
+/// lists can be put back into source order with this.
+pub fn start_offset(node: &impl ToCursors) -> Option<SourceOffset> {
+	struct Start(Option<Cursor>);
+	impl CursorSink for Start {
+		fn append(&mut self, c: Cursor) {
+			if self.0.is_none() && c.offset() != SourceOffset::DUMMY {
+				self.0 = Some(c);
+			}
+		}
+		fn iter_cursors(&self) -> impl Iterator<Item = &Cursor> {
+			self.0.iter()
+		}
+	}
+	let mut start = Start(None);
+	node.to_cursors(&mut start);
+	start.0.map(|c| c.offset())
+}
+
+/// A [fmt::Write] which is told about each Cursor just before that Cursor's text is written, allowing it to record
+/// where each token came from, for example to build a [SourceMap][crate::SourceMap].
+pub trait CursorWrite: fmt::Write {
+	fn mark(&mut self, _cursor: Cursor) {}
+}
+
+/// Wraps a [fmt::Write] which has no use for the Cursors, so that it can be given where a [CursorWrite] is needed.
+pub struct IgnoreCursors<'f, F: fmt::Write + ?Sized>(pub &'f mut F);
+
+impl<F: fmt::Write + ?Sized> fmt::Write for IgnoreCursors<'_, F> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.0.write_str(s)
+	}
+}
+
+impl<F: fmt::Write + ?Sized> CursorWrite for IgnoreCursors<'_, F> {}
+
+impl CursorWrite for String {}
+
+impl CursorWrite for bumpalo::collections::String<'_> {}
+
+impl CursorWrite for fmt::Formatter<'_> {}
+
+impl<W: CursorWrite + ?Sized> CursorWrite for &mut W {
+	fn mark(&mut self, cursor: Cursor) {
+		(**self).mark(cursor)
+	}
+}
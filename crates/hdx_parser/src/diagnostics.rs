@@ -269,6 +269,35 @@ pub struct DisallowedValueWithoutDimension(pub Atom, #[label("This value")] pub
 )]
 pub struct DisallowedMathFunction(pub Atom, #[label("This value")] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0}() cannot take {1} argument(s).")]
+#[diagnostic(help("Try adding or removing arguments"), code(hdx_parser::UnexpectedMathFunctionArguments))]
+pub struct UnexpectedMathFunctionArguments(pub Atom, pub usize, #[label("This function")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The values in this math function have incompatible types, so it can never be resolved.")]
+#[diagnostic(
+	help("Values can only be added, subtracted or compared if they're the same type, for example two lengths."),
+	code(hdx_parser::UnresolvableMathFunction)
+)]
+pub struct UnresolvableMathFunction(#[label("This function")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A + or - in a math function must have whitespace on both sides, otherwise it's read as the sign of a number.")]
+#[diagnostic(
+	help("Try adding whitespace around the operator, like `1px + 2px`."),
+	code(hdx_parser::MathOperatorWithoutWhitespace)
+)]
+pub struct MathOperatorWithoutWhitespace(#[label("This value")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("This math function doesn't resolve to a <{0}>, so it isn't valid here.")]
+#[diagnostic(
+	help("Check the units of each value in the function, for example a <length> needs at least one length."),
+	code(hdx_parser::UnexpectedMathFunctionType)
+)]
+pub struct UnexpectedMathFunctionType(pub Atom, #[label("This function")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Expected an opening curly brace but saw `{0}`")]
 #[diagnostic(help("This is not correct CSS syntax."), code(hdx_parser::ExpectedOpenCurly))]
@@ -372,6 +401,97 @@ pub struct ColorHexWrongLength(pub usize, #[label("This is not the right number
 #[diagnostic(help(""), code(hdx_parser::ReservedKeyframeName))]
 pub struct ReservedKeyframeName(pub Atom, #[label("Rename it, or try wrapping it in quotes")] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0} cannot be used as a counter style name, as it's a reserved word.")]
+#[diagnostic(help("Try picking a different name"), code(hdx_parser::ReservedCounterStyleName))]
+pub struct ReservedCounterStyleName(pub Atom, #[label("Rename this")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A @counter-style using the '{0}' system must have a '{1}' descriptor.")]
+#[diagnostic(
+	help("Try adding a '{1}' descriptor, or using a different system"),
+	code(hdx_parser::CounterStyleMissingDescriptor)
+)]
+pub struct CounterStyleMissingDescriptor(pub Atom, pub Atom, #[label("This rule")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A @counter-style using the '{0}' system must have at least two symbols.")]
+#[diagnostic(help("Try adding another symbol"), code(hdx_parser::CounterStyleTooFewSymbols))]
+pub struct CounterStyleTooFewSymbols(pub Atom, #[label("This only has one symbol")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A @counter-style using the '{0}' system cannot have a '{1}' descriptor.")]
+#[diagnostic(help("Try removing it"), code(hdx_parser::CounterStyleDisallowedDescriptor))]
+pub struct CounterStyleDisallowedDescriptor(pub Atom, pub Atom, #[label("Remove this descriptor")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The lower bound of this range is higher than its upper bound.")]
+#[diagnostic(help("Try swapping the two bounds"), code(hdx_parser::CounterStyleInvalidRange))]
+pub struct CounterStyleInvalidRange(#[label("This range is empty")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The namespace prefix '{0}' has not been declared.")]
+#[diagnostic(
+	severity(Warning),
+	help("Declare it with an @namespace rule at the top of the stylesheet, or remove the prefix."),
+	code(hdx_parser::UndeclaredNamespacePrefix)
+)]
+pub struct UndeclaredNamespacePrefix(pub Atom, #[label("This prefix is unknown")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Values in an @{0} block can have at most {1} number(s).")]
+#[diagnostic(help("Try removing some of the numbers"), code(hdx_parser::TooManyFontFeatureValues))]
+pub struct TooManyFontFeatureValues(pub Atom, pub usize, #[label("This has too many numbers")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The name of a @font-palette-values rule must be a <dashed-ident>, but {0} doesn't start with two dashes.")]
+#[diagnostic(help("Try renaming it to --{0}"), code(hdx_parser::FontPaletteValuesNameMustBeDashed))]
+pub struct FontPaletteValuesNameMustBeDashed(pub Atom, #[label("Rename this")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A track list can have at most one repeat() using auto-fill or auto-fit.")]
+#[diagnostic(help("Try using a number of repetitions instead"), code(hdx_parser::TooManyAutoRepeats))]
+pub struct TooManyAutoRepeats(#[label("This is the second automatic repeat()")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A track list with an automatic repeat() can only contain fixed sizes.")]
+#[diagnostic(
+	help("Try using a <length-percentage>, or a minmax() with a <length-percentage>"),
+	code(hdx_parser::AutoRepeatWithIntrinsicSize)
+)]
+pub struct AutoRepeatWithIntrinsicSize(#[label("This size is not fixed")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("This grid area string contains characters that cannot name an area.")]
+#[diagnostic(
+	help("Area names must be identifiers, and empty cells must be one or more '.'"),
+	code(hdx_parser::InvalidGridAreaString)
+)]
+pub struct InvalidGridAreaString(#[label("This string")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Every row of a grid template must have the same number of columns, but this row has {1} rather than {0}.")]
+#[diagnostic(help("Try adding '.' cells to the shorter rows"), code(hdx_parser::GridAreaColumnsMismatch))]
+pub struct GridAreaColumnsMismatch(pub usize, pub usize, #[label("This row")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The grid area '{0}' does not form a single filled-in rectangle.")]
+#[diagnostic(
+	help("Try renaming the cells that sit outside of the rectangle"),
+	code(hdx_parser::GridAreaNotRectangular)
+)]
+pub struct GridAreaNotRectangular(pub Atom, #[label("This area")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("An OpenType tag must be exactly four printable ASCII characters.")]
+#[diagnostic(help("Try a tag such as \"liga\" or \"wght\""), code(hdx_parser::InvalidOpentypeTag))]
+pub struct InvalidOpentypeTag(#[label("This tag")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Only the final background layer can have a color.")]
+#[diagnostic(help("Try moving the color into the last layer"), code(hdx_parser::BackgroundColorNotInFinalLayer))]
+pub struct BackgroundColorNotInFinalLayer(#[label("This layer has a color")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("An @layer {{}} (block) rule cannot have multiple names.")]
 #[diagnostic(help(""), code(hdx_parser::DisallowedLayerBlockWithMultipleNames))]
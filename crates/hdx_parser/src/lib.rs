@@ -5,6 +5,8 @@ pub mod diagnostics;
 mod macros;
 mod parser;
 mod parser_return;
+mod pretty;
+mod source_map;
 pub mod token_macros;
 mod traits;
 
@@ -15,4 +17,6 @@ pub use hdx_lexer::Span;
 pub use miette::{Error, Result};
 pub use parser::*;
 pub use parser_return::*;
+pub use pretty::{is_declaration, IndentStyle, MathNesting, PrettyOptions};
+pub use source_map::*;
 pub use traits::*;
@@ -129,6 +129,11 @@ impl<'a> Parser<'a> {
 		ParserReturn::new(output, self.source_text, take(&mut self.errors), take(&mut self.trivia))
 	}
 
+	/// Record an error for a node which parsed, but is invalid in its wider context, without failing the parse.
+	pub fn push_error(&mut self, error: Error) {
+		self.errors.push(error);
+	}
+
 	pub fn parse<T: Parse<'a>>(&mut self) -> Result<T> {
 		T::parse(self)
 	}
@@ -149,6 +154,11 @@ impl<'a> Parser<'a> {
 		T::try_parse(self)
 	}
 
+	#[inline]
+	pub fn source_text(&self) -> &'a str {
+		self.source_text
+	}
+
 	#[inline]
 	pub fn parse_atom(&self, c: Cursor) -> hdx_atom::Atom {
 		c.parse_atom(self.lexer.source(), self.allocator)
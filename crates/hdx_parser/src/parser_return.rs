@@ -1,7 +1,9 @@
 use core::fmt;
 
-use crate::{CursorSink, Error, ToCursors};
-use hdx_lexer::{Cursor, Kind};
+use crate::{
+	pretty::PrettyWriter, CursorSink, CursorWrite, Error, IgnoreCursors, MathNesting, PrettyOptions, ToCursors,
+};
+use hdx_lexer::{CommentStyle, Cursor, Kind};
 
 #[derive(Debug)]
 pub struct ParserReturn<'a, T>
@@ -25,24 +27,51 @@ impl<'a, T: ToCursors> ParserReturn<'a, T> {
 		self
 	}
 
+	/// The comment naming the Source Map for the source text, and the URL it names, from the last
+	/// `/*# sourceMappingURL=... */` comment in [trivia][ParserReturn::trivia].
+	pub fn source_mapping_url(&self) -> Option<(Cursor, &'a str)> {
+		self.trivia.iter().rev().filter(|c| c.token().comment_style() == Some(CommentStyle::BlockPound)).find_map(|c| {
+			let text = c.str_slice(self.source_text);
+			let url = text.strip_prefix("/*#")?.strip_suffix("*/")?.trim().strip_prefix("sourceMappingURL=")?;
+			Some((*c, url.trim()))
+		})
+	}
+
 	pub fn write(&self, sink: &mut impl CursorSink, f: &mut impl fmt::Write) -> fmt::Result {
+		self.write_with_map(sink, &mut IgnoreCursors(f))
+	}
+
+	/// Like [write][ParserReturn::write], but `f` is told about each Cursor before it is written, for example so that
+	/// a [SourceMapWriter][crate::SourceMapWriter] can map the output back to the source text.
+	pub fn write_with_map(&self, sink: &mut impl CursorSink, f: &mut impl CursorWrite) -> fmt::Result {
 		if let Some(out) = &self.output {
 			ToCursors::to_cursors(out, sink);
 		}
 		let mut last_kind: Kind = Kind::Eof;
+		let mut math = MathNesting::default();
+		let mut last_operator = false;
 		let mut cursors = sink.iter_cursors().peekable();
 		while let Some(c) = cursors.next() {
 			let t = c.token();
 			let kind = t.kind();
-			if last_kind.ambiguous_without_whitespace() {
+			let operator = math.is_operator(*c);
+			if (operator && last_kind != Kind::Whitespace) || (last_operator && kind != Kind::Whitespace) {
+				f.write_char(' ')?;
+			} else if last_kind.ambiguous_without_whitespace() {
 				match kind {
-					Kind::Number if !t.has_sign() => {
+					Kind::Number | Kind::Dimension if !t.has_sign() => {
+						f.write_char(' ')?;
+					}
+					// A `-` would continue the preceding ident or dimension unit, e.g. `infinite -1`.
+					Kind::Number | Kind::Dimension
+						if matches!(last_kind, Kind::Ident | Kind::Dimension) && t.value().is_sign_negative() =>
+					{
 						f.write_char(' ')?;
 					}
 					// An ident with a trailing `(` is a Function token, always separate these with whitespace to ensure they're not
 					// accidentally combined into a single function token.
 					Kind::LeftParen if last_kind == Kind::Ident => f.write_char(' ')?,
-					Kind::Dimension | Kind::Ident | Kind::Function | Kind::Url => f.write_char(' ')?,
+					Kind::Ident | Kind::Function | Kind::Url => f.write_char(' ')?,
 					_ => {}
 				}
 			} else if last_kind == Kind::Ident && kind == Kind::LeftParen {
@@ -55,11 +84,42 @@ impl<'a, T: ToCursors> ParserReturn<'a, T> {
 					}
 				}
 			}
+			f.mark(*c);
 			c.write_str(self.source_text, f)?;
+			math.update(*c, self.source_text);
 			last_kind = kind;
+			last_operator = operator;
 		}
 		Ok(())
 	}
+
+	/// Writes the output with consistent indentation & whitespace, as described by `options`. Unlike
+	/// [write][ParserReturn::write], comments collected in [trivia][ParserReturn::trivia] are kept.
+	pub fn write_pretty(
+		&self,
+		sink: &mut impl CursorSink,
+		options: &PrettyOptions,
+		f: &mut impl fmt::Write,
+	) -> fmt::Result {
+		self.write_pretty_with_map(sink, options, &mut IgnoreCursors(f))
+	}
+
+	/// Like [write_pretty][ParserReturn::write_pretty], but `f` is told about each Cursor before it is written.
+	pub fn write_pretty_with_map(
+		&self,
+		sink: &mut impl CursorSink,
+		options: &PrettyOptions,
+		f: &mut impl CursorWrite,
+	) -> fmt::Result {
+		if let Some(out) = &self.output {
+			ToCursors::to_cursors(out, sink);
+		}
+		let cursors: Vec<Cursor> = sink.iter_cursors().copied().collect();
+		let mut comments: Vec<Cursor> = self.trivia.iter().filter(|c| **c == Kind::Comment).copied().collect();
+		comments.sort();
+		comments.dedup();
+		PrettyWriter::new(self.source_text, options, f).write(&cursors, &comments)
+	}
 }
 
 impl<T: ToCursors> ToCursors for ParserReturn<'_, T> {
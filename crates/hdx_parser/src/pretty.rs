@@ -0,0 +1,399 @@
+use core::fmt;
+
+use hdx_lexer::{Cursor, Kind, SourceOffset};
+
+use crate::CursorWrite;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IndentStyle {
+	Tabs,
+	Spaces(u8),
+}
+
+/// Options to control how [ParserReturn::write_pretty][crate::ParserReturn::write_pretty] lays out a StyleSheet.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PrettyOptions {
+	pub indent_style: IndentStyle,
+	/// Put each declaration (and nested rule) on its own line. When false, blocks are written on a single line.
+	pub newline_per_declaration: bool,
+	/// Write a space between a declaration's colon and its value, e.g. `color: red` rather than `color:red`.
+	pub space_after_colon: bool,
+	/// How many blank lines to leave between consecutive rules.
+	pub blank_lines_between_rules: u8,
+}
+
+impl Default for PrettyOptions {
+	fn default() -> Self {
+		Self {
+			indent_style: IndentStyle::Tabs,
+			newline_per_declaration: true,
+			space_after_colon: true,
+			blank_lines_between_rules: 1,
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Break {
+	None,
+	Space,
+	Lines(u8),
+}
+
+// Writes a stream of Cursors with consistent whitespace. The stream is expected to come from an AST which has already
+// been parsed, so this only needs to be concerned with the shape of blocks, statements & declarations, rather than
+// validating anything.
+pub(crate) struct PrettyWriter<'a, 'f, F: CursorWrite> {
+	source_text: &'a str,
+	options: &'a PrettyOptions,
+	f: &'f mut F,
+	depth: usize,
+	nesting: usize,
+	pending: Break,
+	at_line_start: bool,
+	at_statement_start: bool,
+	in_declaration: bool,
+	in_attribute_selector: bool,
+	expect_declaration_colon: bool,
+	last: Option<Cursor>,
+	// Typed values may produce Cursors without a source position, so keep track of the last one that has one.
+	last_in_source: Option<Cursor>,
+	math: MathNesting,
+}
+
+impl<'a, 'f, F: CursorWrite> PrettyWriter<'a, 'f, F> {
+	pub(crate) fn new(source_text: &'a str, options: &'a PrettyOptions, f: &'f mut F) -> Self {
+		Self {
+			source_text,
+			options,
+			f,
+			depth: 0,
+			nesting: 0,
+			pending: Break::None,
+			at_line_start: true,
+			at_statement_start: true,
+			in_declaration: false,
+			in_attribute_selector: false,
+			expect_declaration_colon: false,
+			last: None,
+			last_in_source: None,
+			math: MathNesting::default(),
+		}
+	}
+
+	pub(crate) fn write(mut self, cursors: &[Cursor], comments: &[Cursor]) -> fmt::Result {
+		let mut comments = comments.iter().peekable();
+		for (i, c) in cursors.iter().enumerate() {
+			let kind = c.token().kind();
+			if kind == Kind::RightCurly && self.in_declaration && self.options.newline_per_declaration {
+				self.f.write_char(';')?;
+				self.in_declaration = false;
+			}
+			// Cursors without a position in the source (for example ones created by a transform) can't be used to place comments.
+			while let Some(comment) =
+				comments.next_if(|comment| c.offset() != SourceOffset::DUMMY && comment.offset() <= c.offset())
+			{
+				if comment.offset() != c.offset() {
+					self.write_comment(*comment, Some(*c))?;
+				}
+			}
+			self.write_cursor(*c, &cursors[i + 1..])?;
+		}
+		for comment in comments {
+			self.write_comment(*comment, None)?;
+		}
+		if self.last.is_some() {
+			self.f.write_char('\n')?;
+		}
+		Ok(())
+	}
+
+	fn statement_break(&self) -> Break {
+		if self.options.newline_per_declaration {
+			Break::Lines(1)
+		} else {
+			Break::Space
+		}
+	}
+
+	fn rule_break(&self) -> Break {
+		if self.options.newline_per_declaration || self.depth == 0 {
+			Break::Lines(1 + self.options.blank_lines_between_rules)
+		} else {
+			Break::Space
+		}
+	}
+
+	fn flush(&mut self) -> fmt::Result {
+		match self.pending {
+			Break::Lines(n) if self.last.is_some() => {
+				for _ in 0..n {
+					self.f.write_char('\n')?;
+				}
+				for _ in 0..self.depth {
+					match self.options.indent_style {
+						IndentStyle::Tabs => self.f.write_char('\t')?,
+						IndentStyle::Spaces(n) => {
+							for _ in 0..n {
+								self.f.write_char(' ')?;
+							}
+						}
+					}
+				}
+				self.at_line_start = true;
+			}
+			Break::Space if !self.at_line_start => {
+				self.f.write_char(' ')?;
+			}
+			_ => {}
+		}
+		self.pending = Break::None;
+		Ok(())
+	}
+
+	fn emit(&mut self, c: Cursor) -> fmt::Result {
+		self.flush()?;
+		self.f.mark(c);
+		c.write_str(self.source_text, self.f)?;
+		self.at_line_start = false;
+		self.last = Some(c);
+		if c.offset() != SourceOffset::DUMMY {
+			self.last_in_source = Some(c);
+		}
+		Ok(())
+	}
+
+	fn space(&mut self) {
+		if self.pending == Break::None {
+			self.pending = Break::Space;
+		}
+	}
+
+	// Returns the source text between two cursors, if they are both real cursors in source order.
+	fn gap(&self, start: SourceOffset, end: SourceOffset) -> Option<&'a str> {
+		if start == SourceOffset::DUMMY || end == SourceOffset::DUMMY || start.0 > end.0 {
+			return None;
+		}
+		self.source_text.get(start.0 as usize..end.0 as usize)
+	}
+
+	fn write_comment(&mut self, comment: Cursor, next: Option<Cursor>) -> fmt::Result {
+		let same_line = self
+			.last_in_source
+			.and_then(|last| self.gap(last.end_offset(), comment.offset()))
+			.is_some_and(|gap| !gap.contains('\n'));
+		let pending = self.pending;
+		if same_line {
+			self.pending = Break::Space;
+			self.emit(comment)?;
+			self.pending = if pending == Break::None { Break::Space } else { pending };
+		} else {
+			if !matches!(pending, Break::Lines(_)) {
+				self.pending = Break::Lines(1);
+			}
+			self.emit(comment)?;
+			// Keep comments which are separated from the following code by a blank line apart from it.
+			let blank_line = next
+				.and_then(|next| self.gap(comment.end_offset(), next.offset()))
+				.is_some_and(|gap| gap.matches('\n').count() > 1);
+			self.pending = Break::Lines(if blank_line { 2 } else { 1 });
+		}
+		Ok(())
+	}
+
+	fn write_cursor(&mut self, c: Cursor, rest: &[Cursor]) -> fmt::Result {
+		let kind = c.token().kind();
+		let at_statement_start = self.at_statement_start;
+		self.at_statement_start = false;
+		self.math.update(c, self.source_text);
+		match kind {
+			Kind::Whitespace => {
+				if !self.last.is_some_and(|last| {
+					matches!(last.token().kind(), Kind::LeftParen | Kind::LeftSquare | Kind::Function)
+				}) {
+					self.space();
+				}
+				self.at_statement_start = at_statement_start;
+				return Ok(());
+			}
+			Kind::LeftCurly => {
+				self.space();
+				self.emit(c)?;
+				self.depth += 1;
+				self.in_declaration = false;
+				self.at_statement_start = true;
+				self.pending = if rest.first().is_some_and(|next| *next == Kind::RightCurly) {
+					Break::None
+				} else {
+					self.statement_break()
+				};
+				return Ok(());
+			}
+			Kind::RightCurly => {
+				self.depth = self.depth.saturating_sub(1);
+				if self.last.is_none_or(|last| last != Kind::LeftCurly) {
+					self.pending = self.statement_break();
+				}
+				self.emit(c)?;
+				self.in_declaration = false;
+				self.at_statement_start = true;
+				self.pending = match rest.first() {
+					Some(next) if *next == Kind::RightCurly => self.statement_break(),
+					Some(_) => self.rule_break(),
+					None => Break::None,
+				};
+				return Ok(());
+			}
+			Kind::Semicolon if self.nesting == 0 => {
+				self.pending = Break::None;
+				self.emit(c)?;
+				self.in_declaration = false;
+				self.at_statement_start = true;
+				self.pending = if self.depth == 0 { Break::Lines(1) } else { self.statement_break() };
+				return Ok(());
+			}
+			Kind::Ident if at_statement_start && self.depth > 0 && is_declaration(rest) => {
+				self.in_declaration = true;
+				self.expect_declaration_colon = true;
+				return self.emit(c);
+			}
+			Kind::Colon if self.expect_declaration_colon => {
+				self.expect_declaration_colon = false;
+				self.pending = Break::None;
+				self.emit(c)?;
+				if self.options.space_after_colon {
+					self.pending = Break::Space;
+				}
+				return Ok(());
+			}
+			Kind::Comma => {
+				self.pending = Break::None;
+				self.emit(c)?;
+				self.pending = Break::Space;
+				return Ok(());
+			}
+			Kind::RightParen | Kind::RightSquare => {
+				self.nesting = self.nesting.saturating_sub(1);
+				self.in_attribute_selector = false;
+				self.pending = Break::None;
+				return self.emit(c);
+			}
+			Kind::Delim
+				if !self.in_declaration && self.nesting == 0 && matches!(c.token().char(), Some('>' | '+' | '~')) =>
+			{
+				self.space();
+				self.emit(c)?;
+				self.pending = Break::Space;
+				return Ok(());
+			}
+			Kind::Delim if self.in_declaration && self.nesting == 0 && c.token().char() == Some('!') => {
+				self.space();
+				return self.emit(c);
+			}
+			// Comparisons within a condition, e.g. `(width >= 1px)`. `<=` & `>=` are two Delims which must stay together.
+			Kind::Delim
+				if !self.in_declaration
+					&& !self.in_attribute_selector
+					&& self.nesting > 0
+					&& matches!(c.token().char(), Some('<' | '>' | '=')) =>
+			{
+				if self.last.is_some_and(|last| last == Kind::Delim && matches!(last.token().char(), Some('<' | '>'))) {
+					self.pending = Break::None;
+				} else {
+					self.space();
+				}
+				self.emit(c)?;
+				self.pending =
+					if rest.first().is_some_and(|next| *next == Kind::Delim && next.token().char() == Some('=')) {
+						Break::None
+					} else {
+						Break::Space
+					};
+				return Ok(());
+			}
+			Kind::Delim if self.math.is_operator(c) => {
+				self.space();
+				self.emit(c)?;
+				self.pending = Break::Space;
+				return Ok(());
+			}
+			_ => {}
+		}
+		if let Some(last) = self.last {
+			let last_kind = last.token().kind();
+			let opens = matches!(last_kind, Kind::LeftParen | Kind::LeftSquare | Kind::Function);
+			let spaced_in_source = !opens && self.gap(last.end_offset(), c.offset()).is_some_and(|gap| !gap.is_empty());
+			let ambiguous = last_kind.ambiguous_without_whitespace()
+				&& (matches!(kind, Kind::Ident | Kind::Function | Kind::Url)
+					|| (matches!(kind, Kind::Number | Kind::Dimension) && !c.token().has_sign())
+					|| (matches!(kind, Kind::Number | Kind::Dimension)
+						&& matches!(last_kind, Kind::Ident | Kind::Dimension)
+						&& c.token().value().is_sign_negative()));
+			if spaced_in_source || ambiguous {
+				self.space();
+			}
+		}
+		self.emit(c)?;
+		if kind == Kind::LeftSquare {
+			self.in_attribute_selector = !self.in_declaration;
+		}
+		if matches!(kind, Kind::LeftParen | Kind::LeftSquare | Kind::Function) {
+			self.nesting += 1;
+			self.pending = Break::None;
+		}
+		Ok(())
+	}
+}
+
+/// Whether the Cursors following an Ident at the start of a statement make it a declaration. A statement which starts
+/// with an Ident followed by a colon is a declaration, unless a `{` appears before the end of the statement, in which
+/// case it is a nested rule with a pseudo class, e.g. `a:hover {}`.
+pub fn is_declaration(rest: &[Cursor]) -> bool {
+	if rest.first().map(|c| c.token().kind()) != Some(Kind::Colon) {
+		return false;
+	}
+	let mut nesting = 0;
+	for c in rest {
+		match c.token().kind() {
+			Kind::LeftParen | Kind::LeftSquare | Kind::Function => nesting += 1,
+			Kind::RightParen | Kind::RightSquare => nesting -= 1,
+			Kind::LeftCurly if nesting == 0 => return false,
+			Kind::Semicolon | Kind::RightCurly if nesting == 0 => return true,
+			_ => {}
+		}
+	}
+	true
+}
+
+// https://drafts.csswg.org/css-values-4/#math-function
+const MATH_FUNCTIONS: [&str; 21] = [
+	"calc", "min", "max", "clamp", "round", "mod", "rem", "sin", "cos", "tan", "asin", "acos", "atan", "atan2", "pow",
+	"sqrt", "hypot", "log", "exp", "abs", "sign",
+];
+
+/// Keeps track of whether the Cursors being written are within a math function such as `calc()`, where the `+` & `-`
+/// operators must be surrounded by whitespace, otherwise they would be read as the sign of the following number. Each
+/// Cursor should be given to [update][MathNesting::update] as it is written.
+#[derive(Debug, Default, Clone)]
+pub struct MathNesting(Vec<bool>);
+
+impl MathNesting {
+	pub fn update(&mut self, c: Cursor, source_text: &str) {
+		match c.token().kind() {
+			Kind::Function => self.0.push(
+				c.offset() != SourceOffset::DUMMY
+					&& MATH_FUNCTIONS.iter().any(|name| c.eq_ignore_ascii_case(source_text, name)),
+			),
+			Kind::LeftParen => self.0.push(self.0.last() == Some(&true)),
+			Kind::RightParen => {
+				self.0.pop();
+			}
+			_ => {}
+		}
+	}
+
+	/// Whether `c` is a `+` or `-` operator which needs whitespace on either side.
+	pub fn is_operator(&self, c: Cursor) -> bool {
+		self.0.last() == Some(&true) && c == Kind::Delim && matches!(c.token().char(), Some('+' | '-'))
+	}
+}
@@ -0,0 +1,458 @@
+use core::fmt;
+
+use hdx_lexer::{Cursor, SourceOffset};
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use crate::CursorWrite;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A position in the output which came from a position in one of the [sources][SourceMap::sources]. Lines and columns
+/// are zero based, and columns are counted in UTF-16 code units, as required by the Source Map format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mapping {
+	pub generated_line: u32,
+	pub generated_column: u32,
+	pub source: u32,
+	pub original_line: u32,
+	pub original_column: u32,
+}
+
+/// A [Source Map v3](https://tc39.es/ecma426/), which maps positions in some written CSS back to the files it was
+/// parsed from. These are usually built with a [SourceMapWriter].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct SourceMap {
+	/// The name of the file this maps, written as `file`.
+	pub file: Option<String>,
+	pub sources: Vec<String>,
+	pub sources_content: Vec<Option<String>>,
+	mappings: Vec<Mapping>,
+}
+
+impl SourceMap {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a source file, returning the index Mappings should use to refer to it.
+	pub fn add_source(&mut self, name: &str, content: Option<&str>) -> u32 {
+		self.sources.push(name.to_owned());
+		self.sources_content.push(content.map(str::to_owned));
+		self.sources.len() as u32 - 1
+	}
+
+	/// Adds a mapping. Mappings must be added in the order they appear in the output.
+	pub fn add_mapping(&mut self, mapping: Mapping) {
+		self.mappings.push(mapping);
+	}
+
+	pub fn mappings(&self) -> &[Mapping] {
+		&self.mappings
+	}
+
+	/// The `mappings` field: each line of the output separated by `;`, each segment within a line separated by `,`, and
+	/// each segment written as Base64 VLQs relative to the segment before it.
+	pub fn encode_mappings(&self) -> String {
+		let mut str = String::new();
+		let mut line = 0;
+		let mut column = 0;
+		let (mut source, mut original_line, mut original_column) = (0, 0, 0);
+		for mapping in &self.mappings {
+			if mapping.generated_line != line {
+				for _ in line..mapping.generated_line {
+					str.push(';');
+				}
+				line = mapping.generated_line;
+				column = 0;
+			} else if !str.is_empty() && !str.ends_with(';') {
+				str.push(',');
+			}
+			encode_vlq(&mut str, mapping.generated_column as i64 - column as i64);
+			encode_vlq(&mut str, mapping.source as i64 - source as i64);
+			encode_vlq(&mut str, mapping.original_line as i64 - original_line as i64);
+			encode_vlq(&mut str, mapping.original_column as i64 - original_column as i64);
+			column = mapping.generated_column;
+			source = mapping.source;
+			original_line = mapping.original_line;
+			original_column = mapping.original_column;
+		}
+		str
+	}
+
+	pub fn to_json(&self) -> String {
+		let mut str = String::from("{\"version\":3");
+		if let Some(file) = &self.file {
+			str.push_str(",\"file\":");
+			write_json_string(&mut str, file);
+		}
+		str.push_str(",\"sources\":[");
+		for (i, source) in self.sources.iter().enumerate() {
+			if i > 0 {
+				str.push(',');
+			}
+			write_json_string(&mut str, source);
+		}
+		str.push_str("],\"sourcesContent\":[");
+		for (i, content) in self.sources_content.iter().enumerate() {
+			if i > 0 {
+				str.push(',');
+			}
+			match content {
+				Some(content) => write_json_string(&mut str, content),
+				None => str.push_str("null"),
+			}
+		}
+		str.push_str("],\"names\":[],\"mappings\":\"");
+		str.push_str(&self.encode_mappings());
+		str.push_str("\"}");
+		str
+	}
+
+	/// The map as a `data:` URL, for inlining into a `sourceMappingURL` comment.
+	pub fn to_data_url(&self) -> String {
+		let json = self.to_json();
+		let mut str = String::from("data:application/json;charset=utf-8;base64,");
+		for chunk in json.as_bytes().chunks(3) {
+			let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+			let n = (bytes[0] as usize) << 16 | (bytes[1] as usize) << 8 | bytes[2] as usize;
+			for i in 0..4 {
+				if i <= chunk.len() {
+					str.push(BASE64[(n >> (18 - i * 6)) & 0x3f] as char);
+				} else {
+					str.push('=');
+				}
+			}
+		}
+		str
+	}
+
+	/// Reads a Source Map v3 from its JSON. Index maps (maps with `sections`) aren't supported.
+	#[cfg(feature = "serde_json")]
+	pub fn from_json(json: &str) -> Result<Self, String> {
+		let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+		if value.get("version").and_then(Value::as_u64) != Some(3) {
+			return Err("only version 3 source maps are supported".into());
+		}
+		if value.get("sections").is_some() {
+			return Err("index maps are not supported".into());
+		}
+		let source_root = match value.get("sourceRoot").and_then(Value::as_str) {
+			Some(root) if !root.is_empty() && !root.ends_with('/') => format!("{}/", root),
+			Some(root) => root.to_owned(),
+			None => String::new(),
+		};
+		let mut map = Self { file: value.get("file").and_then(Value::as_str).map(str::to_owned), ..Self::default() };
+		let contents = value.get("sourcesContent").and_then(Value::as_array);
+		for (i, source) in value.get("sources").and_then(Value::as_array).into_iter().flatten().enumerate() {
+			let name = format!("{}{}", source_root, source.as_str().unwrap_or_default());
+			let content = contents.and_then(|contents| contents.get(i)).and_then(Value::as_str);
+			map.add_source(&name, content);
+		}
+		let mappings = value.get("mappings").and_then(Value::as_str).ok_or("missing mappings")?;
+		map.mappings = decode_mappings(mappings, map.sources.len() as u32)?;
+		Ok(map)
+	}
+
+	/// Reads a Source Map from a `data:` URL, as found in an inline `sourceMappingURL` comment.
+	#[cfg(feature = "serde_json")]
+	pub fn from_data_url(url: &str) -> Result<Self, String> {
+		let (header, data) = url.strip_prefix("data:").and_then(|url| url.split_once(',')).ok_or("invalid data URL")?;
+		let bytes = if header.ends_with(";base64") { decode_base64(data)? } else { decode_percent(data) };
+		Self::from_json(&String::from_utf8(bytes).map_err(|e| e.to_string())?)
+	}
+
+	/// Finds where a position in the output came from: the mapping on the same line which starts at, or most closely
+	/// before, the column.
+	pub fn original_position(&self, line: u32, column: u32) -> Option<&Mapping> {
+		let i = self.mappings.partition_point(|m| (m.generated_line, m.generated_column) <= (line, column));
+		self.mappings[..i].last().filter(|m| m.generated_line == line)
+	}
+
+	/// Maps the positions which point at `source` through `input`, a Source Map for that source. This makes a map for
+	/// output written from already compiled CSS point at the files it was originally compiled from. Positions `input`
+	/// has no mapping for are dropped, as they only exist in the compiled CSS.
+	pub fn compose(&mut self, source: u32, input: &SourceMap) {
+		let mut sources = vec![];
+		let mut sources_content = vec![];
+		let mut indices = vec![];
+		for (name, content) in
+			self.sources.iter().zip(&self.sources_content).chain(input.sources.iter().zip(&input.sources_content))
+		{
+			if indices.len() == source as usize {
+				indices.push(None);
+				continue;
+			}
+			let index = sources.iter().position(|existing| existing == name).unwrap_or_else(|| {
+				sources.push(name.clone());
+				sources_content.push(content.clone());
+				sources.len() - 1
+			});
+			indices.push(Some(index as u32));
+		}
+		let offset = self.sources.len();
+		self.mappings = self
+			.mappings
+			.iter()
+			.filter_map(|mapping| {
+				if mapping.source != source {
+					return Some(Mapping { source: indices[mapping.source as usize]?, ..*mapping });
+				}
+				let original = input.original_position(mapping.original_line, mapping.original_column)?;
+				Some(Mapping {
+					source: indices[offset + original.source as usize]?,
+					original_line: original.original_line,
+					// Where the output maps into the middle of an input segment, keep the offset into that segment.
+					original_column: original.original_column + (mapping.original_column - original.generated_column),
+					..*mapping
+				})
+			})
+			.collect();
+		self.sources = sources;
+		self.sources_content = sources_content;
+	}
+}
+
+// https://tc39.es/ecma426/#sec-base64-vlq
+fn encode_vlq(str: &mut String, value: i64) {
+	let mut vlq = if value < 0 { ((-value) << 1) | 1 } else { value << 1 };
+	loop {
+		let mut digit = vlq & 0x1f;
+		vlq >>= 5;
+		if vlq > 0 {
+			digit |= 0x20;
+		}
+		str.push(BASE64[digit as usize] as char);
+		if vlq == 0 {
+			break;
+		}
+	}
+}
+
+#[cfg(feature = "serde_json")]
+fn decode_vlq(chars: &mut core::iter::Peekable<core::str::Bytes>) -> Result<i64, String> {
+	let mut value = 0i64;
+	let mut shift = 0;
+	loop {
+		let char = chars.next().ok_or("unexpected end of mappings")?;
+		let digit = BASE64.iter().position(|b| *b == char).ok_or("invalid character in mappings")? as i64;
+		if shift > 60 {
+			return Err("number too large in mappings".into());
+		}
+		value |= (digit & 0x1f) << shift;
+		shift += 5;
+		if digit & 0x20 == 0 {
+			break;
+		}
+	}
+	Ok(if value & 1 == 1 { -(value >> 1) } else { value >> 1 })
+}
+
+#[cfg(feature = "serde_json")]
+fn decode_mappings(str: &str, sources: u32) -> Result<Vec<Mapping>, String> {
+	let mut mappings = vec![];
+	let (mut source, mut original_line, mut original_column) = (0i64, 0i64, 0i64);
+	for (line, segments) in str.split(';').enumerate() {
+		let mut column = 0i64;
+		for segment in segments.split(',').filter(|segment| !segment.is_empty()) {
+			let mut chars = segment.bytes().peekable();
+			let mut fields = vec![];
+			while chars.peek().is_some() {
+				fields.push(decode_vlq(&mut chars)?);
+			}
+			column += fields[0];
+			// Segments with a single field mark output which doesn't come from any source.
+			if fields.len() < 4 {
+				continue;
+			}
+			source += fields[1];
+			original_line += fields[2];
+			original_column += fields[3];
+			if column < 0 || source < 0 || source >= sources as i64 || original_line < 0 || original_column < 0 {
+				return Err("mappings point outside of the sources".into());
+			}
+			mappings.push(Mapping {
+				generated_line: line as u32,
+				generated_column: column as u32,
+				source: source as u32,
+				original_line: original_line as u32,
+				original_column: original_column as u32,
+			});
+		}
+	}
+	mappings.sort();
+	Ok(mappings)
+}
+
+#[cfg(feature = "serde_json")]
+fn decode_base64(str: &str) -> Result<Vec<u8>, String> {
+	let mut bytes = vec![];
+	let mut buffer = 0u32;
+	let mut bits = 0;
+	for char in str.bytes().filter(|b| !b.is_ascii_whitespace() && *b != b'=') {
+		let digit = BASE64.iter().position(|b| *b == char).ok_or("invalid base64")?;
+		buffer = buffer << 6 | digit as u32;
+		bits += 6;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+		}
+	}
+	Ok(bytes)
+}
+
+#[cfg(feature = "serde_json")]
+fn decode_percent(str: &str) -> Vec<u8> {
+	let mut bytes = vec![];
+	let mut i = 0;
+	while i < str.len() {
+		let hex = str.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+		match hex {
+			Some(value) if str.as_bytes()[i] == b'%' => {
+				bytes.push(value);
+				i += 3;
+			}
+			_ => {
+				bytes.push(str.as_bytes()[i]);
+				i += 1;
+			}
+		}
+	}
+	bytes
+}
+
+fn write_json_string(str: &mut String, value: &str) {
+	str.push('"');
+	for char in value.chars() {
+		match char {
+			'"' => str.push_str("\\\""),
+			'\\' => str.push_str("\\\\"),
+			'\n' => str.push_str("\\n"),
+			'\r' => str.push_str("\\r"),
+			'\t' => str.push_str("\\t"),
+			c if (c as u32) < 0x20 => str.push_str(&format!("\\u{:04x}", c as u32)),
+			c => str.push(c),
+		}
+	}
+	str.push('"');
+}
+
+struct MappedSource<'a> {
+	index: u32,
+	source_text: &'a str,
+	line_starts: Vec<u32>,
+}
+
+impl MappedSource<'_> {
+	fn position(&self, offset: u32) -> (u32, u32) {
+		let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+		let line_start = self.line_starts[line] as usize;
+		let column = self.source_text[line_start..offset as usize].encode_utf16().count();
+		(line as u32, column as u32)
+	}
+}
+
+// A run of the text Cursors were parsed from, which was copied from `source` starting at `source_start`.
+struct MappedRange {
+	start: u32,
+	end: u32,
+	source: usize,
+	source_start: u32,
+}
+
+/// Wraps a [fmt::Write], recording a [Mapping] for every Cursor written through it which has a position in one of its
+/// sources. Cursors without a position (for example those created by a transform) are written without a Mapping, so
+/// they're attributed to whichever token came before them.
+///
+/// Each source is expected to follow the last in the source text Cursors were parsed from, so a single source starts
+/// at offset 0, while several sources may be parsed from one concatenated string. Where the source text was stitched
+/// together from parts of sources, such as when bundling, each part can instead be given with
+/// [add_range][SourceMapWriter::add_range].
+pub struct SourceMapWriter<'a, F: fmt::Write> {
+	f: F,
+	map: SourceMap,
+	sources: Vec<MappedSource<'a>>,
+	ranges: Vec<MappedRange>,
+	line: u32,
+	column: u32,
+}
+
+impl<'a, F: fmt::Write> SourceMapWriter<'a, F> {
+	pub fn new(f: F) -> Self {
+		Self { f, map: SourceMap::new(), sources: vec![], ranges: vec![], line: 0, column: 0 }
+	}
+
+	/// Adds a source named `name`, which starts where the previous source ended.
+	pub fn with_source(mut self, name: &str, source_text: &'a str) -> Self {
+		let start = self.ranges.last().map_or(0, |range| range.end);
+		let source = self.add_source(name, source_text);
+		self.add_range(start, source, 0..source_text.len() as u32);
+		self
+	}
+
+	/// Adds a source named `name` without placing it in the source text, returning the index to use with
+	/// [add_range][SourceMapWriter::add_range].
+	pub fn add_source(&mut self, name: &str, source_text: &'a str) -> usize {
+		let line_starts =
+			core::iter::once(0).chain(source_text.match_indices('\n').map(|(i, _)| i as u32 + 1)).collect();
+		let index = self.map.add_source(name, Some(source_text));
+		self.sources.push(MappedSource { index, source_text, line_starts });
+		self.sources.len() - 1
+	}
+
+	/// Records that the source text starting at `start` was copied from `range` within `source`. Ranges must be added
+	/// in the order they appear in the source text, and text outside of any range isn't mapped.
+	pub fn add_range(&mut self, start: u32, source: usize, range: core::ops::Range<u32>) {
+		debug_assert!(self.ranges.last().is_none_or(|last| last.end <= start));
+		let end = start + (range.end - range.start);
+		self.ranges.push(MappedRange { start, end, source, source_start: range.start });
+	}
+
+	pub fn finish(self) -> (F, SourceMap) {
+		(self.f, self.map)
+	}
+}
+
+impl<F: fmt::Write> fmt::Write for SourceMapWriter<'_, F> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		for (i, line) in s.split('\n').enumerate() {
+			if i > 0 {
+				self.line += 1;
+				self.column = 0;
+			}
+			self.column += line.encode_utf16().count() as u32;
+		}
+		self.f.write_str(s)
+	}
+}
+
+impl<F: fmt::Write> CursorWrite for SourceMapWriter<'_, F> {
+	fn mark(&mut self, cursor: Cursor) {
+		let offset = cursor.offset();
+		if offset == SourceOffset::DUMMY {
+			return;
+		}
+		let i = self.ranges.partition_point(|range| range.start <= offset.0);
+		let Some(range) = i.checked_sub(1).map(|i| &self.ranges[i]) else { return };
+		// Transforms may append text after the sources, which can't be mapped back to anything.
+		if offset.0 >= range.end {
+			return;
+		}
+		let source = &self.sources[range.source];
+		let (original_line, original_column) = source.position(offset.0 - range.start + range.source_start);
+		let mapping = Mapping {
+			generated_line: self.line,
+			generated_column: self.column,
+			source: source.index,
+			original_line,
+			original_column,
+		};
+		// Only one mapping is needed per position, for example where a pretty printer writes a comment & token together.
+		if self.map.mappings.last().is_some_and(|last| {
+			last.generated_line == mapping.generated_line && last.generated_column == mapping.generated_column
+		}) {
+			return;
+		}
+		self.map.add_mapping(mapping);
+	}
+}
@@ -67,10 +67,10 @@ pub trait RangedFeature<'a>: Sized {
 
 #[macro_export]
 macro_rules! ranged_feature {
-	($feat: tt[atom!($atom: tt)], $ty: ty) => {
+	($feat: ident$(<$lt: lifetime>)?[atom!($atom: tt)], $ty: ty) => {
 		#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 		#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-		pub enum $feat {
+		pub enum $feat$(<$lt>)? {
 			Left($crate::T![Ident], $crate::Comparison, $ty),
 			Right($ty, $crate::Comparison, $crate::T![Ident]),
 			Range($ty, $crate::Comparison, $crate::T![Ident], $crate::Comparison, $ty),
@@ -79,14 +79,14 @@ macro_rules! ranged_feature {
 	Legacy($crate::T![Ident], $crate::T![:], $ty),
 		}
 
-		impl<'a> $crate::Parse<'a> for $feat {
+		impl<'a> $crate::Parse<'a> for $feat$(<$lt>)? {
 			fn parse(p: &mut $crate::Parser<'a>) -> $crate::Result<Self> {
 				use $crate::RangedFeature;
 				Self::parse_ranged_feature(p, hdx_atom::atom!($atom))
 			}
 		}
 
-		impl<'a> $crate::RangedFeature<'a> for $feat {
+		impl<'a> $crate::RangedFeature<'a> for $feat$(<$lt>)? {
 			type Type = $ty;
 
 			fn new_legacy(
@@ -123,7 +123,7 @@ macro_rules! ranged_feature {
 			}
 		}
 
-		impl<'a> $crate::ToCursors for $feat {
+		impl<'a> $crate::ToCursors for $feat$(<$lt>)? {
 			fn to_cursors(&self, s: &mut impl $crate::CursorSink) {
 				match self {
 					Self::Left(ident, comparison, value) => {
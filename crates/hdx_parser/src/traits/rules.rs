@@ -297,6 +297,12 @@ mod kw {
 	custom_keyword!(Not, atom!("not"));
 }
 
+// https://drafts.csswg.org/mediaqueries-4/#mq-syntax
+// https://drafts.csswg.org/css-conditional-3/#typedef-supports-condition
+// <condition> = not <in-parens> | <in-parens> [ and <in-parens> ]* | <in-parens> [ or <in-parens> ]*
+// <in-parens> = ( <condition> ) | <feature>
+// The keywords are kept alongside each feature (like the commas of a comma separated list) so they can be written back.
+// Only the first <in-parens> of a condition may be a nested ( <condition> ), the rest must be features.
 pub trait ConditionalAtRule<'a>: Sized + Parse<'a>
 where
 	Self: 'a,
@@ -304,56 +310,53 @@ where
 	type Feature: Sized + Parse<'a>;
 
 	fn new_is(feature: Self::Feature) -> Self;
-	fn new_not(features: Self) -> Self;
-	fn new_and(features: Vec<'a, Self::Feature>) -> Self;
-	fn new_or(features: Vec<'a, Self::Feature>) -> Self;
+	fn new_not(keyword: T![Ident], condition: Self) -> Self;
+	fn new_and(features: Vec<'a, (Self::Feature, Option<T![Ident]>)>) -> Self;
+	fn new_or(features: Vec<'a, (Self::Feature, Option<T![Ident]>)>) -> Self;
+	fn new_parens(open: T!['('], condition: Self, close: T![')']) -> Self;
 
 	fn parse_condition(p: &mut Parser<'a>) -> Result<Self> {
-		// handle double parens
-		let mut wrapped = true;
-		let feature = if p.peek::<T!['(']>() {
-			let checkpoint = p.checkpoint();
-			p.parse::<T!['(']>()?;
-			if !p.peek::<T!['(']>() {
-				wrapped = false;
-				p.rewind(checkpoint);
-			}
-			Some(p.parse::<Self::Feature>()?)
-		} else {
-			None
-		};
-		let mut features = Vec::new_in(p.bump());
-		if let Some(feature) = feature {
-			if !p.peek::<T![Ident]>() {
-				return Ok(Self::new_is(feature));
-			}
-			features.push(feature);
+		if p.peek::<kw::Not>() {
+			let keyword = p.parse::<T![Ident]>()?;
+			return Ok(Self::new_not(keyword, Self::parse_in_parens(p)?));
 		}
+		if Self::peek_parens(p) {
+			return Self::parse_in_parens(p);
+		}
+		let mut feature = p.parse::<Self::Feature>()?;
+		let mut features = Vec::new_in(p.bump());
 		if p.peek::<kw::And>() {
-			loop {
-				p.parse::<kw::And>()?;
-				features.push(p.parse::<Self::Feature>()?);
-				if !p.peek::<kw::And>() {
-					if wrapped {
-						p.parse::<T![')']>()?;
-					}
-					return Ok(Self::new_and(features));
-				}
+			while p.peek::<kw::And>() {
+				features.push((feature, Some(p.parse::<T![Ident]>()?)));
+				feature = p.parse::<Self::Feature>()?;
 			}
+			features.push((feature, None));
+			Ok(Self::new_and(features))
 		} else if p.peek::<kw::Or>() {
-			loop {
-				p.parse::<kw::Or>()?;
-				features.push(p.parse::<Self::Feature>()?);
-				if !p.peek::<kw::Or>() {
-					if wrapped {
-						p.parse::<T![')']>()?;
-					}
-					return Ok(Self::new_or(features));
-				}
+			while p.peek::<kw::Or>() {
+				features.push((feature, Some(p.parse::<T![Ident]>()?)));
+				feature = p.parse::<Self::Feature>()?;
 			}
+			features.push((feature, None));
+			Ok(Self::new_or(features))
 		} else {
-			p.parse::<kw::Not>()?;
-			Ok(Self::new_not(p.parse::<Self>()?))
+			Ok(Self::new_is(feature))
+		}
+	}
+
+	fn parse_in_parens(p: &mut Parser<'a>) -> Result<Self> {
+		if Self::peek_parens(p) {
+			let open = p.parse::<T!['(']>()?;
+			let condition = Self::parse_condition(p)?;
+			let close = p.parse::<T![')']>()?;
+			return Ok(Self::new_parens(open, condition, close));
 		}
+		Ok(Self::new_is(p.parse::<Self::Feature>()?))
+	}
+
+	// A nested condition starts with another `(`, or `not`, where a feature would start with its name.
+	fn peek_parens(p: &Parser<'a>) -> bool {
+		let c = p.peek_n(2);
+		p.peek::<T!['(']>() && (c == Kind::LeftParen || (c == Kind::Ident && p.eq_ignore_ascii_case(c, "not")))
 	}
 }
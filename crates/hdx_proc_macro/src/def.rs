@@ -15,7 +15,7 @@ use syn::{
 	Result, Token, Visibility,
 };
 
-use crate::{kebab, pascal};
+use crate::{kebab, pascal, snake};
 
 pub(crate) struct StrWrapped<T: Parse>(pub T);
 impl<T: Parse> Parse for StrWrapped<T> {
@@ -45,6 +45,12 @@ pub trait GenerateToCursorsImpl {
 	}
 }
 
+pub trait GenerateVisitImpl {
+	// Steps to visit each visitable value within `capture`, or None if it holds nothing worth visiting. `accept` is the
+	// path to either `Visitable::accept` or `VisitableMut::accept_mut`.
+	fn visit_steps(&self, capture: TokenStream, accept: &TokenStream) -> Option<TokenStream>;
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum Def {
 	Ident(DefIdent),
@@ -494,6 +500,22 @@ impl Def {
 				let (keywords, others): (Vec<&Def>, Vec<&Def>) = opts.iter().partition(|def| {
 					matches!(def, Def::Ident(_) | Def::Type(DefType::CustomIdent) | Def::Type(DefType::DashedIdent))
 				});
+				// A math function which resolves to a number can't be a dimension, and the number alternative can't hold
+				// a math function, so rather than reporting a type mismatch on the dimension it fails the parse, leaving
+				// the declaration to be kept as a computed value.
+				let has_number =
+					others.iter().any(|def| matches!(def, Def::Type(DefType::Number(_) | DefType::Integer(_))));
+				let has_dimension = others
+					.iter()
+					.any(|def| matches!(def, Def::Type(DefType::Length(_) | DefType::LengthPercentage(_))));
+				let number_math_if = (has_number && has_dimension).then(|| {
+					quote! {
+						if p.peek::<crate::css::types::MathFunction>() && crate::css::types::MathFunction::peek_number(p) {
+							let c: ::hdx_lexer::Cursor = p.parse::<::hdx_parser::T![Any]>()?.into();
+							Err(::hdx_parser::diagnostics::Unexpected(c.into(), c.into()))?
+						}
+					}
+				});
 				let other_if: Vec<TokenStream> = others
 					.into_iter()
 					.with_position()
@@ -589,6 +611,7 @@ impl Def {
 				} else {
 					quote! {
 						#keyword_if
+						#number_math_if
 						#(#other_if)*;
 							let c: ::hdx_lexer::Cursor = p.parse::<::hdx_parser::T![Any]>()?.into();
 							Err(::hdx_parser::diagnostics::Unexpected(c.into(), c.into()))?
@@ -841,6 +864,132 @@ impl Def {
 			}
 		}
 	}
+
+	pub fn generate_visitable_trait_implementation(&self, ident: &Ident, generics: &mut Generics) -> TokenStream {
+		if self.requires_allocator_lifetime() && !generics.lifetimes().any(|l| l.lifetime.ident == "a") {
+			let lt = Lifetime::new("'a", Span::call_site());
+			generics.params.push(GenericParam::from(LifetimeParam::new(lt)));
+		}
+		let (_, gen, _) = generics.split_for_impl();
+		// Visitable is implemented for every lifetime 'a, even if the type itself has no lifetime.
+		let mut impl_generics = generics.clone();
+		if !impl_generics.lifetimes().any(|l| l.lifetime.ident == "a") {
+			let lt = Lifetime::new("'a", Span::call_site());
+			impl_generics.params.push(GenericParam::from(LifetimeParam::new(lt)));
+		}
+		let (impl_gen, _, _) = impl_generics.split_for_impl();
+		let method = format_ident!("visit_{}", snake(ident.to_string()));
+		let accept = quote! { crate::css::visit::Visitable::accept };
+		let accept_mut = quote! { crate::css::visit::VisitableMut::accept_mut };
+		let steps = self.visitable_steps(quote! { &self }, &accept);
+		let steps_mut = self.visitable_steps(quote! { &mut self }, &accept_mut);
+		quote! {
+			#[automatically_derived]
+			impl #impl_gen crate::css::visit::Visitable<'a> for #ident #gen {
+				fn accept<V: crate::css::visit::Visit<'a>>(&self, v: &mut V) {
+					v.#method(self);
+					#steps
+				}
+			}
+
+			#[automatically_derived]
+			impl #impl_gen crate::css::visit::VisitableMut<'a> for #ident #gen {
+				fn accept_mut<V: crate::css::visit::VisitMut<'a>>(&mut self, v: &mut V) {
+					v.#method(self);
+					#steps_mut
+				}
+			}
+		}
+	}
+
+	// The steps of a Visitable implementation, after the node itself has been visited. `this` is `&self` or `&mut self`.
+	fn visitable_steps(&self, this: TokenStream, accept: &TokenStream) -> Option<TokenStream> {
+		match self {
+			Self::Type(_) | Self::Optional(_) | Self::Multiplier(_, DefMultiplierStyle::ZeroOrMore) => {
+				self.visit_steps(quote! { #this.0 }, accept)
+			}
+			Self::Combinator(opts, DefCombinatorStyle::Ordered) => {
+				let steps: Vec<TokenStream> = opts
+					.iter()
+					.enumerate()
+					.filter_map(|(i, def)| {
+						let index = Index { index: i as u32, span: Span::call_site() };
+						def.visit_steps(quote! { #this.#index }, accept)
+					})
+					.collect();
+				(!steps.is_empty()).then(|| quote! { #(#steps)* })
+			}
+			Self::Combinator(opts, DefCombinatorStyle::Options) => {
+				let steps: Vec<TokenStream> = opts
+					.iter()
+					.enumerate()
+					.filter_map(|(i, def)| {
+						let index = Index { index: i as u32, span: Span::call_site() };
+						let step = def.visit_steps(quote! { inner }, accept)?;
+						Some(quote! {
+							if let Some(inner) = #this.#index {
+								#step
+							}
+						})
+					})
+					.collect();
+				(!steps.is_empty()).then(|| quote! { #(#steps)* })
+			}
+			Self::Combinator(opts, DefCombinatorStyle::Alternatives) => {
+				let arms: Vec<TokenStream> = opts
+					.iter()
+					.filter_map(|def| {
+						let var = def.to_variant_name(0);
+						match def {
+							Self::Multiplier(def, DefMultiplierStyle::Range(DefRange::Fixed(val))) => {
+								let idents: Vec<Ident> =
+									(1..=*val as u32).map(|i| format_ident!("inner{}", i)).collect();
+								let steps: Vec<TokenStream> = idents
+									.iter()
+									.map(|ident| def.visit_steps(quote! { #ident }, accept))
+									.collect::<Option<_>>()?;
+								Some(quote! { Self::#var(#(#idents),*) => { #(#steps)* } })
+							}
+							Self::Function(_, _) => {
+								let step = def.visit_steps(quote! { val }, accept)?;
+								Some(quote! { Self::#var(_, val, _) => { #step } })
+							}
+							_ => {
+								let step = def.visit_steps(quote! { inner }, accept)?;
+								Some(quote! { Self::#var(inner) => { #step } })
+							}
+						}
+					})
+					.collect();
+				if arms.is_empty() {
+					return None;
+				}
+				let rest = (arms.len() < opts.len()).then(|| quote! { _ => {} });
+				Some(quote! {
+					match self {
+						#(#arms)*
+						#rest
+					}
+				})
+			}
+			Self::Multiplier(def, DefMultiplierStyle::Range(DefRange::Range(Range { start, end })))
+			| Self::Multiplier(
+				def,
+				DefMultiplierStyle::OneOrMoreCommaSeparated(DefRange::Range(Range { start, end })),
+			) => {
+				let opts: Vec<Def> = (1..=*end as i32)
+					.map(|i| if i <= (*start as i32) { def.deref().clone() } else { Self::Optional(def.clone()) })
+					.collect();
+				Self::Combinator(opts, DefCombinatorStyle::Ordered).visitable_steps(this, accept)
+			}
+			Self::Multiplier(def, DefMultiplierStyle::Range(DefRange::Fixed(val))) => {
+				let opts: Vec<Def> = (1..=*val as u32).map(|_| def.deref().clone()).collect();
+				Self::Combinator(opts, DefCombinatorStyle::Ordered).visitable_steps(this, accept)
+			}
+			Self::Multiplier(_, _) => self.visit_steps(quote! { #this.0 }, accept),
+			_ => None,
+		}
+	}
 }
 
 impl GenerateDefinition for Def {
@@ -986,11 +1135,13 @@ impl GenerateDefinition for Def {
 				}
 				Self::Multiplier(x, style) => match x.as_ref() {
 					Def::Type(ty) => {
+						let life = if ty.requires_allocator_lifetime() { Some(quote! { <'a> }) } else { None };
 						let modname = if matches!(style, DefMultiplierStyle::OneOrMoreCommaSeparated(_)) {
 							let modname = ty.to_type_name();
-							quote! { (#modname, Option<::hdx_parser::T![,]>) }
+							quote! { (#modname #life, Option<::hdx_parser::T![,]>) }
 						} else {
-							ty.to_type_name()
+							let modname = ty.to_type_name();
+							quote! { #modname #life }
 						};
 						quote! { #vis struct #ident #gen(pub ::bumpalo::collections::Vec<'a, #modname>); }
 					}
@@ -1141,6 +1292,40 @@ impl GenerateToCursorsImpl for Def {
 	}
 }
 
+impl GenerateVisitImpl for Def {
+	fn visit_steps(&self, capture: TokenStream, accept: &TokenStream) -> Option<TokenStream> {
+		match self {
+			Self::Type(ty) => ty.visit_steps(capture, accept),
+			Self::Function(_, def) | Self::Group(def, DefGroupStyle::None) => def.visit_steps(capture, accept),
+			Self::Optional(def) => {
+				let step = def.visit_steps(quote! { inner }, accept)?;
+				Some(quote! {
+					if let Some(inner) = #capture {
+						#step
+					}
+				})
+			}
+			Self::Multiplier(def, style) => {
+				let step = def.visit_steps(quote! { item }, accept)?;
+				if matches!(style, DefMultiplierStyle::OneOrMoreCommaSeparated(_)) {
+					Some(quote! {
+						for (item, _) in #capture {
+							#step
+						}
+					})
+				} else {
+					Some(quote! {
+						for item in #capture {
+							#step
+						}
+					})
+				}
+			}
+			_ => None,
+		}
+	}
+}
+
 impl GeneratePeekImpl for Def {
 	fn peek_steps(&self) -> TokenStream {
 		match self {
@@ -1406,7 +1591,7 @@ impl DefType {
 			Self::Time(_) => quote! { types::Time },
 			Self::Resolution(_) => quote! { types::Resolution },
 			Self::Integer(_) => quote! { types::CSSInt },
-			Self::Number(_) => quote! { types::CSSFloat },
+			Self::Number(_) => quote! { types::CSSNumber },
 			Self::Color => quote! { types::Color },
 			Self::Image => quote! { types::Image },
 			Self::Image1D => quote! { types::Image1D },
@@ -1435,10 +1620,74 @@ impl DefType {
 		if let Self::Custom(DefIdent(ident), _) = self {
 			return matches!(
 				ident,
-				&atom!("OutlineColor") | &atom!("BorderTopColorStyleValue") | &atom!("DynamicRangeLimitMix")
+				&atom!("OutlineColor")
+					| &atom!("BorderTopColorStyleValue")
+					| &atom!("BorderBlockStartStyleValue")
+					| &atom!("CaretColorStyleValue")
+					| &atom!("ColumnRuleColorStyleValue")
+					| &atom!("DynamicRangeLimitMix")
+					| &atom!("MathFunction")
+					| &atom!("TrackList")
+					| &atom!("MasonryTrackList")
+					| &atom!("Subgrid")
+					| &atom!("TemplateAreas")
+					| &atom!("TrackSizeList")
+					| &atom!("GridTemplate")
+					| &atom!("Grid")
+					| &atom!("Masonry")
+					| &atom!("GridAutoColumnsStyleValue")
+					| &atom!("FontFamilyList")
+					| &atom!("Font")
+					| &atom!("AlternateValues")
+					| &atom!("FontVariantValues")
+					| &atom!("BgImage")
+					| &atom!("Background")
+					| &atom!("BorderImage")
+					| &atom!("BorderClip")
+					| &atom!("BoxShadow")
+					| &atom!("TransformList")
+					| &atom!("BlockStepSizeStyleValue")
+					| &atom!("BorderTopWidthStyleValue")
+					| &atom!("ColumnGapStyleValue")
+					| &atom!("ColumnRuleWidthStyleValue")
+					| &atom!("ColumnWidthStyleValue")
+					| &atom!("FlexBasisStyleValue")
+					| &atom!("LineWidth")
+					| &atom!("MarginTopStyleValue")
+					| &atom!("MaxWidthStyleValue")
+					| &atom!("MinWidthStyleValue")
+					| &atom!("OutlineWidthStyleValue")
+					| &atom!("PaddingTopStyleValue")
+					| &atom!("Position")
+					| &atom!("RowGapStyleValue")
+					| &atom!("TextDecorationThicknessStyleValue")
+					| &atom!("TopStyleValue")
+					| &atom!("WidthStyleValue")
+					| &atom!("BgSize")
+					| &atom!("BorderImageWidthValue")
+					| &atom!("BorderImageOutsetValue")
+					| &atom!("BorderSideRadius")
+					| &atom!("BorderRadius")
+					| &atom!("ShadowOffset")
+					| &atom!("SnapBlock")
+					| &atom!("SnapInline")
+					| &atom!("TransformOrigin")
+					| &atom!("TranslateValue")
+					| &atom!("BorderRadiusStyleValue")
+					| &atom!("BorderImageWidthStyleValue")
+					| &atom!("BorderImageOutsetStyleValue")
 			);
 		}
-		matches!(self, Self::Image | Self::Image1D)
+		matches!(self, Self::Color | Self::Image | Self::Image1D | Self::Length(_) | Self::LengthPercentage(_))
+	}
+
+	// Types which implement Visitable. Other types are leaves which no Visit has a use for, so are not visited.
+	pub fn is_visitable(&self) -> bool {
+		if let Self::Custom(DefIdent(ident), _) = self {
+			return ident.ends_with("StyleValue")
+				|| matches!(ident, &atom!("LineWidth") | &atom!("BoxShadow") | &atom!("Background"));
+		}
+		matches!(self, Self::Color | Self::Length(_) | Self::LengthPercentage(_))
 	}
 }
 
@@ -1448,6 +1697,12 @@ impl GenerateToCursorsImpl for DefType {
 	}
 }
 
+impl GenerateVisitImpl for DefType {
+	fn visit_steps(&self, capture: TokenStream, accept: &TokenStream) -> Option<TokenStream> {
+		self.is_visitable().then(|| quote! { #accept(#capture, v); })
+	}
+}
+
 impl GeneratePeekImpl for DefType {
 	fn peek_steps(&self) -> TokenStream {
 		match self {
@@ -1473,26 +1728,35 @@ impl GenerateParseImpl for DefType {
 		let checks = self.checks();
 		let check_code = match checks {
 			DefRange::RangeTo(RangeTo { end }) => Some(quote! {
-			let valf32: f32 = #capture_name.into();
 					if #end < valf32 {
 						return Err(::hdx_parser::diagnostics::NumberTooLarge(#end, ::hdx_lexer::Span::new(start, p.offset())))?
 					}
 				}),
 			DefRange::Range(Range { start, end }) => Some(quote! {
-			let valf32: f32 = #capture_name.into();
 					if !(#start..#end).contains(valf32) {
 						return Err(::hdx_parser::diagnostics::NumberOutOfBounds(#capture_name, "#start..#end", ::hdx_lexer::Span::new(start, p.offset())))?
 					}
 				}),
 			DefRange::RangeFrom(RangeFrom { start }) => Some(quote! {
-			let valf32: f32 = #capture_name.into();
 					if #start > valf32 {
 						return Err(::hdx_parser::diagnostics::NumberTooSmall(#start, ::hdx_lexer::Span::new(start, p.offset())))?
 					}
 				}),
 			DefRange::None => None,
 			DefRange::Fixed(_) => None,
-		};
+		}
+		.map(|check| match self {
+			// A math function can't be checked until it has been computed, at which point it's clamped to the range.
+			Self::Length(_) | Self::LengthPercentage(_) => quote! {
+				if let Some(valf32) = #capture_name.to_f32() {
+					#check
+				}
+			},
+			_ => quote! {
+				let valf32: f32 = #capture_name.into();
+				#check
+			},
+		});
 		let start_offset = if check_code.is_some() { Some(quote! { let start = p.offset(); }) } else { None };
 		quote! {
 			#start_offset
@@ -25,18 +25,10 @@ pub fn value(args: TokenStream, input: TokenStream) -> TokenStream {
 }
 
 #[proc_macro_attribute]
-pub fn initial(_args: TokenStream, input: TokenStream) -> TokenStream {
-	input
-	// let args_string = args.to_string();
-	// // CSS specs have a variety of "see individual properties" styles
-	// let args = if args_string == "\"see individual properties\""
-	// {
-	// 	StrWrapped(initial::Args::Individual)
-	// } else {
-	// 	parse_macro_input!(args as StrWrapped<initial::Args>)
-	// };
-	// let ast = parse_macro_input!(input as DeriveInput);
-	// initial::generate(args.0, ast).into()
+pub fn initial(args: TokenStream, input: TokenStream) -> TokenStream {
+	let args = parse_macro_input!(args as initial::Args);
+	let ast = parse_macro_input!(input as DeriveInput);
+	initial::generate(args, ast).into()
 }
 
 #[proc_macro_attribute]
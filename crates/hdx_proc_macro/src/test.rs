@@ -310,6 +310,13 @@ fn enum_type_with_lifetime() {
 	assert_snapshot!(syntax, data, "enum_type_with_lifetime");
 }
 
+#[test]
+fn enum_type_with_math_function() {
+	let syntax = to_valuedef! { auto | <length-percentage> | <math-function> };
+	let data = to_deriveinput! { enum Foo<'a> {} };
+	assert_snapshot!(syntax, data, "enum_type_with_math_function");
+}
+
 #[test]
 fn multiple_keywords() {
 	let syntax = to_valuedef!("black | white | line-through | pink");
@@ -320,14 +327,14 @@ fn multiple_keywords() {
 #[test]
 fn value_group_type_keyword() {
 	let syntax = to_valuedef!( <length [1,]> | line-through );
-	let data = to_deriveinput! { enum Foo {} };
+	let data = to_deriveinput! { enum Foo<'a> {} };
 	assert_snapshot!(syntax, data, "value_group_type_keyword");
 }
 
 #[test]
 fn value_with_multiplier_range() {
 	let syntax = to_valuedef!( <length>{2,4} );
-	let data = to_deriveinput! { struct Foo; };
+	let data = to_deriveinput! { struct Foo<'a>; };
 	assert_snapshot!(syntax, data, "value_with_multiplier_range");
 }
 
@@ -341,7 +348,7 @@ fn keyword_or_type() {
 #[test]
 fn custom_type_with_checks() {
 	let syntax = to_valuedef!(" none | <length-percentage [0,∞]> ");
-	let data = to_deriveinput! { enum Foo {} };
+	let data = to_deriveinput! { enum Foo<'a> {} };
 	assert_snapshot!(syntax, data, "custom_type_with_checks");
 }
 
@@ -355,7 +362,7 @@ fn custom_function_type() {
 #[test]
 fn custom_function_variant_with_args() {
 	let syntax = to_valuedef!(" fit-content | fit-content(<length-percentage [0,∞]>) ");
-	let data = to_deriveinput! { enum Foo {} };
+	let data = to_deriveinput! { enum Foo<'a> {} };
 	assert_snapshot!(syntax, data, "custom_function_variant_with_args");
 }
 
@@ -369,14 +376,14 @@ fn custom_function_variant_with_multiplier_args() {
 #[test]
 fn custom_function_all_optionals() {
 	let syntax = to_valuedef!(" <'caret-color'> || <'caret-animation'> || <'caret-shape'> ");
-	let data = to_deriveinput! { struct Foo; };
+	let data = to_deriveinput! { struct Foo<'a>; };
 	assert_snapshot!(syntax, data, "custom_function_all_optionals");
 }
 
 #[test]
 fn ordered_custom_function_last_option() {
 	let syntax = to_valuedef!(" <'caret-color'> <'caret-animation'>? ");
-	let data = to_deriveinput! { struct Foo; };
+	let data = to_deriveinput! { struct Foo<'a>; };
 	assert_snapshot!(syntax, data, "ordered_custom_function_last_option");
 }
 
@@ -387,6 +394,13 @@ fn struct_with_variable_count_type() {
 	assert_snapshot!(syntax, data, "struct_with_variable_count_type");
 }
 
+#[test]
+fn struct_with_variable_count_type_with_lifetime() {
+	let syntax = to_valuedef!(" <image># ");
+	let data = to_deriveinput! { struct Foo<'a>; };
+	assert_snapshot!(syntax, data, "struct_with_variable_count_type_with_lifetime");
+}
+
 #[test]
 fn enum_with_variable_count_type() {
 	let syntax = to_valuedef!(" auto | <animateable-feature># ");
@@ -411,13 +425,13 @@ fn bounded_range_multiplier_is_optimized_to_options_with_lifetimes_when_necessar
 #[test]
 fn value_fixed_range_color2_optimized() {
 	let syntax = to_valuedef! { <color>{2} };
-	let data = to_deriveinput! { struct Foo {} };
+	let data = to_deriveinput! { struct Foo<'a> {} };
 	assert_snapshot!(syntax, data, "value_fixed_range_color2_optimized");
 }
 
 #[test]
 fn value_fixed_range_auto_color2_optimized() {
 	let syntax = to_valuedef! { auto | <color>{2} };
-	let data = to_deriveinput! { enum Foo {} };
+	let data = to_deriveinput! { enum Foo<'a> {} };
 	assert_snapshot!(syntax, data, "value_fixed_range_auto_color2_optimized");
 }
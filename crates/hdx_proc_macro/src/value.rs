@@ -40,6 +40,7 @@ pub fn generate(defs: Def, ast: DeriveInput) -> TokenStream {
 	let peek_impl = defs.generate_peek_trait_implementation(ident, &mut ast.generics.clone());
 	let parse_impl = defs.generate_parse_trait_implementation(ident, &mut ast.generics.clone());
 	let tocursors_impl = defs.generate_tocursors_trait_implementation(ident, &mut ast.generics.clone());
+	let visitable_impl = defs.generate_visitable_trait_implementation(ident, &mut ast.generics.clone());
 	quote! {
 		#(#attrs)*
 		#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -48,5 +49,6 @@ pub fn generate(defs: Def, ast: DeriveInput) -> TokenStream {
 		#peek_impl
 		#parse_impl
 		#tocursors_impl
+		#visitable_impl
 	}
 }
@@ -1,9 +1,9 @@
 use bumpalo::Bump;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use glob::glob;
-use hdx_ast::css::{visit::VisitableMut, StyleSheet};
-use hdx_parser::{Features, Parser};
-use hdx_transform::ReduceInitial;
+use hdx_ast::css::StyleSheet;
+use hdx_parser::{CursorStream, Features, Parser};
+use hdx_transform::{minify, write_minified};
 #[cfg(target_family = "unix")]
 use pprof::criterion::{Output, PProfProfiler};
 use std::fs::read_to_string;
@@ -37,12 +37,11 @@ fn popular(c: &mut Criterion) {
 					let mut result = Parser::new(&allocator, source_text.as_str(), Features::default())
 						.parse_entirely::<StyleSheet>();
 					let mut string = String::new();
-					if let Some(stylesheet) = result.output.as_mut() {
-						let mut transformer = ReduceInitial::default();
-						stylesheet.accept_mut(&mut transformer);
-						if let Err(e) = stylesheet.write_css(&mut writer) {
-							println!("Failed to write CSS: {:?}", e);
-						}
+					minify(&mut result);
+					if let Err(e) =
+						write_minified(&result, result.source_text, &mut CursorStream::new(&allocator), &mut string)
+					{
+						println!("Failed to write CSS: {:?}", e);
 					}
 				}
 				allocator
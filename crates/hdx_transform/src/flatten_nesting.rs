@@ -0,0 +1,471 @@
+use bumpalo::{collections::Vec, Bump};
+use hdx_ast::css::{
+	visit::VisitMut, Combinator, CompoundSelector, ContainerRule, DocumentRule, FunctionalPseudoClass,
+	IsPseudoFunction, LayerRule, MediaRule, NestedGroupRule, OptionalLayerRuleBlock, Property, Rule, SelectorComponent,
+	SelectorList, StartingStyleRule, StyleDeclaration, StyleRule, StyleSheet, SupportsRule,
+};
+use hdx_lexer::SourceOffset;
+use hdx_parser::{start_offset, Features, Parser, T};
+
+use crate::Transform;
+
+// Tokens which flattened rules need but may not exist in the source text. They are parsed from this text, which is
+// appended to the source text the first time they're needed.
+const GLUE: &str = "* :is(*){}";
+
+struct Glue<'a> {
+	descendant: SelectorComponent<'a>,
+	is: IsPseudoFunction<'a>,
+	open: T!['{'],
+	close: T!['}'],
+}
+
+// Flattens nested style rules into top level style rules, for browsers without support for CSS Nesting. Each nested
+// selector has `&` replaced with its parent's selector, or is prefixed with its parent's selector if it has no `&`.
+// Nested `@media`, `@supports`, `@container`, `@layer`, `@starting-style` & `@scope` rules are hoisted out of their
+// parent, with any declarations inside of them wrapped in a rule using the parent's selector. Declarations which follow
+// a nested rule are wrapped in the same way, so they keep their place after it. Unknown at-rules are left nested.
+// Rules are rebuilt from the cursors of the original rules wherever possible; the few tokens which may not exist in
+// the source text (a descendant combinator, `:is()`, and the curlies of a wrapping rule) are parsed once from [GLUE],
+// placed after the end of the source text.
+// Their cursors point past the original text, so the StyleSheet must be written using [FlattenNesting::source_text].
+#[derive(Default)]
+pub struct FlattenNesting<'a> {
+	allocator: Option<&'a Bump>,
+	source_text: &'a str,
+	glue: Option<Glue<'a>>,
+	changes: usize,
+}
+
+impl<'a> FlattenNesting<'a> {
+	pub fn new(allocator: &'a Bump, source_text: &'a str) -> Self {
+		Self { allocator: Some(allocator), source_text, glue: None, changes: 0 }
+	}
+
+	/// The source text of the StyleSheet, followed by the text of any tokens which flattened rules needed.
+	pub fn source_text(&self) -> &'a str {
+		self.source_text
+	}
+
+	fn glue(&mut self, allocator: &'a Bump) -> &Glue<'a> {
+		if self.glue.is_none() {
+			let offset = SourceOffset(self.source_text.len() as u32);
+			let source_text = bumpalo::format!(in allocator, "{}{}", self.source_text, GLUE).into_bump_str();
+			let mut p = Parser::new(allocator, source_text, Features::default());
+			p.hop(offset);
+			let rule = p.parse::<StyleRule>().expect("glue should parse");
+			let components = &rule.selectors.0[0].components;
+			let SelectorComponent::FunctionalPseudoClass(FunctionalPseudoClass::Is(is)) = &components[2] else {
+				unreachable!("glue should contain :is()")
+			};
+			self.glue = Some(Glue {
+				descendant: components[1].clone(),
+				is: is.clone(),
+				open: rule.style.open,
+				close: rule.style.close.expect("glue should have a closing curly"),
+			});
+			self.source_text = source_text;
+		}
+		self.glue.as_ref().unwrap()
+	}
+
+	fn has_nesting(rule: &Rule) -> bool {
+		matches!(rule, Rule::Style(rule) if !rule.style.rules.is_empty())
+	}
+
+	fn flatten_rules(&mut self, rules: &mut Vec<'a, Rule<'a>>) {
+		let Some(allocator) = self.allocator else { return };
+		if !rules.iter().any(Self::has_nesting) {
+			return;
+		}
+		let mut flat = Vec::with_capacity_in(rules.len(), allocator);
+		for rule in rules.drain(..) {
+			match rule {
+				Rule::Style(rule) => self.flatten_style_rule(rule, &mut flat, allocator),
+				rule => flat.push(rule),
+			}
+		}
+		*rules = flat;
+	}
+
+	// Pushes the rule (if it has any declarations) followed by each of its nested rules, flattened. Declarations which
+	// came after a nested rule are wrapped in a new rule using the same selector, so they stay in source order. Unknown
+	// at-rules can't be hoisted, as it's not known what they'd mean outside of the rule, so they're left nested.
+	fn flatten_style_rule(&mut self, mut rule: StyleRule<'a>, out: &mut Vec<'a, Rule<'a>>, allocator: &'a Bump) {
+		let nested = std::mem::replace(&mut rule.style.rules, Vec::new_in(allocator));
+		if nested.is_empty() {
+			out.push(Rule::Style(rule));
+			return;
+		}
+		let selectors = rule.selectors.clone();
+		let declarations = std::mem::replace(&mut rule.style.declarations, Vec::new_in(allocator));
+		let mut declarations = declarations.into_iter().peekable();
+		// The rule which the current run of declarations is added to, starting with the original rule.
+		let mut current = Some(rule);
+		for nested_rule in nested {
+			let start = start_offset(&nested_rule).unwrap_or(SourceOffset::DUMMY);
+			let mut run = Vec::new_in(allocator);
+			while let Some(declaration) = declarations.next_if(|d| start_offset(d).is_some_and(|d| d < start)) {
+				run.push(declaration);
+			}
+			let unknown = matches!(nested_rule, NestedGroupRule::UnknownAt(_) | NestedGroupRule::Unknown(_));
+			if !run.is_empty() || unknown {
+				let rule = self.extend_run(&mut current, run, &selectors, allocator);
+				if unknown {
+					rule.style.rules.push(nested_rule);
+					continue;
+				}
+			}
+			if let Some(rule) = current.take() {
+				if !rule.style.declarations.is_empty() || !rule.style.rules.is_empty() {
+					out.push(Rule::Style(rule));
+				}
+			}
+			self.changes += 1;
+			self.flatten_nested_rule(into_rule(nested_rule), &selectors, out, allocator);
+		}
+		let run = Vec::from_iter_in(declarations, allocator);
+		if !run.is_empty() {
+			self.extend_run(&mut current, run, &selectors, allocator);
+		}
+		if let Some(rule) = current {
+			if !rule.style.declarations.is_empty() || !rule.style.rules.is_empty() {
+				out.push(Rule::Style(rule));
+			}
+		}
+	}
+
+	// Adds the declarations to the current rule, starting a new rule using the parent's selector if there isn't one.
+	fn extend_run<'b>(
+		&mut self,
+		current: &'b mut Option<StyleRule<'a>>,
+		declarations: Vec<'a, Property<'a>>,
+		selectors: &SelectorList<'a>,
+		allocator: &'a Bump,
+	) -> &'b mut StyleRule<'a> {
+		if current.is_none() {
+			*current = Some(self.wrap_declarations(Vec::new_in(allocator), selectors, allocator));
+		}
+		let rule = current.as_mut().unwrap();
+		rule.style.declarations.extend(declarations);
+		rule
+	}
+
+	fn flatten_nested_rule(
+		&mut self,
+		rule: Rule<'a>,
+		parent: &SelectorList<'a>,
+		out: &mut Vec<'a, Rule<'a>>,
+		allocator: &'a Bump,
+	) {
+		match rule {
+			Rule::Style(mut rule) => {
+				rule.selectors = self.resolve_selectors(&rule.selectors, parent, allocator);
+				self.flatten_style_rule(rule, out, allocator);
+			}
+			Rule::MediaRule(mut rule) => {
+				let declarations = std::mem::replace(&mut rule.block.declarations, Vec::new_in(allocator));
+				rule.block.rules = self.flatten_group(declarations, &mut rule.block.rules, parent, allocator);
+				out.push(Rule::MediaRule(rule));
+			}
+			Rule::SupportsRule(mut rule) => {
+				let declarations = std::mem::replace(&mut rule.block.declarations, Vec::new_in(allocator));
+				rule.block.rules = self.flatten_group(declarations, &mut rule.block.rules, parent, allocator);
+				out.push(Rule::SupportsRule(rule));
+			}
+			Rule::StartingStyleRule(mut rule) => {
+				let declarations = std::mem::replace(&mut rule.block.declarations, Vec::new_in(allocator));
+				rule.block.rules = self.flatten_group(declarations, &mut rule.block.rules, parent, allocator);
+				out.push(Rule::StartingStyleRule(rule));
+			}
+			Rule::ContainerRule(mut rule) => {
+				let declarations = std::mem::replace(&mut rule.block.declarations, Vec::new_in(allocator));
+				rule.block.rules = self.flatten_group(declarations, &mut rule.block.rules, parent, allocator);
+				out.push(Rule::ContainerRule(rule));
+			}
+			Rule::LayerRule(mut rule) => {
+				if let OptionalLayerRuleBlock::Block(block) = &mut rule.block {
+					let declarations = std::mem::replace(&mut block.declarations, Vec::new_in(allocator));
+					block.rules = self.flatten_group(declarations, &mut block.rules, parent, allocator);
+				}
+				out.push(Rule::LayerRule(rule));
+			}
+			// Rules nested in `@scope` are relative to its scoping root rather than to `parent`, so only its
+			// declarations, which applied to `parent`, need wrapping.
+			Rule::ScopeRule(mut rule) => {
+				let declarations = std::mem::replace(&mut rule.block.declarations, Vec::new_in(allocator));
+				if !declarations.is_empty() {
+					let wrapped = self.wrap_declarations(declarations, parent, allocator);
+					rule.block.rules.insert(0, NestedGroupRule::Style(wrapped));
+				}
+				out.push(Rule::ScopeRule(rule));
+			}
+			// Unknown at-rules are left inside of a rule using the parent's selector, as it's not known what they'd mean
+			// outside of it.
+			Rule::UnknownAt(rule) => {
+				let mut wrapped = self.wrap_declarations(Vec::new_in(allocator), parent, allocator);
+				wrapped.style.rules.push(NestedGroupRule::UnknownAt(rule));
+				out.push(Rule::Style(wrapped));
+			}
+			Rule::Unknown(rule) => {
+				let mut wrapped = self.wrap_declarations(Vec::new_in(allocator), parent, allocator);
+				wrapped.style.rules.push(NestedGroupRule::Unknown(rule));
+				out.push(Rule::Style(wrapped));
+			}
+			// Other rules can't contain style rules, so they're hoisted as they are.
+			rule => out.push(rule),
+		}
+	}
+
+	// The rules of a conditional group rule which was nested inside of `parent`, flattened. Each run of declarations
+	// is wrapped in a rule using the parent's selector, which stays in source order with the rules around it.
+	fn flatten_group(
+		&mut self,
+		declarations: Vec<'a, Property<'a>>,
+		rules: &mut Vec<'a, Rule<'a>>,
+		parent: &SelectorList<'a>,
+		allocator: &'a Bump,
+	) -> Vec<'a, Rule<'a>> {
+		let mut flat = Vec::new_in(allocator);
+		let mut declarations = declarations.into_iter().peekable();
+		for rule in rules.drain(..) {
+			if declarations.peek().is_some() {
+				let start = start_offset(&rule).unwrap_or(SourceOffset::DUMMY);
+				let mut run = Vec::new_in(allocator);
+				while let Some(declaration) = declarations.next_if(|d| start_offset(d).is_some_and(|d| d < start)) {
+					run.push(declaration);
+				}
+				self.push_declarations(run, parent, &mut flat, allocator);
+			}
+			self.flatten_nested_rule(rule, parent, &mut flat, allocator);
+		}
+		let run = Vec::from_iter_in(declarations, allocator);
+		self.push_declarations(run, parent, &mut flat, allocator);
+		flat
+	}
+
+	fn push_declarations(
+		&mut self,
+		declarations: Vec<'a, Property<'a>>,
+		parent: &SelectorList<'a>,
+		out: &mut Vec<'a, Rule<'a>>,
+		allocator: &'a Bump,
+	) {
+		if !declarations.is_empty() {
+			out.push(Rule::Style(self.wrap_declarations(declarations, parent, allocator)));
+		}
+	}
+
+	// A rule using the parent's selector, for declarations which were nested in a group rule inside of it.
+	fn wrap_declarations(
+		&mut self,
+		declarations: Vec<'a, Property<'a>>,
+		parent: &SelectorList<'a>,
+		allocator: &'a Bump,
+	) -> StyleRule<'a> {
+		let glue = self.glue(allocator);
+		let style =
+			StyleDeclaration { open: glue.open, declarations, rules: Vec::new_in(allocator), close: Some(glue.close) };
+		StyleRule { selectors: parent.clone(), style }
+	}
+
+	fn resolve_selectors(
+		&mut self,
+		selectors: &SelectorList<'a>,
+		parent: &SelectorList<'a>,
+		allocator: &'a Bump,
+	) -> SelectorList<'a> {
+		let mut resolved = Vec::with_capacity_in(selectors.0.len(), allocator);
+		for selector in &selectors.0 {
+			resolved.push(self.resolve_selector(selector, parent, allocator));
+		}
+		SelectorList(resolved)
+	}
+
+	// https://drafts.csswg.org/css-nesting/#nest-selector
+	fn resolve_selector(
+		&mut self,
+		selector: &CompoundSelector<'a>,
+		parent: &SelectorList<'a>,
+		allocator: &'a Bump,
+	) -> CompoundSelector<'a> {
+		let mut components = Vec::new_in(allocator);
+		// A selector without `&` is relative to its parent, as if it started with `& `.
+		if !selector.components.iter().any(is_nesting) {
+			self.push_parent(&mut components, parent, true, allocator);
+			if !matches!(selector.components.first(), Some(SelectorComponent::Combinator(_))) {
+				components.push(self.glue(allocator).descendant.clone());
+			}
+		}
+		for (i, component) in selector.components.iter().enumerate() {
+			if !is_nesting(component) {
+				components.push(component.clone());
+				continue;
+			}
+			let starts_compound = i == 0 || matches!(selector.components[i - 1], SelectorComponent::Combinator(_));
+			self.push_parent(&mut components, parent, starts_compound, allocator);
+		}
+		CompoundSelector { components, comma: selector.comma }
+	}
+
+	// Replaces one `&`. The parent's components can be used in place of `&` when that keeps their meaning, which is when
+	// the parent is a single selector and `&` starts the selector, or the parent is a single compound selector which can
+	// be joined onto the compound selector `&` is in. Otherwise the parent is wrapped in `:is()`.
+	fn push_parent(
+		&mut self,
+		components: &mut Vec<'a, SelectorComponent<'a>>,
+		parent: &SelectorList<'a>,
+		starts_compound: bool,
+		allocator: &'a Bump,
+	) {
+		if let [selector] = parent.0.as_slice() {
+			let compound = !selector.components.iter().any(|c| matches!(c, SelectorComponent::Combinator(_)));
+			// Type selectors must come first in a compound selector, so can't be joined onto the end of one.
+			let joinable = starts_compound
+				|| !matches!(
+					selector.components.first(),
+					Some(SelectorComponent::Tag(_) | SelectorComponent::Wildcard(_) | SelectorComponent::Namespace(_))
+				);
+			if (components.is_empty() && starts_compound) || (compound && joinable) {
+				components.extend(selector.components.iter().cloned());
+				return;
+			}
+		}
+		let mut is = self.glue(allocator).is.clone();
+		is.value = parent.clone();
+		components.push(SelectorComponent::FunctionalPseudoClass(FunctionalPseudoClass::Is(is)));
+	}
+}
+
+fn is_nesting(component: &SelectorComponent) -> bool {
+	matches!(component, SelectorComponent::Combinator(Combinator::Nesting(_)))
+}
+
+fn into_rule(rule: NestedGroupRule) -> Rule {
+	match rule {
+		NestedGroupRule::ContainerRule(rule) => Rule::ContainerRule(rule),
+		NestedGroupRule::LayerRule(rule) => Rule::LayerRule(rule),
+		NestedGroupRule::MediaRule(rule) => Rule::MediaRule(rule),
+		NestedGroupRule::ScopeRule(rule) => Rule::ScopeRule(rule),
+		NestedGroupRule::StartingStyleRule(rule) => Rule::StartingStyleRule(rule),
+		NestedGroupRule::SupportsRule(rule) => Rule::SupportsRule(rule),
+		NestedGroupRule::UnknownAt(rule) => Rule::UnknownAt(rule),
+		NestedGroupRule::Style(rule) => Rule::Style(rule),
+		NestedGroupRule::Unknown(rule) => Rule::Unknown(rule),
+	}
+}
+
+impl<'a> Transform<'a> for FlattenNesting<'a> {
+	const NAME: &'static str = "flatten-nesting";
+
+	fn changes(&self) -> usize {
+		self.changes
+	}
+}
+
+impl<'a> VisitMut<'a> for FlattenNesting<'a> {
+	fn visit_style_sheet(&mut self, stylesheet: &mut StyleSheet<'a>) {
+		self.flatten_rules(&mut stylesheet.rules);
+	}
+
+	fn visit_container_rule(&mut self, rule: &mut ContainerRule<'a>) {
+		self.flatten_rules(&mut rule.block.rules);
+	}
+
+	fn visit_document_rule(&mut self, rule: &mut DocumentRule<'a>) {
+		self.flatten_rules(&mut rule.block.rules);
+	}
+
+	fn visit_layer_rule(&mut self, rule: &mut LayerRule<'a>) {
+		if let OptionalLayerRuleBlock::Block(block) = &mut rule.block {
+			self.flatten_rules(&mut block.rules);
+		}
+	}
+
+	fn visit_media_rule(&mut self, rule: &mut MediaRule<'a>) {
+		self.flatten_rules(&mut rule.block.rules);
+	}
+
+	fn visit_starting_style_rule(&mut self, rule: &mut StartingStyleRule<'a>) {
+		self.flatten_rules(&mut rule.block.rules);
+	}
+
+	fn visit_supports_rule(&mut self, rule: &mut SupportsRule<'a>) {
+		self.flatten_rules(&mut rule.block.rules);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hdx_ast::css::visit::VisitableMut;
+	use hdx_parser::CursorStream;
+
+	// FlattenNesting appends to the source text, so the transformed StyleSheet needs to be written with its source text
+	// rather than the one it was parsed with.
+	macro_rules! assert_flatten_nesting {
+		($str: literal, $expected: literal) => {{
+			let allocator = Bump::default();
+			let mut result = Parser::new(&allocator, $str, Features::default()).parse_entirely::<StyleSheet>();
+			assert!(result.errors.is_empty(), "{:?}", result.errors);
+			let mut transform = FlattenNesting::new(&allocator, $str);
+			result.output.as_mut().unwrap().accept_mut(&mut transform);
+			result.source_text = transform.source_text();
+			let mut transformed = String::new();
+			result.write(&mut CursorStream::new(&allocator), &mut transformed).unwrap();
+			assert_eq!(transformed, $expected);
+		}};
+	}
+
+	#[test]
+	fn test_transform() {
+		assert_flatten_nesting!("a{color:red;}", "a{color:red;}");
+		assert_flatten_nesting!("a{color:red;b{color:blue;}}", "a{color:red;}a b{color:blue;}");
+		assert_flatten_nesting!("a{&:hover{color:blue;}}", "a:hover{color:blue;}");
+		assert_flatten_nesting!("a{>b{color:blue;}}", "a>b{color:blue;}");
+		assert_flatten_nesting!(".a{.b &{color:blue;}}", ".b .a{color:blue;}");
+		assert_flatten_nesting!(".a{&.b{color:blue;}}", ".a.b{color:blue;}");
+		assert_flatten_nesting!("div{.b&{color:blue;}}", ".b:is(div){color:blue;}");
+		assert_flatten_nesting!(".a,.b{&:hover{color:blue;}}", ":is(.a,.b):hover{color:blue;}");
+		assert_flatten_nesting!(".a,.b{c{color:blue;}}", ":is(.a,.b) c{color:blue;}");
+		assert_flatten_nesting!(".a .b{.c &{color:blue;}}", ".c :is(.a .b){color:blue;}");
+		assert_flatten_nesting!("a{b{c{color:blue;}}}", "a b c{color:blue;}");
+		assert_flatten_nesting!("a{b,c{color:blue;}}", "a b,a c{color:blue;}");
+		assert_flatten_nesting!(
+			"a{color:red;@media print{color:blue;b{color:green;}}}",
+			"a{color:red;}@media print{a{color:blue;}a b{color:green;}}"
+		);
+		assert_flatten_nesting!(
+			"@supports (display:grid){a{@media print{b{color:blue;}}}}",
+			"@supports(display:grid){@media print{a b{color:blue;}}}"
+		);
+		assert_flatten_nesting!(
+			".dialog{opacity:1;@starting-style{opacity:0;}}",
+			".dialog{opacity:1;}@starting-style{.dialog{opacity:0;}}"
+		);
+		assert_flatten_nesting!("a{@scope(.x){color:red;}}", "@scope(.x){a{color:red;}}");
+		assert_flatten_nesting!(
+			".a{@scope(.x) to (.y){color:red;:scope{color:blue;}}}",
+			"@scope(.x)to (.y){.a{color:red;}:scope{color:blue;}}"
+		);
+		assert_flatten_nesting!(".a{&{color:red;}color:blue;}", ".a{color:red;}.a{color:blue;}");
+		assert_flatten_nesting!(
+			".a{color:red;b{color:blue;}width:1px;c{color:green;}}",
+			".a{color:red;}.a b{color:blue;}.a{width:1px;}.a c{color:green;}"
+		);
+		assert_flatten_nesting!(".a{@container (width > 1px){color:red;}}", "@container(width>1px){.a{color:red;}}");
+		assert_flatten_nesting!(".a{@layer x{color:red;b{color:blue;}}}", "@layer x{.a{color:red;}.a b{color:blue;}}");
+		assert_flatten_nesting!(
+			".a{color:red;@foo{color:blue;}b{color:green;}}",
+			".a{color:red;@foo{color:blue;}}.a b{color:green;}"
+		);
+		assert_flatten_nesting!(
+			"a{@media print{b{color:blue;}@foo{color:red;}}}",
+			"@media print{a b{color:blue;}a{@foo{color:red;}}}"
+		);
+		assert_flatten_nesting!(
+			"a{@media print{b{color:blue;}color:red;c{color:green;}}}",
+			"@media print{a b{color:blue;}a{color:red;}a c{color:green;}}"
+		);
+	}
+}
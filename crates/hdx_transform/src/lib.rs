@@ -1,5 +1,20 @@
-// mod reduce_initial;
-// pub use reduce_initial::*;
+mod flatten_nesting;
+mod minify;
+mod pipeline;
+mod reduce_colors;
+mod reduce_initial;
+mod reduce_zero_lengths;
+mod remove_empty_rules;
+mod remove_trailing_semicolons;
+
+pub use flatten_nesting::*;
+pub use minify::*;
+pub use pipeline::*;
+pub use reduce_colors::*;
+pub use reduce_initial::*;
+pub use reduce_zero_lengths::*;
+pub use remove_empty_rules::*;
+pub use remove_trailing_semicolons::*;
 
 #[cfg(test)]
 pub mod test_helpers;
@@ -0,0 +1,253 @@
+use core::fmt;
+
+use hdx_ast::css::StyleSheet;
+use hdx_atom::Atomizable;
+use hdx_lexer::{Cursor, DimensionUnit, Kind};
+use hdx_parser::{is_declaration, CursorSink, CursorWrite, IgnoreCursors, MathNesting, ParserReturn, ToCursors};
+
+use crate::{Pipeline, PipelineReport, TransformOptions};
+
+/// Runs the [TransformOptions::minify] passes over a parsed StyleSheet. Whitespace & numbers are shortened as the
+/// StyleSheet is written, see [write_minified], which must be given the (possibly updated) source text of `result`.
+pub fn minify<'a>(result: &mut ParserReturn<'a, StyleSheet<'a>>) -> PipelineReport {
+	Pipeline::from_options(&TransformOptions::minify()).run(result)
+}
+
+/// Writes a node using as few characters as possible, such that the output parses back to an equivalent node.
+pub fn write_minified(
+	node: &impl ToCursors,
+	source_text: &str,
+	sink: &mut impl CursorSink,
+	f: &mut impl fmt::Write,
+) -> fmt::Result {
+	write_minified_with_map(node, source_text, sink, &mut IgnoreCursors(f))
+}
+
+/// Like [write_minified], but `f` is told about each Cursor before it is written, for example so that a
+/// [SourceMapWriter][hdx_parser::SourceMapWriter] can map the output back to the source text.
+pub fn write_minified_with_map(
+	node: &impl ToCursors,
+	source_text: &str,
+	sink: &mut impl CursorSink,
+	f: &mut impl CursorWrite,
+) -> fmt::Result {
+	node.to_cursors(sink);
+	let cursors: Vec<Cursor> = sink.iter_cursors().copied().collect();
+	MinifyWriter::new(source_text, f).write(&cursors)
+}
+
+// Writes a stream of Cursors with the least whitespace needed to keep tokens apart, and numbers in their shortest form.
+struct MinifyWriter<'a, 'f, F: CursorWrite> {
+	source_text: &'a str,
+	f: &'f mut F,
+	depth: usize,
+	nesting: usize,
+	whitespace: bool,
+	at_statement_start: bool,
+	expect_declaration_colon: bool,
+	in_value: bool,
+	trim_whitespace: bool,
+	math: MathNesting,
+	last: Option<(Kind, bool)>,
+}
+
+impl<'a, 'f, F: CursorWrite> MinifyWriter<'a, 'f, F> {
+	fn new(source_text: &'a str, f: &'f mut F) -> Self {
+		Self {
+			source_text,
+			f,
+			depth: 0,
+			nesting: 0,
+			whitespace: false,
+			at_statement_start: true,
+			expect_declaration_colon: false,
+			in_value: false,
+			trim_whitespace: false,
+			math: MathNesting::default(),
+			last: None,
+		}
+	}
+
+	fn write(mut self, cursors: &[Cursor]) -> fmt::Result {
+		for (i, c) in cursors.iter().enumerate() {
+			let rest = &cursors[i + 1..];
+			let kind = c.token().kind();
+			if kind == Kind::Whitespace {
+				self.whitespace = true;
+				continue;
+			}
+			if kind == Kind::Semicolon && rest.first().is_some_and(|next| *next == Kind::RightParen) {
+				continue;
+			}
+			let at_statement_start = self.at_statement_start;
+			self.at_statement_start = false;
+			match kind {
+				Kind::LeftCurly | Kind::RightCurly => {
+					if kind == Kind::LeftCurly {
+						self.depth += 1;
+					} else {
+						self.depth = self.depth.saturating_sub(1);
+					}
+					self.end_declaration();
+				}
+				Kind::Semicolon if self.nesting == 0 => self.end_declaration(),
+				Kind::Ident if at_statement_start && self.depth > 0 && self.nesting == 0 && is_declaration(rest) => {
+					self.expect_declaration_colon = true;
+				}
+				Kind::Colon if self.expect_declaration_colon => {
+					self.expect_declaration_colon = false;
+					self.in_value = true;
+				}
+				Kind::Delim if self.in_value && self.nesting == 0 && c.token().char() == Some('!') => {
+					self.in_value = false;
+					self.whitespace = false;
+				}
+				// Whitespace around combinators (or comparisons in media queries) is redundant.
+				Kind::Delim if !self.in_value && matches!(c.token().char(), Some('>' | '+' | '~')) => {
+					self.whitespace = false;
+				}
+				Kind::RightParen | Kind::RightSquare => self.nesting = self.nesting.saturating_sub(1),
+				_ => {}
+			}
+			// The `+` & `-` operators in math functions must keep their whitespace, e.g. `calc(1px + 2px)`.
+			let operator = self.math.is_operator(*c);
+			self.whitespace |= operator;
+			self.write_cursor(*c)?;
+			self.math.update(*c, self.source_text);
+			self.whitespace = operator;
+			self.trim_whitespace =
+				kind == Kind::Delim && !self.in_value && matches!(c.token().char(), Some('>' | '+' | '~'));
+			if matches!(kind, Kind::LeftParen | Kind::LeftSquare | Kind::Function) {
+				self.nesting += 1;
+			}
+		}
+		Ok(())
+	}
+
+	fn end_declaration(&mut self) {
+		self.in_value = false;
+		self.expect_declaration_colon = false;
+		self.at_statement_start = true;
+	}
+
+	fn write_cursor(&mut self, c: Cursor) -> fmt::Result {
+		let token = c.token();
+		match token.kind() {
+			Kind::Number => {
+				self.separate(c, Kind::Number, token.has_sign())?;
+				write_number(self.f, token.value(), token.has_sign())
+			}
+			Kind::Dimension if token.dimension_unit() != DimensionUnit::Unknown => {
+				self.separate(c, Kind::Dimension, token.has_sign())?;
+				write_number(self.f, token.value(), token.has_sign())?;
+				self.f.write_str(&token.dimension_unit().to_atom())
+			}
+			kind => {
+				self.separate(c, kind, false)?;
+				c.write_str(self.source_text, self.f)
+			}
+		}
+	}
+
+	// Whitespace is only kept where removing it would change the meaning, or the tokens, of the output. This is called
+	// just before the text of `c` is written (as `kind`, which may differ if the token has been rewritten).
+	fn separate(&mut self, c: Cursor, kind: Kind, has_sign: bool) -> fmt::Result {
+		let whitespace = self.whitespace && !self.trim_whitespace;
+		self.whitespace = false;
+		self.trim_whitespace = false;
+		let last = self.last.replace((kind, has_sign));
+		let Some((last_kind, _)) = last else {
+			self.f.mark(c);
+			return Ok(());
+		};
+		let ambiguous = if last_kind.ambiguous_without_whitespace() {
+			match kind {
+				Kind::Number | Kind::Dimension if matches!(last_kind, Kind::Ident | Kind::Dimension) => {
+					!has_sign || c.token().value().is_sign_negative()
+				}
+				Kind::Number | Kind::Dimension => !has_sign,
+				Kind::LeftParen => last_kind == Kind::Ident,
+				Kind::Ident | Kind::Function | Kind::Url => true,
+				_ => false,
+			}
+		} else {
+			false
+		};
+		let redundant = matches!(
+			last_kind,
+			Kind::LeftParen
+				| Kind::LeftSquare
+				| Kind::Function
+				| Kind::LeftCurly
+				| Kind::RightCurly
+				| Kind::Semicolon
+				| Kind::Colon
+				| Kind::Comma
+		) || matches!(
+			kind,
+			Kind::RightParen | Kind::RightSquare | Kind::LeftCurly | Kind::RightCurly | Kind::Semicolon | Kind::Comma
+		);
+		if ambiguous || (whitespace && !redundant) {
+			self.f.write_char(' ')?;
+		}
+		self.f.mark(c);
+		Ok(())
+	}
+}
+
+// Leading zeros are redundant, e.g. `0.5` can be written as `.5`.
+fn write_number(f: &mut impl fmt::Write, value: f32, has_sign: bool) -> fmt::Result {
+	let str = if has_sign { format!("{:+}", value) } else { format!("{}", value) };
+	match str.split_once("0.") {
+		Some((sign, fraction)) if sign.is_empty() || sign == "-" || sign == "+" => {
+			f.write_str(sign)?;
+			f.write_char('.')?;
+			f.write_str(fraction)
+		}
+		_ => f.write_str(&str),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_minify() {
+		assert_minify!("a { color: red; }", "a{color:red}");
+		assert_minify!("a , b > c { width : 1px ; }\n\n d {}", "a,b>c{width:1px}");
+		assert_minify!("a{margin:0px 0.50em -0.5px 10px}", "a{margin:0 .5em -.5px 10px}");
+		assert_minify!("a{color:#FFFFFF;background:#ff0000}", "a{color:#fff;background:red}");
+		assert_minify!("a{color:white;border:1px solid black}", "a{color:#fff;border:1px solid#000}");
+		assert_minify!("a{color:#11223344}", "a{color:#1234}");
+		assert_minify!("#fff{--foo: #ffffff  0px;flex:1 1 0px}", "#fff{--foo:#ffffff 0px;flex:1 1 0px}");
+		assert_minify!("a{width:calc(0px + 1em)}", "a{width:calc(0px + 1em)}");
+		assert_minify!("@media print { a { b { } } c { opacity: 0.5 } }", "@media print{c{opacity:.5}}");
+		assert_minify!(
+			"@counter-style x { symbols: a; range: infinite -1 }",
+			"@counter-style x{symbols:a;range:infinite -1}"
+		);
+	}
+
+	#[test]
+	fn test_source_map() {
+		use bumpalo::Bump;
+		use hdx_ast::css::StyleSheet;
+		use hdx_parser::{CursorStream, Features, Parser, SourceMapWriter};
+
+		// Columns are counted in UTF-16 code units. Removed rules, and colors which have been rewritten, have no mappings.
+		let source_text = "a { }\n/*é*/b { color: white }";
+		let allocator = Bump::default();
+		let mut result = Parser::new(&allocator, source_text, Features::default()).parse_entirely::<StyleSheet>();
+		super::minify(&mut result);
+		let mut writer = SourceMapWriter::new(String::new()).with_source("a.css", source_text);
+		super::write_minified_with_map(&result, result.source_text, &mut CursorStream::new(&allocator), &mut writer)
+			.unwrap();
+		let (str, map) = writer.finish();
+		assert_eq!(str, "b{color:#fff}");
+		assert_eq!(
+			map.mappings().iter().map(|m| (m.generated_column, m.original_line, m.original_column)).collect::<Vec<_>>(),
+			vec![(0, 1, 5), (1, 1, 7), (2, 1, 9), (7, 1, 14), (12, 1, 22)]
+		);
+	}
+}
@@ -0,0 +1,247 @@
+use core::fmt;
+use std::time::Duration;
+
+use hdx_ast::css::{
+	visit::{VisitMut, VisitableMut},
+	StyleSheet,
+};
+use hdx_parser::ParserReturn;
+
+use crate::{ReduceColors, ReduceInitial, ReduceZeroLengths, RemoveEmptyRules, RemoveTrailingSemicolons};
+
+// A Transform is a VisitMut pass which rewrites a StyleSheet in place. Each Transform has a stable name (used in
+// reports) and keeps count of the changes it has made, so that a Pipeline can tell when it has reached a fixed point.
+pub trait Transform<'a>: VisitMut<'a> {
+	const NAME: &'static str;
+
+	/// The number of changes made to any StyleSheet this Transform has visited.
+	fn changes(&self) -> usize;
+
+	/// Called before each visit of the StyleSheet, with the source text its Cursors point into. Transforms which add
+	/// new values need text for their Cursors to point into, so may return a longer source text (allocated in the
+	/// StyleSheet's allocator), which must start with the given source text.
+	fn prepare(&mut self, _stylesheet: &StyleSheet<'a>, source_text: &'a str) -> &'a str {
+		source_text
+	}
+}
+
+// VisitMut is not object safe, so Pipelines store their passes through this trait instead, which is implemented for
+// every Transform.
+pub trait Pass<'a> {
+	fn name(&self) -> &'static str;
+
+	/// Runs the pass over the parsed StyleSheet, returning the number of changes it made. The source text of `result`
+	/// is updated if the pass needed to add to it, so the StyleSheet must be written with it afterwards.
+	fn run(&mut self, result: &mut ParserReturn<'a, StyleSheet<'a>>) -> usize;
+}
+
+impl<'a, T: Transform<'a>> Pass<'a> for T {
+	fn name(&self) -> &'static str {
+		T::NAME
+	}
+
+	fn run(&mut self, result: &mut ParserReturn<'a, StyleSheet<'a>>) -> usize {
+		let Some(stylesheet) = &mut result.output else { return 0 };
+		result.source_text = self.prepare(stylesheet, result.source_text);
+		let before = self.changes();
+		stylesheet.accept_mut(self);
+		self.changes() - before
+	}
+}
+
+/// Options to select which transforms a [Pipeline] runs, shared between the CLI, WASM & LSP.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default, rename_all = "camelCase"))]
+pub struct TransformOptions {
+	/// Remove rules which contain no declarations. See [RemoveEmptyRules].
+	pub remove_empty_rules: bool,
+	/// Remove the semicolon after the last declaration in a block. See [RemoveTrailingSemicolons].
+	pub remove_trailing_semicolons: bool,
+	/// Replace the `initial` keyword with the property's initial value, where that is shorter. See [ReduceInitial].
+	pub reduce_initial: bool,
+	/// Rewrite colors to their shortest equivalent hex or named color. See [ReduceColors].
+	pub reduce_colors: bool,
+	/// Drop the units from zero lengths. See [ReduceZeroLengths].
+	pub reduce_zero_lengths: bool,
+	/// Re-run every pass until none of them make any changes, rather than running each pass once.
+	pub fixed_point: bool,
+}
+
+impl TransformOptions {
+	/// Every transform which makes the StyleSheet smaller without changing what it means.
+	pub fn minify() -> Self {
+		Self {
+			remove_empty_rules: true,
+			remove_trailing_semicolons: true,
+			reduce_initial: true,
+			reduce_colors: true,
+			reduce_zero_lengths: true,
+			fixed_point: true,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassReport {
+	pub name: &'static str,
+	/// The total time spent in this pass, across every iteration.
+	pub duration: Duration,
+	/// The total number of changes this pass made, across every iteration.
+	pub changes: usize,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PipelineReport {
+	pub passes: Vec<PassReport>,
+	/// How many times the passes were run. This is only ever more than 1 for a fixed point Pipeline.
+	pub iterations: usize,
+}
+
+impl PipelineReport {
+	pub fn changes(&self) -> usize {
+		self.passes.iter().map(|pass| pass.changes).sum()
+	}
+
+	pub fn duration(&self) -> Duration {
+		self.passes.iter().map(|pass| pass.duration).sum()
+	}
+}
+
+impl fmt::Display for PipelineReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for pass in &self.passes {
+			writeln!(f, "{}: {} change(s) in {:?}", pass.name, pass.changes, pass.duration)?;
+		}
+		write!(f, "{} change(s) in {:?} over {} iteration(s)", self.changes(), self.duration(), self.iterations)
+	}
+}
+
+/// Runs an ordered list of passes over a StyleSheet.
+///
+/// ```
+/// use bumpalo::Bump;
+/// use hdx_ast::css::StyleSheet;
+/// use hdx_parser::{Features, Parser};
+/// use hdx_transform::{Pipeline, RemoveEmptyRules};
+///
+/// let allocator = Bump::default();
+/// let mut result = Parser::new(&allocator, "a{}b{color:red}", Features::default()).parse_entirely::<StyleSheet>();
+/// let report = Pipeline::new().register(RemoveEmptyRules::default()).run(&mut result);
+/// assert_eq!(report.changes(), 1);
+/// ```
+#[derive(Default)]
+pub struct Pipeline<'a> {
+	passes: Vec<Box<dyn Pass<'a> + 'a>>,
+	fixed_point: bool,
+}
+
+impl<'a> Pipeline<'a> {
+	// Guards against passes which undo each other's changes, which would otherwise never reach a fixed point.
+	pub const MAX_ITERATIONS: usize = 16;
+
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// A Pipeline with each of the built in transforms enabled by the options, in the order they should run.
+	pub fn from_options(options: &TransformOptions) -> Self {
+		let mut pipeline = Self::new().fixed_point(options.fixed_point);
+		if options.remove_empty_rules {
+			pipeline = pipeline.register(RemoveEmptyRules::default());
+		}
+		if options.remove_trailing_semicolons {
+			pipeline = pipeline.register(RemoveTrailingSemicolons::default());
+		}
+		if options.reduce_initial {
+			pipeline = pipeline.register(ReduceInitial::default());
+		}
+		if options.reduce_colors {
+			pipeline = pipeline.register(ReduceColors::default());
+		}
+		if options.reduce_zero_lengths {
+			pipeline = pipeline.register(ReduceZeroLengths::default());
+		}
+		pipeline
+	}
+
+	/// Adds a pass to run after all of the passes already in the Pipeline.
+	pub fn register(mut self, pass: impl Pass<'a> + 'a) -> Self {
+		self.passes.push(Box::new(pass));
+		self
+	}
+
+	pub fn fixed_point(mut self, fixed_point: bool) -> Self {
+		self.fixed_point = fixed_point;
+		self
+	}
+
+	pub fn run(&mut self, result: &mut ParserReturn<'a, StyleSheet<'a>>) -> PipelineReport {
+		let mut report = PipelineReport {
+			passes: self
+				.passes
+				.iter()
+				.map(|pass| PassReport { name: pass.name(), duration: Duration::ZERO, changes: 0 })
+				.collect(),
+			iterations: 0,
+		};
+		loop {
+			report.iterations += 1;
+			let mut changes = 0;
+			for (pass, pass_report) in self.passes.iter_mut().zip(report.passes.iter_mut()) {
+				let (pass_changes, duration) = time(|| pass.run(result));
+				pass_report.duration += duration;
+				pass_report.changes += pass_changes;
+				changes += pass_changes;
+			}
+			if !self.fixed_point || changes == 0 || report.iterations >= Self::MAX_ITERATIONS {
+				return report;
+			}
+		}
+	}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+	let start = std::time::Instant::now();
+	let value = f();
+	(value, start.elapsed())
+}
+
+// Instant panics on wasm32-unknown-unknown, so passes aren't timed there.
+#[cfg(target_arch = "wasm32")]
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+	(f(), Duration::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use hdx_parser::{Features, Parser};
+
+	#[test]
+	fn test_pipeline() {
+		let allocator = Bump::default();
+		let mut result = Parser::new(&allocator, "a{b{}}c{min-width:initial;color:#ff0000;}", Features::default())
+			.parse_entirely::<StyleSheet>();
+		let report = Pipeline::from_options(&TransformOptions::minify()).run(&mut result);
+		assert_eq!(
+			report.passes.iter().map(|pass| (pass.name, pass.changes)).collect::<Vec<_>>(),
+			vec![
+				(RemoveEmptyRules::NAME, 1),
+				(RemoveTrailingSemicolons::NAME, 1),
+				(ReduceInitial::NAME, 1),
+				(ReduceColors::NAME, 1),
+				(ReduceZeroLengths::NAME, 0)
+			]
+		);
+		// ReduceInitial & ReduceColors add the text of the new values to the source text.
+		assert_eq!(result.source_text, "a{b{}}c{min-width:initial;color:#ff0000;} auto red");
+		// The second iteration makes no changes, so the Pipeline stops.
+		assert_eq!(report.iterations, 2);
+
+		let mut result = Parser::new(&allocator, "a{}", Features::default()).parse_entirely::<StyleSheet>();
+		let report = Pipeline::new().run(&mut result);
+		assert_eq!(report, PipelineReport { passes: vec![], iterations: 1 });
+	}
+}
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+
+use bumpalo::Bump;
+use hdx_ast::css::{
+	visit::{Visit, VisitMut, Visitable},
+	Color, StyleSheet,
+};
+use hdx_lexer::{Cursor, SourceOffset};
+use hdx_parser::{Features, Parser};
+
+use crate::Transform;
+
+// Rewrites hex & named colors to the shortest equivalent hex or named color, for example `#FFFFFF` becomes `#fff`
+// and `#ff0000` becomes `red`. The text of each new color is added to the source text once, before the StyleSheet is
+// visited, and each color which shortens to it shares the same parsed Color.
+#[derive(Default)]
+pub struct ReduceColors<'a> {
+	replacements: HashMap<String, Color<'a>>,
+	source_text: &'a str,
+	changes: usize,
+}
+
+impl<'a> ReduceColors<'a> {
+	fn intern(&mut self, allocator: &'a Bump, source_text: &'a str, colors: Vec<String>) -> &'a str {
+		let mut text = String::from(source_text);
+		let mut offsets = vec![];
+		for color in colors {
+			if offsets.iter().any(|(existing, _)| *existing == color) {
+				continue;
+			}
+			// Each color is separated so that it lexes as its own token, e.g. `#fffred` would be a single hash.
+			text.push(' ');
+			let offset = SourceOffset(text.len() as u32);
+			text.push_str(&color);
+			offsets.push((color, offset));
+		}
+		let source_text = allocator.alloc_str(&text);
+		for (color, offset) in offsets {
+			let mut p = Parser::new(allocator, source_text, Features::default());
+			p.hop(offset);
+			if let Ok(parsed) = p.parse::<Color>() {
+				self.replacements.insert(color, parsed);
+			}
+		}
+		source_text
+	}
+}
+
+impl<'a> Transform<'a> for ReduceColors<'a> {
+	const NAME: &'static str = "reduce-colors";
+
+	fn changes(&self) -> usize {
+		self.changes
+	}
+
+	fn prepare(&mut self, stylesheet: &StyleSheet<'a>, source_text: &'a str) -> &'a str {
+		let mut colors = ShorterColors { source_text, colors: vec![] };
+		stylesheet.accept(&mut colors);
+		self.replacements.clear();
+		self.source_text = if colors.colors.is_empty() {
+			source_text
+		} else {
+			self.intern(stylesheet.rules.bump(), source_text, colors.colors)
+		};
+		self.source_text
+	}
+}
+
+impl<'a> VisitMut<'a> for ReduceColors<'a> {
+	fn visit_color(&mut self, color: &mut Color<'a>) {
+		let Some(shorter) = shorter_color(color, self.source_text) else { return };
+		if let Some(replacement) = self.replacements.get(&shorter) {
+			*color = *replacement;
+			self.changes += 1;
+		}
+	}
+}
+
+// Collects the text of the shorter form of each color which has one.
+#[derive(Default)]
+struct ShorterColors<'a> {
+	source_text: &'a str,
+	colors: Vec<String>,
+}
+
+impl<'a> Visit<'a> for ShorterColors<'a> {
+	fn visit_color(&mut self, color: &Color<'a>) {
+		self.colors.extend(shorter_color(color, self.source_text));
+	}
+}
+
+// The shortest way to write a hex or named color, if it's shorter than the color as written.
+fn shorter_color(color: &Color, source_text: &str) -> Option<String> {
+	let (c, rgba) = match color {
+		Color::Hex(hash) => {
+			let c = Cursor::from(*hash);
+			(c, parse_hex(&c.str_slice(source_text)[1..])?)
+		}
+		Color::Named(named) => {
+			let c = Cursor::from(named);
+			(c, named_color(c.str_slice(source_text))? << 8 | 0xff)
+		}
+		_ => return None,
+	};
+	if c.token().contains_escape_chars() {
+		return None;
+	}
+	let shortest = shortest_color(rgba);
+	(shortest.len() < c.str_slice(source_text).len()).then_some(shortest)
+}
+
+// Parses the digits of a hex color into an RGBA value.
+fn parse_hex(str: &str) -> Option<u32> {
+	if !str.bytes().all(|b| b.is_ascii_hexdigit()) {
+		return None;
+	}
+	let digits = match str.len() {
+		3 | 4 => str.chars().flat_map(|c| [c, c]).collect::<String>(),
+		6 | 8 => str.to_string(),
+		_ => return None,
+	};
+	let value = u32::from_str_radix(&digits, 16).ok()?;
+	Some(if digits.len() == 6 { value << 8 | 0xff } else { value })
+}
+
+fn named_color(str: &str) -> Option<u32> {
+	NAMED_COLORS.iter().find(|(name, _)| name.eq_ignore_ascii_case(str)).map(|(_, rgb)| *rgb)
+}
+
+// Returns the shortest way to write an RGBA color, either as a hex color or as a named color.
+fn shortest_color(rgba: u32) -> String {
+	let (hex, len) = if rgba & 0xff == 0xff { (rgba >> 8, 6) } else { (rgba, 8) };
+	let mut digits = format!("{:01$x}", hex, len);
+	let bytes = digits.as_bytes();
+	if bytes.chunks(2).all(|pair| pair[0] == pair[1]) {
+		digits = bytes.chunks(2).map(|pair| pair[0] as char).collect();
+	}
+	if len == 6 {
+		let name =
+			NAMED_COLORS.iter().filter(|(_, rgb)| *rgb == hex).map(|(name, _)| *name).min_by_key(|name| name.len());
+		if let Some(name) = name.filter(|name| name.len() <= digits.len()) {
+			return name.to_string();
+		}
+	}
+	format!("#{}", digits)
+}
+
+// https://drafts.csswg.org/css-color/#named-colors
+const NAMED_COLORS: &[(&str, u32)] = &[
+	("aliceblue", 0xf0f8ff),
+	("antiquewhite", 0xfaebd7),
+	("aqua", 0x00ffff),
+	("aquamarine", 0x7fffd4),
+	("azure", 0xf0ffff),
+	("beige", 0xf5f5dc),
+	("bisque", 0xffe4c4),
+	("black", 0x000000),
+	("blanchedalmond", 0xffebcd),
+	("blue", 0x0000ff),
+	("blueviolet", 0x8a2be2),
+	("brown", 0xa52a2a),
+	("burlywood", 0xdeb887),
+	("cadetblue", 0x5f9ea0),
+	("chartreuse", 0x7fff00),
+	("chocolate", 0xd2691e),
+	("coral", 0xff7f50),
+	("cornflowerblue", 0x6495ed),
+	("cornsilk", 0xfff8dc),
+	("crimson", 0xdc143c),
+	("cyan", 0x00ffff),
+	("darkblue", 0x00008b),
+	("darkcyan", 0x008b8b),
+	("darkgoldenrod", 0xb8860b),
+	("darkgray", 0xa9a9a9),
+	("darkgreen", 0x006400),
+	("darkgrey", 0xa9a9a9),
+	("darkkhaki", 0xbdb76b),
+	("darkmagenta", 0x8b008b),
+	("darkolivegreen", 0x556b2f),
+	("darkorange", 0xff8c00),
+	("darkorchid", 0x9932cc),
+	("darkred", 0x8b0000),
+	("darksalmon", 0xe9967a),
+	("darkseagreen", 0x8fbc8f),
+	("darkslateblue", 0x483d8b),
+	("darkslategray", 0x2f4f4f),
+	("darkslategrey", 0x2f4f4f),
+	("darkturquoise", 0x00ced1),
+	("darkviolet", 0x9400d3),
+	("deeppink", 0xff1493),
+	("deepskyblue", 0x00bfff),
+	("dimgray", 0x696969),
+	("dimgrey", 0x696969),
+	("dodgerblue", 0x1e90ff),
+	("firebrick", 0xb22222),
+	("floralwhite", 0xfffaf0),
+	("forestgreen", 0x228b22),
+	("fuchsia", 0xff00ff),
+	("gainsboro", 0xdcdcdc),
+	("ghostwhite", 0xf8f8ff),
+	("gold", 0xffd700),
+	("goldenrod", 0xdaa520),
+	("gray", 0x808080),
+	("green", 0x008000),
+	("greenyellow", 0xadff2f),
+	("grey", 0x808080),
+	("honeydew", 0xf0fff0),
+	("hotpink", 0xff69b4),
+	("indianred", 0xcd5c5c),
+	("indigo", 0x4b0082),
+	("ivory", 0xfffff0),
+	("khaki", 0xf0e68c),
+	("lavender", 0xe6e6fa),
+	("lavenderblush", 0xfff0f5),
+	("lawngreen", 0x7cfc00),
+	("lemonchiffon", 0xfffacd),
+	("lightblue", 0xadd8e6),
+	("lightcoral", 0xf08080),
+	("lightcyan", 0xe0ffff),
+	("lightgoldenrodyellow", 0xfafad2),
+	("lightgray", 0xd3d3d3),
+	("lightgreen", 0x90ee90),
+	("lightgrey", 0xd3d3d3),
+	("lightpink", 0xffb6c1),
+	("lightsalmon", 0xffa07a),
+	("lightseagreen", 0x20b2aa),
+	("lightskyblue", 0x87cefa),
+	("lightslategray", 0x778899),
+	("lightslategrey", 0x778899),
+	("lightsteelblue", 0xb0c4de),
+	("lightyellow", 0xffffe0),
+	("lime", 0x00ff00),
+	("limegreen", 0x32cd32),
+	("linen", 0xfaf0e6),
+	("magenta", 0xff00ff),
+	("maroon", 0x800000),
+	("mediumaquamarine", 0x66cdaa),
+	("mediumblue", 0x0000cd),
+	("mediumorchid", 0xba55d3),
+	("mediumpurple", 0x9370db),
+	("mediumseagreen", 0x3cb371),
+	("mediumslateblue", 0x7b68ee),
+	("mediumspringgreen", 0x00fa9a),
+	("mediumturquoise", 0x48d1cc),
+	("mediumvioletred", 0xc71585),
+	("midnightblue", 0x191970),
+	("mintcream", 0xf5fffa),
+	("mistyrose", 0xffe4e1),
+	("moccasin", 0xffe4b5),
+	("navajowhite", 0xffdead),
+	("navy", 0x000080),
+	("oldlace", 0xfdf5e6),
+	("olive", 0x808000),
+	("olivedrab", 0x6b8e23),
+	("orange", 0xffa500),
+	("orangered", 0xff4500),
+	("orchid", 0xda70d6),
+	("palegoldenrod", 0xeee8aa),
+	("palegreen", 0x98fb98),
+	("paleturquoise", 0xafeeee),
+	("palevioletred", 0xdb7093),
+	("papayawhip", 0xffefd5),
+	("peachpuff", 0xffdab9),
+	("peru", 0xcd853f),
+	("pink", 0xffc0cb),
+	("plum", 0xdda0dd),
+	("powderblue", 0xb0e0e6),
+	("purple", 0x800080),
+	("rebeccapurple", 0x663399),
+	("red", 0xff0000),
+	("rosybrown", 0xbc8f8f),
+	("royalblue", 0x4169e1),
+	("saddlebrown", 0x8b4513),
+	("salmon", 0xfa8072),
+	("sandybrown", 0xf4a460),
+	("seagreen", 0x2e8b57),
+	("seashell", 0xfff5ee),
+	("sienna", 0xa0522d),
+	("silver", 0xc0c0c0),
+	("skyblue", 0x87ceeb),
+	("slateblue", 0x6a5acd),
+	("slategray", 0x708090),
+	("slategrey", 0x708090),
+	("snow", 0xfffafa),
+	("springgreen", 0x00ff7f),
+	("steelblue", 0x4682b4),
+	("tan", 0xd2b48c),
+	("teal", 0x008080),
+	("thistle", 0xd8bfd8),
+	("tomato", 0xff6347),
+	("turquoise", 0x40e0d0),
+	("violet", 0xee82ee),
+	("wheat", 0xf5deb3),
+	("white", 0xffffff),
+	("whitesmoke", 0xf5f5f5),
+	("yellow", 0xffff00),
+	("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_transform() {
+		assert_transform!(ReduceColors, "a{color:#FFFFFF;background:#ff0000}", "a{color:#fff;background:red}");
+		assert_transform!(ReduceColors, "a{color:white;border:1px solid black}", "a{color:#fff;border:1px solid #000}");
+		assert_transform!(ReduceColors, "a{color:#11223344}", "a{color:#1234}");
+		assert_transform!(
+			ReduceColors,
+			"a{box-shadow:0 0 1px #000000,1px 1px #FF0000}",
+			"a{box-shadow:0 0 1px #000,1px 1px red}"
+		);
+		// Custom properties & selectors aren't colors, so are left alone.
+		assert_transform!(ReduceColors, "#ffffff{--foo:#ffffff}", "#ffffff{--foo:#ffffff}");
+		// Colors which are already as short as they can be are left alone.
+		assert_transform!(ReduceColors, "a{color:#FFF;background:red}", "a{color:#FFF;background:red}");
+	}
+}
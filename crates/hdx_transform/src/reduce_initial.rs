@@ -1,18 +1,121 @@
+use std::collections::HashMap;
+
+use bumpalo::Bump;
 use hdx_ast::css::{
 	properties::{Property, StyleValue},
-	visit::VisitMut,
+	visit::{Visit, VisitMut, Visitable},
+	StyleSheet,
 };
+use hdx_atom::Atom;
+use hdx_lexer::{Cursor, SourceOffset};
+use hdx_parser::{Features, Parser};
+
+use crate::Transform;
 
+// Replaces the `initial` keyword with the property's initial value, where that is shorter, for example
+// `min-width:initial` becomes `min-width:auto`. The text of each initial value is added to the source text once,
+// before the StyleSheet is visited, and each property with that initial value shares the same parsed StyleValue.
 #[derive(Default)]
-pub struct ReduceInitial();
+pub struct ReduceInitial<'a> {
+	// Keyed by the Cursor of each property's name, as names may be written in any case.
+	replacements: HashMap<Cursor, StyleValue<'a>>,
+	changes: usize,
+}
 
-impl<'a> VisitMut<'a> for ReduceInitial {
-	fn visit_property(&mut self, property: &mut Property<'a>) {
-		if matches!(&property.value, StyleValue::Initial(_)) {
-			if let Some(def) = StyleValue::default_for(&property.name) {
-				property.value = def;
+impl<'a> ReduceInitial<'a> {
+	fn intern(&mut self, allocator: &'a Bump, source_text: &'a str, properties: Vec<(Cursor, Cursor)>) -> &'a str {
+		let mut text = String::from(source_text);
+		let mut offsets: Vec<(&'static str, SourceOffset)> = vec![];
+		let mut names: Vec<(Atom, &'static str, SourceOffset)> = vec![];
+		let mut shorter = vec![];
+		for (c, keyword) in properties {
+			let name = c.parse_atom_lower(source_text, allocator);
+			let Some(initial) = StyleValue::initial_text(&name) else { continue };
+			if initial.len() >= keyword.str_slice(source_text).len() {
+				continue;
+			}
+			if !names.iter().any(|(existing, _, _)| *existing == name) {
+				let offset = match offsets.iter().find(|(existing, _)| *existing == initial) {
+					Some((_, offset)) => *offset,
+					None => {
+						// Each value is separated so that it lexes on its own, e.g. `none` then `0` would be `none0`.
+						text.push(' ');
+						let offset = SourceOffset(text.len() as u32);
+						text.push_str(initial);
+						offsets.push((initial, offset));
+						offset
+					}
+				};
+				names.push((name.clone(), initial, offset));
+			}
+			shorter.push((c, name));
+		}
+		if shorter.is_empty() {
+			return source_text;
+		}
+		let source_text = allocator.alloc_str(&text);
+		let mut values = HashMap::new();
+		for (name, initial, offset) in names {
+			// The Parser stops at the end of the value, as it must parse all of it. The prefix is the same, so the
+			// Cursors still point into the full source text.
+			let end = offset.0 as usize + initial.len();
+			let mut p = Parser::new(allocator, &source_text[..end], Features::default());
+			p.hop(offset);
+			if let Some(value) = StyleValue::parse_initial(&mut p, &name) {
+				values.insert(name, value);
+			}
+		}
+		for (c, name) in shorter {
+			if let Some(value) = values.get(&name) {
+				self.replacements.insert(c, value.clone());
 			}
 		}
+		source_text
+	}
+}
+
+impl<'a> Transform<'a> for ReduceInitial<'a> {
+	const NAME: &'static str = "reduce-initial";
+
+	fn changes(&self) -> usize {
+		self.changes
+	}
+
+	fn prepare(&mut self, stylesheet: &StyleSheet<'a>, source_text: &'a str) -> &'a str {
+		let mut initials = InitialProperties::default();
+		stylesheet.accept(&mut initials);
+		self.replacements.clear();
+		if initials.properties.is_empty() {
+			source_text
+		} else {
+			self.intern(stylesheet.rules.bump(), source_text, initials.properties)
+		}
+	}
+}
+
+impl<'a> VisitMut<'a> for ReduceInitial<'a> {
+	fn visit_property(&mut self, property: &mut Property<'a>) {
+		if !matches!(property.value, StyleValue::Initial(_)) {
+			return;
+		}
+		if let Some(replacement) = self.replacements.get(&Cursor::from(property.name)) {
+			property.value = replacement.clone();
+			self.changes += 1;
+		}
+	}
+}
+
+// Collects the name & keyword of each property which is set to `initial`.
+#[derive(Default)]
+struct InitialProperties {
+	properties: Vec<(Cursor, Cursor)>,
+}
+
+impl<'a> Visit<'a> for InitialProperties {
+	fn visit_property(&mut self, property: &Property<'a>) {
+		if let StyleValue::Initial(keyword) = &property.value {
+			self.properties.push((property.name.into(), (*keyword).into()));
+		}
 	}
 }
 
@@ -21,8 +124,7 @@ mod tests {
 	use super::*;
 	use crate::test_helpers::*;
 
-	// FIXME: This fails but we want green CI for now.
-	// #[test]
+	#[test]
 	fn test_transform() {
 		assert_transform!(
 			ReduceInitial,
@@ -34,12 +136,33 @@ mod tests {
 				orphans: initial;
 			}"#,
 			r#"body {
-				appearance: auto;
+				appearance: none;
 				direction: ltr;
 				min-width: auto;
 				object-fit: fill;
 				orphans: 2;
 			}"#
 		);
+		// Properties which share an initial value share its text.
+		assert_transform!(
+			ReduceInitial,
+			"a{min-width:initial;min-height:INITIAL}",
+			"a{min-width:auto;min-height:auto}"
+		);
+		// `currentcolor` is longer than `initial`, so it's left alone.
+		assert_transform!(ReduceInitial, "a{border-top-color:initial}", "a{border-top-color:initial}");
+	}
+
+	#[test]
+	fn test_source_text() {
+		use crate::Pass;
+		use bumpalo::Bump;
+
+		let allocator = Bump::default();
+		let source_text = "a{min-width:initial;min-height:initial;direction:initial}b{min-width:initial}";
+		let mut result = Parser::new(&allocator, source_text, Features::default()).parse_entirely::<StyleSheet>();
+		assert_eq!(ReduceInitial::default().run(&mut result), 4);
+		// Each initial value is added once, however many properties use it.
+		assert_eq!(result.source_text, format!("{} auto ltr", source_text));
 	}
 }
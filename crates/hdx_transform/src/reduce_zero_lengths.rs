@@ -0,0 +1,50 @@
+use hdx_ast::css::{visit::VisitMut, Length, LengthPercentage};
+use hdx_parser::T;
+
+use crate::Transform;
+
+// Zero lengths don't need a unit, for example `margin:0px` can be written as `margin:0`. Zero percentages are left
+// alone, as a percentage can behave differently to a length, for example `flex-basis:0%` when the container has no
+// definite size.
+#[derive(Default)]
+pub struct ReduceZeroLengths {
+	changes: usize,
+}
+
+impl Transform<'_> for ReduceZeroLengths {
+	const NAME: &'static str = "reduce-zero-lengths";
+
+	fn changes(&self) -> usize {
+		self.changes
+	}
+}
+
+impl VisitMut<'_> for ReduceZeroLengths {
+	fn visit_length(&mut self, length: &mut Length) {
+		if !matches!(length, Length::Zero(_)) && length.to_f32() == Some(0.0) {
+			*length = Length::Zero(<T![Number]>::ZERO);
+			self.changes += 1;
+		}
+	}
+
+	fn visit_length_percentage(&mut self, length: &mut LengthPercentage) {
+		if !matches!(length, LengthPercentage::Zero(_) | LengthPercentage::Percent(_)) && length.to_f32() == Some(0.0) {
+			*length = LengthPercentage::Zero(<T![Number]>::ZERO);
+			self.changes += 1;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_transform() {
+		assert_transform!(ReduceZeroLengths, "a{margin:0px 0.5em -0px 10px}", "a{margin:0 0.5em 0 10px}");
+		assert_transform!(ReduceZeroLengths, "a{box-shadow:0px 0px 1px red}", "a{box-shadow:0 0 1px red}");
+		assert_transform!(ReduceZeroLengths, "a{border:0px solid}", "a{border:0 solid}");
+		assert_transform!(ReduceZeroLengths, "a{width:0%;--foo:0px}", "a{width:0%;--foo:0px}");
+	}
+}
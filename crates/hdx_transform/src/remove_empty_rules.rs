@@ -0,0 +1,104 @@
+use bumpalo::collections::Vec;
+use hdx_ast::css::{
+	visit::VisitMut, ContainerRule, DocumentRule, LayerRule, MediaRule, NestedGroupRule, OptionalLayerRuleBlock, Rule,
+	StyleDeclaration, StyleSheet, SupportsRule,
+};
+
+use crate::Transform;
+
+// Rules which contain no declarations have no effect, neither do conditional group rules which only contain rules
+// without declarations. Other rules, such as @layer or @keyframes, can affect the cascade even when empty, so they're
+// left alone.
+#[derive(Default)]
+pub struct RemoveEmptyRules {
+	changes: usize,
+}
+
+impl RemoveEmptyRules {
+	fn is_empty_rule(rule: &Rule) -> bool {
+		match rule {
+			Rule::Style(rule) => Self::is_empty_style(&rule.style),
+			Rule::ContainerRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			Rule::DocumentRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			Rule::MediaRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			Rule::SupportsRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			_ => false,
+		}
+	}
+
+	fn is_empty_nested_rule(rule: &NestedGroupRule) -> bool {
+		match rule {
+			NestedGroupRule::Style(rule) => Self::is_empty_style(&rule.style),
+			NestedGroupRule::ContainerRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			NestedGroupRule::MediaRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			NestedGroupRule::SupportsRule(rule) => rule.block.rules.iter().all(Self::is_empty_rule),
+			_ => false,
+		}
+	}
+
+	fn is_empty_style(style: &StyleDeclaration) -> bool {
+		style.declarations.is_empty() && style.rules.iter().all(Self::is_empty_nested_rule)
+	}
+
+	fn remove_empty_rules(&mut self, rules: &mut Vec<'_, Rule>) {
+		let len = rules.len();
+		rules.retain(|rule| !Self::is_empty_rule(rule));
+		self.changes += len - rules.len();
+	}
+}
+
+impl Transform<'_> for RemoveEmptyRules {
+	const NAME: &'static str = "remove-empty-rules";
+
+	fn changes(&self) -> usize {
+		self.changes
+	}
+}
+
+impl<'a> VisitMut<'a> for RemoveEmptyRules {
+	fn visit_style_sheet(&mut self, stylesheet: &mut StyleSheet<'a>) {
+		self.remove_empty_rules(&mut stylesheet.rules);
+	}
+
+	fn visit_style_declaration(&mut self, style: &mut StyleDeclaration<'a>) {
+		let len = style.rules.len();
+		style.rules.retain(|rule| !Self::is_empty_nested_rule(rule));
+		self.changes += len - style.rules.len();
+	}
+
+	fn visit_container_rule(&mut self, rule: &mut ContainerRule<'a>) {
+		self.remove_empty_rules(&mut rule.block.rules);
+	}
+
+	fn visit_document_rule(&mut self, rule: &mut DocumentRule<'a>) {
+		self.remove_empty_rules(&mut rule.block.rules);
+	}
+
+	fn visit_layer_rule(&mut self, rule: &mut LayerRule<'a>) {
+		if let OptionalLayerRuleBlock::Block(block) = &mut rule.block {
+			self.remove_empty_rules(&mut block.rules);
+		}
+	}
+
+	fn visit_media_rule(&mut self, rule: &mut MediaRule<'a>) {
+		self.remove_empty_rules(&mut rule.block.rules);
+	}
+
+	fn visit_supports_rule(&mut self, rule: &mut SupportsRule<'a>) {
+		self.remove_empty_rules(&mut rule.block.rules);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_transform() {
+		assert_transform!(RemoveEmptyRules, "a{}b{color:red;}", "b{color:red;}");
+		assert_transform!(RemoveEmptyRules, "a{b{}}c{d{color:red;}}", "c{d{color:red;}}");
+		assert_transform!(RemoveEmptyRules, "@media print{a{}}b{color:red;}", "b{color:red;}");
+		assert_transform!(RemoveEmptyRules, "@layer foo{a{}}", "@layer foo{}");
+	}
+}
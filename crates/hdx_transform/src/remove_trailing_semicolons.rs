@@ -0,0 +1,86 @@
+use hdx_ast::css::{visit::VisitMut, FontFaceRule, Keyframe, MarginRule, PageRule, PropertyRule, StyleDeclaration};
+
+use crate::Transform;
+
+// The last declaration in a block doesn't need a semicolon, as the closing `}` ends it. Blocks which have rules after
+// their declarations still need it, otherwise the rule's prelude would be parsed as part of the declaration's value.
+#[derive(Default)]
+pub struct RemoveTrailingSemicolons {
+	changes: usize,
+}
+
+impl Transform<'_> for RemoveTrailingSemicolons {
+	const NAME: &'static str = "remove-trailing-semicolons";
+
+	fn changes(&self) -> usize {
+		self.changes
+	}
+}
+
+impl<'a> VisitMut<'a> for RemoveTrailingSemicolons {
+	fn visit_style_declaration(&mut self, style: &mut StyleDeclaration<'a>) {
+		if style.rules.is_empty() {
+			if let Some(declaration) = style.declarations.last_mut() {
+				if declaration.semicolon.take().is_some() {
+					self.changes += 1;
+				}
+			}
+		}
+	}
+
+	fn visit_font_face_rule(&mut self, rule: &mut FontFaceRule<'a>) {
+		if let Some(property) = rule.block.properties.last_mut() {
+			if property.semicolon.take().is_some() {
+				self.changes += 1;
+			}
+		}
+	}
+
+	fn visit_keyframe(&mut self, keyframe: &mut Keyframe<'a>) {
+		if let Some(property) = keyframe.block.properties.last_mut() {
+			if property.semicolon.take().is_some() {
+				self.changes += 1;
+			}
+		}
+	}
+
+	fn visit_margin_rule(&mut self, rule: &mut MarginRule<'a>) {
+		if let Some(property) = rule.block.properties.last_mut() {
+			if property.semicolon.take().is_some() {
+				self.changes += 1;
+			}
+		}
+	}
+
+	fn visit_page_rule(&mut self, rule: &mut PageRule<'a>) {
+		if rule.block.rules.is_empty() {
+			if let Some(property) = rule.block.properties.last_mut() {
+				if property.semicolon.take().is_some() {
+					self.changes += 1;
+				}
+			}
+		}
+	}
+
+	fn visit_property_rule(&mut self, rule: &mut PropertyRule<'a>) {
+		if let Some(property) = rule.block.properties.last_mut() {
+			if property.semicolon.take().is_some() {
+				self.changes += 1;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn test_transform() {
+		assert_transform!(RemoveTrailingSemicolons, "a{color:red;}", "a{color:red}");
+		assert_transform!(RemoveTrailingSemicolons, "a{color:red;width:1px;}", "a{color:red;width:1px}");
+		assert_transform!(RemoveTrailingSemicolons, "a{color:red;b{color:blue;}}", "a{color:red;b{color:blue}}");
+		assert_transform!(RemoveTrailingSemicolons, "@font-face{font-display:swap;}", "@font-face{font-display:swap}");
+	}
+}
@@ -3,46 +3,38 @@ macro_rules! assert_transform {
 	($transform: ident, $str: literal, $expected: literal) => {
 		{
 			use bumpalo::Bump;
-			use hdx_ast::css::{StyleSheet, visit::VisitableMut};
-			use hdx_parser::{Features, Parser};
+			use hdx_ast::css::StyleSheet;
+			use hdx_parser::{CursorStream, Features, Parser};
+			use $crate::Pass;
 
 			let allocator = Bump::default();
-			let mut parser = Parser::new(&allocator, $str, Features::default());
-			let result = parser.parse_entirely::<StyleSheet>();
+			let mut result = Parser::new(&allocator, $str, Features::default()).parse_entirely::<StyleSheet>();
 			if !result.errors.is_empty() {
 				panic!("\n\nParse on {}:{} failed. ({:?}) saw error {:?}", file!(), line!(), $str, result.errors[0]);
 			}
-			let mut node = result.output.unwrap();
 
-			let mut eparser = Parser::new(&allocator, $expected, Features::default());
-			let eresult = eparser.parse_entirely::<StyleSheet>();
+			let mut eresult = Parser::new(&allocator, $expected, Features::default()).parse_entirely::<StyleSheet>();
 			if !eresult.errors.is_empty() {
-				panic!("\n\nParse expected on {}:{} failed. ({:?}) saw error {:?}", file!(), line!(), $expected, result.errors[0]);
+				panic!("\n\nParse expected on {}:{} failed. ({:?}) saw error {:?}", file!(), line!(), $expected, eresult.errors[0]);
 			}
-			let mut enode = eresult.output.unwrap();
 
 			let mut string = String::new();
-			node.(&mut writer).unwrap();
+			result.write(&mut CursorStream::new(&allocator), &mut string).unwrap();
 
 			let mut expected = String::new();
-			let mut ewriter = BaseCssWriter::new(&mut expected, OutputOption::all_bits());
-			enode.write_css(&mut ewriter).unwrap();
+			eresult.write(&mut CursorStream::new(&allocator), &mut expected).unwrap();
 
 			let mut transformed_string = String::new();
-			let mut transformed_writer = BaseCssWriter::new(&mut transformed_string, OutputOption::all_bits());
-			let mut t = $transform::default();
-			node.accept_mut(&mut t);
-			node.write_css(&mut transformed_writer).unwrap();
+			$transform::default().run(&mut result);
+			result.write(&mut CursorStream::new(&allocator), &mut transformed_string).unwrap();
 
-			if $expected != expected {
+			if transformed_string != expected {
 				panic!("\n\nTransform on {}:{} failed: did not match expected format:\n\n```pre-transformed\n{}```\n```transformed\n{}```\n```expected\n{}\n```", file!(), line!(), string, transformed_string, expected);
 			}
 
 			let mut etransformed_string = String::new();
-			let mut etransformed_writer = BaseCssWriter::new(&mut etransformed_string, OutputOption::all_bits());
-			let mut t = $transform::default();
-			enode.accept_mut(&mut t);
-			enode.write_css(&mut etransformed_writer).unwrap();
+			$transform::default().run(&mut eresult);
+			eresult.write(&mut CursorStream::new(&allocator), &mut etransformed_string).unwrap();
 
 			if expected != etransformed_string {
 				panic!("\n\nTransform on {}:{} failed. First transform was corrected but re-running the transform caused a different output, which is not desired:\n\n        transformed: {:?}\n second-transform: {:?}", file!(), line!(), expected, etransformed_string);
@@ -53,3 +45,41 @@ macro_rules! assert_transform {
 
 #[cfg(test)]
 pub(crate) use assert_transform;
+
+#[cfg(test)]
+macro_rules! assert_minify {
+	($str: literal, $expected: literal) => {
+		{
+			use bumpalo::Bump;
+			use hdx_ast::css::StyleSheet;
+			use hdx_parser::{CursorStream, Features, Parser};
+
+			let allocator = Bump::default();
+			let mut result = Parser::new(&allocator, $str, Features::default()).parse_entirely::<StyleSheet>();
+			if !result.errors.is_empty() {
+				panic!("\n\nParse on {}:{} failed. ({:?}) saw error {:?}", file!(), line!(), $str, result.errors[0]);
+			}
+			$crate::minify(&mut result);
+			let mut minified = String::new();
+			$crate::write_minified(&result, result.source_text, &mut CursorStream::new(&allocator), &mut minified).unwrap();
+			if minified != $expected {
+				panic!("\n\nMinify on {}:{} failed: did not match expected format:\n\n   parsed: {:?}\n minified: {:?}\n expected: {:?}", file!(), line!(), $str, minified, $expected);
+			}
+
+			// Minifying the minified output must parse to the same StyleSheet, and so produce the same output again.
+			let mut result = Parser::new(&allocator, &minified, Features::default()).parse_entirely::<StyleSheet>();
+			if !result.errors.is_empty() {
+				panic!("\n\nParse of minified output on {}:{} failed. ({:?}) saw error {:?}", file!(), line!(), minified, result.errors[0]);
+			}
+			$crate::minify(&mut result);
+			let mut reminified = String::new();
+			$crate::write_minified(&result, result.source_text, &mut CursorStream::new(&allocator), &mut reminified).unwrap();
+			if minified != reminified {
+				panic!("\n\nMinify on {}:{} failed. Re-minifying the output produced a different result:\n\n   minified: {:?}\n reminified: {:?}", file!(), line!(), minified, reminified);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+pub(crate) use assert_minify;
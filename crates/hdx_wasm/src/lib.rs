@@ -1,7 +1,8 @@
 use bumpalo::Bump;
 use hdx_ast::css::StyleSheet;
 use hdx_lexer::{Kind, Lexer};
-use hdx_parser::{CursorStream, Features, Parser, ToCursors};
+use hdx_parser::{CursorStream, Features, Parser};
+use hdx_transform::{Pipeline, TransformOptions};
 #[cfg(not(feature = "fancy"))]
 use miette::JSONReportHandler;
 use miette::NamedSource;
@@ -62,14 +63,31 @@ pub fn parse(source_text: String) -> Result<SerializableParserResult, serde_wasm
 #[wasm_bindgen]
 pub fn minify(source_text: String) -> Result<String, serde_wasm_bindgen::Error> {
 	let allocator = Bump::default();
-	let result = Parser::new(&allocator, source_text.as_str(), Features::default()).parse_entirely::<StyleSheet>();
+	let mut result = Parser::new(&allocator, source_text.as_str(), Features::default()).parse_entirely::<StyleSheet>();
 	if !result.errors.is_empty() {
 		return Err(serde_wasm_bindgen::Error::new("Parse error"));
 	}
+	Pipeline::from_options(&TransformOptions::minify()).run(&mut result);
 	let mut output_string = String::new();
 	let mut stream = CursorStream::new(&allocator);
-	result.to_cursors(&mut stream);
-	if let Err(_) = result.write(&mut stream, &mut output_string) {
+	if hdx_transform::write_minified(&result, result.source_text, &mut stream, &mut output_string).is_err() {
+		return Err(serde_wasm_bindgen::Error::new("Write error"));
+	}
+	Ok(output_string)
+}
+
+#[cfg(feature = "serde")]
+#[wasm_bindgen]
+pub fn transform(source_text: String, options: JsValue) -> Result<String, serde_wasm_bindgen::Error> {
+	let options: TransformOptions = serde_wasm_bindgen::from_value(options)?;
+	let allocator = Bump::default();
+	let mut result = Parser::new(&allocator, source_text.as_str(), Features::default()).parse_entirely::<StyleSheet>();
+	if !result.errors.is_empty() {
+		return Err(serde_wasm_bindgen::Error::new("Parse error"));
+	}
+	Pipeline::from_options(&options).run(&mut result);
+	let mut output_string = String::new();
+	if result.write(&mut CursorStream::new(&allocator), &mut output_string).is_err() {
 		return Err(serde_wasm_bindgen::Error::new("Write error"));
 	}
 	Ok(output_string)